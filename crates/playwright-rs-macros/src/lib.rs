@@ -1,4 +1,5 @@
-//! Compile-time-validated selector macros for [playwright-rs].
+//! Compile-time-validated selector macros, the test attribute and the
+//! page object derive for [playwright-rs].
 //!
 //! Most users get this crate transitively through `playwright-rs`
 //! (the `macros` feature is on by default) and never need to depend on
@@ -13,26 +14,39 @@
 //! See the `playwright-rs` crate root for the broader Observability /
 //! macros story.
 
+mod loc;
+mod page_object;
+mod role;
+mod selector;
+mod test_attr;
+
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{LitStr, parse_macro_input};
+use syn::{DeriveInput, ItemFn, LitStr, parse_macro_input};
 
 /// Compile-time-validated Playwright selector. Expands to a `&'static
 /// str` containing the same selector verbatim, with the validation a
 /// one-time compile-time check.
 ///
-/// Catches:
-/// - empty / whitespace-only selectors
-/// - unbalanced `[]`, `()`, `{}` brackets
-/// - unknown engine prefixes (e.g. `foo=...` instead of one of
-///   `css=`, `xpath=`, `text=`, `role=`, `id=`, `data-testid=`, `nth=`,
-///   `internal:*=...`)
+/// The selector is split on `>>` as Playwright splits it, and each part
+/// is checked against the grammar of its engine:
+/// - CSS (the default) is tokenized as CSS and checked for well-formed
+///   compound selectors, combinators and attribute selectors, including
+///   Playwright's extensions: `:has-text()`, `:text()`, `:text-is()`,
+///   `:text-matches()`, `:visible`, `:nth-match()` and the layout
+///   pseudo-classes `:right-of()` etc. Pseudo-elements are rejected.
+/// - XPath (`xpath=`, or a selector starting with `//` or `..`) is parsed
+///   as XPath 1.0: axes, node tests, predicates and the core functions.
+/// - `role=` names must be ARIA roles, and their `[name=...]`,
+///   `[level=...]`, `[pressed]` etc. attributes must take valid values.
+/// - `text=`, `nth=`, `visible=`, `id=`, `data-testid=` and the
+///   `internal:*=` engines that `get_by_*` methods produce are checked
+///   for the body each expects; nested selectors (`internal:has="..."`)
+///   are validated recursively.
 ///
-/// Anything else passes through. Selector engines have rich grammars
-/// of their own (CSS, XPath, role-name semantics, `>>` chaining); this
-/// macro punts on those for the v0.1 surface and lets the Playwright
-/// server give the runtime error if the selector turns out invalid.
-/// Future versions will tighten this up — see issue #81.
+/// Unknown engine prefixes, empty parts and unbalanced brackets or quotes
+/// are errors too. Each error points at the offending part of the
+/// selector, with a caret line under it in the message.
 ///
 /// # Example
 ///
@@ -42,129 +56,200 @@ use syn::{LitStr, parse_macro_input};
 /// let _ok = locator!("#submit");
 /// let _ok = locator!("text=Hello");
 /// let _ok = locator!("xpath=//button[@id='submit']");
+/// let _ok = locator!("article:has-text(\"Intro\") >> role=link[name=\"More\" i]");
 /// // let _bad = locator!("");                 // compile error: empty selector
 /// // let _bad = locator!("button[disabled");  // compile error: unbalanced [
 /// // let _bad = locator!("foo=bar");          // compile error: unknown engine
+/// // let _bad = locator!("p::before");        // compile error: pseudo-element
+/// // let _bad = locator!("//a[startswith()]"); // compile error: unknown XPath function
 /// ```
 #[proc_macro]
 pub fn locator(input: TokenStream) -> TokenStream {
     let lit = parse_macro_input!(input as LitStr);
     let value = lit.value();
 
-    if let Err(msg) = validate_selector(&value) {
-        return syn::Error::new(lit.span(), msg).to_compile_error().into();
+    if let Err(err) = selector::validate(&value) {
+        return selector_error(&lit, err).to_compile_error().into();
     }
 
     quote! { #lit }.into()
 }
 
-/// Validate a Playwright selector string. Returns `Err(message)` when
-/// the selector is rejectable at compile time; the message is the
-/// diagnostic shown to the user.
-fn validate_selector(s: &str) -> Result<(), String> {
-    if s.trim().is_empty() {
-        return Err("selector is empty or whitespace-only".to_string());
-    }
-
-    check_balanced_brackets(s)?;
-
-    if let Some((engine, _rest)) = split_engine_prefix(s)
-        && !is_known_engine(engine)
-    {
-        return Err(format!(
-            "unknown selector engine `{engine}=...`; expected one of \
-             css, xpath, text, role, id, data-testid, nth, or an \
-             `internal:*=` prefix"
-        ));
-    }
-
-    Ok(())
+/// A locator built from typed steps instead of one selector string.
+/// Expands to the `get_by_*`, `filter`, `nth`, `and_` and `or_` calls the
+/// steps stand for, so the result is a `playwright_rs::Locator`.
+///
+/// `loc!(root, step >> step >> ...)` takes the root (a `Page`, `Frame`,
+/// `FrameLocator` or `Locator`, by value or reference) and one or more
+/// steps. The first step finds elements:
+/// - `"css"` or `locator("css")` — a selector, validated as in
+///   [`locator!`](macro@locator).
+/// - `role(button, name = "Save")` — `get_by_role`. The role must be an
+///   ARIA role and the options `GetByRoleOptions` setters: `name`,
+///   `description`, `exact`, `level`, `checked`, `disabled`, `selected`,
+///   `expanded`, `pressed` and `include_hidden`.
+/// - `test_id("x")` — `get_by_test_id`.
+/// - `text("x")`, `label`, `placeholder`, `alt_text`, `title` — the
+///   matching `get_by_*`, with an optional `exact = true`.
+///
+/// Later steps may also narrow the locator before them:
+/// - `has_text("x")`, `has_not_text("x")`, `has(chain)`, `has_not(chain)`
+///   — `filter` with the `FilterOptions` of the same name.
+/// - `and(chain)`, `or(chain)` — `and_` / `or_`.
+/// - `nth(i)`, `first`, `last`.
+///
+/// Nested chains start from the same root. The inner locator of a filter
+/// is relative to each matched element, as in Playwright, so `has(...)`
+/// and `has_not(...)` need a `Page` or `Frame` root; with any other root
+/// they fail to compile.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use playwright_rs::loc;
+///
+/// let save = loc!(page, role(button, name = "Save") >> has_text("draft") >> nth(0));
+/// let row = loc!(page, "tr" >> has(role(checkbox, checked = true)) >> last);
+/// ```
+#[proc_macro]
+pub fn loc(input: TokenStream) -> TokenStream {
+    let parsed = parse_macro_input!(input as loc::Loc);
+    loc::expand(parsed).into()
 }
 
-/// Track depth of `()`, `[]`, `{}`. Returns `Err` on the first
-/// imbalance — either an unmatched closer or any unclosed opener at
-/// end of input. Bracket characters inside string literals (`"..."`,
-/// `'...'`) are skipped because Playwright selectors can carry quoted
-/// values like `text="Hello"` or `[aria-label='go [back]']`.
-fn check_balanced_brackets(s: &str) -> Result<(), String> {
-    let mut stack: Vec<char> = Vec::new();
-    let mut chars = s.chars().peekable();
-
-    while let Some(c) = chars.next() {
-        match c {
-            '\\' => {
-                // skip the escaped char
-                let _ = chars.next();
-            }
-            '"' | '\'' => {
-                // skip until matching closing quote (or end of input);
-                // honour `\<x>` so an escaped quote inside the value
-                // doesn't terminate the run
-                let quote = c;
-                loop {
-                    match chars.next() {
-                        None => break,
-                        Some('\\') => {
-                            let _ = chars.next();
-                        }
-                        Some(q) if q == quote => break,
-                        Some(_) => {}
-                    }
-                }
-            }
-            '(' | '[' | '{' => stack.push(c),
-            ')' | ']' | '}' => match (stack.pop(), c) {
-                (Some('('), ')') | (Some('['), ']') | (Some('{'), '}') => {}
-                (Some(open), close) => {
-                    return Err(format!(
-                        "mismatched bracket: `{open}` opened, `{close}` closed"
-                    ));
-                }
-                (None, close) => {
-                    return Err(format!("unmatched closing `{close}`"));
-                }
-            },
-            _ => {}
-        }
-    }
-
-    if let Some(open) = stack.pop() {
-        return Err(format!("unclosed `{open}`"));
-    }
-
-    Ok(())
+/// Runs an `async fn` as a browser test. Each test gets a fresh
+/// `BrowserContext` and `Page`, asked for by parameter type, on a browser
+/// shared with every other test in the binary that asks for the same one;
+/// the context is closed when the test ends, whether it returns or panics.
+///
+/// Expands to a `#[test]` that drives
+/// `playwright_rs::testing::PlaywrightTest`, so it needs no tokio
+/// attribute of its own. Use it through the re-export,
+/// `#[playwright_rs::test]`.
+///
+/// Arguments, all optional:
+/// - `browser = "firefox"` — `"chromium"` (default), `"firefox"` or
+///   `"webkit"`. A list, `browser = ["chromium", "webkit"]`, turns the
+///   function into a module with one test per browser.
+/// - `headless = false` — show the browser window.
+/// - `device = "iPhone 13"` — emulate a device from `Playwright::devices`.
+/// - `trace = "retain-on-failure"` — a trace mode as in
+///   `playwright.config`: `"off"`, `"on"`, `"retain-on-failure"` or
+///   `"on-first-retry"`. Traces are written under `test-results/`, and
+///   a failing test's output ends with the path of the one kept for it.
+///
+/// Parameters may be any of `Page`, `BrowserContext`, `Browser` and
+/// `Playwright`. The function may return `()` or `Result<(), E>` for any
+/// `E: Debug`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use playwright_rs::{BrowserContext, Page};
+///
+/// #[playwright_rs::test(browser = ["chromium", "firefox"], trace = "retain-on-failure")]
+/// async fn shows_heading(page: Page) -> playwright_rs::Result<()> {
+///     page.goto("https://example.com", None).await?;
+///     Ok(())
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn test(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut parsed = test_attr::TestArgs::default();
+    let parser = syn::meta::parser(|meta| parsed.parse(meta));
+    parse_macro_input!(args with parser);
+    let func = parse_macro_input!(item as ItemFn);
+
+    test_attr::expand(parsed, func)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-/// If the selector starts with `<word>=`, return `(word, rest)`.
-/// Returns `None` for selectors that don't carry an explicit engine
-/// prefix (CSS is the default).
-///
-/// `internal:visible=` and similar internal-prefixed engines also have
-/// an `=`, but they all start with `internal:` so we treat the
-/// `internal:` namespace specially as known.
-fn split_engine_prefix(s: &str) -> Option<(&str, &str)> {
-    let s = s.trim_start();
-    let eq_pos = s.find('=')?;
-    let prefix = &s[..eq_pos];
-    let rest = &s[eq_pos + 1..];
+/// Implements `playwright_rs::PageObject` for a struct of locators, so it
+/// can be built from a `Page`, `Frame`, `FrameLocator` or parent
+/// `Locator` with `Type::locate(&root).await?`.
+///
+/// Each field says how it is found, relative to that root:
+/// - `#[locator("form#login")]` — a selector, validated as in
+///   [`locator!`](macro@locator).
+/// - `#[test_id("username")]` — `get_by_test_id`.
+/// - `#[role(button, name = "Save")]` — `get_by_role`, with the role
+///   first and then any of `name`, `description`, `exact`, `level`,
+///   `checked`, `disabled`, `selected`, `expanded`, `pressed` and
+///   `include_hidden`.
+///
+/// Fields typed `Locator` take the locator as is. Any other type is a
+/// nested page object, built from the field's locator, or from the same
+/// root if the field has no attribute. `Vec<Locator>` and
+/// `Vec<Component>` hold one entry per element matched when the page
+/// object is built, via `Locator::all`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use playwright_rs::{Locator, PageObject};
+///
+/// #[derive(PageObject)]
+/// struct TodoItem {
+///     #[role(checkbox)]
+///     toggle: Locator,
+///     #[test_id("todo-title")]
+///     title: Locator,
+/// }
+///
+/// #[derive(PageObject)]
+/// struct TodoPage {
+///     #[role(textbox, name = "What needs to be done?")]
+///     new_todo: Locator,
+///     #[test_id("todo-item")]
+///     items: Vec<TodoItem>,
+/// }
+///
+/// let todos = TodoPage::locate(&page).await?;
+/// todos.items[0].toggle.check(None).await?;
+/// ```
+#[proc_macro_derive(PageObject, attributes(locator, role, test_id))]
+pub fn derive_page_object(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    page_object::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    // The prefix must look like an identifier (letters, digits, `-`,
-    // `:`). Bail otherwise — `[attr=val]` and similar shouldn't be
-    // misread as an engine.
-    if prefix.is_empty()
-        || !prefix
-            .chars()
-            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == ':')
+/// A selector error as a compile error, pointed at the offending part of
+/// the literal. Narrowing a literal's span needs a nightly compiler, so on
+/// stable the whole literal is underlined and the message repeats the
+/// selector with a caret line under the part that is wrong.
+fn selector_error(lit: &LitStr, err: selector::SelectorError) -> syn::Error {
+    let value = lit.value();
+    let selector::SelectorError { range, message } = err;
+    let whole = range.start == 0 && range.end >= value.len();
+
+    // The literal's source text, when it is the value verbatim (no escapes),
+    // lets selector offsets map to literal offsets.
+    let token = lit.token();
+    let source = token.to_string();
+    let open = source.find('"').map(|q| q + 1);
+    let close = source.rfind('"');
+    if let (Some(open), Some(close)) = (open, close)
+        && open <= close
+        && source[open..close] == value
+        && let Some(span) = token.subspan(open + range.start..open + range.end.max(range.start + 1))
     {
-        return None;
+        return syn::Error::new(span, message);
     }
 
-    Some((prefix, rest))
-}
-
-fn is_known_engine(engine: &str) -> bool {
-    matches!(
-        engine,
-        "css" | "xpath" | "text" | "role" | "id" | "data-testid" | "nth"
-    ) || engine.starts_with("internal:")
+    if whole || value.contains('\n') {
+        return syn::Error::new(lit.span(), message);
+    }
+    let indent = value[..range.start].chars().count();
+    let width = value[range.clone()].chars().count().max(1);
+    syn::Error::new(
+        lit.span(),
+        format!(
+            "{message}\n  {value}\n  {}{}",
+            " ".repeat(indent),
+            "^".repeat(width)
+        ),
+    )
 }
@@ -0,0 +1,253 @@
+//! `loc!(root, step >> step >> ...)` — parse a chain of locator steps and
+//! expand it to the `get_by_*` / `filter` / `nth` / `and_` / `or_` calls
+//! it stands for.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, Ident, LitBool, LitStr, Token, parenthesized};
+
+use crate::role::Role;
+
+/// The `get_by_*` methods that take a text and an `exact` flag, by step name.
+const TEXT_STEPS: [(&str, &str); 5] = [
+    ("text", "get_by_text"),
+    ("label", "get_by_label"),
+    ("placeholder", "get_by_placeholder"),
+    ("alt_text", "get_by_alt_text"),
+    ("title", "get_by_title"),
+];
+
+pub(crate) struct Loc {
+    root: Expr,
+    chain: Chain,
+}
+
+impl Parse for Loc {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let root = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let chain = input.parse()?;
+        Ok(Self { root, chain })
+    }
+}
+
+/// Steps separated by `>>`; the first one finds elements from the root.
+struct Chain(Vec<Step>);
+
+impl Parse for Chain {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let steps = Punctuated::<Step, Token![>>]>::parse_separated_nonempty(input)?;
+        if !input.is_empty() {
+            return Err(input.error("expected `>>` before the next step"));
+        }
+        let steps: Vec<Step> = steps.into_iter().collect();
+        if let Some(first) = steps.first()
+            && first.kind.narrows()
+        {
+            return Err(syn::Error::new(
+                first.name.span(),
+                format!(
+                    "`{}` narrows a locator, so it can't start a chain; start with a \
+                     selector, `role(...)`, `test_id(...)` or a text step",
+                    first.name,
+                ),
+            ));
+        }
+        Ok(Self(steps))
+    }
+}
+
+struct Step {
+    /// The step as written, for error spans; a selector's literal for `"..."`.
+    name: Ident,
+    kind: StepKind,
+}
+
+enum StepKind {
+    Selector(LitStr),
+    Role(Role),
+    TestId(LitStr),
+    Text {
+        method: &'static str,
+        text: LitStr,
+        exact: Option<LitBool>,
+    },
+    HasText(LitStr),
+    HasNotText(LitStr),
+    Has(Chain),
+    HasNot(Chain),
+    And(Chain),
+    Or(Chain),
+    Nth(Expr),
+    First,
+    Last,
+}
+
+impl StepKind {
+    /// Whether the step refines the locator before it rather than finding
+    /// elements of its own, and so needs one.
+    fn narrows(&self) -> bool {
+        !matches!(
+            self,
+            Self::Selector(_) | Self::Role(_) | Self::TestId(_) | Self::Text { .. }
+        )
+    }
+}
+
+impl Parse for Step {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            let selector = selector(input)?;
+            return Ok(Self {
+                name: Ident::new("locator", selector.span()),
+                kind: StepKind::Selector(selector),
+            });
+        }
+        let name: Ident = input.parse()?;
+        let key = name.to_string();
+        let kind = match key.as_str() {
+            "first" => StepKind::First,
+            "last" => StepKind::Last,
+            _ => {
+                let content;
+                parenthesized!(content in input);
+                match key.as_str() {
+                    "locator" => StepKind::Selector(selector(&content)?),
+                    "role" => StepKind::Role(Role::parse(content.parse()?, &name)?),
+                    "test_id" => {
+                        let id: LitStr = content.parse()?;
+                        if id.value().is_empty() {
+                            return Err(syn::Error::new(id.span(), "test id is empty"));
+                        }
+                        StepKind::TestId(id)
+                    }
+                    "has_text" => StepKind::HasText(content.parse()?),
+                    "has_not_text" => StepKind::HasNotText(content.parse()?),
+                    "has" => StepKind::Has(content.parse()?),
+                    "has_not" => StepKind::HasNot(content.parse()?),
+                    "and" => StepKind::And(content.parse()?),
+                    "or" => StepKind::Or(content.parse()?),
+                    "nth" => StepKind::Nth(content.parse()?),
+                    _ => match TEXT_STEPS.iter().find(|(step, _)| *step == key) {
+                        Some((_, method)) => text_step(method, &content)?,
+                        None => {
+                            return Err(syn::Error::new(
+                                name.span(),
+                                format!(
+                                    "unknown step `{key}`; expected a selector, `locator`, \
+                                     `role`, `test_id`, `text`, `label`, `placeholder`, \
+                                     `alt_text`, `title`, `has_text`, `has_not_text`, `has`, \
+                                     `has_not`, `and`, `or`, `nth`, `first` or `last`"
+                                ),
+                            ));
+                        }
+                    },
+                }
+            }
+        };
+        Ok(Self { name, kind })
+    }
+}
+
+/// A selector literal, validated as `locator!` validates it.
+fn selector(input: ParseStream) -> syn::Result<LitStr> {
+    let lit: LitStr = input.parse()?;
+    if let Err(err) = crate::selector::validate(&lit.value()) {
+        return Err(crate::selector_error(&lit, err));
+    }
+    Ok(lit)
+}
+
+/// `text("Sign in")` or `text("Sign in", exact = true)`.
+fn text_step(method: &'static str, input: ParseStream) -> syn::Result<StepKind> {
+    let text = input.parse()?;
+    let mut exact = None;
+    if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+        let key: Ident = input.parse()?;
+        if key != "exact" {
+            return Err(syn::Error::new(key.span(), "expected `exact = true|false`"));
+        }
+        input.parse::<Token![=]>()?;
+        exact = Some(input.parse()?);
+    }
+    if !input.is_empty() {
+        return Err(input.error("unexpected argument"));
+    }
+    Ok(StepKind::Text {
+        method,
+        text,
+        exact,
+    })
+}
+
+pub(crate) fn expand(loc: Loc) -> TokenStream {
+    // Mixed-site, so the binding can't shadow or be seen by user code.
+    let root = Ident::new("root", Span::mixed_site());
+    let expr = &loc.root;
+    let chain = chain(&quote! { #root }, &loc.chain);
+    quote! {{
+        let #root = &(#expr);
+        #chain
+    }}
+}
+
+/// The chain as one expression, starting from `root`. Nested chains
+/// (`has`, `and`, ...) start from the same root as the outer one; for
+/// `has` and `has_not` that root goes through `__loc::filter_root`, which
+/// only accepts a `Page` or `Frame`.
+fn chain(root: &TokenStream, chain: &Chain) -> TokenStream {
+    let mut expr = root.clone();
+    for step in &chain.0 {
+        expr = match &step.kind {
+            StepKind::Selector(selector) => quote! { #expr.locator(#selector) },
+            StepKind::Role(role) => {
+                let arguments = role.arguments();
+                quote! { #expr.get_by_role(#arguments) }
+            }
+            StepKind::TestId(id) => quote! { #expr.get_by_test_id(#id) },
+            StepKind::Text {
+                method,
+                text,
+                exact,
+            } => {
+                let method = Ident::new(method, step.name.span());
+                let exact = exact
+                    .clone()
+                    .unwrap_or_else(|| LitBool::new(false, step.name.span()));
+                quote! { #expr.#method(#text, #exact) }
+            }
+            StepKind::HasText(text) => filter(&expr, quote! { .has_text(#text) }),
+            StepKind::HasNotText(text) => filter(&expr, quote! { .has_not_text(#text) }),
+            StepKind::Has(inner) => {
+                let inner = self::chain(&filter_root(root), inner);
+                filter(&expr, quote! { .has(#inner) })
+            }
+            StepKind::HasNot(inner) => {
+                let inner = self::chain(&filter_root(root), inner);
+                filter(&expr, quote! { .has_not(#inner) })
+            }
+            StepKind::And(inner) => {
+                let inner = self::chain(root, inner);
+                quote! { #expr.and_(&#inner) }
+            }
+            StepKind::Or(inner) => {
+                let inner = self::chain(root, inner);
+                quote! { #expr.or_(&#inner) }
+            }
+            StepKind::Nth(index) => quote! { #expr.nth(#index) },
+            StepKind::First => quote! { #expr.first() },
+            StepKind::Last => quote! { #expr.last() },
+        };
+    }
+    expr
+}
+
+fn filter_root(root: &TokenStream) -> TokenStream {
+    quote! { ::playwright_rs::__loc::filter_root(#root) }
+}
+
+fn filter(expr: &TokenStream, options: TokenStream) -> TokenStream {
+    quote! { #expr.filter(::playwright_rs::FilterOptions::default()#options) }
+}
@@ -0,0 +1,170 @@
+//! `#[derive(PageObject)]` — read each field's locator attribute and
+//! expand to an `impl playwright_rs::PageObject` that builds every field
+//! from the root it is given.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Fields, GenericArgument, LitStr, PathArguments, Type};
+
+use crate::role::Role;
+
+/// How a field finds its element(s), relative to the root.
+enum Source {
+    Selector(LitStr),
+    TestId(LitStr),
+    Role(Role),
+}
+
+/// What a field holds, by its type.
+enum Shape<'a> {
+    Locator,
+    Locators,
+    Component(&'a Type),
+    Components(&'a Type),
+}
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "#[derive(PageObject)] does not support generic structs",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "#[derive(PageObject)] needs a struct with named fields",
+                ));
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "#[derive(PageObject)] can only be derived for structs",
+            ));
+        }
+    };
+
+    let inits = fields
+        .iter()
+        .map(|field| {
+            let name = field.ident.as_ref().expect("named field");
+            let value = field_value(&field.attrs, &field.ty)?;
+            Ok(quote! { #name: #value })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let ident = &input.ident;
+    Ok(quote! {
+        impl ::playwright_rs::PageObject for #ident {
+            fn locate<R: ::playwright_rs::LocatorRoot + ?::core::marker::Sized>(
+                root: &R,
+            ) -> impl ::core::future::Future<Output = ::playwright_rs::Result<Self>>
+                   + ::core::marker::Send {
+                async move {
+                    ::core::result::Result::Ok(Self { #(#inits,)* })
+                }
+            }
+        }
+    })
+}
+
+/// The expression that builds one field.
+fn field_value(attrs: &[Attribute], ty: &Type) -> syn::Result<TokenStream> {
+    let source = source(attrs)?;
+    let shape = shape(ty);
+    let locator = source.as_ref().map(|source| match source {
+        Source::Selector(selector) => {
+            quote! { ::playwright_rs::LocatorRoot::locator(root, #selector) }
+        }
+        Source::TestId(id) => quote! { ::playwright_rs::LocatorRoot::get_by_test_id(root, #id) },
+        Source::Role(role) => {
+            let arguments = role.arguments();
+            quote! { ::playwright_rs::LocatorRoot::get_by_role(root, #arguments) }
+        }
+    });
+
+    Ok(match (shape, locator) {
+        (Shape::Locator, Some(locator)) => locator,
+        (Shape::Locators, Some(locator)) => quote! { #locator.all().await? },
+        (Shape::Component(ty), Some(locator)) => quote! {
+            <#ty as ::playwright_rs::PageObject>::locate(&#locator).await?
+        },
+        (Shape::Components(ty), Some(locator)) => quote! {{
+            let mut items = ::std::vec::Vec::new();
+            for item in #locator.all().await? {
+                items.push(<#ty as ::playwright_rs::PageObject>::locate(&item).await?);
+            }
+            items
+        }},
+        // A component without a locator shares its parent's root.
+        (Shape::Component(ty), None) => quote! {
+            <#ty as ::playwright_rs::PageObject>::locate(root).await?
+        },
+        (Shape::Locator | Shape::Locators | Shape::Components(_), None) => {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "this field needs #[locator(\"...\")], #[role(...)] or #[test_id(\"...\")]",
+            ));
+        }
+    })
+}
+
+fn shape(ty: &Type) -> Shape<'_> {
+    let Type::Path(path) = ty else {
+        return Shape::Component(ty);
+    };
+    let Some(last) = path.path.segments.last() else {
+        return Shape::Component(ty);
+    };
+    if last.ident == "Locator" && last.arguments.is_none() {
+        return Shape::Locator;
+    }
+    if last.ident == "Vec"
+        && let PathArguments::AngleBracketed(args) = &last.arguments
+        && args.args.len() == 1
+        && let Some(GenericArgument::Type(item)) = args.args.first()
+    {
+        return match shape(item) {
+            Shape::Locator => Shape::Locators,
+            _ => Shape::Components(item),
+        };
+    }
+    Shape::Component(ty)
+}
+
+/// The field's one locator attribute, validated.
+fn source(attrs: &[Attribute]) -> syn::Result<Option<Source>> {
+    let mut found = None;
+    for attr in attrs {
+        let source = if attr.path().is_ident("locator") {
+            let selector: LitStr = attr.parse_args()?;
+            if let Err(err) = crate::selector::validate(&selector.value()) {
+                return Err(crate::selector_error(&selector, err));
+            }
+            Source::Selector(selector)
+        } else if attr.path().is_ident("test_id") {
+            let id: LitStr = attr.parse_args()?;
+            if id.value().is_empty() {
+                return Err(syn::Error::new(id.span(), "test id is empty"));
+            }
+            Source::TestId(id)
+        } else if attr.path().is_ident("role") {
+            let tokens = attr.meta.require_list()?.tokens.clone();
+            Source::Role(Role::parse(tokens, attr)?)
+        } else {
+            continue;
+        };
+        if found.is_some() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "a field takes only one of #[locator], #[role] and #[test_id]",
+            ));
+        }
+        found = Some(source);
+    }
+    Ok(found)
+}
@@ -0,0 +1,105 @@
+//! `button, name = "Save", exact = true`: an ARIA role and its
+//! `GetByRoleOptions`, as written in `#[role(...)]` and `loc!`'s
+//! `role(...)` step.
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::parse::Parser;
+use syn::{Ident, LitBool, LitInt, LitStr, Token};
+
+use crate::selector::ARIA_ROLES;
+
+/// `GetByRoleOptions` setters accepted after the role.
+const STR_OPTIONS: [&str; 2] = ["name", "description"];
+const BOOL_OPTIONS: [&str; 7] = [
+    "exact",
+    "checked",
+    "disabled",
+    "selected",
+    "expanded",
+    "pressed",
+    "include_hidden",
+];
+
+pub(crate) struct Role {
+    /// The `AriaRole` variant, spanned at the role as written.
+    variant: Ident,
+    /// `.name("Save").exact(true)`, applied to `GetByRoleOptions::default()`.
+    options: TokenStream,
+}
+
+impl Role {
+    /// Parse the role's arguments. `whole` is what a missing role is
+    /// reported against.
+    pub(crate) fn parse(tokens: TokenStream, whole: impl ToTokens) -> syn::Result<Self> {
+        let mut variant = None;
+        let mut options = TokenStream::new();
+        let parser = syn::meta::parser(|meta| {
+            let Some(ident) = meta.path.get_ident() else {
+                return Err(meta.error("expected a role or an option name"));
+            };
+            if !meta.input.peek(Token![=]) {
+                if variant.is_some() {
+                    return Err(meta.error("the role is already given"));
+                }
+                variant = Some(role_variant(ident)?);
+                return Ok(());
+            }
+            let key = ident.to_string();
+            let value = meta.value()?;
+            if STR_OPTIONS.contains(&key.as_str()) {
+                let lit: LitStr = value.parse()?;
+                options.extend(quote! { .#ident(#lit) });
+            } else if BOOL_OPTIONS.contains(&key.as_str()) {
+                let lit: LitBool = value.parse()?;
+                options.extend(quote! { .#ident(#lit) });
+            } else if key == "level" {
+                let lit: LitInt = value.parse()?;
+                if !(1..=6).contains(&lit.base10_parse::<u32>()?) {
+                    return Err(syn::Error::new(lit.span(), "heading level must be 1 to 6"));
+                }
+                options.extend(quote! { .level(#lit) });
+            } else {
+                return Err(meta.error(
+                    "unknown role option; expected `name`, `description`, `exact`, `level`, \
+                     `checked`, `disabled`, `selected`, `expanded`, `pressed` or `include_hidden`",
+                ));
+            }
+            Ok(())
+        });
+        parser.parse2(tokens)?;
+        let Some(variant) = variant else {
+            return Err(syn::Error::new_spanned(
+                whole,
+                "missing role, e.g. role(button, name = \"Save\")",
+            ));
+        };
+        Ok(Self { variant, options })
+    }
+
+    /// The two arguments of a `get_by_role` call.
+    pub(crate) fn arguments(&self) -> TokenStream {
+        let Self { variant, options } = self;
+        quote! {
+            ::playwright_rs::AriaRole::#variant,
+            ::core::option::Option::Some(::playwright_rs::GetByRoleOptions::default()#options)
+        }
+    }
+}
+
+/// `button` → `Button`, the `AriaRole` variant.
+fn role_variant(role: &Ident) -> syn::Result<Ident> {
+    let name = role.to_string();
+    if !ARIA_ROLES.contains(&name.as_str()) {
+        return Err(syn::Error::new(
+            role.span(),
+            format!("unknown ARIA role `{name}`"),
+        ));
+    }
+    let mut chars = name.chars();
+    let variant = chars
+        .next()
+        .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default();
+    Ok(format_ident!("{}", variant, span = role.span()))
+}
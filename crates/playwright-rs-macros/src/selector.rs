@@ -0,0 +1,764 @@
+//! Compile-time validation of Playwright selectors.
+//!
+//! Architecture Reference:
+//! - JavaScript: playwright/packages/playwright-core/src/utils/isomorphic/selectorParser.ts
+//!   (`>>` chains, engine prefixes, `internal:*` bodies)
+//! - JavaScript: playwright/packages/playwright-core/src/utils/isomorphic/cssParser.ts
+//!   (CSS with Playwright's pseudo-classes)
+//!
+//! A selector is split into its `>>` chain the way Playwright splits it,
+//! and each part is checked by the grammar of its engine. Every error
+//! carries the byte range it is about, so the diagnostic can point at the
+//! offending part of the literal rather than the whole of it.
+
+mod css;
+mod xpath;
+
+use std::ops::Range;
+
+/// A rejected selector: what is wrong, and where in the selector string.
+pub(crate) struct SelectorError {
+    pub(crate) range: Range<usize>,
+    pub(crate) message: String,
+}
+
+impl SelectorError {
+    fn new(range: Range<usize>, message: impl Into<String>) -> Self {
+        Self {
+            range,
+            message: message.into(),
+        }
+    }
+}
+
+type Result<T> = std::result::Result<T, SelectorError>;
+
+/// `AriaRole::as_str` in playwright-rs; keep the two lists in step.
+/// `test_locator_macro_accepts_every_aria_role` there fails if they drift.
+pub(crate) const ARIA_ROLES: [&str; 82] = [
+    "alert",
+    "alertdialog",
+    "application",
+    "article",
+    "banner",
+    "blockquote",
+    "button",
+    "caption",
+    "cell",
+    "checkbox",
+    "code",
+    "columnheader",
+    "combobox",
+    "complementary",
+    "contentinfo",
+    "definition",
+    "deletion",
+    "dialog",
+    "directory",
+    "document",
+    "emphasis",
+    "feed",
+    "figure",
+    "form",
+    "generic",
+    "grid",
+    "gridcell",
+    "group",
+    "heading",
+    "img",
+    "insertion",
+    "link",
+    "list",
+    "listbox",
+    "listitem",
+    "log",
+    "main",
+    "marquee",
+    "math",
+    "meter",
+    "menu",
+    "menubar",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "navigation",
+    "none",
+    "note",
+    "option",
+    "paragraph",
+    "presentation",
+    "progressbar",
+    "radio",
+    "radiogroup",
+    "region",
+    "row",
+    "rowgroup",
+    "rowheader",
+    "scrollbar",
+    "search",
+    "searchbox",
+    "separator",
+    "slider",
+    "spinbutton",
+    "status",
+    "strong",
+    "subscript",
+    "superscript",
+    "switch",
+    "tab",
+    "table",
+    "tablist",
+    "tabpanel",
+    "term",
+    "textbox",
+    "time",
+    "timer",
+    "toolbar",
+    "tooltip",
+    "tree",
+    "treegrid",
+    "treeitem",
+];
+
+/// Validate a Playwright selector string.
+pub(crate) fn validate(s: &str) -> Result<()> {
+    if s.trim().is_empty() {
+        return Err(SelectorError::new(
+            0..s.len(),
+            "selector is empty or whitespace-only",
+        ));
+    }
+    validate_chain(s, 0)
+}
+
+/// Split `s` on `>>` as Playwright's `parseSelectorString` does, then
+/// validate each part. `offset` is where `s` starts in the literal.
+fn validate_chain(s: &str, offset: usize) -> Result<()> {
+    let mut captured = false;
+    for part in split_chain(s) {
+        let text = &s[part.clone()];
+        let trimmed_start = part.start + (text.len() - text.trim_start().len());
+        let trimmed = text.trim();
+        let range = offset + trimmed_start..offset + trimmed_start + trimmed.len();
+        if trimmed.is_empty() {
+            return Err(SelectorError::new(
+                offset + part.start..offset + part.end,
+                "empty selector in a `>>` chain",
+            ));
+        }
+
+        let (mut engine, body_start) = match engine_prefix(trimmed) {
+            Some(engine) => (engine, engine.len() + 1),
+            None => (implicit_engine(trimmed), 0),
+        };
+        if let Some(rest) = engine.strip_prefix('*') {
+            if captured {
+                return Err(SelectorError::new(
+                    range,
+                    "only one selector in a chain can capture with `*`",
+                ));
+            }
+            captured = true;
+            engine = rest;
+        }
+        let engine_range = range.start..range.start + body_start.saturating_sub(1);
+        let body = &trimmed[body_start..];
+        validate_engine(engine, engine_range, body, range.start + body_start)?;
+    }
+    Ok(())
+}
+
+/// The byte ranges of the `>>`-separated parts of `s`. A `>>` inside a
+/// quoted string does not split, except in the body of a `text=` part,
+/// where quotes after the first character are literal text.
+fn split_chain(s: &str) -> Vec<Range<usize>> {
+    let bytes = s.as_bytes();
+    let mut parts = Vec::new();
+    let mut quote = None;
+    let mut start = 0;
+    let mut i = 0;
+    let ignores_quotes = |start: usize, i: usize| {
+        let prefix = s[start..i].trim_start();
+        prefix
+            .strip_prefix("text")
+            .map(str::trim_start)
+            .and_then(|rest| rest.strip_prefix('='))
+            .is_some_and(|body| !body.is_empty())
+    };
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c == b'\\' && i + 1 < bytes.len() {
+            i += 2;
+        } else if Some(c) == quote {
+            quote = None;
+            i += 1;
+        } else if quote.is_none() && matches!(c, b'"' | b'\'' | b'`') && !ignores_quotes(start, i) {
+            quote = Some(c);
+            i += 1;
+        } else if quote.is_none() && c == b'>' && bytes.get(i + 1) == Some(&b'>') {
+            parts.push(start..i);
+            i += 2;
+            start = i;
+        } else {
+            i += 1;
+        }
+    }
+    parts.push(start..s.len());
+    parts
+}
+
+/// If the part starts with `<engine>=`, the engine name. Like Playwright,
+/// only a name made of `[a-zA-Z0-9_+:*-]` counts, so `[attr=val]` and
+/// similar are not misread as an engine.
+fn engine_prefix(part: &str) -> Option<&str> {
+    let eq = part.find('=')?;
+    let name = &part[..eq];
+    (!name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+' | ':' | '*')))
+    .then_some(name)
+}
+
+/// The engine of a part without a prefix: quoted text, XPath for `//`
+/// and `..`, otherwise CSS.
+fn implicit_engine(part: &str) -> &'static str {
+    let quoted = |q: char| part.len() > 1 && part.starts_with(q) && part.ends_with(q);
+    if quoted('"') || quoted('\'') {
+        "text"
+    } else if part.trim_start_matches('(').starts_with("//") || part.starts_with("..") {
+        "xpath"
+    } else {
+        "css"
+    }
+}
+
+fn is_known_engine(engine: &str) -> bool {
+    matches!(
+        engine,
+        "css" | "xpath" | "text" | "role" | "id" | "data-testid" | "nth" | "visible"
+    ) || engine.starts_with("internal:")
+}
+
+/// Check one chain part against its engine's grammar. `engine_range` is
+/// the prefix (empty if implicit) and `at` where `body` starts.
+fn validate_engine(engine: &str, engine_range: Range<usize>, body: &str, at: usize) -> Result<()> {
+    if !is_known_engine(engine) {
+        return Err(SelectorError::new(
+            engine_range,
+            format!(
+                "unknown selector engine `{engine}=...`; expected one of \
+                 css, xpath, text, role, id, data-testid, nth, visible, or an \
+                 `internal:*=` prefix"
+            ),
+        ));
+    }
+    let whole = at..at + body.len();
+    let non_empty = |what: &str| {
+        if body.trim().is_empty() {
+            Err(SelectorError::new(
+                engine_range.start..whole.end,
+                format!("`{engine}=` needs {what}"),
+            ))
+        } else {
+            Ok(())
+        }
+    };
+    match engine {
+        "css" => {
+            non_empty("a CSS selector")?;
+            css::validate(body, at)
+        }
+        "xpath" => {
+            non_empty("an XPath expression")?;
+            xpath::validate(body, at)
+        }
+        "text" => {
+            non_empty("text to match")?;
+            text_body(body, at, false)
+        }
+        "internal:text" | "internal:has-text" | "internal:has-not-text" | "internal:label" => {
+            text_body(body, at, true)
+        }
+        "role" | "internal:role" => role_body(body, at),
+        "internal:attr" | "internal:testid" => {
+            let mut cursor = Cursor::new(body, at);
+            cursor.skip_whitespace();
+            if !cursor.peek_is('[') {
+                return Err(SelectorError::new(
+                    whole,
+                    format!("`{engine}=` expects `[attribute=\"value\"]`"),
+                ));
+            }
+            attributes(&mut cursor, |_, _| Ok(()))
+        }
+        "internal:has" | "internal:has-not" | "internal:and" | "internal:or" | "internal:chain" => {
+            nested_selector(engine, body, at)
+        }
+        "id" | "data-testid" => non_empty("a value"),
+        "nth" => match body.trim().parse::<i64>() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SelectorError::new(
+                whole,
+                "`nth=` expects an integer index, e.g. `nth=0` or `nth=-1`",
+            )),
+        },
+        "visible" => match body.trim() {
+            "true" | "false" => Ok(()),
+            _ => Err(SelectorError::new(
+                whole,
+                "`visible=` expects `true` or `false`",
+            )),
+        },
+        _ => non_empty("a body"),
+    }
+}
+
+/// The body of `text=` and the `internal:` text engines: a quoted string
+/// with an optional `i` (case-insensitive) or `s` (exact) suffix, a
+/// `/regex/flags`, or (for `text=` only) unquoted text.
+fn text_body(body: &str, at: usize, quoted_only: bool) -> Result<()> {
+    let whole = at..at + body.len();
+    let trimmed = body.trim_end();
+    if trimmed.starts_with('"') || trimmed.starts_with('\'') {
+        let mut cursor = Cursor::new(body, at);
+        cursor.string()?;
+        let _ = cursor.eat('i') || cursor.eat('s');
+        cursor.skip_whitespace();
+        if !cursor.at_end() {
+            return Err(SelectorError::new(
+                cursor.pos_range(),
+                "unexpected text after the quoted string; only an `i` or `s` suffix may follow",
+            ));
+        }
+        return Ok(());
+    }
+    if trimmed.starts_with('/') {
+        return regex(body, at);
+    }
+    if quoted_only {
+        return Err(SelectorError::new(
+            whole,
+            "expected a quoted string such as `\"Save\"i`, or a `/regex/`",
+        ));
+    }
+    Ok(())
+}
+
+/// A JavaScript-style `/pattern/flags` literal.
+fn regex(body: &str, at: usize) -> Result<()> {
+    let trimmed = body.trim_end();
+    let close = trimmed.rfind('/').filter(|&i| i > 0);
+    let Some(close) = close else {
+        return Err(SelectorError::new(
+            at..at + body.len(),
+            "unterminated regular expression; expected `/pattern/flags`",
+        ));
+    };
+    let flags = &trimmed[close + 1..];
+    if let Some((i, flag)) = flags
+        .char_indices()
+        .find(|(_, c)| !matches!(c, 'd' | 'g' | 'i' | 'm' | 's' | 'u' | 'v' | 'y'))
+    {
+        let start = at + close + 1 + i;
+        return Err(SelectorError::new(
+            start..start + flag.len_utf8(),
+            format!("unknown regular expression flag `{flag}`"),
+        ));
+    }
+    if close == 1 {
+        return Err(SelectorError::new(at..at + 2, "empty regular expression"));
+    }
+    Ok(())
+}
+
+/// `role=button[name="Save" i][pressed]`: an ARIA role, then attributes
+/// from Playwright's role engine.
+fn role_body(body: &str, at: usize) -> Result<()> {
+    let mut cursor = Cursor::new(body, at);
+    cursor.skip_whitespace();
+    let start = cursor.pos;
+    while !cursor.at_end() && !cursor.peek_is('[') && !cursor.peek_whitespace() {
+        cursor.bump();
+    }
+    let role = &body[start..cursor.pos];
+    let role_range = at + start..at + cursor.pos;
+    if role.is_empty() {
+        return Err(SelectorError::new(
+            at..at + body.len(),
+            "`role=` needs a role, e.g. `role=button`",
+        ));
+    }
+    if !ARIA_ROLES.contains(&role) {
+        return Err(SelectorError::new(
+            role_range,
+            format!("unknown ARIA role `{role}`"),
+        ));
+    }
+    cursor.skip_whitespace();
+    attributes(&mut cursor, |name, value| {
+        let (range, value) = match value {
+            Some(value) => value,
+            None => match name.text {
+                "name" | "description" | "level" => {
+                    return Err(SelectorError::new(
+                        name.range,
+                        format!("`[{}]` needs a value", name.text),
+                    ));
+                }
+                _ => return Ok(()),
+            },
+        };
+        let expect = |ok: bool, what: &str| {
+            if ok {
+                Ok(())
+            } else {
+                Err(SelectorError::new(
+                    range.clone(),
+                    format!("`{}` expects {what}", name.text),
+                ))
+            }
+        };
+        match name.text {
+            "name" | "description" => expect(
+                matches!(
+                    value,
+                    AttrValue::String | AttrValue::Regex | AttrValue::Word(_)
+                ),
+                "a string or a regular expression",
+            ),
+            "level" => expect(
+                matches!(value, AttrValue::Word(w) if w.parse::<u32>().is_ok()),
+                "an integer",
+            ),
+            "checked" | "pressed" => expect(
+                matches!(value, AttrValue::Word("true" | "false") | AttrValue::Mixed),
+                "`true`, `false` or `\"mixed\"`",
+            ),
+            "disabled" | "expanded" | "selected" | "include-hidden" => expect(
+                matches!(value, AttrValue::Word("true" | "false")),
+                "`true` or `false`",
+            ),
+            other => Err(SelectorError::new(
+                name.range,
+                format!(
+                    "unknown role attribute `{other}`; expected one of checked, description, \
+                     disabled, expanded, include-hidden, level, name, pressed, selected"
+                ),
+            )),
+        }
+    })
+}
+
+/// A JSON-quoted selector nested in an `internal:has=` and friends.
+fn nested_selector(engine: &str, body: &str, at: usize) -> Result<()> {
+    let mut cursor = Cursor::new(body, at);
+    cursor.skip_whitespace();
+    if !cursor.peek_is('"') {
+        return Err(SelectorError::new(
+            at..at + body.len(),
+            format!("`{engine}=` expects a JSON-quoted selector"),
+        ));
+    }
+    let start = cursor.pos;
+    let inner = cursor.string()?;
+    cursor.skip_whitespace();
+    if !cursor.at_end() {
+        return Err(SelectorError::new(
+            cursor.pos_range(),
+            "unexpected text after the quoted selector",
+        ));
+    }
+    if inner.trim().is_empty() {
+        return Err(SelectorError::new(
+            at + start..at + cursor.pos,
+            "the nested selector is empty",
+        ));
+    }
+    // Without escapes the decoded selector is the raw text between the
+    // quotes, so its errors can point into the literal.
+    let raw = &body[start + 1..cursor.pos - 1];
+    if raw == inner {
+        validate_chain(&inner, at + start + 1)
+    } else {
+        validate_chain(&inner, 0)
+            .map_err(|e| SelectorError::new(at + start..at + cursor.pos, e.message))
+    }
+}
+
+/// The name in `[name=value]`.
+struct AttrName<'a> {
+    text: &'a str,
+    range: Range<usize>,
+}
+
+/// The value in `[name=value]`, by kind.
+enum AttrValue<'a> {
+    /// A quoted string, other than `"mixed"`.
+    String,
+    /// The quoted string `"mixed"`.
+    Mixed,
+    Regex,
+    /// An unquoted word: `true`, `3`, `Save`.
+    Word(&'a str),
+}
+
+/// `[name]`, `[name=value]` and `[name="value" i]`, repeated to the end
+/// of the body. `check` sees each name and value.
+fn attributes<'a>(
+    cursor: &mut Cursor<'a>,
+    mut check: impl FnMut(AttrName<'a>, Option<(Range<usize>, AttrValue<'a>)>) -> Result<()>,
+) -> Result<()> {
+    loop {
+        cursor.skip_whitespace();
+        if cursor.at_end() {
+            return Ok(());
+        }
+        if !cursor.eat('[') {
+            return Err(SelectorError::new(
+                cursor.pos_range(),
+                "expected `[` to start an attribute",
+            ));
+        }
+        cursor.skip_whitespace();
+        let start = cursor.pos;
+        while !cursor.at_end()
+            && (cursor.peek_word_char() || cursor.peek_is('-') || cursor.peek_is('_'))
+        {
+            cursor.bump();
+        }
+        let name = AttrName {
+            text: &cursor.s[start..cursor.pos],
+            range: cursor.at + start..cursor.at + cursor.pos,
+        };
+        if name.text.is_empty() {
+            return Err(SelectorError::new(
+                cursor.pos_range(),
+                "expected an attribute name",
+            ));
+        }
+        cursor.skip_whitespace();
+        if cursor.eat(']') {
+            check(name, None)?;
+            continue;
+        }
+        if !cursor.eat('=') {
+            return Err(SelectorError::new(
+                cursor.pos_range(),
+                "expected `=` or `]`",
+            ));
+        }
+        cursor.skip_whitespace();
+        let value_start = cursor.pos;
+        let value = if cursor.peek_is('"') || cursor.peek_is('\'') {
+            let value = cursor.string()?;
+            cursor.skip_whitespace();
+            let _ = cursor.eat('i') || cursor.eat('s') || cursor.eat('I') || cursor.eat('S');
+            if value == "mixed" {
+                AttrValue::Mixed
+            } else {
+                AttrValue::String
+            }
+        } else if cursor.peek_is('/') {
+            cursor.bump();
+            let mut escaped = false;
+            loop {
+                match cursor.peek() {
+                    None => {
+                        return Err(SelectorError::new(
+                            cursor.at + value_start..cursor.at + cursor.pos,
+                            "unterminated regular expression",
+                        ));
+                    }
+                    Some('\\') if !escaped => escaped = true,
+                    Some('/') if !escaped => break,
+                    Some(_) => escaped = false,
+                }
+                cursor.bump();
+            }
+            cursor.bump();
+            while cursor.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+                cursor.bump();
+            }
+            regex(&cursor.s[value_start..cursor.pos], cursor.at + value_start)?;
+            AttrValue::Regex
+        } else {
+            while !cursor.at_end() && !cursor.peek_is(']') {
+                cursor.bump();
+            }
+            AttrValue::Word(cursor.s[value_start..cursor.pos].trim())
+        };
+        let value_range = cursor.at + value_start..cursor.at + cursor.pos;
+        cursor.skip_whitespace();
+        if !cursor.eat(']') {
+            return Err(SelectorError::new(
+                cursor.pos_range(),
+                "expected `]` to close the attribute",
+            ));
+        }
+        check(name, Some((value_range, value)))?;
+    }
+}
+
+/// A byte cursor over a selector body, `at` bytes into the literal.
+struct Cursor<'a> {
+    s: &'a str,
+    at: usize,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(s: &'a str, at: usize) -> Self {
+        Self { s, at, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.s[self.pos..].chars().next()
+    }
+
+    fn peek_is(&self, c: char) -> bool {
+        self.peek() == Some(c)
+    }
+
+    fn peek_whitespace(&self) -> bool {
+        self.peek().is_some_and(char::is_whitespace)
+    }
+
+    fn peek_word_char(&self) -> bool {
+        self.peek().is_some_and(char::is_alphanumeric)
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.s.len()
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let hit = self.peek_is(c);
+        if hit {
+            self.bump();
+        }
+        hit
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek_whitespace() {
+            self.bump();
+        }
+    }
+
+    /// The next character's range in the literal, or the end of input.
+    fn pos_range(&self) -> Range<usize> {
+        let len = self.peek().map_or(0, char::len_utf8);
+        self.at + self.pos..self.at + self.pos + len
+    }
+
+    /// A `"..."` or `'...'` string with backslash escapes, decoded.
+    fn string(&mut self) -> Result<String> {
+        let start = self.pos;
+        let quote = self.peek().expect("at a quote");
+        self.bump();
+        let mut value = String::new();
+        loop {
+            match self.peek() {
+                None => {
+                    return Err(SelectorError::new(
+                        self.at + start..self.at + start + 1,
+                        format!("unclosed `{quote}`"),
+                    ));
+                }
+                Some('\\') => {
+                    self.bump();
+                    match self.peek() {
+                        Some(c) => value.push(match c {
+                            'n' => '\n',
+                            't' => '\t',
+                            'r' => '\r',
+                            c => c,
+                        }),
+                        None => continue,
+                    }
+                    self.bump();
+                }
+                Some(c) if c == quote => {
+                    self.bump();
+                    return Ok(value);
+                }
+                Some(c) => {
+                    value.push(c);
+                    self.bump();
+                }
+            }
+        }
+    }
+}
+
+/// Track depth of `()`, `[]`, `{}`. Returns `Err` on the first
+/// imbalance — either an unmatched closer or any unclosed opener or
+/// quote at end of input. Bracket characters inside string literals
+/// (`"..."`, `'...'`) are skipped because selectors can carry quoted
+/// values like `[aria-label='go [back]']`.
+fn check_balanced_brackets(s: &str, at: usize) -> Result<()> {
+    let mut stack: Vec<(usize, char)> = Vec::new();
+    let mut chars = s.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                // skip the escaped char
+                let _ = chars.next();
+            }
+            '"' | '\'' => {
+                // skip until matching closing quote; honour `\<x>` so an
+                // escaped quote inside the value doesn't terminate the run
+                let quote = c;
+                loop {
+                    match chars.next() {
+                        None => {
+                            return Err(SelectorError::new(
+                                at + i..at + i + 1,
+                                format!("unclosed `{quote}`"),
+                            ));
+                        }
+                        Some((_, '\\')) => {
+                            let _ = chars.next();
+                        }
+                        Some((_, q)) if q == quote => break,
+                        Some(_) => {}
+                    }
+                }
+            }
+            '(' | '[' | '{' => stack.push((i, c)),
+            ')' | ']' | '}' => match (stack.pop(), c) {
+                (Some((_, '(')), ')') | (Some((_, '[')), ']') | (Some((_, '{')), '}') => {}
+                (Some((_, open)), close) => {
+                    return Err(SelectorError::new(
+                        at + i..at + i + 1,
+                        format!("mismatched bracket: `{open}` opened, `{close}` closed"),
+                    ));
+                }
+                (None, close) => {
+                    return Err(SelectorError::new(
+                        at + i..at + i + 1,
+                        format!("unmatched closing `{close}`"),
+                    ));
+                }
+            },
+            _ => {}
+        }
+    }
+
+    if let Some((i, open)) = stack.pop() {
+        return Err(SelectorError::new(
+            at + i..at + i + 1,
+            format!("unclosed `{open}`"),
+        ));
+    }
+
+    Ok(())
+}
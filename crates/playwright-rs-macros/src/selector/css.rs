@@ -0,0 +1,505 @@
+//! CSS selectors as Playwright parses them: the selector grammar of
+//! `cssParser.ts` over the CSS tokenizer, with Playwright's pseudo-classes
+//! (`:has-text()`, `:visible`, `:nth-match()`, layout selectors) checked
+//! for the arguments its evaluator expects.
+
+use super::{Result, SelectorError};
+use cssparser::{ParseError, ParseErrorKind, Parser, ParserInput, Token};
+use std::ops::Range;
+
+/// Pseudo-classes taking one string, and the most strings they take.
+const TEXT_PSEUDOS: [(&str, usize); 4] = [
+    ("has-text", 1),
+    ("text", 1),
+    ("text-is", 1),
+    ("text-matches", 2),
+];
+
+/// Pseudo-classes taking a selector and an optional maximum distance.
+const LAYOUT_PSEUDOS: [&str; 5] = ["right-of", "left-of", "above", "below", "near"];
+
+/// Pseudo-classes taking a selector list.
+const SELECTOR_PSEUDOS: [&str; 5] = ["has", "is", "where", "not", "light"];
+
+/// Pseudo-classes taking an `An+B` argument.
+const NTH_PSEUDOS: [&str; 4] = [
+    "nth-child",
+    "nth-last-child",
+    "nth-of-type",
+    "nth-last-of-type",
+];
+
+/// Validate `css`, which starts `at` bytes into the literal.
+pub(super) fn validate(css: &str, at: usize) -> Result<()> {
+    super::check_balanced_brackets(css, at)?;
+    let mut input = ParserInput::new(css);
+    let mut parser = Parser::new(&mut input);
+    let css = Css { src: css, at };
+    css.selector_list(&mut parser)?;
+    css.expect_end(&mut parser)
+}
+
+type Nested<'i> = ParseError<'i, SelectorError>;
+
+/// The CSS being parsed, and where it starts in the literal.
+#[derive(Clone, Copy)]
+struct Css<'s> {
+    src: &'s str,
+    at: usize,
+}
+
+impl Css<'_> {
+    /// Comma-separated complex selectors, up to the end of the input or
+    /// of the enclosing block.
+    fn selector_list(self, p: &mut Parser<'_, '_>) -> Result<()> {
+        loop {
+            self.complex(p)?;
+            p.skip_whitespace();
+            let state = p.state();
+            match p.next() {
+                Ok(Token::Comma) => {}
+                _ => {
+                    p.reset(&state);
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Compound selectors joined by combinators. A leading combinator is
+    /// relative to `:scope`, as Playwright allows.
+    fn complex(self, p: &mut Parser<'_, '_>) -> Result<()> {
+        p.skip_whitespace();
+        if let Some(combinator) = self.combinator(p) {
+            self.after_combinator(p, combinator)?;
+        } else {
+            self.compound(p)?;
+        }
+        loop {
+            let state = p.state();
+            let had_space = matches!(p.next_including_whitespace(), Ok(Token::WhiteSpace(_)));
+            if !had_space {
+                p.reset(&state);
+            }
+            if let Some(combinator) = self.combinator(p) {
+                self.after_combinator(p, combinator)?;
+                continue;
+            }
+            let before = p.state();
+            let ends = match p.next() {
+                Err(_) | Ok(Token::Comma | Token::CloseParenthesis) => true,
+                Ok(_) => false,
+            };
+            p.reset(&before);
+            if ends || !had_space {
+                // Anything other than a combinator or the end directly
+                // after a compound is reported by `expect_end`.
+                return Ok(());
+            }
+            self.compound(p)?;
+        }
+    }
+
+    /// Consume `>`, `+` or `~` if next (after whitespace).
+    fn combinator(self, p: &mut Parser<'_, '_>) -> Option<Range<usize>> {
+        let state = p.state();
+        p.skip_whitespace();
+        let start = p.position().byte_index();
+        match p.next() {
+            Ok(Token::Delim('>' | '+' | '~')) => Some(self.range(start, p.position().byte_index())),
+            _ => {
+                p.reset(&state);
+                None
+            }
+        }
+    }
+
+    fn after_combinator(self, p: &mut Parser<'_, '_>, combinator: Range<usize>) -> Result<()> {
+        p.skip_whitespace();
+        if p.is_exhausted() {
+            return Err(SelectorError::new(
+                combinator,
+                "expected a selector after this combinator",
+            ));
+        }
+        self.compound(p)
+    }
+
+    /// Type, `#id`, `.class`, `[attr]` and `:pseudo` selectors with no
+    /// whitespace between them.
+    fn compound(self, p: &mut Parser<'_, '_>) -> Result<()> {
+        let mut parts = 0;
+        loop {
+            let state = p.state();
+            let start = p.position().byte_index();
+            let token = match p.next_including_whitespace() {
+                Ok(token) => token.clone(),
+                Err(_) => break,
+            };
+            let range = self.range(start, p.position().byte_index());
+            match token {
+                Token::Ident(_) | Token::Delim('*') if parts == 0 => {}
+                Token::Ident(_) | Token::Delim('*') => {
+                    return Err(SelectorError::new(
+                        range,
+                        "a type selector must come first in a compound selector",
+                    ));
+                }
+                Token::IDHash(_) => {}
+                Token::Hash(ref id) => {
+                    return Err(SelectorError::new(
+                        range,
+                        format!("`#{id}` is not a valid id selector; use `[id=\"{id}\"]`"),
+                    ));
+                }
+                Token::Delim('.') => {
+                    let start = p.position().byte_index();
+                    if !matches!(p.next_including_whitespace(), Ok(Token::Ident(_))) {
+                        let end = p.position().byte_index().max(start + 1);
+                        return Err(SelectorError::new(
+                            self.range(range.start - self.at, end),
+                            "expected a class name after `.`",
+                        ));
+                    }
+                }
+                Token::Colon => self.pseudo(p, range)?,
+                Token::SquareBracketBlock => self.nested(p, |css, p| css.attribute(p, &range))?,
+                Token::WhiteSpace(_)
+                | Token::Comma
+                | Token::CloseParenthesis
+                | Token::Delim('>' | '+' | '~') => {
+                    p.reset(&state);
+                    break;
+                }
+                _ => return Err(self.unexpected(range)),
+            }
+            parts += 1;
+        }
+        if parts == 0 {
+            let start = p.position().byte_index();
+            let end = match p.next_including_whitespace() {
+                Ok(_) => p.position().byte_index(),
+                Err(_) => start,
+            };
+            return Err(SelectorError::new(
+                self.range(start, end),
+                "expected a selector",
+            ));
+        }
+        Ok(())
+    }
+
+    /// After a `:`: a pseudo-class, by name or with arguments.
+    fn pseudo(self, p: &mut Parser<'_, '_>, colon: Range<usize>) -> Result<()> {
+        let start = p.position().byte_index();
+        let token = p.next_including_whitespace().cloned();
+        let range = self.range(start, p.position().byte_index());
+        match token {
+            Ok(Token::Ident(name)) => {
+                let name = name.to_ascii_lowercase();
+                let needs_args = TEXT_PSEUDOS.iter().any(|(n, _)| *n == name)
+                    || LAYOUT_PSEUDOS.contains(&name.as_str())
+                    || SELECTOR_PSEUDOS.contains(&name.as_str())
+                    || NTH_PSEUDOS.contains(&name.as_str())
+                    || name == "nth-match";
+                if needs_args {
+                    return Err(SelectorError::new(
+                        colon.start..range.end,
+                        format!("`:{name}` needs arguments: `:{name}(...)`"),
+                    ));
+                }
+                Ok(())
+            }
+            Ok(Token::Function(name)) => {
+                let name = name.to_ascii_lowercase();
+                let whole = colon.start..range.end;
+                self.nested(p, |css, p| css.pseudo_args(p, &name, whole))
+            }
+            Ok(Token::Colon) => Err(SelectorError::new(
+                colon.start..range.end,
+                "pseudo-elements (`::name`) match no element and are not supported",
+            )),
+            _ => Err(SelectorError::new(
+                colon.start..range.end.max(colon.end),
+                "expected a pseudo-class name after `:`",
+            )),
+        }
+    }
+
+    /// The arguments of `:name(...)`, checked for the pseudo-classes
+    /// whose arguments Playwright or the browser constrain.
+    fn pseudo_args(self, p: &mut Parser<'_, '_>, name: &str, whole: Range<usize>) -> Result<()> {
+        if let Some(&(_, max)) = TEXT_PSEUDOS.iter().find(|(n, _)| *n == name) {
+            let mut count = 0;
+            loop {
+                p.skip_whitespace();
+                let start = p.position().byte_index();
+                match p.next().cloned() {
+                    Ok(Token::QuotedString(_)) if count < max => count += 1,
+                    Ok(_) | Err(_) => {
+                        let end = p.position().byte_index();
+                        return Err(SelectorError::new(
+                            if end > start {
+                                self.range(start, end)
+                            } else {
+                                whole
+                            },
+                            if max == 1 {
+                                format!("`:{name}()` expects a single quoted string")
+                            } else {
+                                format!("`:{name}()` expects a quoted pattern and optional flags")
+                            },
+                        ));
+                    }
+                }
+                p.skip_whitespace();
+                if p.is_exhausted() {
+                    return Ok(());
+                }
+                let start = p.position().byte_index();
+                if !matches!(p.next(), Ok(Token::Comma)) {
+                    return Err(self.unexpected(self.range(start, p.position().byte_index())));
+                }
+            }
+        }
+        if SELECTOR_PSEUDOS.contains(&name) {
+            self.non_empty(p, name, &whole)?;
+            self.selector_list(p)?;
+            return self.expect_end(p);
+        }
+        if LAYOUT_PSEUDOS.contains(&name) {
+            self.non_empty(p, name, &whole)?;
+            self.complex(p)?;
+            p.skip_whitespace();
+            if !p.is_exhausted() {
+                let start = p.position().byte_index();
+                if !matches!(p.next(), Ok(Token::Comma)) {
+                    return Err(self.unexpected(self.range(start, p.position().byte_index())));
+                }
+                self.number(p, name, "a maximum distance in pixels")?;
+            }
+            return self.expect_end(p);
+        }
+        if name == "nth-match" {
+            // `:nth-match(selector, ..., n)`: a selector list, then a
+            // one-based index.
+            self.non_empty(p, name, &whole)?;
+            let mut selectors = 0;
+            loop {
+                p.skip_whitespace();
+                let state = p.state();
+                if let Ok(Token::Number { .. }) = p.next() {
+                    p.reset(&state);
+                    break;
+                }
+                p.reset(&state);
+                self.complex(p)?;
+                selectors += 1;
+                p.skip_whitespace();
+                let start = p.position().byte_index();
+                if !matches!(p.next(), Ok(Token::Comma)) {
+                    return Err(SelectorError::new(
+                        if p.position().byte_index() > start {
+                            self.range(start, p.position().byte_index())
+                        } else {
+                            whole
+                        },
+                        "`:nth-match()` expects a selector, then `,` and a one-based index",
+                    ));
+                }
+            }
+            if selectors == 0 {
+                return Err(SelectorError::new(
+                    whole,
+                    "`:nth-match()` expects a selector before the index",
+                ));
+            }
+            let (start, end, value) = self.number(p, name, "a one-based index")?;
+            if value.is_none_or(|n| n < 1) {
+                return Err(SelectorError::new(
+                    self.range(start, end),
+                    "`:nth-match()` index must be an integer of at least 1",
+                ));
+            }
+            return self.expect_end(p);
+        }
+        if NTH_PSEUDOS.contains(&name) {
+            p.skip_whitespace();
+            let start = p.position().byte_index();
+            if cssparser::parse_nth(p).is_err() {
+                let end = p.position().byte_index().max(start);
+                return Err(SelectorError::new(
+                    if end > start {
+                        self.range(start, end)
+                    } else {
+                        whole
+                    },
+                    format!("`:{name}()` expects `An+B`, `odd` or `even`"),
+                ));
+            }
+            p.skip_whitespace();
+            if name.starts_with("nth-") && name.ends_with("child") && !p.is_exhausted() {
+                let state = p.state();
+                if matches!(p.next(), Ok(Token::Ident(of)) if of.eq_ignore_ascii_case("of")) {
+                    self.non_empty(p, name, &whole)?;
+                    self.selector_list(p)?;
+                } else {
+                    p.reset(&state);
+                }
+            }
+            return self.expect_end(p);
+        }
+        if name == "scope" || name == "visible" {
+            return Err(SelectorError::new(
+                whole,
+                format!("`:{name}` takes no arguments"),
+            ));
+        }
+        // Any other pseudo-class is the browser's to interpret.
+        while p.next_including_whitespace_and_comments().is_ok() {}
+        Ok(())
+    }
+
+    /// The inside of `[...]`: a name, then optionally an operator, a
+    /// quoted or identifier value and an `i` / `s` flag.
+    fn attribute(self, p: &mut Parser<'_, '_>, block: &Range<usize>) -> Result<()> {
+        p.skip_whitespace();
+        let start = p.position().byte_index();
+        match p.next() {
+            Ok(Token::Ident(_)) => {}
+            Ok(_) => {
+                let range = self.range(start, p.position().byte_index());
+                return Err(SelectorError::new(range, "expected an attribute name"));
+            }
+            Err(_) => {
+                return Err(SelectorError::new(
+                    block.clone(),
+                    "expected an attribute name inside `[]`",
+                ));
+            }
+        }
+        p.skip_whitespace();
+        if p.is_exhausted() {
+            return Ok(());
+        }
+        let start = p.position().byte_index();
+        match p.next() {
+            Ok(
+                Token::Delim('=')
+                | Token::IncludeMatch
+                | Token::DashMatch
+                | Token::PrefixMatch
+                | Token::SuffixMatch
+                | Token::SubstringMatch,
+            ) => {}
+            _ => {
+                return Err(SelectorError::new(
+                    self.range(start, p.position().byte_index()),
+                    "expected `]` or one of `=`, `~=`, `|=`, `^=`, `$=`, `*=`",
+                ));
+            }
+        }
+        p.skip_whitespace();
+        let start = p.position().byte_index();
+        match p.next().cloned() {
+            Ok(Token::Ident(_) | Token::QuotedString(_)) => {}
+            Ok(_) => {
+                let range = self.range(start, p.position().byte_index());
+                return Err(SelectorError::new(
+                    range,
+                    "attribute values that are not identifiers must be quoted",
+                ));
+            }
+            Err(_) => {
+                return Err(SelectorError::new(
+                    block.clone(),
+                    "expected an attribute value after the operator",
+                ));
+            }
+        }
+        p.skip_whitespace();
+        if p.is_exhausted() {
+            return Ok(());
+        }
+        let start = p.position().byte_index();
+        match p.next() {
+            Ok(Token::Ident(flag)) if matches!(&**flag, "i" | "I" | "s" | "S") => {}
+            _ => {
+                return Err(SelectorError::new(
+                    self.range(start, p.position().byte_index()),
+                    "expected `]`, or an `i` or `s` flag",
+                ));
+            }
+        }
+        self.expect_end(p)
+    }
+
+    /// A number argument: its byte range and integer value, if any.
+    fn number(
+        self,
+        p: &mut Parser<'_, '_>,
+        name: &str,
+        what: &str,
+    ) -> Result<(usize, usize, Option<i32>)> {
+        p.skip_whitespace();
+        let start = p.position().byte_index();
+        match p.next() {
+            Ok(&Token::Number { int_value, .. }) => {
+                Ok((start, p.position().byte_index(), int_value))
+            }
+            _ => Err(SelectorError::new(
+                self.range(start, p.position().byte_index().max(start)),
+                format!("`:{name}()` expects {what}"),
+            )),
+        }
+    }
+
+    fn non_empty(self, p: &mut Parser<'_, '_>, name: &str, whole: &Range<usize>) -> Result<()> {
+        p.skip_whitespace();
+        if p.is_exhausted() {
+            return Err(SelectorError::new(
+                whole.clone(),
+                format!("`:{name}()` needs a selector"),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Parse the block just opened, mapping errors back out of cssparser.
+    fn nested<'i>(
+        self,
+        p: &mut Parser<'i, '_>,
+        parse: impl for<'tt> FnOnce(Self, &mut Parser<'i, 'tt>) -> Result<()>,
+    ) -> Result<()> {
+        p.parse_nested_block(|p| {
+            parse(self, p).map_err(|e| p.new_custom_error::<_, SelectorError>(e))
+        })
+        .map_err(|e: Nested<'_>| match e.kind {
+            ParseErrorKind::Custom(e) => e,
+            ParseErrorKind::Basic(_) => SelectorError::new(self.at..self.at, "invalid CSS"),
+        })
+    }
+
+    /// Fail unless the input (or block) is used up.
+    fn expect_end(self, p: &mut Parser<'_, '_>) -> Result<()> {
+        p.skip_whitespace();
+        let start = p.position().byte_index();
+        if p.next_including_whitespace().is_err() {
+            return Ok(());
+        }
+        let range = self.range(start, p.position().byte_index());
+        Err(self.unexpected(range))
+    }
+
+    fn unexpected(self, range: Range<usize>) -> SelectorError {
+        let text = &self.src[range.start - self.at..range.end - self.at];
+        SelectorError::new(
+            range,
+            format!("unexpected `{}` in CSS selector", text.trim()),
+        )
+    }
+
+    fn range(self, start: usize, end: usize) -> Range<usize> {
+        self.at + start..self.at + end
+    }
+}
@@ -0,0 +1,495 @@
+//! XPath 1.0 syntax, as `document.evaluate` accepts it: the lexical
+//! structure of section 3.7 of the spec, then a recursive-descent pass
+//! over its expression grammar. Only syntax is checked, plus the names the
+//! grammar fixes (axes, node types and the core function library).
+
+use super::{Result, SelectorError};
+use std::ops::Range;
+
+const AXES: [&str; 13] = [
+    "ancestor",
+    "ancestor-or-self",
+    "attribute",
+    "child",
+    "descendant",
+    "descendant-or-self",
+    "following",
+    "following-sibling",
+    "namespace",
+    "parent",
+    "preceding",
+    "preceding-sibling",
+    "self",
+];
+
+const NODE_TYPES: [&str; 4] = ["comment", "text", "processing-instruction", "node"];
+
+const FUNCTIONS: [&str; 27] = [
+    "last",
+    "position",
+    "count",
+    "id",
+    "local-name",
+    "namespace-uri",
+    "name",
+    "string",
+    "concat",
+    "starts-with",
+    "contains",
+    "substring-before",
+    "substring-after",
+    "substring",
+    "string-length",
+    "normalize-space",
+    "translate",
+    "boolean",
+    "not",
+    "true",
+    "false",
+    "lang",
+    "number",
+    "sum",
+    "floor",
+    "ceiling",
+    "round",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Dot,
+    DotDot,
+    At,
+    Comma,
+    ColonColon,
+    Slash,
+    DoubleSlash,
+    Pipe,
+    Plus,
+    Minus,
+    Eq,
+    Neq,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Star,
+    /// An `NCName`, `QName` or `prefix:*`.
+    Name(String),
+    Literal,
+    Number,
+    Variable,
+}
+
+/// Validate `xpath`, which starts `at` bytes into the literal.
+pub(super) fn validate(xpath: &str, at: usize) -> Result<()> {
+    super::check_balanced_brackets(xpath, at)?;
+    let tokens = tokenize(xpath, at)?;
+    let end = at + xpath.len();
+    let mut parser = XPath {
+        tokens,
+        pos: 0,
+        end,
+    };
+    parser.expr()?;
+    if let Some((_, range)) = parser.tokens.get(parser.pos) {
+        return Err(SelectorError::new(
+            range.clone(),
+            format!(
+                "unexpected `{}` in XPath",
+                &xpath[range.start - at..range.end - at]
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn is_name_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '\u{B7}')
+}
+
+fn tokenize(s: &str, at: usize) -> Result<Vec<(Tok, Range<usize>)>> {
+    let mut tokens = Vec::new();
+    let mut chars = s.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let next = chars.peek().map(|&(_, c)| c);
+        let mut end = i + c.len_utf8();
+        let tok = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Tok::LParen,
+            ')' => Tok::RParen,
+            '[' => Tok::LBracket,
+            ']' => Tok::RBracket,
+            '@' => Tok::At,
+            ',' => Tok::Comma,
+            '|' => Tok::Pipe,
+            '+' => Tok::Plus,
+            '-' => Tok::Minus,
+            '=' => Tok::Eq,
+            '*' => Tok::Star,
+            '/' if next == Some('/') => {
+                chars.next();
+                end += 1;
+                Tok::DoubleSlash
+            }
+            '/' => Tok::Slash,
+            '!' if next == Some('=') => {
+                chars.next();
+                end += 1;
+                Tok::Neq
+            }
+            '<' | '>' if next == Some('=') => {
+                chars.next();
+                end += 1;
+                if c == '<' { Tok::Le } else { Tok::Ge }
+            }
+            '<' => Tok::Lt,
+            '>' => Tok::Gt,
+            ':' if next == Some(':') => {
+                chars.next();
+                end += 1;
+                Tok::ColonColon
+            }
+            '"' | '\'' => {
+                let quote = c;
+                loop {
+                    match chars.next() {
+                        Some((j, q)) if q == quote => {
+                            end = j + 1;
+                            break;
+                        }
+                        Some(_) => {}
+                        None => {
+                            return Err(SelectorError::new(
+                                at + i..at + i + 1,
+                                format!("unclosed `{quote}`"),
+                            ));
+                        }
+                    }
+                }
+                Tok::Literal
+            }
+            '.' if next == Some('.') => {
+                chars.next();
+                end += 1;
+                Tok::DotDot
+            }
+            '.' if next.is_some_and(|c| c.is_ascii_digit()) => {
+                while let Some(&(j, d)) = chars.peek().filter(|(_, d)| d.is_ascii_digit()) {
+                    end = j + d.len_utf8();
+                    chars.next();
+                }
+                Tok::Number
+            }
+            '.' => Tok::Dot,
+            c if c.is_ascii_digit() => {
+                let mut seen_dot = false;
+                while let Some(&(j, d)) = chars
+                    .peek()
+                    .filter(|(_, d)| d.is_ascii_digit() || (*d == '.' && !seen_dot))
+                {
+                    seen_dot |= d == '.';
+                    end = j + d.len_utf8();
+                    chars.next();
+                }
+                Tok::Number
+            }
+            '$' => {
+                let (name_end, _) = name(s, i + 1);
+                if name_end == i + 1 {
+                    return Err(SelectorError::new(
+                        at + i..at + i + 1,
+                        "expected a variable name after `$`",
+                    ));
+                }
+                while chars.peek().is_some_and(|&(j, _)| j < name_end) {
+                    chars.next();
+                }
+                end = name_end;
+                Tok::Variable
+            }
+            c if is_name_start(c) => {
+                let (name_end, _) = name(s, i);
+                while chars.peek().is_some_and(|&(j, _)| j < name_end) {
+                    chars.next();
+                }
+                end = name_end;
+                Tok::Name(s[i..end].to_string())
+            }
+            other => {
+                return Err(SelectorError::new(
+                    at + i..at + end,
+                    format!("unexpected `{other}` in XPath"),
+                ));
+            }
+        };
+        tokens.push((tok, at + i..at + end));
+    }
+    Ok(tokens)
+}
+
+/// The end of the `NCName`, `QName` or `prefix:*` starting at `start`,
+/// and whether it had a prefix.
+fn name(s: &str, start: usize) -> (usize, bool) {
+    let ncname = |from: usize| {
+        let mut chars = s[from..].char_indices();
+        match chars.next() {
+            Some((_, c)) if is_name_start(c) => {}
+            _ => return from,
+        }
+        from + chars
+            .find(|&(_, c)| !is_name_char(c))
+            .map_or(s.len() - from, |(j, _)| j)
+    };
+    let end = ncname(start);
+    if end == start {
+        return (end, false);
+    }
+    // `prefix:local` or `prefix:*`, but not `axis::`.
+    let rest = &s[end..];
+    if rest.starts_with(':') && !rest.starts_with("::") {
+        if rest[1..].starts_with('*') {
+            return (end + 2, true);
+        }
+        let local = ncname(end + 1);
+        if local > end + 1 {
+            return (local, true);
+        }
+    }
+    (end, false)
+}
+
+struct XPath {
+    tokens: Vec<(Tok, Range<usize>)>,
+    pos: usize,
+    end: usize,
+}
+
+impl XPath {
+    fn peek(&self) -> Option<&Tok> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn peek_at(&self, n: usize) -> Option<&Tok> {
+        self.tokens.get(self.pos + n).map(|(t, _)| t)
+    }
+
+    fn range(&self) -> Range<usize> {
+        self.tokens
+            .get(self.pos)
+            .map_or(self.end..self.end, |(_, r)| r.clone())
+    }
+
+    fn eat(&mut self, tok: &Tok) -> bool {
+        let hit = self.peek() == Some(tok);
+        if hit {
+            self.pos += 1;
+        }
+        hit
+    }
+
+    fn eat_name(&mut self, names: &[&str]) -> bool {
+        let hit = matches!(self.peek(), Some(Tok::Name(n)) if names.contains(&n.as_str()));
+        if hit {
+            self.pos += 1;
+        }
+        hit
+    }
+
+    fn expect(&mut self, tok: &Tok, what: &str) -> Result<()> {
+        if self.eat(tok) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected {what}")))
+        }
+    }
+
+    fn error(&self, message: impl Into<String>) -> SelectorError {
+        let message = message.into();
+        if self.pos >= self.tokens.len() {
+            SelectorError::new(self.range(), format!("{message} at the end of the XPath"))
+        } else {
+            SelectorError::new(self.range(), message)
+        }
+    }
+
+    /// `OrExpr` and every binary level below it down to `UnaryExpr`.
+    fn expr(&mut self) -> Result<()> {
+        self.binary(0)
+    }
+
+    fn binary(&mut self, level: usize) -> Result<()> {
+        if level == 6 {
+            return self.unary();
+        }
+        self.binary(level + 1)?;
+        loop {
+            let matched = match level {
+                0 => self.eat_name(&["or"]),
+                1 => self.eat_name(&["and"]),
+                2 => self.eat(&Tok::Eq) || self.eat(&Tok::Neq),
+                3 => [Tok::Lt, Tok::Le, Tok::Gt, Tok::Ge]
+                    .iter()
+                    .any(|t| self.eat(t)),
+                4 => self.eat(&Tok::Plus) || self.eat(&Tok::Minus),
+                _ => self.eat(&Tok::Star) || self.eat_name(&["div", "mod"]),
+            };
+            if !matched {
+                return Ok(());
+            }
+            self.binary(level + 1)?;
+        }
+    }
+
+    fn unary(&mut self) -> Result<()> {
+        while self.eat(&Tok::Minus) {}
+        self.path()?;
+        while self.eat(&Tok::Pipe) {
+            self.path()?;
+        }
+        Ok(())
+    }
+
+    /// `PathExpr`: a location path, or a filter expression optionally
+    /// followed by one.
+    fn path(&mut self) -> Result<()> {
+        match self.peek() {
+            Some(Tok::Slash) => {
+                self.pos += 1;
+                if self.starts_step() {
+                    self.relative_path()?;
+                }
+                Ok(())
+            }
+            Some(Tok::DoubleSlash) => {
+                self.pos += 1;
+                self.relative_path()
+            }
+            Some(Tok::Variable | Tok::LParen | Tok::Literal | Tok::Number) => self.filter(),
+            Some(Tok::Name(name))
+                if self.peek_at(1) == Some(&Tok::LParen)
+                    && !NODE_TYPES.contains(&name.as_str()) =>
+            {
+                self.filter()
+            }
+            _ => self.relative_path(),
+        }
+    }
+
+    fn filter(&mut self) -> Result<()> {
+        self.primary()?;
+        self.predicates()?;
+        if self.eat(&Tok::Slash) || self.eat(&Tok::DoubleSlash) {
+            self.relative_path()?;
+        }
+        Ok(())
+    }
+
+    fn primary(&mut self) -> Result<()> {
+        match self.peek().cloned() {
+            Some(Tok::Variable | Tok::Literal | Tok::Number) => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(Tok::LParen) => {
+                self.pos += 1;
+                self.expr()?;
+                self.expect(&Tok::RParen, "`)`")
+            }
+            Some(Tok::Name(name)) => {
+                if !FUNCTIONS.contains(&name.as_str()) {
+                    return Err(self.error(format!("unknown XPath function `{name}()`")));
+                }
+                self.pos += 2;
+                if self.eat(&Tok::RParen) {
+                    return Ok(());
+                }
+                loop {
+                    self.expr()?;
+                    if self.eat(&Tok::RParen) {
+                        return Ok(());
+                    }
+                    self.expect(&Tok::Comma, "`,` or `)`")?;
+                }
+            }
+            _ => Err(self.error("expected an expression")),
+        }
+    }
+
+    fn starts_step(&self) -> bool {
+        matches!(
+            self.peek(),
+            Some(Tok::Name(_) | Tok::Star | Tok::Dot | Tok::DotDot | Tok::At)
+        )
+    }
+
+    fn relative_path(&mut self) -> Result<()> {
+        self.step()?;
+        while self.eat(&Tok::Slash) || self.eat(&Tok::DoubleSlash) {
+            self.step()?;
+        }
+        Ok(())
+    }
+
+    fn step(&mut self) -> Result<()> {
+        if self.eat(&Tok::Dot) || self.eat(&Tok::DotDot) {
+            return Ok(());
+        }
+        if !self.eat(&Tok::At)
+            && let Some(Tok::Name(axis)) = self.peek().cloned()
+            && self.peek_at(1) == Some(&Tok::ColonColon)
+        {
+            if !AXES.contains(&axis.as_str()) {
+                return Err(self.error(format!("unknown XPath axis `{axis}::`")));
+            }
+            self.pos += 2;
+        }
+        self.node_test()?;
+        self.predicates()
+    }
+
+    fn node_test(&mut self) -> Result<()> {
+        match self.peek().cloned() {
+            Some(Tok::Star) => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(Tok::Name(name)) => {
+                self.pos += 1;
+                if !self.eat(&Tok::LParen) {
+                    return Ok(());
+                }
+                if !NODE_TYPES.contains(&name.as_str()) {
+                    self.pos -= 2;
+                    return Err(self.error(format!(
+                        "`{name}()` is not a node test; expected `node()`, `text()`, \
+                         `comment()` or `processing-instruction()`"
+                    )));
+                }
+                if name == "processing-instruction" {
+                    self.eat(&Tok::Literal);
+                }
+                self.expect(&Tok::RParen, "`)`")
+            }
+            _ => Err(self.error("expected a node test such as `div`, `*` or `text()`")),
+        }
+    }
+
+    fn predicates(&mut self) -> Result<()> {
+        while self.eat(&Tok::LBracket) {
+            if self.peek() == Some(&Tok::RBracket) {
+                return Err(self.error("empty predicate `[]`"));
+            }
+            self.expr()?;
+            self.expect(&Tok::RBracket, "`]`")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+//! `#[playwright_rs::test]` — parse the attribute, then expand to a
+//! plain `#[test]` that hands the body to
+//! `playwright_rs::testing::PlaywrightTest`, which owns the runtime,
+//! the browser and the cleanup.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::{Expr, ExprArray, FnArg, Ident, ItemFn, Lit, LitBool, LitStr, Token, Type};
+
+const BROWSERS: [&str; 3] = ["chromium", "firefox", "webkit"];
+const TRACE_MODES: [(&str, &str); 4] = [
+    ("off", "Off"),
+    ("on", "On"),
+    ("retain-on-failure", "RetainOnFailure"),
+    ("on-first-retry", "OnFirstRetry"),
+];
+
+#[derive(Default)]
+pub(crate) struct TestArgs {
+    /// One entry per generated test; more than one makes a matrix.
+    browsers: Vec<LitStr>,
+    matrix: bool,
+    headless: Option<LitBool>,
+    device: Option<LitStr>,
+    trace: Option<Ident>,
+}
+
+impl TestArgs {
+    pub(crate) fn parse(&mut self, meta: syn::meta::ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("browser") {
+            match meta.value()?.parse::<Expr>()? {
+                Expr::Lit(lit) => self.browsers = vec![browser_name(&lit.lit)?],
+                Expr::Array(ExprArray { elems, .. }) => {
+                    self.browsers = elems
+                        .iter()
+                        .map(|e| match e {
+                            Expr::Lit(lit) => browser_name(&lit.lit),
+                            other => {
+                                Err(syn::Error::new_spanned(other, "expected a string literal"))
+                            }
+                        })
+                        .collect::<syn::Result<_>>()?;
+                    if self.browsers.is_empty() {
+                        return Err(meta.error("the browser matrix is empty"));
+                    }
+                    for (i, b) in self.browsers.iter().enumerate() {
+                        if self.browsers[..i].iter().any(|a| a.value() == b.value()) {
+                            return Err(syn::Error::new(b.span(), "browser listed twice"));
+                        }
+                    }
+                    self.matrix = true;
+                }
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        "expected \"chromium\", \"firefox\", \"webkit\" or a list of them",
+                    ));
+                }
+            }
+        } else if meta.path.is_ident("headless") {
+            self.headless = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("device") {
+            self.device = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("trace") {
+            let lit: LitStr = meta.value()?.parse()?;
+            let value = lit.value();
+            let Some((_, variant)) = TRACE_MODES.iter().find(|(mode, _)| *mode == value) else {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "unknown trace mode; expected \"off\", \"on\", \"retain-on-failure\" \
+                     or \"on-first-retry\"",
+                ));
+            };
+            self.trace = Some(Ident::new(variant, lit.span()));
+        } else {
+            return Err(
+                meta.error("unknown argument; expected `browser`, `headless`, `device` or `trace`")
+            );
+        }
+        Ok(())
+    }
+}
+
+fn browser_name(lit: &Lit) -> syn::Result<LitStr> {
+    match lit {
+        Lit::Str(s) if BROWSERS.contains(&s.value().as_str()) => Ok(s.clone()),
+        _ => Err(syn::Error::new_spanned(
+            lit,
+            "unknown browser; expected \"chromium\", \"firefox\" or \"webkit\"",
+        )),
+    }
+}
+
+pub(crate) fn expand(args: TestArgs, mut func: ItemFn) -> syn::Result<TokenStream> {
+    if func.sig.asyncness.is_none() {
+        return Err(syn::Error::new_spanned(
+            func.sig.fn_token,
+            "#[playwright_rs::test] functions must be `async fn`",
+        ));
+    }
+    if !func.sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &func.sig.generics,
+            "#[playwright_rs::test] functions cannot be generic",
+        ));
+    }
+
+    let fixtures = func
+        .sig
+        .inputs
+        .iter()
+        .map(fixture_for)
+        .collect::<syn::Result<Punctuated<TokenStream, Token![,]>>>()?;
+
+    // Test attributes (`#[ignore]`, `#[should_panic]`) belong on the
+    // generated `#[test]`; docs stay with the function.
+    let (docs, test_attrs): (Vec<_>, Vec<_>) = std::mem::take(&mut func.attrs)
+        .into_iter()
+        .partition(|a| a.path().is_ident("doc"));
+    func.attrs = docs;
+
+    let name = func.sig.ident.clone();
+    let vis = std::mem::replace(&mut func.vis, syn::Visibility::Inherited);
+
+    let mut config = TokenStream::new();
+    if let Some(headless) = &args.headless {
+        config.extend(quote! { .headless(#headless) });
+    }
+    if let Some(device) = &args.device {
+        config.extend(quote! { .device(#device) });
+    }
+    if let Some(trace) = &args.trace {
+        config.extend(quote! { .trace(::playwright_rs::testing::ArtifactMode::#trace) });
+    }
+
+    let test_fn = |fn_name: &Ident, browser: Option<&LitStr>, test_name: TokenStream| {
+        let browser = browser.map(|b| quote! { .browser(#b) });
+        quote! {
+            #(#test_attrs)*
+            #[::core::prelude::v1::test]
+            #vis fn #fn_name() {
+                ::playwright_rs::testing::PlaywrightTest::new(#test_name)
+                    #browser
+                    #config
+                    .run(|__fixtures| #name(#fixtures))
+            }
+        }
+    };
+
+    if !args.matrix {
+        let test = test_fn(
+            &name,
+            args.browsers.first(),
+            quote! { ::core::concat!(::core::module_path!(), "::", ::core::stringify!(#name)) },
+        );
+        // The body becomes an item inside the generated test, where it
+        // shadows the test's own name.
+        let mut test: ItemFn = syn::parse2(test)?;
+        test.block
+            .stmts
+            .insert(0, syn::Stmt::Item(syn::Item::Fn(func)));
+        return Ok(quote! { #test });
+    }
+
+    let tests = args.browsers.iter().map(|browser| {
+        let fn_name = format_ident!("{}", browser.value(), span = browser.span());
+        test_fn(
+            &fn_name,
+            Some(browser),
+            quote! { ::core::concat!(::core::module_path!(), "::", #browser) },
+        )
+    });
+    Ok(quote! {
+        #vis mod #name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #func
+
+            #(#tests)*
+        }
+    })
+}
+
+/// The `TestFixtures` field a test parameter asks for, by type name.
+fn fixture_for(arg: &FnArg) -> syn::Result<TokenStream> {
+    let FnArg::Typed(typed) = arg else {
+        return Err(syn::Error::new_spanned(
+            arg,
+            "test functions take no `self`",
+        ));
+    };
+    let field = match &*typed.ty {
+        Type::Path(path) => {
+            path.path
+                .segments
+                .last()
+                .and_then(|s| match s.ident.to_string().as_str() {
+                    "Playwright" => Some("playwright"),
+                    "Browser" => Some("browser"),
+                    "BrowserContext" => Some("context"),
+                    "Page" => Some("page"),
+                    _ => None,
+                })
+        }
+        _ => None,
+    };
+    let Some(field) = field else {
+        return Err(syn::Error::new_spanned(
+            &typed.ty,
+            "unsupported test argument; expected `Page`, `BrowserContext`, `Browser` \
+             or `Playwright`",
+        ));
+    };
+    let field = Ident::new(field, Span::call_site());
+    Ok(quote! { ::core::clone::Clone::clone(&__fixtures.#field) })
+}
@@ -0,0 +1,12 @@
+// trybuild harness for `loc!` diagnostics; same layout as
+// `locator_compile_fail.rs`. Each snippet is rejected while the steps are
+// parsed, so none of them needs playwright-rs to build.
+#[test]
+fn compile_fail_cases() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/loc_unknown_role.rs");
+    t.compile_fail("tests/ui/loc_unknown_role_option.rs");
+    t.compile_fail("tests/ui/loc_unknown_step.rs");
+    t.compile_fail("tests/ui/loc_narrowing_first.rs");
+    t.compile_fail("tests/ui/loc_invalid_selector.rs");
+}
@@ -9,4 +9,11 @@ fn compile_fail_cases() {
     t.compile_fail("tests/ui/unclosed_bracket.rs");
     t.compile_fail("tests/ui/unmatched_closer.rs");
     t.compile_fail("tests/ui/unknown_engine.rs");
+    t.compile_fail("tests/ui/css_pseudo_element.rs");
+    t.compile_fail("tests/ui/css_bad_pseudo_argument.rs");
+    t.compile_fail("tests/ui/xpath_unknown_function.rs");
+    t.compile_fail("tests/ui/xpath_empty_predicate.rs");
+    t.compile_fail("tests/ui/unknown_aria_role.rs");
+    t.compile_fail("tests/ui/nth_not_integer.rs");
+    t.compile_fail("tests/ui/text_unclosed_quote.rs");
 }
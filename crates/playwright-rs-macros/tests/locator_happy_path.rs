@@ -43,8 +43,7 @@ fn internal_engine_prefixes_pass() {
 
 #[test]
 fn chained_selectors_with_engine_arrow_pass() {
-    // Playwright's `>>` selector chaining isn't validated structurally
-    // by this macro yet, but valid chains pass through.
+    // Each `>>`-separated part is validated with its own engine.
     let s = locator!("div.container >> text=Submit");
     assert_eq!(s, "div.container >> text=Submit");
 }
@@ -56,3 +55,110 @@ fn quoted_brackets_inside_attribute_value_pass() {
     let s = locator!("[aria-label='go [back]']");
     assert_eq!(s, "[aria-label='go [back]']");
 }
+
+#[test]
+fn css_combinators_and_lists_pass() {
+    assert_eq!(locator!("ul > li + li ~ li"), "ul > li + li ~ li");
+    assert_eq!(
+        locator!("form input, form button"),
+        "form input, form button"
+    );
+    assert_eq!(locator!("*"), "*");
+    assert_eq!(locator!("> div"), "> div");
+    assert_eq!(
+        locator!("a[href^=\"https\" i]:not(.external)"),
+        "a[href^=\"https\" i]:not(.external)"
+    );
+    assert_eq!(locator!("li:nth-child(2n + 1)"), "li:nth-child(2n + 1)");
+    assert_eq!(
+        locator!("li:nth-child(odd of .item)"),
+        "li:nth-child(odd of .item)"
+    );
+    assert_eq!(locator!("#\\31 23"), "#\\31 23");
+}
+
+#[test]
+fn playwright_css_extensions_pass() {
+    assert_eq!(
+        locator!("article:has-text(\"Playwright\")"),
+        "article:has-text(\"Playwright\")"
+    );
+    assert_eq!(locator!("button:visible"), "button:visible");
+    assert_eq!(
+        locator!(":nth-match(:text(\"Buy\"), 3)"),
+        ":nth-match(:text(\"Buy\"), 3)"
+    );
+    assert_eq!(
+        locator!("input:right-of(:text(\"Username\"), 50)"),
+        "input:right-of(:text(\"Username\"), 50)"
+    );
+    assert_eq!(
+        locator!("div:has(> button.primary)"),
+        "div:has(> button.primary)"
+    );
+    assert_eq!(
+        locator!("p:text-matches(\"^sign in$\", \"i\")"),
+        "p:text-matches(\"^sign in$\", \"i\")"
+    );
+}
+
+#[test]
+fn xpath_expressions_pass() {
+    assert_eq!(locator!("//div"), "//div");
+    assert_eq!(locator!("..").len(), 2);
+    assert_eq!(locator!("(//li)[last()]"), "(//li)[last()]");
+    assert_eq!(
+        locator!("xpath=//a[contains(@class, 'nav') and not(@hidden)]/following-sibling::*[1]"),
+        "xpath=//a[contains(@class, 'nav') and not(@hidden)]/following-sibling::*[1]"
+    );
+    assert_eq!(
+        locator!("xpath=//tr[position() mod 2 = 0]/td[text()=\"it's\"]"),
+        "xpath=//tr[position() mod 2 = 0]/td[text()=\"it's\"]"
+    );
+    assert_eq!(
+        locator!("xpath=/html/body//*[@data-id > 3 or count(ancestor::ul) >= 2]"),
+        "xpath=/html/body//*[@data-id > 3 or count(ancestor::ul) >= 2]"
+    );
+}
+
+#[test]
+fn role_and_text_bodies_pass() {
+    assert_eq!(
+        locator!("role=button[name=\"Save\" i][pressed]"),
+        "role=button[name=\"Save\" i][pressed]"
+    );
+    assert_eq!(
+        locator!("role=heading[level=2][name=/intro/i]"),
+        "role=heading[level=2][name=/intro/i]"
+    );
+    assert_eq!(
+        locator!("role=checkbox[checked=\"mixed\"]"),
+        "role=checkbox[checked=\"mixed\"]"
+    );
+    assert_eq!(locator!("text=\"Log in\"i"), "text=\"Log in\"i");
+    assert_eq!(locator!("text=/log\\s*in/i"), "text=/log\\s*in/i");
+    assert_eq!(locator!("\"Exact text\""), "\"Exact text\"");
+    assert_eq!(locator!("text=Don't >> nth=-1"), "text=Don't >> nth=-1");
+}
+
+#[test]
+fn internal_engine_bodies_pass() {
+    assert_eq!(
+        locator!("internal:has-text=\"Hello\"i"),
+        "internal:has-text=\"Hello\"i"
+    );
+    assert_eq!(
+        locator!("div >> internal:has=\"button >> text=Go\""),
+        "div >> internal:has=\"button >> text=Go\""
+    );
+    assert_eq!(
+        locator!("internal:testid=[data-testid=\"submit\"s]"),
+        "internal:testid=[data-testid=\"submit\"s]"
+    );
+    assert_eq!(
+        locator!("internal:role=link[name=\"Docs\"s]"),
+        "internal:role=link[name=\"Docs\"s]"
+    );
+    assert_eq!(locator!("li >> visible=true"), "li >> visible=true");
+    assert_eq!(locator!("*css=ul >> li"), "*css=ul >> li");
+}
@@ -0,0 +1,11 @@
+// trybuild harness for `#[derive(PageObject)]` diagnostics; same layout as
+// `locator_compile_fail.rs`. Each snippet is rejected before expansion,
+// so none of them needs playwright-rs to build.
+#[test]
+fn compile_fail_cases() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/page_object_invalid_selector.rs");
+    t.compile_fail("tests/ui/page_object_missing_attribute.rs");
+    t.compile_fail("tests/ui/page_object_two_attributes.rs");
+    t.compile_fail("tests/ui/page_object_unknown_role_option.rs");
+}
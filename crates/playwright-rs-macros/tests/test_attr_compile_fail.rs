@@ -0,0 +1,11 @@
+// trybuild harness for `#[test]` attribute diagnostics; same layout as
+// `locator_compile_fail.rs`. Each snippet is rejected before expansion,
+// so none of them needs playwright-rs to build.
+#[test]
+fn compile_fail_cases() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/test_not_async.rs");
+    t.compile_fail("tests/ui/test_unknown_browser.rs");
+    t.compile_fail("tests/ui/test_unknown_trace_mode.rs");
+    t.compile_fail("tests/ui/test_unsupported_argument.rs");
+}
@@ -0,0 +1,6 @@
+use playwright_rs_macros::loc;
+
+fn main() {
+    let page = ();
+    let _ = loc!(page, role(button, label = "Save"));
+}
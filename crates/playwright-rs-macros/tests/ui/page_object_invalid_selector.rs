@@ -0,0 +1,9 @@
+struct Locator;
+
+#[derive(playwright_rs_macros::PageObject)]
+struct Toolbar {
+    #[locator("button[disabled")]
+    save: Locator,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+struct Locator;
+
+#[derive(playwright_rs_macros::PageObject)]
+struct Toolbar {
+    save: Locator,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+struct Locator;
+
+#[derive(playwright_rs_macros::PageObject)]
+struct Toolbar {
+    #[test_id("save")]
+    #[role(button, name = "Save")]
+    save: Locator,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+struct Locator;
+
+#[derive(playwright_rs_macros::PageObject)]
+struct Toolbar {
+    #[role(button, label = "Save")]
+    save: Locator,
+}
+
+fn main() {}
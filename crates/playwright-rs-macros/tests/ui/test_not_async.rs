@@ -0,0 +1,4 @@
+#[playwright_rs_macros::test]
+fn not_async() {}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[playwright_rs_macros::test(browser = ["chromium", "edge"])]
+async fn unknown_browser() {}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[playwright_rs_macros::test(trace = "retain_on_failure")]
+async fn unknown_trace_mode() {}
+
+fn main() {}
@@ -0,0 +1,8 @@
+struct Frame;
+
+#[playwright_rs_macros::test]
+async fn unsupported_argument(frame: Frame) {
+    let _ = frame;
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+use playwright_rs_macros::locator;
+
+fn main() {
+    let _ = locator!("role=buton[name=\"Save\"]");
+}
@@ -7,7 +7,9 @@
 //! useful when diagnosing crashed-mid-action traces.
 
 use crate::error::Result;
-use crate::event::{ActionError, AfterEvent, BeforeEvent, InputEvent, LogEvent, Point, TraceEvent};
+use crate::event::{
+    ActionError, AfterEvent, BeforeEvent, InputEvent, LogEvent, Point, StackFrame, TraceEvent,
+};
 use serde_json::Value;
 use std::collections::HashMap;
 
@@ -32,6 +34,21 @@ pub struct Action {
     pub before_snapshot: Option<String>,
     pub after_snapshot: Option<String>,
     pub point: Option<Point>,
+    /// Client call stack, innermost frame first. Empty when the client
+    /// sent no location.
+    pub stack: Vec<StackFrame>,
+}
+
+impl Action {
+    /// The call site that issued the action — the innermost stack frame.
+    pub fn location(&self) -> Option<&StackFrame> {
+        self.stack.first()
+    }
+
+    /// The selector the action targeted, for element actions.
+    pub fn selector(&self) -> Option<&str> {
+        self.params.get("selector").and_then(Value::as_str)
+    }
 }
 
 /// One log line attached to an action via the `log` event.
@@ -60,6 +77,10 @@ pub struct ActionStream<I> {
     /// in a deterministic order at end-of-stream.
     pending_order: Vec<String>,
     upstream_done: bool,
+    /// Set at end-of-stream and at each chunk boundary (a new
+    /// `context-options` event): whatever is still pending was
+    /// truncated, and call ids may be reused by the next chunk.
+    draining: bool,
 }
 
 impl<I> ActionStream<I>
@@ -72,6 +93,7 @@ where
             pending: HashMap::new(),
             pending_order: Vec::new(),
             upstream_done: false,
+            draining: false,
         }
     }
 }
@@ -84,14 +106,17 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            if self.upstream_done {
+            if self.draining {
                 // Drain truncated actions one at a time.
                 while let Some(call_id) = self.pending_order.pop() {
                     if let Some(builder) = self.pending.remove(&call_id) {
                         return Some(Ok(builder.finalize_truncated()));
                     }
                 }
-                return None;
+                if self.upstream_done {
+                    return None;
+                }
+                self.draining = false;
             }
 
             let event = match self.events.next() {
@@ -99,11 +124,13 @@ where
                 Some(Err(e)) => return Some(Err(e)),
                 None => {
                     self.upstream_done = true;
+                    self.draining = true;
                     continue;
                 }
             };
 
             match event {
+                TraceEvent::ContextOptions(_) => self.draining = true,
                 TraceEvent::Before(b) => {
                     let call_id = b.call_id.clone();
                     if !self.pending.contains_key(&call_id) {
@@ -152,6 +179,7 @@ struct ActionBuilder {
     before_snapshot: Option<String>,
     logs: Vec<LogLine>,
     input: Option<InputEvent>,
+    stack: Vec<StackFrame>,
 }
 
 impl ActionBuilder {
@@ -168,6 +196,7 @@ impl ActionBuilder {
             before_snapshot: b.before_snapshot,
             logs: Vec::new(),
             input: None,
+            stack: b.stack,
         }
     }
 
@@ -189,6 +218,7 @@ impl ActionBuilder {
             before_snapshot: self.before_snapshot,
             after_snapshot: a.after_snapshot,
             point: a.point,
+            stack: self.stack,
         }
     }
 
@@ -210,6 +240,7 @@ impl ActionBuilder {
             before_snapshot: self.before_snapshot,
             after_snapshot: None,
             point: None,
+            stack: self.stack,
         }
     }
 }
@@ -0,0 +1,302 @@
+//! [`AsyncTraceReader`] — the [`TraceReader`] API as tokio `Stream`s
+//! (feature `tokio`).
+//!
+//! Zip decompression and JSON parsing are CPU work, so rather than
+//! re-implement the parser over async IO this runs the synchronous
+//! reader on tokio's blocking pool and forwards items through a bounded
+//! channel. The executor never blocks, the parser (and its
+//! forward-compat behaviour) is the same code the sync API uses, and a
+//! slow consumer applies backpressure instead of buffering the trace.
+//!
+//! Every stream and [`resource`](AsyncTraceReader::resource) call works
+//! on its own clone of the reader. The clones share the source through
+//! [`SharedSource`], which keeps a cursor per clone and locks the source
+//! only for a single read, so a stream that is waiting on its consumer
+//! never holds up anything else.
+
+use crate::action::Action;
+use crate::error::{Result, TraceError};
+use crate::event::{ContextOptions, RawEvent, TraceEvent};
+use crate::network::NetworkEntry;
+use crate::timeline::TimelineEntry;
+use crate::trace::{TraceChunk, TraceReader};
+use bytes::Bytes;
+use futures_core::Stream;
+use std::io::{self, Cursor, Read, Seek, SeekFrom};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+use tokio::runtime::Handle;
+use tokio::sync::mpsc;
+
+/// Items buffered between the blocking parser and the consumer.
+const CHANNEL_CAPACITY: usize = 64;
+
+trait Source: Read + Seek + Send {}
+impl<T: Read + Seek + Send> Source for T {}
+
+type SyncReader = TraceReader<SharedSource>;
+
+/// Async counterpart of [`TraceReader`].
+///
+/// Each streaming method returns an independent `'static` stream, so
+/// streams can be moved into spawned tasks. Streams opened from the
+/// same reader run side by side, and [`resource`](Self::resource) can
+/// be called while a stream is being consumed.
+///
+/// ```no_run
+/// use futures_util::StreamExt;
+/// use playwright_rs_trace::AsyncTraceReader;
+///
+/// # async fn run(upload: bytes::Bytes) -> playwright_rs_trace::Result<()> {
+/// let reader = AsyncTraceReader::from_bytes(upload).await?;
+/// let mut actions = reader.actions();
+/// while let Some(action) = actions.next().await {
+///     let action = action?;
+///     if let Some(error) = &action.error {
+///         eprintln!("{}.{} failed: {}", action.class, action.method, error.message);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AsyncTraceReader {
+    inner: SyncReader,
+    chunks: Vec<TraceChunk>,
+}
+
+impl AsyncTraceReader {
+    /// Open a trace from an async source such as a `tokio::fs::File`.
+    /// The archive is read on demand — only the parts a stream touches
+    /// are fetched.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub async fn open<R>(reader: R) -> Result<Self>
+    where
+        R: AsyncRead + AsyncSeek + Unpin + Send + 'static,
+    {
+        let source = BlockingIo {
+            inner: reader,
+            handle: Handle::current(),
+        };
+        Self::open_source(Box::new(source)).await
+    }
+
+    /// Open a trace already held in memory, e.g. an HTTP upload body.
+    pub async fn from_bytes(bytes: Bytes) -> Result<Self> {
+        Self::open_source(Box::new(Cursor::new(bytes))).await
+    }
+
+    async fn open_source(source: Box<dyn Source>) -> Result<Self> {
+        let source = SharedSource::new(source);
+        let reader = blocking(move || TraceReader::open(source)).await?;
+        Ok(Self {
+            chunks: reader.chunks().to_vec(),
+            inner: reader,
+        })
+    }
+
+    /// The `context-options` metadata from the trace's first event.
+    pub fn context(&self) -> &ContextOptions {
+        &self.chunks[0].context
+    }
+
+    /// The recordings in this trace; see [`TraceReader::chunks`].
+    pub fn chunks(&self) -> &[TraceChunk] {
+        &self.chunks
+    }
+
+    /// Stream form of [`TraceReader::raw_events`].
+    pub fn raw_events(&self) -> impl Stream<Item = Result<RawEvent>> + Send + Unpin + 'static {
+        self.stream(|reader, emit| {
+            for item in reader.raw_events()? {
+                if !emit(item) {
+                    break;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Stream form of [`TraceReader::events`].
+    pub fn events(&self) -> impl Stream<Item = Result<TraceEvent>> + Send + Unpin + 'static {
+        self.stream(|reader, emit| {
+            for item in reader.events()? {
+                if !emit(item) {
+                    break;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Stream form of [`TraceReader::actions`].
+    pub fn actions(&self) -> impl Stream<Item = Result<Action>> + Send + Unpin + 'static {
+        self.stream(|reader, emit| {
+            for item in reader.actions()? {
+                if !emit(item) {
+                    break;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Stream form of [`TraceReader::network`].
+    pub fn network(&self) -> impl Stream<Item = Result<NetworkEntry>> + Send + Unpin + 'static {
+        self.stream(|reader, emit| {
+            for item in reader.network()? {
+                if !emit(item) {
+                    break;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Stream form of [`TraceReader::timeline`]. Like the sync
+    /// version, nothing is yielded until every chunk has been read.
+    pub fn timeline(&self) -> impl Stream<Item = Result<TimelineEntry>> + Send + Unpin + 'static {
+        self.stream(|reader, emit| {
+            for item in reader.timeline()? {
+                if !emit(Ok(item)) {
+                    break;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Async form of [`TraceReader::resource`].
+    pub async fn resource(&self, sha1: &str) -> Result<Vec<u8>> {
+        let mut reader = self.inner.clone();
+        let sha1 = sha1.to_string();
+        blocking(move || reader.resource(&sha1)).await
+    }
+
+    /// Run `produce` on the blocking pool, forwarding what it emits.
+    /// `emit` returns `false` once the consumer has dropped the stream,
+    /// so abandoning a stream stops the parse rather than finishing it.
+    fn stream<T, F>(&self, produce: F) -> ChannelStream<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut SyncReader, &mut dyn FnMut(Result<T>) -> bool) -> Result<()>
+            + Send
+            + 'static,
+    {
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+        let mut reader = self.inner.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut emit = |item| tx.blocking_send(item).is_ok();
+            if let Err(e) = produce(&mut reader, &mut emit) {
+                let _ = tx.blocking_send(Err(e));
+            }
+        });
+        ChannelStream { rx }
+    }
+}
+
+async fn blocking<T, F>(f: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| TraceError::Io(io::Error::other(e)))?
+}
+
+struct ChannelStream<T> {
+    rx: mpsc::Receiver<Result<T>>,
+}
+
+impl<T> Stream for ChannelStream<T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+/// Presents an async source as `Read + Seek` to code running on the
+/// blocking pool, by blocking that (non-executor) thread on each call.
+struct BlockingIo<R> {
+    inner: R,
+    handle: Handle,
+}
+
+impl<R: AsyncRead + Unpin> Read for BlockingIo<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.handle.block_on(self.inner.read(buf))
+    }
+}
+
+impl<R: AsyncSeek + Unpin> Seek for BlockingIo<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.handle.block_on(self.inner.seek(pos))
+    }
+}
+
+/// One source read through several independent cursors, one per clone.
+/// The source is locked for a single `read` or `seek` at a time, and is
+/// only re-seeked when another clone moved it since this one last read.
+#[derive(Clone)]
+struct SharedSource {
+    io: Arc<Mutex<SharedIo>>,
+    pos: u64,
+}
+
+struct SharedIo {
+    source: Box<dyn Source>,
+    /// Where `source` is positioned, or `None` after a failed call.
+    pos: Option<u64>,
+}
+
+impl SharedSource {
+    fn new(source: Box<dyn Source>) -> Self {
+        Self {
+            io: Arc::new(Mutex::new(SharedIo { source, pos: None })),
+            pos: 0,
+        }
+    }
+}
+
+fn lock(io: &Mutex<SharedIo>) -> std::sync::MutexGuard<'_, SharedIo> {
+    // A panic mid-call leaves `pos` as `None`, so the next call
+    // re-seeks and the source is still usable.
+    io.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+impl Read for SharedSource {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut io = lock(&self.io);
+        if io.pos.take() != Some(self.pos) {
+            io.source.seek(SeekFrom::Start(self.pos))?;
+        }
+        let n = io.source.read(buf)?;
+        self.pos += n as u64;
+        io.pos = Some(self.pos);
+        Ok(n)
+    }
+}
+
+impl Seek for SharedSource {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.pos = match pos {
+            SeekFrom::Start(offset) => offset,
+            SeekFrom::Current(delta) => self.pos.checked_add_signed(delta).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "seek before start of source")
+            })?,
+            SeekFrom::End(_) => {
+                let mut io = lock(&self.io);
+                io.pos = None;
+                let end = io.source.seek(pos)?;
+                io.pos = Some(end);
+                end
+            }
+        };
+        Ok(self.pos)
+    }
+}
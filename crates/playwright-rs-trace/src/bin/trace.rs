@@ -0,0 +1,669 @@
+//! `trace` — inspect a Playwright `.trace.zip` from the command line.
+//!
+//! A headless alternative to the Node trace viewer for machines (CI
+//! runners, containers) that have no Node install. Every subcommand
+//! reads the trace through [`playwright_rs_trace::TraceReader`], so it
+//! sees exactly what a library caller would.
+//!
+//! ```text
+//! trace summary  run.trace.zip
+//! trace actions  run.trace.zip
+//! trace errors   run.trace.zip
+//! trace export   run.trace.zip --format junit -o report.xml
+//! ```
+
+use clap::{Parser, Subcommand, ValueEnum};
+use playwright_rs_trace::diff::{self, ActionDivergence, DiffOptions, NetworkDivergence, Run};
+use playwright_rs_trace::{
+    Action, ConsoleEvent, ContextOptions, HarContent, HarExportOptions, NetworkEntry, SystemEvent,
+    TraceEvent,
+};
+use serde_json::{Value, json};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+type CliResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+#[derive(Parser)]
+#[command(name = "trace", version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    cmd: Cmd,
+}
+
+#[derive(Subcommand)]
+enum Cmd {
+    /// Context, browser, and action / request / error counts.
+    Summary { trace: PathBuf },
+    /// Table of actions in start order, with durations and errors.
+    Actions { trace: PathBuf },
+    /// HAR-like listing of recorded requests.
+    Network { trace: PathBuf },
+    /// Failed actions, page errors, and console errors.
+    Errors { trace: PathBuf },
+    /// Browser console output.
+    Console { trace: PathBuf },
+    /// Compare a candidate run against a baseline run of the same test.
+    Diff {
+        baseline: PathBuf,
+        candidate: PathBuf,
+        /// Report a timing regression only when slower by at least this
+        /// many milliseconds.
+        #[arg(long, default_value_t = 100.0)]
+        min_slowdown_ms: f64,
+    },
+    /// Convert the trace to a machine-readable format.
+    Export {
+        trace: PathBuf,
+        #[arg(long, value_enum)]
+        format: ExportFormat,
+        /// Write to this file instead of stdout.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// HAR only: how bodies are stored. `attach` writes them next to
+        /// the output file, or into it when the output ends in `.zip`.
+        #[arg(long, value_enum, default_value = "embed")]
+        content: BodyContent,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum BodyContent {
+    Embed,
+    Attach,
+    Omit,
+}
+
+impl From<BodyContent> for HarContent {
+    fn from(value: BodyContent) -> Self {
+        match value {
+            BodyContent::Embed => HarContent::Embed,
+            BodyContent::Attach => HarContent::Attach,
+            BodyContent::Omit => HarContent::Omit,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ExportFormat {
+    /// Context, actions, network, and console as one JSON document.
+    Json,
+    /// One `<testcase>` per action; failed actions carry a `<failure>`.
+    Junit,
+    /// HAR 1.2 document of the `trace.network` stream, replayable with
+    /// `route_from_har`.
+    Har,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli.cmd) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("trace: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cmd: Cmd) -> CliResult<()> {
+    let stdout = io::stdout();
+    match cmd {
+        Cmd::Summary { trace } => summary(&load(&trace)?, &mut stdout.lock()),
+        Cmd::Actions { trace } => actions(&load(&trace)?, &mut stdout.lock()),
+        Cmd::Network { trace } => network(&load(&trace)?, &mut stdout.lock()),
+        Cmd::Errors { trace } => errors(&load(&trace)?, &mut stdout.lock()),
+        Cmd::Console { trace } => console(&load(&trace)?, &mut stdout.lock()),
+        Cmd::Diff {
+            baseline,
+            candidate,
+            min_slowdown_ms,
+        } => diff(&baseline, &candidate, min_slowdown_ms, &mut stdout.lock()),
+        Cmd::Export {
+            trace,
+            format: ExportFormat::Har,
+            output,
+            content,
+        } => export_har(&trace, output.as_deref(), content.into()),
+        Cmd::Export {
+            trace,
+            format,
+            output,
+            content: _,
+        } => {
+            let loaded = load(&trace)?;
+            let mut out: Box<dyn Write> = match output {
+                Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+                None => Box::new(stdout.lock()),
+            };
+            match format {
+                ExportFormat::Json => export_json(&loaded, &mut out)?,
+                ExportFormat::Junit => export_junit(&loaded, &trace, &mut out)?,
+                ExportFormat::Har => unreachable!("handled above"),
+            }
+            out.flush()?;
+            Ok(())
+        }
+    }
+}
+
+/// Everything the subcommands need, read in one pass per stream.
+struct Loaded {
+    context: ContextOptions,
+    /// Sorted by `start_time` — the reader yields `after`-arrival order.
+    actions: Vec<Action>,
+    network: Vec<NetworkEntry>,
+    console: Vec<ConsoleEvent>,
+    page_errors: Vec<SystemEvent>,
+}
+
+fn load(path: &Path) -> CliResult<Loaded> {
+    let mut reader = playwright_rs_trace::open(path)?;
+    let mut actions = reader.actions()?.collect::<Result<Vec<_>, _>>()?;
+    actions.sort_by(|a, b| a.start_time.total_cmp(&b.start_time));
+    let network = reader.network()?.collect::<Result<Vec<_>, _>>()?;
+
+    let mut console = Vec::new();
+    let mut page_errors = Vec::new();
+    for event in reader.events()? {
+        match event? {
+            TraceEvent::Console(c) => console.push(c),
+            TraceEvent::Event(e) if e.method == "pageError" => page_errors.push(e),
+            _ => {}
+        }
+    }
+
+    Ok(Loaded {
+        context: reader.context().clone(),
+        actions,
+        network,
+        console,
+        page_errors,
+    })
+}
+
+impl Loaded {
+    fn failed_actions(&self) -> impl Iterator<Item = &Action> {
+        self.actions.iter().filter(|a| a.error.is_some())
+    }
+
+    fn console_errors(&self) -> impl Iterator<Item = &ConsoleEvent> {
+        self.console.iter().filter(|c| c.level == "error")
+    }
+
+    /// Offset from the context's start, so times read as "ms into the
+    /// test" rather than raw monotonic clock values.
+    fn rel(&self, time: f64) -> f64 {
+        time - self.context.monotonic_time
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Subcommands
+// ---------------------------------------------------------------------------
+
+fn summary(loaded: &Loaded, out: &mut impl Write) -> CliResult<()> {
+    let ctx = &loaded.context;
+    let failed = loaded.failed_actions().count();
+    let span = loaded
+        .actions
+        .iter()
+        .filter_map(|a| a.end_time)
+        .fold(None, |max: Option<f64>, t| {
+            Some(max.map_or(t, |m| m.max(t)))
+        })
+        .map(|end| loaded.rel(end));
+
+    writeln!(out, "trace:       v{}", ctx.version)?;
+    writeln!(out, "context:     {}", or_dash(&ctx.context_id))?;
+    writeln!(
+        out,
+        "browser:     {} on {}",
+        or_dash(&ctx.browser_name),
+        or_dash(&ctx.platform)
+    )?;
+    writeln!(
+        out,
+        "recorded:    playwright {} ({} client)",
+        or_dash(&ctx.playwright_version),
+        or_dash(&ctx.sdk_language)
+    )?;
+    writeln!(
+        out,
+        "actions:     {} ({failed} failed)",
+        loaded.actions.len()
+    )?;
+    writeln!(out, "requests:    {}", loaded.network.len())?;
+    writeln!(
+        out,
+        "console:     {} ({} errors)",
+        loaded.console.len(),
+        loaded.console_errors().count()
+    )?;
+    writeln!(out, "page errors: {}", loaded.page_errors.len())?;
+    if let Some(span) = span {
+        writeln!(out, "duration:    {}", ms(span))?;
+    }
+    Ok(())
+}
+
+fn actions(loaded: &Loaded, out: &mut impl Write) -> CliResult<()> {
+    writeln!(
+        out,
+        "{:>10}  {:>10}  {:<24}  {:<40}  ERROR",
+        "START", "DURATION", "ACTION", "TARGET"
+    )?;
+    for action in &loaded.actions {
+        let duration = match action.end_time {
+            Some(end) => ms(end - action.start_time),
+            None => "(no end)".to_string(),
+        };
+        let line = format!(
+            "{:>10}  {:>10}  {:<24}  {:<40}  {}",
+            ms(loaded.rel(action.start_time)),
+            duration,
+            action_name(action),
+            truncate(&action_target(action), 40),
+            action
+                .error
+                .as_ref()
+                .map(|e| first_line(&e.message))
+                .unwrap_or_default(),
+        );
+        writeln!(out, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+fn network(loaded: &Loaded, out: &mut impl Write) -> CliResult<()> {
+    writeln!(
+        out,
+        "{:<7} {:>6} {:>10} {:>10}  {:<24}  URL",
+        "METHOD", "STATUS", "SIZE", "TIME", "TYPE"
+    )?;
+    for entry in &loaded.network {
+        writeln!(
+            out,
+            "{:<7} {:>6} {:>10} {:>10}  {:<24}  {}",
+            entry.request.method,
+            entry
+                .response
+                .status
+                .map_or("-".to_string(), |s| s.to_string()),
+            entry
+                .response
+                .content
+                .size
+                .map_or("-".to_string(), |s| format!("{s} B")),
+            entry.time.map_or("-".to_string(), ms),
+            truncate(&entry.response.content.mime_type, 24),
+            entry.request.url,
+        )?;
+    }
+    Ok(())
+}
+
+fn errors(loaded: &Loaded, out: &mut impl Write) -> CliResult<()> {
+    let mut any = false;
+    for action in loaded.failed_actions() {
+        any = true;
+        let error = action.error.as_ref().expect("filtered on error");
+        writeln!(
+            out,
+            "[{}] action {} {}: {}",
+            ms(loaded.rel(action.start_time)),
+            action_name(action),
+            action_target(action),
+            error_text(&error.name, &error.message),
+        )?;
+    }
+    for event in &loaded.page_errors {
+        any = true;
+        writeln!(
+            out,
+            "[{}] page error: {}",
+            ms(loaded.rel(event.time)),
+            page_error_message(&event.params),
+        )?;
+    }
+    for message in loaded.console_errors() {
+        any = true;
+        writeln!(
+            out,
+            "[{}] console.error: {}{}",
+            ms(loaded.rel(message.time)),
+            message.text,
+            console_location(message),
+        )?;
+    }
+    if !any {
+        writeln!(out, "no errors")?;
+    }
+    Ok(())
+}
+
+fn console(loaded: &Loaded, out: &mut impl Write) -> CliResult<()> {
+    for message in &loaded.console {
+        writeln!(
+            out,
+            "[{}] {:<7} {}{}",
+            ms(loaded.rel(message.time)),
+            or_dash(&message.level),
+            message.text,
+            console_location(message),
+        )?;
+    }
+    Ok(())
+}
+
+fn diff(
+    baseline: &Path,
+    candidate: &Path,
+    min_slowdown_ms: f64,
+    out: &mut impl Write,
+) -> CliResult<()> {
+    let baseline = Run::load(&mut playwright_rs_trace::open(baseline)?)?;
+    let candidate = Run::load(&mut playwright_rs_trace::open(candidate)?)?;
+    let options = DiffOptions::default().min_slowdown_ms(min_slowdown_ms);
+    let report = diff::diff(&baseline, &candidate, &options);
+
+    if report.is_empty() {
+        writeln!(out, "no differences")?;
+        return Ok(());
+    }
+    for divergence in &report.actions {
+        match divergence {
+            ActionDivergence::Missing { key, occurrence } => {
+                writeln!(out, "- action {key} #{occurrence}")?
+            }
+            ActionDivergence::Extra { key, occurrence } => {
+                writeln!(out, "+ action {key} #{occurrence}")?
+            }
+            ActionDivergence::Outcome {
+                key,
+                occurrence,
+                baseline_error,
+                candidate_error,
+            } => writeln!(
+                out,
+                "~ action {key} #{occurrence}: {} -> {}",
+                outcome(baseline_error),
+                outcome(candidate_error),
+            )?,
+        }
+    }
+    for slow in &report.timing_regressions {
+        writeln!(
+            out,
+            "~ slower {} #{}: {} -> {}",
+            slow.key,
+            slow.occurrence,
+            ms(slow.baseline_ms),
+            ms(slow.candidate_ms),
+        )?;
+    }
+    for message in &report.new_console_errors {
+        writeln!(out, "+ console.error: {}", message.text)?;
+    }
+    for request in &report.network {
+        match request {
+            NetworkDivergence::Missing { method, url } => {
+                writeln!(out, "- request {method} {url}")?
+            }
+            NetworkDivergence::Extra { method, url } => writeln!(out, "+ request {method} {url}")?,
+            NetworkDivergence::Status {
+                method,
+                url,
+                baseline,
+                candidate,
+            } => writeln!(
+                out,
+                "~ request {method} {url}: {} -> {}",
+                status(*baseline),
+                status(*candidate),
+            )?,
+        }
+    }
+    Ok(())
+}
+
+fn outcome(error: &Option<String>) -> String {
+    match error {
+        Some(message) => format!("failed ({})", first_line(message)),
+        None => "ok".to_string(),
+    }
+}
+
+fn status(status: Option<u16>) -> String {
+    status.map_or("-".to_string(), |s| s.to_string())
+}
+
+// ---------------------------------------------------------------------------
+// Export formats
+// ---------------------------------------------------------------------------
+
+fn export_json(loaded: &Loaded, out: &mut impl Write) -> CliResult<()> {
+    let ctx = &loaded.context;
+    let doc = json!({
+        "context": {
+            "version": ctx.version,
+            "contextId": ctx.context_id,
+            "browserName": ctx.browser_name,
+            "playwrightVersion": ctx.playwright_version,
+            "platform": ctx.platform,
+            "sdkLanguage": ctx.sdk_language,
+            "wallTime": ctx.wall_time,
+            "monotonicTime": ctx.monotonic_time,
+            "options": ctx.options,
+        },
+        "actions": loaded.actions.iter().map(action_json).collect::<Vec<_>>(),
+        "network": loaded.network.iter().map(|e| &e.raw_snapshot).collect::<Vec<_>>(),
+        "console": loaded.console.iter().map(|c| json!({
+            "level": c.level,
+            "text": c.text,
+            "time": c.time,
+            "pageId": c.page_id,
+            "location": c.location.as_ref().map(|l| json!({
+                "url": l.url,
+                "lineNumber": l.line_number,
+                "columnNumber": l.column_number,
+            })),
+        })).collect::<Vec<_>>(),
+        "pageErrors": loaded.page_errors.iter().map(|e| json!({
+            "time": e.time,
+            "pageId": e.page_id,
+            "message": page_error_message(&e.params),
+        })).collect::<Vec<_>>(),
+    });
+    serde_json::to_writer_pretty(&mut *out, &doc)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+fn action_json(action: &Action) -> Value {
+    json!({
+        "callId": action.call_id,
+        "parentId": action.parent_id,
+        "class": action.class,
+        "method": action.method,
+        "title": action.title,
+        "pageId": action.page_id,
+        "startTime": action.start_time,
+        "endTime": action.end_time,
+        "duration": action.end_time.map(|end| end - action.start_time),
+        "params": action.params,
+        "result": action.result,
+        "error": action.error.as_ref().map(|e| json!({ "name": e.name, "message": e.message })),
+        "logs": action.logs.iter().map(|l| json!({ "time": l.time, "message": l.message })).collect::<Vec<_>>(),
+    })
+}
+
+fn export_junit(loaded: &Loaded, path: &Path, out: &mut impl Write) -> CliResult<()> {
+    let suite = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "trace".to_string());
+    let failures = loaded.failed_actions().count();
+    let total: f64 = loaded
+        .actions
+        .iter()
+        .filter_map(|a| a.end_time.map(|end| end - a.start_time))
+        .sum();
+
+    writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        out,
+        r#"<testsuites tests="{}" failures="{failures}" time="{:.3}">"#,
+        loaded.actions.len(),
+        total / 1000.0,
+    )?;
+    writeln!(
+        out,
+        r#"  <testsuite name="{}" tests="{}" failures="{failures}" time="{:.3}">"#,
+        xml_escape(&suite),
+        loaded.actions.len(),
+        total / 1000.0,
+    )?;
+    for action in &loaded.actions {
+        let duration = action.end_time.map_or(0.0, |end| end - action.start_time);
+        let target = action_target(action);
+        let name = if target.is_empty() {
+            action_name(action)
+        } else {
+            format!("{} {target}", action_name(action))
+        };
+        write!(
+            out,
+            r#"    <testcase classname="{}" name="{}" time="{:.3}""#,
+            xml_escape(&action.class),
+            xml_escape(&name),
+            duration / 1000.0,
+        )?;
+        match &action.error {
+            Some(error) => {
+                writeln!(out, ">")?;
+                writeln!(
+                    out,
+                    r#"      <failure type="{}" message="{}">{}</failure>"#,
+                    xml_escape(&error.name),
+                    xml_escape(first_line(&error.message)),
+                    xml_escape(&error.message),
+                )?;
+                writeln!(out, "    </testcase>")?;
+            }
+            None => writeln!(out, "/>")?,
+        }
+    }
+    writeln!(out, "  </testsuite>")?;
+    writeln!(out, "</testsuites>")?;
+    Ok(())
+}
+
+fn export_har(trace: &Path, output: Option<&Path>, content: HarContent) -> CliResult<()> {
+    let mut reader = playwright_rs_trace::open(trace)?;
+    let options = HarExportOptions::default().content(content);
+    let export = playwright_rs_trace::export_har(&mut reader, &options)?;
+    match output {
+        Some(path) if path.extension().is_some_and(|ext| ext == "zip") => export.save_zip(path)?,
+        Some(path) => export.save(path)?,
+        None if !export.attachments.is_empty() => {
+            return Err("`--content attach` needs `-o <file>` to write bodies next to".into());
+        }
+        None => {
+            let mut out = io::stdout().lock();
+            export.write_json(&mut out)?;
+            writeln!(out)?;
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Formatting helpers
+// ---------------------------------------------------------------------------
+
+fn action_name(action: &Action) -> String {
+    match &action.title {
+        Some(title) if !title.is_empty() => title.clone(),
+        _ => format!("{}.{}", action.class, action.method),
+    }
+}
+
+/// The selector or URL an action operated on, when it has one.
+fn action_target(action: &Action) -> String {
+    ["selector", "url"]
+        .iter()
+        .find_map(|key| action.params.get(*key).and_then(Value::as_str))
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn page_error_message(params: &Value) -> String {
+    // `pageError` carries a SerializedError: `{ error: { name, message,
+    // stack } }` for thrown Errors, `{ value }` for anything else thrown.
+    let error = params.get("error").unwrap_or(params);
+    error
+        .get("error")
+        .and_then(|e| e.get("message"))
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .unwrap_or_else(|| error.to_string())
+}
+
+fn console_location(message: &ConsoleEvent) -> String {
+    match &message.location {
+        Some(loc) if !loc.url.is_empty() => {
+            format!(" ({}:{}:{})", loc.url, loc.line_number, loc.column_number)
+        }
+        _ => String::new(),
+    }
+}
+
+fn error_text(name: &str, message: &str) -> String {
+    if name.is_empty() {
+        first_line(message).to_string()
+    } else {
+        format!("{name}: {}", first_line(message))
+    }
+}
+
+fn ms(value: f64) -> String {
+    format!("{value:.1}ms")
+}
+
+fn or_dash(s: &str) -> &str {
+    if s.is_empty() { "-" } else { s }
+}
+
+fn first_line(s: &str) -> &str {
+    s.lines().next().unwrap_or_default()
+}
+
+fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        s.to_string()
+    } else {
+        let kept: String = s.chars().take(max.saturating_sub(1)).collect();
+        format!("{kept}…")
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // XML 1.0 forbids most control characters even when escaped;
+            // error messages can carry ANSI colour codes from `expect`.
+            c if c.is_control() && !matches!(c, '\n' | '\r' | '\t') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
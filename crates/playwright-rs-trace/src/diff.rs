@@ -0,0 +1,399 @@
+//! Compare traces of the same test across runs.
+//!
+//! The question this answers is "why did the flaky test fail *this*
+//! time": load each run into a [`Run`], then [`diff`] a passing run
+//! against a failing one, or feed several runs to [`flakiness`] to see
+//! which actions misbehave in some of them.
+//!
+//! Actions are aligned by [`ActionKey`] — API name, selector, and
+//! call-site location — plus the occurrence index of that key within
+//! the run, so the third `click("#save")` in one run pairs with the
+//! third in the other. Timestamps and call ids are never compared: they
+//! differ on every run by construction.
+
+use crate::action::Action;
+use crate::error::Result;
+use crate::event::{ConsoleEvent, TraceEvent};
+use crate::network::NetworkEntry;
+use crate::trace::TraceReader;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{Read, Seek};
+
+/// Everything from one trace that the comparison looks at.
+#[derive(Debug, Clone)]
+pub struct Run {
+    /// Actions sorted by `start_time`.
+    pub actions: Vec<Action>,
+    pub network: Vec<NetworkEntry>,
+    pub console: Vec<ConsoleEvent>,
+}
+
+impl Run {
+    /// Read actions, network entries, and console messages from a trace.
+    pub fn load<R: Read + Seek>(reader: &mut TraceReader<R>) -> Result<Self> {
+        let mut actions = reader.actions()?.collect::<Result<Vec<_>>>()?;
+        actions.sort_by(|a, b| a.start_time.total_cmp(&b.start_time));
+        let network = reader.network()?.collect::<Result<Vec<_>>>()?;
+        let mut console = Vec::new();
+        for event in reader.events()? {
+            if let TraceEvent::Console(c) = event? {
+                console.push(c);
+            }
+        }
+        Ok(Self {
+            actions,
+            network,
+            console,
+        })
+    }
+
+    fn console_errors(&self) -> impl Iterator<Item = &ConsoleEvent> {
+        self.console.iter().filter(|c| c.level == "error")
+    }
+}
+
+/// Identity of an action across runs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ActionKey {
+    /// `class.method`, e.g. `Frame.click`.
+    pub api: String,
+    pub selector: Option<String>,
+    /// `file:line:column` of the innermost client frame, when recorded.
+    pub location: Option<String>,
+}
+
+impl ActionKey {
+    pub fn of(action: &Action) -> Self {
+        Self {
+            api: format!("{}.{}", action.class, action.method),
+            selector: action.selector().map(str::to_string),
+            location: action
+                .location()
+                .map(|f| format!("{}:{}:{}", f.file, f.line, f.column)),
+        }
+    }
+}
+
+impl fmt::Display for ActionKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.api)?;
+        if let Some(selector) = &self.selector {
+            write!(f, "({selector})")?;
+        }
+        if let Some(location) = &self.location {
+            write!(f, " at {location}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Thresholds for [`diff`]. A timing regression is reported only when
+/// the candidate is slower by at least `min_slowdown_ms` **and** by a
+/// factor of at least `min_ratio`, so fast actions jittering by a few
+/// milliseconds don't drown out the one that really stalled.
+#[derive(Debug, Clone)]
+pub struct DiffOptions {
+    pub min_slowdown_ms: f64,
+    pub min_ratio: f64,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        Self {
+            min_slowdown_ms: 100.0,
+            min_ratio: 1.5,
+        }
+    }
+}
+
+impl DiffOptions {
+    pub fn min_slowdown_ms(mut self, ms: f64) -> Self {
+        self.min_slowdown_ms = ms;
+        self
+    }
+
+    pub fn min_ratio(mut self, ratio: f64) -> Self {
+        self.min_ratio = ratio;
+        self
+    }
+}
+
+/// How one aligned action differs between baseline and candidate.
+#[derive(Debug, Clone)]
+pub enum ActionDivergence {
+    /// In the baseline, absent from the candidate.
+    Missing { key: ActionKey, occurrence: usize },
+    /// In the candidate, absent from the baseline.
+    Extra { key: ActionKey, occurrence: usize },
+    /// Present in both; succeeded in one and failed in the other, or
+    /// failed differently.
+    Outcome {
+        key: ActionKey,
+        occurrence: usize,
+        baseline_error: Option<String>,
+        candidate_error: Option<String>,
+    },
+}
+
+/// An aligned action that took noticeably longer in the candidate.
+#[derive(Debug, Clone)]
+pub struct TimingRegression {
+    pub key: ActionKey,
+    pub occurrence: usize,
+    pub baseline_ms: f64,
+    pub candidate_ms: f64,
+}
+
+/// How the requests differ between runs. Requests are aligned by
+/// method and URL plus occurrence index, like actions.
+#[derive(Debug, Clone)]
+pub enum NetworkDivergence {
+    Missing {
+        method: String,
+        url: String,
+    },
+    Extra {
+        method: String,
+        url: String,
+    },
+    Status {
+        method: String,
+        url: String,
+        baseline: Option<u16>,
+        candidate: Option<u16>,
+    },
+}
+
+/// Result of [`diff`]. Every list is in candidate order where that's
+/// defined, so the first item is the earliest point the runs diverge.
+#[derive(Debug, Clone, Default)]
+pub struct TraceDiff {
+    pub actions: Vec<ActionDivergence>,
+    pub timing_regressions: Vec<TimingRegression>,
+    /// `console.error` messages in the candidate with no identical
+    /// message in the baseline.
+    pub new_console_errors: Vec<ConsoleEvent>,
+    pub network: Vec<NetworkDivergence>,
+}
+
+impl TraceDiff {
+    /// `true` when nothing differs beyond the configured thresholds.
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+            && self.timing_regressions.is_empty()
+            && self.new_console_errors.is_empty()
+            && self.network.is_empty()
+    }
+}
+
+/// Compare a candidate run (typically the failing one) against a
+/// baseline.
+pub fn diff(baseline: &Run, candidate: &Run, options: &DiffOptions) -> TraceDiff {
+    let mut out = TraceDiff::default();
+
+    let base = index_by_occurrence(&baseline.actions, ActionKey::of);
+    let mut matched = vec![false; baseline.actions.len()];
+    let mut seen: HashMap<ActionKey, usize> = HashMap::new();
+    for action in &candidate.actions {
+        let key = ActionKey::of(action);
+        let occurrence = bump(&mut seen, &key);
+        let Some(&i) = base.get(&(key.clone(), occurrence)) else {
+            out.actions
+                .push(ActionDivergence::Extra { key, occurrence });
+            continue;
+        };
+        matched[i] = true;
+        let before = &baseline.actions[i];
+
+        let baseline_error = before.error.as_ref().map(|e| e.message.clone());
+        let candidate_error = action.error.as_ref().map(|e| e.message.clone());
+        if baseline_error != candidate_error {
+            out.actions.push(ActionDivergence::Outcome {
+                key: key.clone(),
+                occurrence,
+                baseline_error,
+                candidate_error,
+            });
+        }
+
+        if let (Some(b), Some(c)) = (duration(before), duration(action))
+            && c - b >= options.min_slowdown_ms
+            && c >= b * options.min_ratio
+        {
+            out.timing_regressions.push(TimingRegression {
+                key,
+                occurrence,
+                baseline_ms: b,
+                candidate_ms: c,
+            });
+        }
+    }
+    let mut seen: HashMap<ActionKey, usize> = HashMap::new();
+    for (action, matched) in baseline.actions.iter().zip(matched) {
+        let key = ActionKey::of(action);
+        let occurrence = bump(&mut seen, &key);
+        if !matched {
+            out.actions
+                .push(ActionDivergence::Missing { key, occurrence });
+        }
+    }
+
+    let mut known_errors: HashMap<&str, usize> = HashMap::new();
+    for message in baseline.console_errors() {
+        *known_errors.entry(message.text.as_str()).or_default() += 1;
+    }
+    for message in candidate.console_errors() {
+        match known_errors.get_mut(message.text.as_str()) {
+            Some(n) if *n > 0 => *n -= 1,
+            _ => out.new_console_errors.push(message.clone()),
+        }
+    }
+
+    let request_key = |e: &NetworkEntry| (e.request.method.clone(), e.request.url.clone());
+    let base = index_by_occurrence(&baseline.network, request_key);
+    let mut matched = vec![false; baseline.network.len()];
+    let mut seen = HashMap::new();
+    for entry in &candidate.network {
+        let key = request_key(entry);
+        let occurrence = bump(&mut seen, &key);
+        let (method, url) = key.clone();
+        match base.get(&(key, occurrence)) {
+            Some(&i) => {
+                matched[i] = true;
+                let before = baseline.network[i].response.status;
+                if before != entry.response.status {
+                    out.network.push(NetworkDivergence::Status {
+                        method,
+                        url,
+                        baseline: before,
+                        candidate: entry.response.status,
+                    });
+                }
+            }
+            None => out.network.push(NetworkDivergence::Extra { method, url }),
+        }
+    }
+    for (entry, matched) in baseline.network.iter().zip(matched) {
+        if !matched {
+            out.network.push(NetworkDivergence::Missing {
+                method: entry.request.method.clone(),
+                url: entry.request.url.clone(),
+            });
+        }
+    }
+
+    out
+}
+
+/// Per-action statistics across several runs of the same test.
+#[derive(Debug, Clone)]
+pub struct ActionStats {
+    pub key: ActionKey,
+    pub occurrence: usize,
+    /// Runs in which this action appeared at all.
+    pub present: usize,
+    /// Runs in which it appeared and failed.
+    pub failed: usize,
+    /// Durations of the completed occurrences, in run order.
+    pub durations_ms: Vec<f64>,
+}
+
+impl ActionStats {
+    /// Appeared in some runs but not all, or failed in some but not all
+    /// of the runs it appeared in.
+    pub fn is_flaky(&self, runs: usize) -> bool {
+        (self.present > 0 && self.present < runs) || (self.failed > 0 && self.failed < self.present)
+    }
+
+    pub fn mean_ms(&self) -> Option<f64> {
+        if self.durations_ms.is_empty() {
+            None
+        } else {
+            Some(self.durations_ms.iter().sum::<f64>() / self.durations_ms.len() as f64)
+        }
+    }
+
+    pub fn max_ms(&self) -> Option<f64> {
+        self.durations_ms.iter().copied().reduce(f64::max)
+    }
+}
+
+/// Result of [`flakiness`].
+#[derive(Debug, Clone)]
+pub struct FlakinessReport {
+    pub runs: usize,
+    /// One entry per aligned action, in first-seen order.
+    pub actions: Vec<ActionStats>,
+}
+
+impl FlakinessReport {
+    /// Actions whose presence or outcome varied between runs.
+    pub fn flaky(&self) -> impl Iterator<Item = &ActionStats> {
+        self.actions.iter().filter(|a| a.is_flaky(self.runs))
+    }
+}
+
+/// Align actions across any number of runs and tally how consistently
+/// each one appears and succeeds.
+pub fn flakiness(runs: &[Run]) -> FlakinessReport {
+    let mut index: HashMap<(ActionKey, usize), usize> = HashMap::new();
+    let mut actions: Vec<ActionStats> = Vec::new();
+    for run in runs {
+        let mut seen = HashMap::new();
+        for action in &run.actions {
+            let key = ActionKey::of(action);
+            let occurrence = bump(&mut seen, &key);
+            let slot = *index.entry((key.clone(), occurrence)).or_insert_with(|| {
+                actions.push(ActionStats {
+                    key,
+                    occurrence,
+                    present: 0,
+                    failed: 0,
+                    durations_ms: Vec::new(),
+                });
+                actions.len() - 1
+            });
+            let stats = &mut actions[slot];
+            stats.present += 1;
+            if action.error.is_some() {
+                stats.failed += 1;
+            }
+            if let Some(d) = duration(action) {
+                stats.durations_ms.push(d);
+            }
+        }
+    }
+    FlakinessReport {
+        runs: runs.len(),
+        actions,
+    }
+}
+
+fn duration(action: &Action) -> Option<f64> {
+    action.end_time.map(|end| end - action.start_time)
+}
+
+/// Returns the occurrence index of `key` so far and advances it.
+fn bump<K: Clone + Eq + std::hash::Hash>(seen: &mut HashMap<K, usize>, key: &K) -> usize {
+    let n = seen.entry(key.clone()).or_default();
+    let occurrence = *n;
+    *n += 1;
+    occurrence
+}
+
+fn index_by_occurrence<T, K: Clone + Eq + std::hash::Hash>(
+    items: &[T],
+    key: impl Fn(&T) -> K,
+) -> HashMap<(K, usize), usize> {
+    let mut seen = HashMap::new();
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let k = key(item);
+            let occurrence = bump(&mut seen, &k);
+            ((k, occurrence), i)
+        })
+        .collect()
+}
@@ -20,6 +20,12 @@ pub enum TraceError {
     #[error("missing entry: {0}")]
     MissingEntry(&'static str),
 
+    #[error("missing resource: resources/{0}")]
+    MissingResource(String),
+
+    #[error("no chunk {index}: trace has {count}")]
+    NoSuchChunk { index: usize, count: usize },
+
     #[error("unsupported trace version {found}, expected {expected}")]
     UnsupportedVersion { found: u32, expected: u32 },
 
@@ -75,6 +75,30 @@ pub enum TraceEvent {
     Unknown(RawEvent),
 }
 
+impl TraceEvent {
+    /// The page the event belongs to, for kinds that record one.
+    pub fn page_id(&self) -> Option<&str> {
+        match self {
+            TraceEvent::Before(b) => b.page_id.as_deref(),
+            TraceEvent::Console(c) => c.page_id.as_deref(),
+            TraceEvent::Event(e) => e.page_id.as_deref(),
+            TraceEvent::FrameSnapshot(f) => Some(&f.page_id),
+            TraceEvent::ScreencastFrame(s) => Some(&s.page_id),
+            TraceEvent::Unknown(raw) => raw.as_value().get("pageId").and_then(Value::as_str),
+            _ => None,
+        }
+    }
+
+    /// The frame the event belongs to. Only frame snapshots record one.
+    pub fn frame_id(&self) -> Option<&str> {
+        match self {
+            TraceEvent::FrameSnapshot(f) => Some(&f.frame_id),
+            TraceEvent::Unknown(raw) => raw.as_value().get("frameId").and_then(Value::as_str),
+            _ => None,
+        }
+    }
+}
+
 // Internal enum used purely for serde-driven dispatch on the `type`
 // field. Public callers always see `TraceEvent`.
 #[derive(Deserialize)]
@@ -154,6 +178,23 @@ pub struct BeforeEvent {
     pub before_snapshot: Option<String>,
     pub step_id: Option<String>,
     pub parent_id: Option<String>,
+    /// Client call stack, innermost frame first. Empty when the client
+    /// sent no location for the call.
+    #[serde(default)]
+    pub stack: Vec<StackFrame>,
+}
+
+/// One frame of an action's client-side call stack.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StackFrame {
+    pub file: String,
+    #[serde(default)]
+    pub line: u32,
+    #[serde(default)]
+    pub column: u32,
+    #[serde(default)]
+    pub function: Option<String>,
 }
 
 /// Optional input-coordinate / input-snapshot reference attached to an
@@ -196,7 +237,10 @@ pub struct ConsoleEvent {
     /// `"log"`, `"warn"`, `"error"`, `"info"`, `"debug"`, etc. Kept as
     /// a string because Playwright extends this set; matching at the
     /// call site keeps us forward-compatible.
-    #[serde(rename = "type", default)]
+    ///
+    /// Read from `messageType`: the event's own `type` field is the
+    /// `"console"` discriminator and is consumed by the tagged dispatch.
+    #[serde(rename = "messageType", default)]
     pub level: String,
     #[serde(default)]
     pub text: String,
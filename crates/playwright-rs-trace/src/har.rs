@@ -0,0 +1,329 @@
+//! Export the `trace.network` stream as a HAR 1.2 document.
+//!
+//! The trace already records HAR-shaped entries; what it lacks for a
+//! standalone HAR is the `log` envelope, the `pages` list, and the
+//! bodies, which live in `resources/<sha1>` rather than inline. The
+//! exporter fills those in so the result can be opened in devtools or
+//! handed to `Page::route_from_har` to replay a CI failure offline.
+//!
+//! Entries are copied from [`NetworkEntry::raw_snapshot`] rather than
+//! rebuilt from the typed fields, so HAR fields and Playwright
+//! extensions the parser doesn't model survive the round trip.
+
+use crate::error::{Result, TraceError};
+use crate::network::NetworkEntry;
+use crate::trace::TraceReader;
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
+use serde_json::{Map, Value, json};
+use std::collections::{HashMap, HashSet};
+use std::io::{Read, Seek, Write};
+use std::path::Path;
+
+/// How response and request bodies are stored in the exported HAR.
+///
+/// Mirrors `playwright-rs`'s `HarContent` for recorded HARs, so an
+/// exported trace and a recorded HAR look alike to a consumer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HarContent {
+    /// Drop bodies; keep sizes and MIME types.
+    Omit,
+    /// Inline bodies: UTF-8 text verbatim, anything else as base64.
+    #[default]
+    Embed,
+    /// Reference bodies via `_file`, stored alongside the HAR (or as zip
+    /// entries by [`HarExport::save_zip`]).
+    Attach,
+}
+
+/// Options for [`export_har`].
+#[derive(Debug, Clone, Default)]
+pub struct HarExportOptions {
+    pub content: HarContent,
+}
+
+impl HarExportOptions {
+    /// How bodies are stored (`Omit` / `Embed` / `Attach`).
+    pub fn content(mut self, content: HarContent) -> Self {
+        self.content = content;
+        self
+    }
+}
+
+/// A HAR document exported from a trace, plus the body files it
+/// references when exported with [`HarContent::Attach`].
+#[derive(Debug, Clone)]
+pub struct HarExport {
+    /// The HAR document (`{ "log": { ... } }`).
+    pub har: Value,
+    /// `(file name, bytes)` for every `_file` reference in [`har`](Self::har).
+    /// Empty unless exported with [`HarContent::Attach`].
+    pub attachments: Vec<(String, Vec<u8>)>,
+}
+
+impl HarExport {
+    /// Serialize the HAR document as pretty-printed JSON.
+    pub fn write_json<W: Write>(&self, writer: W) -> Result<()> {
+        serde_json::to_writer_pretty(writer, &self.har)
+            .map_err(|source| TraceError::Json { line: 0, source })
+    }
+
+    /// Write the HAR to `path`, with attachments as sibling files in
+    /// the same directory (where `route_from_har` resolves `_file`).
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        for (name, bytes) in &self.attachments {
+            std::fs::write(dir.join(name), bytes)?;
+        }
+        let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+        self.write_json(file)
+    }
+
+    /// Write a zip holding the HAR as `har.har` alongside its
+    /// attachments, the layout Playwright itself records for a `.zip`
+    /// HAR path.
+    pub fn save_zip<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let file = std::fs::File::create(path)?;
+        let mut zip = zip::ZipWriter::new(file);
+        let opts = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+        zip.start_file("har.har", opts)?;
+        self.write_json(&mut zip)?;
+        for (name, bytes) in &self.attachments {
+            zip.start_file(name.as_str(), opts)?;
+            zip.write_all(bytes)?;
+        }
+        zip.finish()?;
+        Ok(())
+    }
+}
+
+/// Build a HAR 1.2 document from a trace's network stream.
+///
+/// Bodies are resolved from the trace's `resources/` directory
+/// according to [`HarExportOptions::content`]; a body the trace
+/// references but doesn't contain (a truncated zip) is treated as
+/// absent rather than failing the export.
+pub fn export_har<R: Read + Seek>(
+    reader: &mut TraceReader<R>,
+    options: &HarExportOptions,
+) -> Result<HarExport> {
+    let entries = reader.network()?.collect::<Result<Vec<NetworkEntry>>>()?;
+    let context = reader.context().clone();
+
+    let mut attachments: Vec<(String, Vec<u8>)> = Vec::new();
+    let mut attached: HashSet<String> = HashSet::new();
+    let mut har_entries = Vec::with_capacity(entries.len());
+    for entry in &entries {
+        let mut har_entry = match &entry.raw_snapshot {
+            Value::Object(map) => map.clone(),
+            _ => Map::new(),
+        };
+        fill_entry_defaults(&mut har_entry);
+
+        if let Some(request) = har_entry.get_mut("request").and_then(Value::as_object_mut) {
+            let content_type = header_value(request, "content-type");
+            if let Some(post_data) = request.get_mut("postData").and_then(Value::as_object_mut) {
+                let sha1 = post_data
+                    .remove("_sha1")
+                    .and_then(|v| v.as_str().map(str::to_string));
+                post_data
+                    .entry("mimeType")
+                    .or_insert_with(|| Value::String(content_type.unwrap_or_default()));
+                if let Some(sha1) = sha1 {
+                    let body = load_body(reader, options.content, &sha1);
+                    match (options.content, body) {
+                        (HarContent::Attach, Some(bytes)) => {
+                            post_data.insert("_file".into(), Value::String(sha1.clone()));
+                            attach(&mut attachments, &mut attached, sha1, bytes);
+                        }
+                        (HarContent::Embed, Some(bytes)) => embed(post_data, bytes),
+                        _ => {}
+                    }
+                }
+                post_data
+                    .entry("text")
+                    .or_insert_with(|| Value::String(String::new()));
+            }
+        }
+
+        if let Some(content) = har_entry
+            .get_mut("response")
+            .and_then(|r| r.get_mut("content"))
+            .and_then(Value::as_object_mut)
+        {
+            let sha1 = content
+                .remove("_sha1")
+                .and_then(|v| v.as_str().map(str::to_string));
+            if let Some(sha1) = sha1 {
+                let body = load_body(reader, options.content, &sha1);
+                match (options.content, body) {
+                    (HarContent::Attach, Some(bytes)) => {
+                        content.insert("_file".into(), Value::String(sha1.clone()));
+                        attach(&mut attachments, &mut attached, sha1, bytes);
+                    }
+                    (HarContent::Embed, Some(bytes)) => embed(content, bytes),
+                    _ => {}
+                }
+            }
+        }
+
+        har_entries.push(Value::Object(har_entry));
+    }
+
+    let har = json!({
+        "log": {
+            "version": "1.2",
+            "creator": {
+                "name": env!("CARGO_PKG_NAME"),
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "browser": {
+                "name": context.browser_name,
+                "version": "",
+            },
+            "pages": pages(&entries),
+            "entries": har_entries,
+        }
+    });
+
+    Ok(HarExport { har, attachments })
+}
+
+/// Stores a body as `text`: as is if it is UTF-8, otherwise base64 with
+/// `"encoding": "base64"`, so binary bodies survive the export.
+fn embed(target: &mut Map<String, Value>, bytes: Vec<u8>) {
+    match String::from_utf8(bytes) {
+        Ok(text) => {
+            target.insert("text".into(), Value::String(text));
+        }
+        Err(e) => {
+            target.insert("text".into(), Value::String(STANDARD.encode(e.as_bytes())));
+            target.insert("encoding".into(), Value::String("base64".into()));
+        }
+    }
+}
+
+fn load_body<R: Read + Seek>(
+    reader: &mut TraceReader<R>,
+    content: HarContent,
+    sha1: &str,
+) -> Option<Vec<u8>> {
+    match content {
+        HarContent::Omit => None,
+        HarContent::Embed | HarContent::Attach => reader.resource(sha1).ok(),
+    }
+}
+
+fn attach(
+    attachments: &mut Vec<(String, Vec<u8>)>,
+    attached: &mut HashSet<String>,
+    name: String,
+    bytes: Vec<u8>,
+) {
+    // Resources are SHA-1 addressed, so a body shared by several
+    // entries (a cached script, a retried POST) is written once.
+    if attached.insert(name.clone()) {
+        attachments.push((name, bytes));
+    }
+}
+
+/// One `pages` record per distinct `pageref`, in first-seen order. The
+/// trace doesn't record page titles, so the page's first document URL
+/// stands in, falling back to the page id.
+fn pages(entries: &[NetworkEntry]) -> Vec<Value> {
+    let mut order: Vec<&str> = Vec::new();
+    let mut pages: HashMap<&str, (String, Option<String>)> = HashMap::new();
+    for entry in entries {
+        let Some(page_ref) = entry.page_ref.as_deref() else {
+            continue;
+        };
+        let is_document = entry
+            .raw_snapshot
+            .get("_resourceType")
+            .and_then(Value::as_str)
+            == Some("document");
+        let page = pages.entry(page_ref).or_insert_with(|| {
+            order.push(page_ref);
+            (entry.started_date_time.clone(), None)
+        });
+        if is_document && page.1.is_none() {
+            page.1 = Some(entry.request.url.clone());
+        }
+    }
+    order
+        .into_iter()
+        .map(|id| {
+            let (started, title) = &pages[id];
+            json!({
+                "startedDateTime": started,
+                "id": id,
+                "title": title.as_deref().unwrap_or(id),
+                "pageTimings": { "onContentLoad": -1, "onLoad": -1 },
+            })
+        })
+        .collect()
+}
+
+/// The trace omits HAR-required members that are always empty for it
+/// (`cache`) and, for synthetic or older traces, some the spec requires
+/// on every entry. Fill them so strict HAR consumers accept the file.
+fn fill_entry_defaults(entry: &mut Map<String, Value>) {
+    entry.entry("cache").or_insert_with(|| json!({}));
+    entry.entry("time").or_insert(json!(-1));
+
+    let timings = entry
+        .entry("timings")
+        .or_insert_with(|| json!({}))
+        .as_object_mut();
+    if let Some(timings) = timings {
+        // `send`, `wait` and `receive` are required and may not be -1.
+        for key in ["send", "wait", "receive"] {
+            timings.entry(key).or_insert(json!(0));
+        }
+        for key in ["blocked", "dns", "connect", "ssl"] {
+            timings.entry(key).or_insert(json!(-1));
+        }
+    }
+
+    for side in ["request", "response"] {
+        if let Some(obj) = entry.get_mut(side).and_then(Value::as_object_mut) {
+            obj.entry("cookies").or_insert_with(|| json!([]));
+            obj.entry("headers").or_insert_with(|| json!([]));
+            obj.entry("headersSize").or_insert(json!(-1));
+            obj.entry("bodySize").or_insert(json!(-1));
+            obj.entry("httpVersion").or_insert(json!(""));
+        }
+    }
+    if let Some(request) = entry.get_mut("request").and_then(Value::as_object_mut) {
+        request.entry("queryString").or_insert_with(|| json!([]));
+    }
+    if let Some(response) = entry.get_mut("response").and_then(Value::as_object_mut) {
+        response.entry("redirectURL").or_insert(json!(""));
+        response.entry("statusText").or_insert(json!(""));
+        if let Some(content) = response
+            .entry("content")
+            .or_insert_with(|| json!({}))
+            .as_object_mut()
+        {
+            content.entry("size").or_insert(json!(-1));
+            content.entry("mimeType").or_insert(json!("x-unknown"));
+        }
+    }
+}
+
+fn header_value(request: &Map<String, Value>, name: &str) -> Option<String> {
+    request
+        .get("headers")?
+        .as_array()?
+        .iter()
+        .find(|h| {
+            h.get("name")
+                .and_then(Value::as_str)
+                .is_some_and(|n| n.eq_ignore_ascii_case(name))
+        })
+        .and_then(|h| h.get("value"))
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
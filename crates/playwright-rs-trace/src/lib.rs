@@ -58,13 +58,24 @@
 //!   case; use this unless you specifically need the raw event stream.
 //! - [`network`] — `NetworkEntry`s from the `trace.network` HAR-shape
 //!   stream (request / response pairs). Independent of the action
-//!   stream — collect-and-sort if you need a merged chronological
-//!   view.
+//!   stream — use [`timeline`] for a merged chronological view.
 //!
 //! [`raw_events`]: TraceReader::raw_events
 //! [`events`]: TraceReader::events
 //! [`actions`]: TraceReader::actions
 //! [`network`]: TraceReader::network
+//! [`timeline`]: TraceReader::timeline
+//!
+//! # Multi-chunk traces
+//!
+//! A zip can hold several recordings — one per context when a test
+//! runner merges them, or one per `start_chunk`/`stop_chunk` slice —
+//! as `0-trace.trace`, `1-trace.trace`, …. [`TraceReader::chunks`]
+//! lists them with their `context-options`; the entry points above
+//! walk all of them in order, and the `chunk_*` variants read one.
+//! [`TraceReader::timeline`] merges actions and requests from every
+//! chunk onto the wall clock, attributed to chunk, context, page and
+//! frame.
 //!
 //! # Forward compatibility
 //!
@@ -74,28 +85,52 @@
 //! the parser models and route anything else to
 //! [`TraceEvent::Unknown`] so nothing is silently dropped.
 //!
+//! # Async
+//!
+//! With the `tokio` feature, `AsyncTraceReader` exposes the same
+//! entry points as `Stream`s over an `AsyncRead + AsyncSeek` source or
+//! an in-memory `Bytes` buffer, for services that receive traces over
+//! the network. Parsing runs on tokio's blocking pool, so it never
+//! stalls the executor.
+//!
+//! # Comparing runs
+//!
+//! The [`diff`] module aligns the actions of two or more traces of the
+//! same test and reports where a flaky run diverged: missing or extra
+//! actions, changed outcomes, timing regressions, new console errors,
+//! and differing requests.
+//!
 //! See the crate `README.md` for the full slice-plan and roadmap.
 //!
 //! [pw]: https://playwright.dev/
 
 mod action;
+#[cfg(feature = "tokio")]
+mod async_reader;
+pub mod diff;
 mod error;
 mod event;
+mod har;
 mod jsonl;
 mod network;
+mod timeline;
 mod trace;
 
 pub use action::{Action, ActionStream, LogLine};
+#[cfg(feature = "tokio")]
+pub use async_reader::AsyncTraceReader;
 pub use error::{Result, TraceError};
 pub use event::{
     ActionError, AfterEvent, BeforeEvent, ConsoleEvent, ConsoleLocation, ContextOptions,
     FrameSnapshotEvent, InputEvent, LogEvent, Point, RawEvent, ResourceOverride,
-    ScreencastFrameEvent, SystemEvent, TraceEvent, Viewport,
+    ScreencastFrameEvent, StackFrame, SystemEvent, TraceEvent, Viewport,
 };
+pub use har::{HarContent, HarExport, HarExportOptions, export_har};
 pub use network::{
     HeaderEntry, NetworkEntry, RequestPostData, RequestSnapshot, ResponseContent, ResponseSnapshot,
 };
-pub use trace::{TraceReader, open};
+pub use timeline::{TimelineEntry, TimelineItem};
+pub use trace::{TraceChunk, TraceReader, open};
 
 // crates.io renders README.md, so its example is the first code a prospective
 // user copies — and it was marked `ignore`, which rustdoc never compiles. It
@@ -0,0 +1,68 @@
+//! [`TimelineEntry`] — actions and network entries from every chunk of
+//! a trace on one clock. Built by [`TraceReader::timeline`].
+//!
+//! [`TraceReader::timeline`]: crate::TraceReader::timeline
+
+use crate::action::Action;
+use crate::network::NetworkEntry;
+use crate::trace::TraceChunk;
+
+/// What a [`TimelineEntry`] records.
+#[derive(Debug, Clone)]
+pub enum TimelineItem {
+    Action(Action),
+    Network(NetworkEntry),
+}
+
+/// One action or request, attributed to where it happened.
+#[derive(Debug, Clone)]
+pub struct TimelineEntry {
+    /// Index into [`TraceReader::chunks`](crate::TraceReader::chunks).
+    pub chunk: usize,
+    /// `contextId` of the chunk's `context-options`.
+    pub context_id: String,
+    pub page_id: Option<String>,
+    /// The frame a request was issued from (`_frameref`). Playwright
+    /// doesn't record a frame on actions, so it is `None` for those.
+    pub frame_id: Option<String>,
+    /// Wall-clock milliseconds since the Unix epoch: an action's start,
+    /// a request's `_monotonicTime`. `None` for requests recorded
+    /// without trace info.
+    pub time: Option<f64>,
+    pub item: TimelineItem,
+}
+
+impl TimelineEntry {
+    pub(crate) fn action(index: usize, chunk: &TraceChunk, action: Action) -> Self {
+        Self {
+            chunk: index,
+            context_id: chunk.context.context_id.clone(),
+            page_id: action.page_id.clone(),
+            frame_id: None,
+            time: Some(chunk.wall_time(action.start_time)),
+            item: TimelineItem::Action(action),
+        }
+    }
+
+    pub(crate) fn network(index: usize, chunk: &TraceChunk, entry: NetworkEntry) -> Self {
+        Self {
+            chunk: index,
+            context_id: chunk.context.context_id.clone(),
+            page_id: entry.page_ref.clone(),
+            frame_id: entry.frame_ref.clone(),
+            time: entry.monotonic_time.map(|t| chunk.wall_time(t)),
+            item: TimelineItem::Network(entry),
+        }
+    }
+}
+
+/// Chronological, untimed entries last. Stable, so ties keep chunk
+/// order and actions ahead of the requests they triggered.
+pub(crate) fn sort(entries: &mut [TimelineEntry]) {
+    entries.sort_by(|a, b| match (a.time, b.time) {
+        (Some(a), Some(b)) => a.total_cmp(&b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+}
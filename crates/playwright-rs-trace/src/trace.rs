@@ -6,27 +6,85 @@ use crate::error::{Result, TraceError};
 use crate::event::{ContextOptions, RawEvent, TraceEvent};
 use crate::jsonl::JsonLines;
 use crate::network::NetworkEntry;
-use std::io::{BufRead, BufReader, Read, Seek};
+use crate::timeline::{self, TimelineEntry};
+use serde_json::{Map, Value};
+use std::io::{BufRead, BufReader, Cursor, Read, Seek};
 use std::path::Path;
 use zip::ZipArchive;
+use zip::read::ZipFile;
 
 const TRACE_ENTRY: &str = "trace.trace";
-const NETWORK_ENTRY: &str = "trace.network";
+const TRACE_SUFFIX: &str = ".trace";
+const NETWORK_SUFFIX: &str = ".network";
 const SUPPORTED_VERSION: u32 = 8;
 const RESOURCE_SNAPSHOT_KIND: &str = "resource-snapshot";
+const RESOURCES_DIR: &str = "resources/";
+
+/// One recording inside a trace zip — a `<name>.trace` event stream,
+/// its `<name>.network` companion, and the `context-options` it opens
+/// with.
+///
+/// A trace from a single `Tracing::start`/`stop` has one chunk named
+/// `trace`. Zips holding several chunks or contexts (a test runner
+/// merging each context's recording, or one context traced in
+/// `start_chunk`/`stop_chunk` slices) number them `0-trace`,
+/// `1-trace`, ….
+#[derive(Debug, Clone)]
+pub struct TraceChunk {
+    /// Entry-name stem shared by the chunk's files, e.g. `trace` or
+    /// `1-trace`.
+    pub name: String,
+    pub context: ContextOptions,
+    has_network: bool,
+}
+
+impl TraceChunk {
+    /// Name of the chunk's event stream in the zip.
+    pub fn trace_entry(&self) -> String {
+        format!("{}{TRACE_SUFFIX}", self.name)
+    }
+
+    /// Name of the chunk's network stream in the zip, if it has one.
+    pub fn network_entry(&self) -> Option<String> {
+        self.has_network
+            .then(|| format!("{}{NETWORK_SUFFIX}", self.name))
+    }
+
+    /// Convert a monotonic timestamp recorded in this chunk (action
+    /// `start_time`, network `_monotonic_time`) to wall-clock
+    /// milliseconds since the Unix epoch. Chunks recorded by different
+    /// processes only share a clock after this conversion.
+    pub fn wall_time(&self, monotonic: f64) -> f64 {
+        if self.context.wall_time == 0.0 {
+            // Older recordings without the anchor: leave the clock as is.
+            return monotonic;
+        }
+        self.context.wall_time + (monotonic - self.context.monotonic_time)
+    }
+}
 
 /// Streaming reader over a Playwright trace zip.
 ///
-/// Opens the archive and parses the first event (`context-options`)
-/// eagerly so the trace's metadata is available without consuming the
-/// rest of the stream. Subsequent calls to
+/// Opens the archive and parses each chunk's first event
+/// (`context-options`) eagerly so the trace's metadata is available
+/// without consuming the rest of the stream. Subsequent calls to
 /// [`raw_events`](Self::raw_events), [`events`](Self::events), or
 /// [`actions`](Self::actions) iterate the remaining events lazily;
 /// each call extracts a fresh JSONL stream from the archive, so the
 /// reader can be iterated multiple times.
+///
+/// The whole-trace iterators walk every [chunk](Self::chunks) in
+/// order. A single-chunk trace is streamed straight out of the zip;
+/// with several chunks one chunk's stream is decompressed into memory
+/// at a time. The `chunk_*` variants stream one chunk without
+/// buffering.
+///
+/// A reader over a `Clone` source is itself `Clone`; the copy shares the
+/// parsed archive index but reads through its own source.
+#[derive(Clone)]
 pub struct TraceReader<R: Read + Seek> {
     zip: ZipArchive<R>,
-    context: ContextOptions,
+    chunks: Vec<TraceChunk>,
 }
 
 impl<R: Read + Seek> TraceReader<R> {
@@ -34,32 +92,61 @@ impl<R: Read + Seek> TraceReader<R> {
     /// file-on-disk case prefer [`crate::open`].
     pub fn open(reader: R) -> Result<Self> {
         let mut zip = ZipArchive::new(reader)?;
-        let context = parse_context(&mut zip)?;
-        if context.version != SUPPORTED_VERSION {
-            return Err(TraceError::UnsupportedVersion {
-                found: context.version,
-                expected: SUPPORTED_VERSION,
+        let mut names: Vec<String> = zip
+            .file_names()
+            .filter(|name| !name.starts_with(RESOURCES_DIR))
+            .filter_map(|name| name.strip_suffix(TRACE_SUFFIX))
+            .map(str::to_string)
+            .collect();
+        names.sort_by_key(|name| chunk_ordinal(name));
+        if names.is_empty() {
+            return Err(TraceError::MissingEntry(TRACE_ENTRY));
+        }
+
+        let mut chunks = Vec::with_capacity(names.len());
+        for name in names {
+            let context = parse_context(&mut zip, &format!("{name}{TRACE_SUFFIX}"))?;
+            if context.version != SUPPORTED_VERSION {
+                return Err(TraceError::UnsupportedVersion {
+                    found: context.version,
+                    expected: SUPPORTED_VERSION,
+                });
+            }
+            let has_network = zip
+                .index_for_name(&format!("{name}{NETWORK_SUFFIX}"))
+                .is_some();
+            chunks.push(TraceChunk {
+                name,
+                context,
+                has_network,
             });
         }
-        Ok(Self { zip, context })
+        Ok(Self { zip, chunks })
     }
 
     /// The `context-options` metadata from the trace's first event.
+    /// For multi-chunk traces this is the first chunk's; see
+    /// [`chunks`](Self::chunks) for the rest.
     pub fn context(&self) -> &ContextOptions {
-        &self.context
+        &self.chunks[0].context
+    }
+
+    /// The recordings in this trace, in order. Always at least one.
+    pub fn chunks(&self) -> &[TraceChunk] {
+        &self.chunks
     }
 
-    /// Lossless stream of every JSONL event in `trace.trace`. Yields a
+    /// Lossless stream of every JSONL event in the trace. Yields a
     /// [`RawEvent`] per line; callers can dispatch on
     /// [`RawEvent::kind`](crate::RawEvent::kind) to handle event types
     /// the typed enum doesn't model.
     ///
-    /// The first event (`context-options`) is **included** in the
-    /// stream; if you only need it, [`context`](Self::context) is
+    /// Each chunk's first event (`context-options`) is **included** in
+    /// the stream; if you only need it, [`context`](Self::context) is
     /// already cached.
     pub fn raw_events(&mut self) -> Result<impl Iterator<Item = Result<RawEvent>>> {
-        let entry = self.zip.by_name(TRACE_ENTRY)?;
-        let lines = JsonLines::new(BufReader::new(entry));
+        let entries = self.chunks.iter().map(TraceChunk::trace_entry).collect();
+        let lines = ChunkLines::open(&mut self.zip, entries)?;
         Ok(lines.map(|res| res.map(RawEvent::new)))
     }
 
@@ -80,57 +167,218 @@ impl<R: Read + Seek> TraceReader<R> {
     /// Actions are yielded in `after`-arrival order, **not** strictly
     /// in `start_time` order — concurrent calls can interleave.
     /// Callers wanting chronological order should collect into a
-    /// `Vec` and sort by [`Action::start_time`](crate::Action::start_time).
+    /// `Vec` and sort by [`Action::start_time`](crate::Action::start_time),
+    /// or use [`timeline`](Self::timeline).
     ///
     /// Truncated actions (no matching `after` event, e.g. a trace cut
-    /// short by a crash) are emitted at end-of-stream with
+    /// short by a crash) are emitted at the end of their chunk with
     /// `end_time = None` rather than discarded.
     pub fn actions(&mut self) -> Result<impl Iterator<Item = Result<Action>>> {
         Ok(ActionStream::new(self.events()?))
     }
 
-    /// Streaming iterator over [`NetworkEntry`] records from
-    /// `trace.network`. Yields zero items when the trace recorded no
-    /// requests (the entry is present but empty).
+    /// Streaming iterator over [`NetworkEntry`] records from every
+    /// chunk's network stream. Yields zero items when the trace
+    /// recorded no requests.
     ///
     /// HAR fields not modelled on [`NetworkEntry`] are preserved on
     /// [`NetworkEntry::raw_snapshot`].
     pub fn network(&mut self) -> Result<impl Iterator<Item = Result<NetworkEntry>>> {
-        let entry = self.zip.by_name(NETWORK_ENTRY)?;
-        let lines = JsonLines::new(BufReader::new(entry));
-        Ok(lines.map(|res| {
-            let mut map = res?;
-            // Check the discriminator before deserialising the
-            // payload — otherwise serde rejects an unexpected kind
-            // with a confusing "missing field `snapshot`" message.
-            let kind = map
-                .get("type")
-                .and_then(|v| v.as_str())
-                .unwrap_or("")
-                .to_string();
-            if kind != RESOURCE_SNAPSHOT_KIND {
-                return Err(TraceError::MalformedAction {
-                    call_id: String::new(),
-                    reason: format!(
-                        "trace.network: expected `{RESOURCE_SNAPSHOT_KIND}` event, got `{kind}`",
-                    ),
-                });
+        let entries = self
+            .chunks
+            .iter()
+            .filter_map(TraceChunk::network_entry)
+            .collect();
+        let lines = ChunkLines::open(&mut self.zip, entries)?;
+        Ok(lines.map(parse_network_line))
+    }
+
+    /// [`raw_events`](Self::raw_events) for one chunk.
+    pub fn chunk_raw_events(
+        &mut self,
+        chunk: usize,
+    ) -> Result<impl Iterator<Item = Result<RawEvent>>> {
+        let entry = self.chunk(chunk)?.trace_entry();
+        let lines = ChunkLines::open(&mut self.zip, vec![entry])?;
+        Ok(lines.map(|res| res.map(RawEvent::new)))
+    }
+
+    /// [`events`](Self::events) for one chunk.
+    pub fn chunk_events(
+        &mut self,
+        chunk: usize,
+    ) -> Result<impl Iterator<Item = Result<TraceEvent>>> {
+        Ok(self
+            .chunk_raw_events(chunk)?
+            .map(|res| res.map(|raw| raw.into_typed())))
+    }
+
+    /// [`actions`](Self::actions) for one chunk.
+    pub fn chunk_actions(&mut self, chunk: usize) -> Result<impl Iterator<Item = Result<Action>>> {
+        Ok(ActionStream::new(self.chunk_events(chunk)?))
+    }
+
+    /// [`network`](Self::network) for one chunk. Empty when the chunk
+    /// has no network stream.
+    pub fn chunk_network(
+        &mut self,
+        chunk: usize,
+    ) -> Result<impl Iterator<Item = Result<NetworkEntry>>> {
+        let entries = self.chunk(chunk)?.network_entry().into_iter().collect();
+        let lines = ChunkLines::open(&mut self.zip, entries)?;
+        Ok(lines.map(parse_network_line))
+    }
+
+    /// Actions and network entries from every chunk, merged into one
+    /// chronological sequence on the wall clock (see
+    /// [`TraceChunk::wall_time`]) and attributed to their chunk,
+    /// context, page and frame.
+    ///
+    /// Sorting needs everything at once, so unlike the other iterators
+    /// this collects actions and network entries (not snapshots) into
+    /// memory before yielding. Network entries recorded without
+    /// `_monotonicTime` have no position on the clock and come last.
+    pub fn timeline(&mut self) -> Result<impl Iterator<Item = TimelineEntry>> {
+        let mut entries = Vec::new();
+        for index in 0..self.chunks.len() {
+            let chunk = self.chunks[index].clone();
+            for action in self.chunk_actions(index)? {
+                entries.push(TimelineEntry::action(index, &chunk, action?));
             }
-            let snapshot = map
-                .remove("snapshot")
-                .ok_or_else(|| TraceError::MalformedAction {
-                    call_id: String::new(),
-                    reason: "trace.network: resource-snapshot missing `snapshot` payload".into(),
-                })?;
-            NetworkEntry::from_snapshot(snapshot)
-                .map_err(|source| TraceError::Json { line: 0, source })
-        }))
+            for entry in self.chunk_network(index)? {
+                entries.push(TimelineEntry::network(index, &chunk, entry?));
+            }
+        }
+        timeline::sort(&mut entries);
+        Ok(entries.into_iter())
+    }
+
+    /// Bytes of `resources/<sha1>` — a request or response body
+    /// referenced by [`NetworkEntry`], or a screencast frame. `sha1` is
+    /// the reference exactly as the trace records it, extension
+    /// included (`"168ea33e….html"`). Resources are shared by every
+    /// chunk in the zip.
+    pub fn resource(&mut self, sha1: &str) -> Result<Vec<u8>> {
+        let mut entry = match self.zip.by_name(&format!("{RESOURCES_DIR}{sha1}")) {
+            Ok(entry) => entry,
+            Err(zip::result::ZipError::FileNotFound) => {
+                return Err(TraceError::MissingResource(sha1.to_string()));
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let mut bytes = Vec::with_capacity(entry.size() as usize);
+        entry.read_to_end(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    fn chunk(&self, chunk: usize) -> Result<&TraceChunk> {
+        self.chunks.get(chunk).ok_or(TraceError::NoSuchChunk {
+            index: chunk,
+            count: self.chunks.len(),
+        })
+    }
+}
+
+/// Sort key for chunk names: the unnumbered `trace` first, then `0-trace`,
+/// `1-trace`, … numerically (so `10-` follows `9-`), then anything else
+/// by name.
+fn chunk_ordinal(name: &str) -> (u8, u64, String) {
+    if name == "trace" {
+        return (0, 0, String::new());
+    }
+    match name.split_once('-').and_then(|(n, _)| n.parse().ok()) {
+        Some(n) => (1, n, name.to_string()),
+        None => (2, 0, name.to_string()),
+    }
+}
+
+/// JSONL over a sequence of zip entries. One entry is streamed straight
+/// from the archive; several are decompressed one at a time, because a
+/// `ZipFile` borrows the archive and the next entry can't be opened
+/// while it's alive.
+enum ChunkLines<'a, R: Read + Seek> {
+    Streaming(Box<JsonLines<BufReader<ZipFile<'a, R>>>>),
+    Buffered {
+        zip: &'a mut ZipArchive<R>,
+        entries: std::vec::IntoIter<String>,
+        current: Option<JsonLines<Cursor<Vec<u8>>>>,
+    },
+}
+
+impl<'a, R: Read + Seek> ChunkLines<'a, R> {
+    fn open(zip: &'a mut ZipArchive<R>, entries: Vec<String>) -> Result<Self> {
+        if let [entry] = entries.as_slice() {
+            return Ok(Self::Streaming(Box::new(JsonLines::new(BufReader::new(
+                zip.by_name(entry)?,
+            )))));
+        }
+        Ok(Self::Buffered {
+            zip,
+            entries: entries.into_iter(),
+            current: None,
+        })
+    }
+}
+
+impl<R: Read + Seek> Iterator for ChunkLines<'_, R> {
+    type Item = Result<Map<String, Value>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Streaming(lines) => lines.next(),
+            Self::Buffered {
+                zip,
+                entries,
+                current,
+            } => loop {
+                if let Some(item) = current.as_mut().and_then(Iterator::next) {
+                    return Some(item);
+                }
+                let name = entries.next()?;
+                let mut bytes = Vec::new();
+                let read = zip
+                    .by_name(&name)
+                    .map_err(TraceError::from)
+                    .and_then(|mut entry| Ok(entry.read_to_end(&mut bytes)?));
+                if let Err(e) = read {
+                    return Some(Err(e));
+                }
+                *current = Some(JsonLines::new(Cursor::new(bytes)));
+            },
+        }
+    }
+}
+
+fn parse_network_line(res: Result<Map<String, Value>>) -> Result<NetworkEntry> {
+    let mut map = res?;
+    // Check the discriminator before deserialising the
+    // payload — otherwise serde rejects an unexpected kind
+    // with a confusing "missing field `snapshot`" message.
+    let kind = map
+        .get("type")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
+    if kind != RESOURCE_SNAPSHOT_KIND {
+        return Err(TraceError::MalformedAction {
+            call_id: String::new(),
+            reason: format!(
+                "trace.network: expected `{RESOURCE_SNAPSHOT_KIND}` event, got `{kind}`",
+            ),
+        });
     }
+    let snapshot = map
+        .remove("snapshot")
+        .ok_or_else(|| TraceError::MalformedAction {
+            call_id: String::new(),
+            reason: "trace.network: resource-snapshot missing `snapshot` payload".into(),
+        })?;
+    NetworkEntry::from_snapshot(snapshot).map_err(|source| TraceError::Json { line: 0, source })
 }
 
-fn parse_context<R: Read + Seek>(zip: &mut ZipArchive<R>) -> Result<ContextOptions> {
+fn parse_context<R: Read + Seek>(zip: &mut ZipArchive<R>, name: &str) -> Result<ContextOptions> {
     let entry = zip
-        .by_name(TRACE_ENTRY)
+        .by_name(name)
         .map_err(|_| TraceError::MissingEntry(TRACE_ENTRY))?;
     let mut reader = BufReader::new(entry);
     let mut line = String::new();
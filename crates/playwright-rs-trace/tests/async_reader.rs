@@ -0,0 +1,138 @@
+//! `AsyncTraceReader` yields what the sync reader yields.
+#![cfg(feature = "tokio")]
+
+use bytes::Bytes;
+use futures_util::StreamExt;
+use playwright_rs_trace::{AsyncTraceReader, TraceReader};
+use std::io::{Cursor, Write as _};
+use std::time::Duration;
+
+const BASIC_FIXTURE: &[u8] = include_bytes!("fixtures/basic.trace.zip");
+
+#[tokio::test]
+async fn from_bytes_streams_actions_like_sync_reader() {
+    let mut sync = TraceReader::open(Cursor::new(BASIC_FIXTURE)).expect("open sync");
+    let expected: Vec<_> = sync
+        .actions()
+        .expect("actions")
+        .map(|a| a.expect("action").call_id)
+        .collect();
+
+    let reader = AsyncTraceReader::from_bytes(Bytes::from_static(BASIC_FIXTURE))
+        .await
+        .expect("open async");
+    assert_eq!(reader.context().browser_name, "chromium");
+    let actual: Vec<_> = reader
+        .actions()
+        .map(|a| a.expect("action").call_id)
+        .collect()
+        .await;
+    assert!(!actual.is_empty());
+    assert_eq!(actual, expected);
+}
+
+#[tokio::test]
+async fn open_reads_from_async_file() {
+    let path = std::env::temp_dir().join(format!("async-reader-{}.zip", std::process::id()));
+    std::fs::write(&path, BASIC_FIXTURE).expect("write fixture");
+    let file = tokio::fs::File::open(&path).await.expect("open file");
+
+    let reader = AsyncTraceReader::open(file).await.expect("open async");
+    let network: Vec<_> = reader.network().collect().await;
+    assert_eq!(network.len(), 1);
+    let entry = network.into_iter().next().unwrap().expect("entry");
+    let sha1 = entry.response.content.sha1.clone().expect("body recorded");
+    let body = reader.resource(&sha1).await.expect("resource");
+    assert_eq!(body.len(), 99);
+    std::fs::remove_file(&path).ok();
+}
+
+#[tokio::test]
+async fn streams_share_the_archive() {
+    let reader = AsyncTraceReader::from_bytes(Bytes::from_static(BASIC_FIXTURE))
+        .await
+        .expect("open async");
+    let events = reader.events();
+    let raw = reader.raw_events();
+    let (events, raw) = tokio::join!(events.count(), raw.count());
+    assert!(events > 0);
+    assert_eq!(events, raw);
+}
+
+#[tokio::test]
+async fn resource_can_be_read_while_streaming_network() {
+    // More entries than the stream buffers, so the producer is still
+    // running when the loop asks for each body.
+    const ENTRIES: usize = 200;
+    let reader = AsyncTraceReader::from_bytes(Bytes::from(network_trace(ENTRIES)))
+        .await
+        .expect("open async");
+
+    let read_all = async {
+        let mut network = reader.network();
+        let mut bodies = Vec::new();
+        while let Some(entry) = network.next().await {
+            let entry = entry.expect("entry");
+            let sha1 = entry.response.content.sha1.expect("body recorded");
+            bodies.push(reader.resource(&sha1).await.expect("resource"));
+        }
+        bodies
+    };
+    let bodies = tokio::time::timeout(Duration::from_secs(30), read_all)
+        .await
+        .expect("streaming and reading resources finished");
+    assert_eq!(bodies.len(), ENTRIES);
+    assert_eq!(
+        bodies[ENTRIES - 1],
+        format!("body {}", ENTRIES - 1).into_bytes()
+    );
+}
+
+#[tokio::test]
+async fn dropped_stream_releases_the_reader() {
+    let reader = AsyncTraceReader::from_bytes(Bytes::from_static(BASIC_FIXTURE))
+        .await
+        .expect("open async");
+    let mut first = reader.raw_events();
+    first.next().await.expect("one event").expect("valid");
+    drop(first);
+    assert!(reader.actions().count().await > 0);
+}
+
+#[tokio::test]
+async fn invalid_archive_is_an_error() {
+    let result = AsyncTraceReader::from_bytes(Bytes::from_static(b"not a zip")).await;
+    assert!(result.is_err());
+}
+
+/// A trace with `entries` GET requests, each with its own response body.
+fn network_trace(entries: usize) -> Vec<u8> {
+    let mut buf = Vec::new();
+    {
+        let mut zip = zip::ZipWriter::new(Cursor::new(&mut buf));
+        let opts = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+        zip.start_file("trace.trace", opts).expect("start trace");
+        zip.write_all(
+            br#"{"type":"context-options","version":8,"browserName":"chromium","playwrightVersion":"1.62.1"}
+"#,
+        )
+        .expect("write trace");
+        zip.start_file("trace.network", opts)
+            .expect("start network");
+        for i in 0..entries {
+            writeln!(
+                zip,
+                r#"{{"type":"resource-snapshot","snapshot":{{"startedDateTime":"2026-05-04T12:00:00.000Z","time":1,"request":{{"method":"GET","url":"http://127.0.0.1:5555/{i}","httpVersion":"HTTP/1.1","headers":[],"headersSize":-1,"bodySize":0}},"response":{{"status":200,"statusText":"OK","httpVersion":"HTTP/1.1","headers":[],"headersSize":-1,"bodySize":-1,"redirectURL":"","content":{{"size":-1,"mimeType":"text/plain","_sha1":"{i}.txt"}}}}}}}}"#
+            )
+            .expect("write line");
+        }
+        for i in 0..entries {
+            zip.start_file(format!("resources/{i}.txt"), opts)
+                .expect("start resource");
+            write!(zip, "body {i}").expect("write resource");
+        }
+        zip.finish().expect("finish zip");
+    }
+    buf
+}
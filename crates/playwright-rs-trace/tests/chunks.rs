@@ -0,0 +1,187 @@
+//! Zips holding several recordings (`0-trace.trace`, `1-trace.trace`, …).
+
+use playwright_rs_trace::{TimelineItem, TraceError, TraceEvent, TraceReader};
+use std::io::{Cursor, Write as _};
+
+const BASIC_FIXTURE: &[u8] = include_bytes!("fixtures/basic.trace.zip");
+
+#[test]
+fn single_recording_is_one_chunk() {
+    let reader = TraceReader::open(Cursor::new(BASIC_FIXTURE)).expect("open fixture");
+    let chunks = reader.chunks();
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(chunks[0].name, "trace");
+    assert_eq!(chunks[0].network_entry().as_deref(), Some("trace.network"));
+}
+
+#[test]
+fn chunks_are_ordered_numerically() {
+    let mut reader = open_merged();
+    let names: Vec<_> = reader.chunks().iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["0-trace", "1-trace", "10-trace"]);
+    let contexts: Vec<_> = reader
+        .chunks()
+        .iter()
+        .map(|c| c.context.context_id.as_str())
+        .collect();
+    assert_eq!(contexts, ["context@a", "context@b", "context@c"]);
+    assert_eq!(reader.context().context_id, "context@a");
+
+    let chunk_one: Vec<_> = reader
+        .chunk_actions(1)
+        .expect("chunk actions")
+        .map(|a| a.expect("action").selector().map(str::to_string))
+        .collect();
+    assert_eq!(chunk_one, [Some("#b".to_string())]);
+}
+
+#[test]
+fn whole_trace_iterators_walk_every_chunk() {
+    let mut reader = open_merged();
+    let actions: Vec<_> = reader
+        .actions()
+        .expect("actions")
+        .map(|a| a.expect("action"))
+        .collect();
+    // Each chunk reuses `call@1`; none of them may swallow another's.
+    let selectors: Vec<_> = actions.iter().map(|a| a.selector().unwrap()).collect();
+    assert_eq!(selectors, ["#a", "#truncated", "#b", "#c"]);
+    assert!(actions[1].end_time.is_none(), "truncated at chunk boundary");
+
+    let urls: Vec<_> = reader
+        .network()
+        .expect("network")
+        .map(|n| n.expect("entry").request.url)
+        .collect();
+    assert_eq!(urls, ["http://app/a", "http://app/b"]);
+}
+
+#[test]
+fn timeline_merges_on_wall_clock_with_attribution() {
+    let mut reader = open_merged();
+    let timeline: Vec<_> = reader.timeline().expect("timeline").collect();
+    let order: Vec<_> = timeline
+        .iter()
+        .map(|e| match &e.item {
+            TimelineItem::Action(a) => a.selector().unwrap().to_string(),
+            TimelineItem::Network(n) => n.request.url.clone(),
+        })
+        .collect();
+    // Chunk 1 was recorded by a process whose monotonic clock started
+    // later but whose wall clock is earlier, so it sorts first.
+    assert_eq!(
+        order,
+        [
+            "#b",
+            "http://app/b",
+            "#a",
+            "#truncated",
+            "http://app/a",
+            "#c"
+        ]
+    );
+
+    let request = &timeline[1];
+    assert_eq!(request.chunk, 1);
+    assert_eq!(request.context_id, "context@b");
+    assert_eq!(request.page_id.as_deref(), Some("page@b"));
+    assert_eq!(request.frame_id.as_deref(), Some("frame@b"));
+    assert_eq!(request.time, Some(1_000_000.0 + 15.0));
+    assert_eq!(timeline[0].page_id.as_deref(), Some("page@b"));
+}
+
+#[test]
+fn chunk_without_network_and_unknown_chunk() {
+    let mut reader = open_merged();
+    assert_eq!(reader.chunks()[2].network_entry(), None);
+    assert_eq!(reader.chunk_network(2).expect("network").count(), 0);
+    assert!(matches!(
+        reader.chunk_events(3).err(),
+        Some(TraceError::NoSuchChunk { index: 3, count: 3 })
+    ));
+}
+
+#[test]
+fn events_carry_their_page() {
+    let mut reader = open_merged();
+    let pages: Vec<_> = reader
+        .chunk_events(0)
+        .expect("events")
+        .map(|e| e.expect("event"))
+        .filter(|e| matches!(e, TraceEvent::Before(_)))
+        .map(|e| e.page_id().map(str::to_string))
+        .collect();
+    assert_eq!(pages, [Some("page@a".into()), Some("page@a".into())]);
+}
+
+fn open_merged() -> TraceReader<Cursor<Vec<u8>>> {
+    let chunk_a = [
+        context("context@a", 2_000_000.0, 100.0),
+        before(1, "#a", "page@a", 110.0),
+        after(1, 120.0),
+        before(2, "#truncated", "page@a", 130.0),
+    ];
+    let chunk_b = [
+        context("context@b", 1_000_000.0, 5000.0),
+        before(1, "#b", "page@b", 5010.0),
+        after(1, 5020.0),
+    ];
+    let chunk_c = [
+        context("context@c", 3_000_000.0, 0.0),
+        before(1, "#c", "page@c", 10.0),
+        after(1, 20.0),
+    ];
+    let zip = build_zip(&[
+        // Out of order on purpose: `10-` must follow `1-`, not `0-`.
+        ("10-trace.trace", chunk_c.join("\n")),
+        ("0-trace.trace", chunk_a.join("\n")),
+        (
+            "0-trace.network",
+            request("http://app/a", "page@a", "frame@a", 140.0),
+        ),
+        ("1-trace.trace", chunk_b.join("\n")),
+        (
+            "1-trace.network",
+            request("http://app/b", "page@b", "frame@b", 5015.0),
+        ),
+    ]);
+    TraceReader::open(Cursor::new(zip)).expect("open merged trace")
+}
+
+fn context(id: &str, wall: f64, monotonic: f64) -> String {
+    format!(
+        r#"{{"type":"context-options","version":8,"browserName":"chromium","contextId":"{id}","wallTime":{wall},"monotonicTime":{monotonic}}}"#
+    )
+}
+
+fn before(call: u32, selector: &str, page: &str, t: f64) -> String {
+    format!(
+        r#"{{"type":"before","callId":"call@{call}","startTime":{t},"class":"Frame","method":"click","pageId":"{page}","params":{{"selector":"{selector}"}}}}"#
+    )
+}
+
+fn after(call: u32, t: f64) -> String {
+    format!(r#"{{"type":"after","callId":"call@{call}","endTime":{t}}}"#)
+}
+
+fn request(url: &str, page: &str, frame: &str, t: f64) -> String {
+    format!(
+        r#"{{"type":"resource-snapshot","snapshot":{{"pageref":"{page}","_frameref":"{frame}","_monotonicTime":{t},"startedDateTime":"","request":{{"method":"GET","url":"{url}"}},"response":{{"status":200,"content":{{}}}}}}}}"#
+    )
+}
+
+fn build_zip(entries: &[(&str, String)]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    {
+        let mut zip = zip::ZipWriter::new(Cursor::new(&mut buf));
+        let opts = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+        for (name, body) in entries {
+            zip.start_file(*name, opts).expect("start entry");
+            zip.write_all(body.as_bytes()).expect("write entry");
+            zip.write_all(b"\n").expect("write newline");
+        }
+        zip.finish().expect("finish zip");
+    }
+    buf
+}
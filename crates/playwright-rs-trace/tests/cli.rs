@@ -0,0 +1,232 @@
+//! End-to-end tests for the `trace` binary (feature `cli`).
+//!
+//! Runs the built binary against the checked-in fixture and against a
+//! synthetic trace carrying a failed action, so the error paths are
+//! covered without needing a browser to produce a failure.
+
+#![cfg(feature = "cli")]
+
+use std::io::{Cursor, Write as _};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+const BASIC_FIXTURE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/basic.trace.zip"
+);
+
+fn trace(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_trace"))
+        .args(args)
+        .output()
+        .expect("spawn trace binary")
+}
+
+fn stdout_of(args: &[&str]) -> String {
+    let output = trace(args);
+    assert!(
+        output.status.success(),
+        "trace {args:?} failed:\nstderr: {}",
+        String::from_utf8_lossy(&output.stderr),
+    );
+    String::from_utf8(output.stdout).expect("utf-8 stdout")
+}
+
+#[test]
+fn summary_reports_context_and_counts() {
+    let out = stdout_of(&["summary", BASIC_FIXTURE]);
+    assert!(out.contains("chromium"), "browser name missing:\n{out}");
+    assert!(out.contains("actions:     3 (0 failed)"), "{out}");
+    assert!(out.contains("requests:    1"), "{out}");
+}
+
+#[test]
+fn actions_lists_click_with_selector() {
+    let out = stdout_of(&["actions", BASIC_FIXTURE]);
+    let click = out
+        .lines()
+        .find(|l| l.contains("Frame.click"))
+        .unwrap_or_else(|| panic!("no click row:\n{out}"));
+    assert!(click.contains("#b"), "selector missing from row: {click}");
+}
+
+#[test]
+fn network_lists_the_navigation() {
+    let out = stdout_of(&["network", BASIC_FIXTURE]);
+    assert!(
+        out.lines()
+            .any(|l| l.starts_with("GET") && l.contains("200") && l.contains("127.0.0.1")),
+        "{out}",
+    );
+}
+
+#[test]
+fn console_prints_level_and_text() {
+    let out = stdout_of(&["console", BASIC_FIXTURE]);
+    assert!(out.contains("log") && out.contains("hi"), "{out}");
+}
+
+#[test]
+fn errors_reports_failed_action_and_console_error() {
+    let dir = tempdir();
+    let path = write_failing_trace(&dir);
+    let out = stdout_of(&["errors", path.to_str().unwrap()]);
+    assert!(
+        out.contains("Frame.click") && out.contains("TimeoutError: Timeout 500ms exceeded."),
+        "{out}",
+    );
+    assert!(out.contains("console.error: boom"), "{out}");
+    assert!(
+        !out.contains("call log:"),
+        "only the first line of the message: {out}"
+    );
+}
+
+#[test]
+fn errors_says_so_when_clean() {
+    let out = stdout_of(&["errors", BASIC_FIXTURE]);
+    assert_eq!(out.trim(), "no errors");
+}
+
+#[test]
+fn export_junit_marks_failed_action() {
+    let dir = tempdir();
+    let path = write_failing_trace(&dir);
+    let out = stdout_of(&["export", path.to_str().unwrap(), "--format", "junit"]);
+    assert!(out.contains(r#"tests="2" failures="1""#), "{out}");
+    assert!(
+        out.contains(r#"<failure type="TimeoutError" message="Timeout 500ms exceeded.">"#),
+        "{out}",
+    );
+    assert!(
+        out.contains("&lt;button&gt;"),
+        "message body is escaped: {out}"
+    );
+}
+
+#[test]
+fn export_json_to_file_parses() {
+    let dir = tempdir();
+    let output = dir.join("trace.json");
+    stdout_of(&[
+        "export",
+        BASIC_FIXTURE,
+        "--format",
+        "json",
+        "-o",
+        output.to_str().unwrap(),
+    ]);
+    let doc: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(&output).expect("read export")).expect("json");
+    assert_eq!(doc["context"]["browserName"], "chromium");
+    assert_eq!(doc["actions"].as_array().map(Vec::len), Some(3));
+    assert_eq!(doc["network"].as_array().map(Vec::len), Some(1));
+}
+
+#[test]
+fn export_har_wraps_network_entries() {
+    let out = stdout_of(&["export", BASIC_FIXTURE, "--format", "har"]);
+    let doc: serde_json::Value = serde_json::from_str(&out).expect("har json");
+    assert_eq!(doc["log"]["version"], "1.2");
+    assert_eq!(doc["log"]["entries"].as_array().map(Vec::len), Some(1));
+}
+
+#[test]
+fn export_har_attach_to_zip() {
+    let dir = tempdir();
+    let output = dir.join("replay.zip");
+    stdout_of(&[
+        "export",
+        BASIC_FIXTURE,
+        "--format",
+        "har",
+        "--content",
+        "attach",
+        "-o",
+        output.to_str().unwrap(),
+    ]);
+    let zip = zip::ZipArchive::new(std::fs::File::open(&output).expect("open")).expect("zip");
+    let names: Vec<_> = zip.file_names().collect();
+    assert!(names.contains(&"har.har"), "{names:?}");
+    assert_eq!(names.len(), 2, "har plus one body: {names:?}");
+}
+
+#[test]
+fn export_har_attach_to_stdout_is_refused() {
+    let output = trace(&[
+        "export",
+        BASIC_FIXTURE,
+        "--format",
+        "har",
+        "--content",
+        "attach",
+    ]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("-o"));
+}
+
+#[test]
+fn diff_reports_outcome_change() {
+    let dir = tempdir();
+    let failing = write_failing_trace(&dir);
+    assert_eq!(
+        stdout_of(&["diff", BASIC_FIXTURE, BASIC_FIXTURE]).trim(),
+        "no differences"
+    );
+    let out = stdout_of(&["diff", BASIC_FIXTURE, failing.to_str().unwrap()]);
+    assert!(out.contains("+ action Frame.click(#save) #0"), "{out}");
+    assert!(out.contains("- action Frame.click(#b) #0"), "{out}");
+}
+
+#[test]
+fn missing_file_fails_with_message() {
+    let output = trace(&["summary", "/nonexistent/trace.zip"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("trace: "));
+}
+
+/// A scratch directory unique to this test process and test name.
+fn tempdir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "playwright-rs-trace-cli-{}-{}",
+        std::process::id(),
+        std::thread::current()
+            .name()
+            .unwrap_or("test")
+            .replace("::", "-"),
+    ));
+    std::fs::create_dir_all(&dir).expect("create scratch dir");
+    dir
+}
+
+/// A two-action trace: a passing `goto` and a `click` that timed out,
+/// plus one `console.error`.
+fn write_failing_trace(dir: &Path) -> PathBuf {
+    let trace = [
+        r#"{"type":"context-options","version":8,"browserName":"chromium","playwrightVersion":"1.62.1","monotonicTime":100}"#,
+        r#"{"type":"before","callId":"call@1","startTime":101,"class":"Frame","method":"goto","params":{"url":"http://localhost/"}}"#,
+        r#"{"type":"after","callId":"call@1","endTime":120}"#,
+        r##"{"type":"before","callId":"call@2","startTime":121,"class":"Frame","method":"click","params":{"selector":"#save"}}"##,
+        r#"{"type":"console","messageType":"error","text":"boom","args":[],"time":125}"#,
+        r#"{"type":"after","callId":"call@2","endTime":621,"error":{"name":"TimeoutError","message":"Timeout 500ms exceeded.\ncall log:\n  - waiting for <button>"}}"#,
+    ];
+
+    let mut buf = Vec::new();
+    {
+        let mut zip = zip::ZipWriter::new(Cursor::new(&mut buf));
+        let opts = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+        zip.start_file("trace.trace", opts).expect("start trace");
+        for line in trace {
+            zip.write_all(line.as_bytes()).expect("write line");
+            zip.write_all(b"\n").expect("write newline");
+        }
+        zip.start_file("trace.network", opts)
+            .expect("start network");
+        zip.finish().expect("finish zip");
+    }
+
+    let path = dir.join("failing.trace.zip");
+    std::fs::write(&path, buf).expect("write synthetic trace");
+    path
+}
@@ -0,0 +1,284 @@
+//! Cross-run comparison in `playwright_rs_trace::diff`.
+
+use playwright_rs_trace::TraceReader;
+use playwright_rs_trace::diff::{
+    ActionDivergence, DiffOptions, NetworkDivergence, Run, diff, flakiness,
+};
+use std::io::{Cursor, Write as _};
+
+const BASIC_FIXTURE: &[u8] = include_bytes!("fixtures/basic.trace.zip");
+
+/// One recorded call: `(method, selector, duration_ms, error)`.
+type Call<'a> = (&'a str, &'a str, f64, Option<&'a str>);
+
+#[test]
+fn identical_runs_have_no_differences() {
+    let run = load(BASIC_FIXTURE.to_vec());
+    let report = diff(&run, &run, &DiffOptions::default());
+    assert!(report.is_empty(), "{report:?}");
+}
+
+#[test]
+fn reports_changed_outcome_and_missing_action() {
+    let baseline = synthetic(
+        &[
+            ("click", "#open", 20.0, None),
+            ("fill", "#name", 10.0, None),
+            ("click", "#save", 30.0, None),
+        ],
+        &[],
+        &[],
+    );
+    let candidate = synthetic(
+        &[
+            ("click", "#open", 20.0, None),
+            ("click", "#save", 5000.0, Some("Timeout 5000ms exceeded.")),
+        ],
+        &[],
+        &[],
+    );
+    let report = diff(&baseline, &candidate, &DiffOptions::default());
+
+    let outcome = report
+        .actions
+        .iter()
+        .find_map(|d| match d {
+            ActionDivergence::Outcome {
+                key,
+                baseline_error,
+                candidate_error,
+                ..
+            } => Some((key, baseline_error, candidate_error)),
+            _ => None,
+        })
+        .expect("changed outcome reported");
+    assert_eq!(outcome.0.api, "Frame.click");
+    assert_eq!(outcome.0.selector.as_deref(), Some("#save"));
+    assert_eq!(outcome.1, &None);
+    assert_eq!(outcome.2.as_deref(), Some("Timeout 5000ms exceeded."));
+
+    assert!(
+        report.actions.iter().any(|d| matches!(
+            d,
+            ActionDivergence::Missing { key, .. } if key.api == "Frame.fill"
+        )),
+        "{:?}",
+        report.actions,
+    );
+    assert_eq!(report.timing_regressions.len(), 1);
+    assert_eq!(report.timing_regressions[0].candidate_ms, 5000.0);
+}
+
+#[test]
+fn repeated_actions_align_by_occurrence() {
+    let baseline = synthetic(
+        &[
+            ("click", "#next", 10.0, None),
+            ("click", "#next", 10.0, None),
+        ],
+        &[],
+        &[],
+    );
+    let candidate = synthetic(
+        &[
+            ("click", "#next", 10.0, None),
+            ("click", "#next", 10.0, None),
+            ("click", "#next", 10.0, None),
+        ],
+        &[],
+        &[],
+    );
+    let report = diff(&baseline, &candidate, &DiffOptions::default());
+    assert_eq!(report.actions.len(), 1, "{:?}", report.actions);
+    assert!(matches!(
+        report.actions[0],
+        ActionDivergence::Extra { occurrence: 2, .. }
+    ));
+}
+
+#[test]
+fn small_slowdowns_are_not_regressions() {
+    let baseline = synthetic(&[("click", "#a", 10.0, None)], &[], &[]);
+    // 5x slower but only 40ms: below the default 100ms floor.
+    let candidate = synthetic(&[("click", "#a", 50.0, None)], &[], &[]);
+    assert!(diff(&baseline, &candidate, &DiffOptions::default()).is_empty());
+    let strict = DiffOptions::default().min_slowdown_ms(10.0);
+    assert_eq!(
+        diff(&baseline, &candidate, &strict)
+            .timing_regressions
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn new_console_errors_are_counted_per_message() {
+    let baseline = synthetic(&[], &["stale token"], &[]);
+    let candidate = synthetic(&[], &["stale token", "stale token", "boom"], &[]);
+    let report = diff(&baseline, &candidate, &DiffOptions::default());
+    let texts: Vec<_> = report
+        .new_console_errors
+        .iter()
+        .map(|c| c.text.as_str())
+        .collect();
+    assert_eq!(texts, ["stale token", "boom"]);
+}
+
+#[test]
+fn network_status_and_missing_requests() {
+    let baseline = synthetic(
+        &[],
+        &[],
+        &[
+            ("GET", "http://app/api/user", 200),
+            ("GET", "http://app/a.js", 200),
+        ],
+    );
+    let candidate = synthetic(
+        &[],
+        &[],
+        &[
+            ("GET", "http://app/api/user", 500),
+            ("POST", "http://app/log", 204),
+        ],
+    );
+    let report = diff(&baseline, &candidate, &DiffOptions::default());
+    assert!(report.network.iter().any(|n| matches!(
+        n,
+        NetworkDivergence::Status { url, baseline: Some(200), candidate: Some(500), .. }
+            if url == "http://app/api/user"
+    )));
+    assert!(
+        report
+            .network
+            .iter()
+            .any(|n| matches!(n, NetworkDivergence::Extra { method, .. } if method == "POST"))
+    );
+    assert!(
+        report.network.iter().any(
+            |n| matches!(n, NetworkDivergence::Missing { url, .. } if url == "http://app/a.js")
+        )
+    );
+}
+
+#[test]
+fn call_site_location_separates_same_selector() {
+    let with_stack = |line: u32| {
+        trace_zip(
+            &[
+                format!(
+                    r##"{{"type":"before","callId":"call@1","startTime":1,"class":"Frame","method":"click","params":{{"selector":"#b"}},"stack":[{{"file":"tests/login.rs","line":{line},"column":9}}]}}"##
+                ),
+                r#"{"type":"after","callId":"call@1","endTime":2}"#.to_string(),
+            ],
+            &[],
+        )
+    };
+    let a = load(with_stack(10));
+    let b = load(with_stack(42));
+    assert_eq!(
+        a.actions[0].location().map(|f| f.line),
+        Some(10),
+        "stack parsed onto the action"
+    );
+    let report = diff(&a, &b, &DiffOptions::default());
+    assert_eq!(report.actions.len(), 2, "different call sites don't align");
+}
+
+#[test]
+fn flakiness_flags_inconsistent_actions() {
+    let runs = [
+        synthetic(
+            &[("click", "#a", 10.0, None), ("click", "#b", 10.0, None)],
+            &[],
+            &[],
+        ),
+        synthetic(
+            &[
+                ("click", "#a", 12.0, None),
+                ("click", "#b", 900.0, Some("Timeout")),
+            ],
+            &[],
+            &[],
+        ),
+        synthetic(&[("click", "#a", 11.0, None)], &[], &[]),
+    ];
+    let report = flakiness(&runs);
+    assert_eq!(report.runs, 3);
+
+    let a = &report.actions[0];
+    assert_eq!(a.key.selector.as_deref(), Some("#a"));
+    assert_eq!((a.present, a.failed), (3, 0));
+    assert!(!a.is_flaky(3));
+    assert_eq!(a.mean_ms(), Some(11.0));
+
+    let flaky: Vec<_> = report.flaky().map(|s| s.key.selector.as_deref()).collect();
+    assert_eq!(flaky, [Some("#b")]);
+    assert_eq!(report.actions[1].max_ms(), Some(900.0));
+}
+
+fn load(zip: Vec<u8>) -> Run {
+    let mut reader = TraceReader::open(Cursor::new(zip)).expect("open trace");
+    Run::load(&mut reader).expect("load run")
+}
+
+/// Build a run from calls, console errors, and `(method, url, status)`
+/// requests. Calls are laid out back to back from t=0.
+fn synthetic(calls: &[Call], console_errors: &[&str], requests: &[(&str, &str, u16)]) -> Run {
+    let mut lines = Vec::new();
+    let mut t = 0.0;
+    for (i, (method, selector, duration, error)) in calls.iter().enumerate() {
+        lines.push(format!(
+            r#"{{"type":"before","callId":"call@{i}","startTime":{t},"class":"Frame","method":"{method}","params":{{"selector":"{selector}"}}}}"#
+        ));
+        let error = error
+            .map(|m| format!(r#","error":{{"name":"TimeoutError","message":"{m}"}}"#))
+            .unwrap_or_default();
+        lines.push(format!(
+            r#"{{"type":"after","callId":"call@{i}","endTime":{}{error}}}"#,
+            t + duration
+        ));
+        t += duration;
+    }
+    for text in console_errors {
+        lines.push(format!(
+            r#"{{"type":"console","messageType":"error","text":"{text}","time":{t}}}"#
+        ));
+    }
+    let network: Vec<String> = requests
+        .iter()
+        .map(|(method, url, status)| {
+            format!(
+                r#"{{"type":"resource-snapshot","snapshot":{{"startedDateTime":"","request":{{"method":"{method}","url":"{url}"}},"response":{{"status":{status},"content":{{}}}}}}}}"#
+            )
+        })
+        .collect();
+    load(trace_zip(&lines, &network))
+}
+
+fn trace_zip(trace_lines: &[String], network_lines: &[String]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    {
+        let mut zip = zip::ZipWriter::new(Cursor::new(&mut buf));
+        let opts = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+        zip.start_file("trace.trace", opts).expect("start trace");
+        zip.write_all(
+            br#"{"type":"context-options","version":8,"browserName":"chromium","playwrightVersion":"1.62.1"}
+"#,
+        )
+        .expect("write header");
+        for line in trace_lines {
+            zip.write_all(line.as_bytes()).expect("write line");
+            zip.write_all(b"\n").expect("write newline");
+        }
+        zip.start_file("trace.network", opts)
+            .expect("start network");
+        for line in network_lines {
+            zip.write_all(line.as_bytes()).expect("write line");
+            zip.write_all(b"\n").expect("write newline");
+        }
+        zip.finish().expect("finish zip");
+    }
+    buf
+}
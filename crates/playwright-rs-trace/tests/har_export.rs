@@ -0,0 +1,197 @@
+//! HAR 1.2 export of the trace network stream.
+
+use playwright_rs_trace::{HarContent, HarExportOptions, TraceReader, export_har};
+use serde_json::Value;
+use std::io::{Cursor, Read as _, Write as _};
+
+const BASIC_FIXTURE: &[u8] = include_bytes!("fixtures/basic.trace.zip");
+
+/// Body of the fixture's entry page, stored as `resources/<sha1>.html`.
+const FIXTURE_HTML_SHA1: &str = "168ea33ec180f20b6d59868f3a7276e6c48e003c.html";
+
+fn export(content: HarContent) -> playwright_rs_trace::HarExport {
+    let mut reader = TraceReader::open(Cursor::new(BASIC_FIXTURE)).expect("open fixture");
+    export_har(&mut reader, &HarExportOptions::default().content(content)).expect("export")
+}
+
+fn only_entry(har: &Value) -> &Value {
+    let entries = har["log"]["entries"].as_array().expect("entries array");
+    assert_eq!(entries.len(), 1, "fixture records one request");
+    &entries[0]
+}
+
+#[test]
+fn envelope_is_har_1_2_with_pages() {
+    let export = export(HarContent::Embed);
+    let log = &export.har["log"];
+    assert_eq!(log["version"], "1.2");
+    assert_eq!(log["creator"]["name"], "playwright-rs-trace");
+    assert_eq!(log["browser"]["name"], "chromium");
+
+    let pages = log["pages"].as_array().expect("pages array");
+    assert_eq!(pages.len(), 1);
+    let entry = only_entry(&export.har);
+    assert_eq!(pages[0]["id"], entry["pageref"], "entry points at its page");
+    assert_eq!(
+        pages[0]["title"], entry["request"]["url"],
+        "title falls back to the page's first document URL",
+    );
+    assert!(pages[0]["pageTimings"].is_object());
+}
+
+#[test]
+fn embed_inlines_text_body_and_drops_sha1() {
+    let export = export(HarContent::Embed);
+    let content = &only_entry(&export.har)["response"]["content"];
+    assert!(
+        content.get("_sha1").is_none(),
+        "trace-only reference removed"
+    );
+    assert!(
+        content["text"]
+            .as_str()
+            .is_some_and(|t| t.contains("<button")),
+        "body inlined: {content}",
+    );
+    assert!(content.get("encoding").is_none(), "UTF-8 body stays text");
+    assert!(export.attachments.is_empty());
+}
+
+#[test]
+fn attach_references_files_and_returns_them() {
+    let export = export(HarContent::Attach);
+    let content = &only_entry(&export.har)["response"]["content"];
+    assert_eq!(content["_file"], FIXTURE_HTML_SHA1);
+    assert!(content.get("text").is_none());
+    assert_eq!(export.attachments.len(), 1);
+    assert_eq!(export.attachments[0].0, FIXTURE_HTML_SHA1);
+    assert!(String::from_utf8_lossy(&export.attachments[0].1).contains("<button"));
+}
+
+#[test]
+fn omit_keeps_size_without_body() {
+    let export = export(HarContent::Omit);
+    let content = &only_entry(&export.har)["response"]["content"];
+    assert!(content.get("text").is_none() && content.get("_file").is_none());
+    assert_eq!(content["size"], 99);
+}
+
+#[test]
+fn timings_and_unmodelled_fields_survive() {
+    let export = export(HarContent::Embed);
+    let entry = only_entry(&export.har);
+    let timings = &entry["timings"];
+    for key in ["send", "wait", "receive"] {
+        assert!(timings[key].as_f64().is_some_and(|v| v >= 0.0), "{key}");
+    }
+    assert_eq!(timings["dns"], 0.181, "recorded timings copied verbatim");
+    assert_eq!(entry["_resourceType"], "document", "extensions kept");
+    assert_eq!(entry["serverIPAddress"], "127.0.0.1");
+}
+
+#[test]
+fn binary_post_and_response_bodies() {
+    let snapshot = r#"{"type":"resource-snapshot","snapshot":{"startedDateTime":"2026-05-04T12:00:00.000Z","time":3,"request":{"method":"POST","url":"http://127.0.0.1:5555/upload","httpVersion":"HTTP/1.1","headers":[{"name":"Content-Type","value":"application/json"}],"headersSize":-1,"bodySize":7,"postData":{"_sha1":"req.json"}},"response":{"status":200,"statusText":"OK","httpVersion":"HTTP/1.1","headers":[],"headersSize":-1,"bodySize":4,"redirectURL":"","content":{"size":4,"mimeType":"image/png","_sha1":"resp.png"}}}}"#;
+    let zip = build_zip(
+        &[snapshot],
+        &[
+            ("resources/req.json", br#"{"a":1}"#),
+            ("resources/resp.png", &[0x89, b'P', 0xff, 0x00]),
+        ],
+    );
+    let mut reader = TraceReader::open(Cursor::new(zip)).expect("open synthetic");
+    let export = export_har(&mut reader, &HarExportOptions::default()).expect("export");
+    let entry = only_entry(&export.har);
+
+    let post = &entry["request"]["postData"];
+    assert_eq!(post["mimeType"], "application/json", "from Content-Type");
+    assert_eq!(post["text"], r#"{"a":1}"#);
+    assert!(post.get("_sha1").is_none());
+
+    let content = &entry["response"]["content"];
+    assert_eq!(content["encoding"], "base64");
+    assert_eq!(content["text"], "iVD/AA==");
+
+    // Members the trace never records are filled so strict HAR readers
+    // accept the entry.
+    assert_eq!(entry["cache"], serde_json::json!({}));
+    assert_eq!(entry["request"]["cookies"], serde_json::json!([]));
+    assert_eq!(entry["request"]["queryString"], serde_json::json!([]));
+    assert_eq!(entry["timings"]["blocked"], -1);
+}
+
+#[test]
+fn binary_post_body_is_base64_not_lossy() {
+    let snapshot = r#"{"type":"resource-snapshot","snapshot":{"startedDateTime":"2026-05-04T12:00:00.000Z","time":1,"request":{"method":"POST","url":"http://127.0.0.1:5555/upload","httpVersion":"HTTP/1.1","headers":[{"name":"Content-Type","value":"application/octet-stream"}],"headersSize":-1,"bodySize":3,"postData":{"_sha1":"req.bin"}},"response":{"status":204,"statusText":"No Content","httpVersion":"HTTP/1.1","headers":[],"headersSize":-1,"bodySize":0,"redirectURL":"","content":{"size":0,"mimeType":""}}}}"#;
+    let zip = build_zip(&[snapshot], &[("resources/req.bin", &[0x00, 0xff, 0xfe])]);
+    let mut reader = TraceReader::open(Cursor::new(zip)).expect("open synthetic");
+    let export = export_har(&mut reader, &HarExportOptions::default()).expect("export");
+
+    let post = &only_entry(&export.har)["request"]["postData"];
+    assert_eq!(post["encoding"], "base64");
+    assert_eq!(post["text"], "AP/+");
+}
+
+#[test]
+fn missing_resource_is_absent_body_not_error() {
+    let snapshot = r#"{"type":"resource-snapshot","snapshot":{"startedDateTime":"2026-05-04T12:00:00.000Z","time":1,"request":{"method":"GET","url":"http://example.com/","httpVersion":"HTTP/1.1","headers":[],"headersSize":-1,"bodySize":0},"response":{"status":200,"statusText":"OK","httpVersion":"HTTP/1.1","headers":[],"headersSize":-1,"bodySize":5,"redirectURL":"","content":{"size":5,"mimeType":"text/plain","_sha1":"gone.txt"}}}}"#;
+    let zip = build_zip(&[snapshot], &[]);
+    let mut reader = TraceReader::open(Cursor::new(zip)).expect("open synthetic");
+    assert!(reader.resource("gone.txt").is_err());
+    let export = export_har(&mut reader, &HarExportOptions::default()).expect("export");
+    let content = &only_entry(&export.har)["response"]["content"];
+    assert!(content.get("text").is_none());
+    assert!(content.get("_sha1").is_none());
+}
+
+#[test]
+fn save_zip_writes_har_and_attachments() {
+    let export = export(HarContent::Attach);
+    let path = std::env::temp_dir().join(format!("har-export-{}.zip", std::process::id()));
+    export.save_zip(&path).expect("save zip");
+
+    let mut zip = zip::ZipArchive::new(std::fs::File::open(&path).expect("open")).expect("zip");
+    let mut har = String::new();
+    zip.by_name("har.har")
+        .expect("har.har entry")
+        .read_to_string(&mut har)
+        .expect("read har");
+    let har: Value = serde_json::from_str(&har).expect("json");
+    assert_eq!(har["log"]["version"], "1.2");
+    assert!(zip.by_name(FIXTURE_HTML_SHA1).is_ok(), "attachment stored");
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn resource_reads_body_bytes() {
+    let mut reader = TraceReader::open(Cursor::new(BASIC_FIXTURE)).expect("open fixture");
+    let body = reader.resource(FIXTURE_HTML_SHA1).expect("resource");
+    assert_eq!(body.len(), 99);
+}
+
+fn build_zip(network_lines: &[&str], resources: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    {
+        let mut zip = zip::ZipWriter::new(Cursor::new(&mut buf));
+        let opts = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+        zip.start_file("trace.trace", opts).expect("start trace");
+        zip.write_all(
+            br#"{"type":"context-options","version":8,"browserName":"chromium","playwrightVersion":"1.62.1"}
+"#,
+        )
+        .expect("write trace");
+        zip.start_file("trace.network", opts)
+            .expect("start network");
+        for line in network_lines {
+            zip.write_all(line.as_bytes()).expect("write line");
+            zip.write_all(b"\n").expect("write newline");
+        }
+        for (name, bytes) in resources {
+            zip.start_file(*name, opts).expect("start resource");
+            zip.write_all(bytes).expect("write resource");
+        }
+        zip.finish().expect("finish zip");
+    }
+    buf
+}
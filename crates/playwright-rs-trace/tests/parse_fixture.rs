@@ -93,6 +93,24 @@ fn typed_events_includes_known_kinds() {
     );
 }
 
+/// The console level lives in `messageType`; the event's own `type` is
+/// the `"console"` discriminator. Reading `type` left `level` empty for
+/// every message, so nothing could filter on `"error"`.
+#[test]
+fn console_event_carries_message_type_as_level() {
+    let mut reader = open_basic();
+    let console = reader
+        .events()
+        .expect("events stream")
+        .filter_map(|ev| match ev.expect("typed event") {
+            TraceEvent::Console(c) => Some(c),
+            _ => None,
+        })
+        .find(|c| c.text == "hi")
+        .expect("console message from the recorded onclick handler");
+    assert_eq!(console.level, "log");
+}
+
 #[test]
 fn actions_reassemble_a_click() {
     let mut reader = open_basic();
@@ -1,50 +1,44 @@
 // trace_on_failure example - save a Playwright trace when a test fails,
 // while ensuring browser/tracing cleanup always runs.
 //
-// Shows: anyhow + RUST_BACKTRACE=1 for failure-line diagnostics, plus the
-// explicit try-finally pattern for trace-on-failure (no async Drop in Rust).
+// Shows: anyhow + RUST_BACKTRACE=1 for failure-line diagnostics, plus
+// `ArtifactPolicy`, which starts the trace, runs the test, and keeps the
+// trace only if the test failed (there is no async Drop in Rust, so the
+// policy owns the context for the length of the test instead).
 //
 // Run:
 //     cargo run --package playwright-rs --example trace_on_failure
 //
 // To see the failure path: edit the assertion below to fail, then re-run with
 //     RUST_BACKTRACE=1 cargo run --package playwright-rs --example trace_on_failure
-// On failure, `trace.zip` is written and can be opened at https://trace.playwright.dev.
+// On failure, `test-results/example-domain-heading/trace.zip` is written and
+// can be opened at https://trace.playwright.dev.
 
 use anyhow::{Context, Result, ensure};
 use playwright_rs::Playwright;
-use playwright_rs::protocol::{BrowserContext, TracingStartOptions, TracingStopOptions};
+use playwright_rs::protocol::BrowserContext;
+use playwright_rs::testing::{ArtifactMode, ArtifactPolicy};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let playwright = Playwright::launch().await?;
     let browser = playwright.chromium().launch().await?;
     let context = browser.new_context().await?;
-    let tracing = context.tracing().await?;
 
-    // Optional: add .live(true) to allow attaching the trace viewer to an
-    // in-progress recording. Default false works for the typical post-mortem
-    // workflow used here.
-    tracing
-        .start(Some(
-            TracingStartOptions::default()
-                .name("trace-on-failure")
-                .screenshots(true)
-                .snapshots(true),
-        ))
+    // `On` keeps every trace and `OnFirstRetry` only records on a retry;
+    // `RetainOnFailure` is the usual choice for CI.
+    let policy = ArtifactPolicy::new(ArtifactMode::RetainOnFailure);
+    let run = policy
+        .run("example domain heading", context, |context| async move {
+            run_test(&context).await
+        })
         .await?;
-
-    let result = run_test(&context).await;
-
-    let stop_options = if result.is_err() {
-        TracingStopOptions::default().path("trace.zip")
-    } else {
-        TracingStopOptions::default()
-    };
-    let _ = tracing.stop(Some(stop_options)).await;
     let _ = browser.close().await;
 
-    result.context("test failed")
+    if let Some(trace) = &run.artifacts.trace {
+        eprintln!("trace saved to {}", trace.display());
+    }
+    run.into_outcome().context("test failed")
 }
 
 async fn run_test(context: &BrowserContext) -> Result<()> {
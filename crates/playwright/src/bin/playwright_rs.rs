@@ -1,5 +1,9 @@
 //! playwright-rs CLI — bootstrap the Playwright driver into a stable
-//! user cache and install browsers.
+//! user cache, install browsers, and record sessions as Rust tests.
+//!
+//! `show-trace`, `open`, `screenshot`, `pdf` and `uninstall` run the
+//! driver's own CLI from that cache, so none of them needs Node or `npx`
+//! (and none can pick up a driver of a different version).
 //!
 //! For downstream binaries distributed via `cargo install`, the
 //! compile-time `$OUT_DIR` driver path is invalidated when Cargo cleans
@@ -7,7 +11,8 @@
 //! `dirs::cache_dir()/playwright-rust/<version>/`, which the library's
 //! runtime resolution chain probes after the bundled lookup.
 
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
+use std::ffi::OsString;
 use std::path::PathBuf;
 use std::process::ExitCode;
 
@@ -35,26 +40,233 @@ enum Cmd {
         /// Intended for CI smoke tests and `cargo install` post-install bootstrap.
         #[arg(long)]
         driver_only: bool,
+        /// Print what would be installed, and where, without downloading browsers.
+        #[arg(long, conflicts_with = "driver_only")]
+        dry_run: bool,
+    },
+    /// Remove the browsers installed by this driver version.
+    Uninstall {
+        /// Remove every Playwright browser installation, not just this version's.
+        #[arg(long)]
+        all: bool,
+    },
+    /// Open traces in the trace viewer.
+    ShowTrace {
+        /// Trace zips or trace URLs. Omit to open an empty viewer.
+        traces: Vec<OsString>,
+        /// Serve the viewer on this host instead of opening a window.
+        #[arg(long)]
+        host: Option<String>,
+        /// Serve the viewer on this port instead of opening a window.
+        #[arg(long)]
+        port: Option<u16>,
     },
+    /// Open a page in a headed browser with the Playwright Inspector.
+    Open {
+        /// Page to open.
+        url: Option<String>,
+        #[command(flatten)]
+        page: PageArgs,
+    },
+    /// Save a screenshot of a page.
+    Screenshot {
+        url: String,
+        /// Where to save the image (`.png` or `.jpeg`).
+        filename: PathBuf,
+        /// Capture the whole scrollable page.
+        #[arg(long)]
+        full_page: bool,
+        #[command(flatten)]
+        wait: WaitArgs,
+        #[command(flatten)]
+        page: PageArgs,
+    },
+    /// Save a page as PDF (Chromium only).
+    Pdf {
+        url: String,
+        /// Where to save the PDF.
+        filename: PathBuf,
+        /// Paper format, e.g. `Letter` or `A4`.
+        #[arg(long)]
+        paper_format: Option<String>,
+        #[command(flatten)]
+        wait: WaitArgs,
+        #[command(flatten)]
+        page: PageArgs,
+    },
+    /// Open a browser with the recorder and write what you do as a Rust test.
+    Codegen {
+        /// Page to open first.
+        url: Option<String>,
+        /// Browser to record in: `chromium`, `firefox` or `webkit`.
+        #[arg(long, short, default_value = "chromium")]
+        browser: String,
+        /// Write the test to this file (kept up to date while recording)
+        /// instead of printing it when the browser closes.
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+        /// Name of the generated test function.
+        #[arg(long, default_value = "recorded")]
+        test_name: String,
+        /// Attribute to generate `get_by_test_id` locators for.
+        #[arg(long)]
+        test_id_attribute: Option<String>,
+    },
+}
+
+/// Browser and context options shared by `open`, `screenshot` and `pdf`,
+/// spelled as the driver CLI spells them.
+#[derive(Args)]
+struct PageArgs {
+    /// Browser to use: `chromium`, `firefox`, `webkit`, or a channel such
+    /// as `chrome` or `msedge`.
+    #[arg(long, short)]
+    browser: Option<String>,
+    /// Emulate a device, e.g. "iPhone 13".
+    #[arg(long)]
+    device: Option<String>,
+    /// Viewport size as `width,height`, e.g. `1280,720`.
+    #[arg(long)]
+    viewport_size: Option<String>,
+    /// Emulate `light` or `dark` color scheme.
+    #[arg(long)]
+    color_scheme: Option<String>,
+    /// Locale, e.g. `en-GB`.
+    #[arg(long)]
+    lang: Option<String>,
+}
+
+impl PageArgs {
+    fn push_to(&self, args: &mut Vec<OsString>) {
+        push_option(args, "--browser", &self.browser);
+        push_option(args, "--device", &self.device);
+        push_option(args, "--viewport-size", &self.viewport_size);
+        push_option(args, "--color-scheme", &self.color_scheme);
+        push_option(args, "--lang", &self.lang);
+    }
+}
+
+/// What `screenshot` and `pdf` wait for after the page loads.
+#[derive(Args)]
+struct WaitArgs {
+    /// Wait for this selector to appear before capturing.
+    #[arg(long)]
+    wait_for_selector: Option<String>,
+    /// Wait this many milliseconds before capturing.
+    #[arg(long)]
+    wait_for_timeout: Option<u64>,
+}
+
+impl WaitArgs {
+    fn push_to(&self, args: &mut Vec<OsString>) {
+        push_option(args, "--wait-for-selector", &self.wait_for_selector);
+        push_option(args, "--wait-for-timeout", &self.wait_for_timeout);
+    }
+}
+
+fn push_option(args: &mut Vec<OsString>, flag: &str, value: &Option<impl ToString>) {
+    if let Some(value) = value {
+        args.push(flag.into());
+        args.push(value.to_string().into());
+    }
 }
 
 #[tokio::main]
 async fn main() -> ExitCode {
     let cli = Cli::parse();
 
-    match cli.cmd {
+    let result = match cli.cmd {
         Cmd::Install {
             browsers,
             with_deps,
             driver_only,
-        } => match run_install(browsers, with_deps, driver_only).await {
-            Ok(()) => ExitCode::SUCCESS,
-            Err(e) => {
-                eprintln!("playwright-rs: {e}");
-                ExitCode::FAILURE
+            dry_run: false,
+        } => run_install(browsers, with_deps, driver_only)
+            .await
+            .map(|()| ExitCode::SUCCESS),
+        Cmd::Install {
+            browsers,
+            with_deps,
+            dry_run: true,
+            ..
+        } => {
+            let mut args: Vec<OsString> = vec!["install".into(), "--dry-run".into()];
+            args.extend(browsers.into_iter().map(OsString::from));
+            // Same implicit `--with-deps` on Linux as a real install.
+            if with_deps || cfg!(target_os = "linux") {
+                args.push("--with-deps".into());
+            }
+            run_driver(args).await
+        }
+        Cmd::Uninstall { all } => {
+            let mut args: Vec<OsString> = vec!["uninstall".into()];
+            if all {
+                args.push("--all".into());
+            }
+            run_driver(args).await
+        }
+        Cmd::ShowTrace { traces, host, port } => {
+            let mut args: Vec<OsString> = vec!["show-trace".into()];
+            push_option(&mut args, "--host", &host);
+            push_option(&mut args, "--port", &port);
+            args.extend(traces);
+            run_driver(args).await
+        }
+        Cmd::Open { url, page } => {
+            let mut args: Vec<OsString> = vec!["open".into()];
+            page.push_to(&mut args);
+            args.extend(url.map(OsString::from));
+            run_driver(args).await
+        }
+        Cmd::Screenshot {
+            url,
+            filename,
+            full_page,
+            wait,
+            page,
+        } => {
+            let mut args: Vec<OsString> = vec!["screenshot".into()];
+            page.push_to(&mut args);
+            wait.push_to(&mut args);
+            if full_page {
+                args.push("--full-page".into());
+            }
+            args.extend([url.into(), filename.into()]);
+            run_driver(args).await
+        }
+        Cmd::Pdf {
+            url,
+            filename,
+            paper_format,
+            wait,
+            page,
+        } => match page.browser.as_deref() {
+            None | Some("chromium" | "cr" | "chrome" | "msedge") => {
+                let mut args: Vec<OsString> = vec!["pdf".into()];
+                page.push_to(&mut args);
+                wait.push_to(&mut args);
+                push_option(&mut args, "--paper-format", &paper_format);
+                args.extend([url.into(), filename.into()]);
+                run_driver(args).await
+            }
+            Some(other) => {
+                Err(format!("`pdf` needs Chromium; `{other}` can't print to PDF").into())
             }
         },
-    }
+        Cmd::Codegen {
+            url,
+            browser,
+            output,
+            test_name,
+            test_id_attribute,
+        } => run_codegen(url, browser, output, test_name, test_id_attribute)
+            .await
+            .map(|()| ExitCode::SUCCESS),
+    };
+    result.unwrap_or_else(|e| {
+        eprintln!("playwright-rs: {e}");
+        ExitCode::FAILURE
+    })
 }
 
 async fn run_install(
@@ -62,10 +274,7 @@ async fn run_install(
     with_deps: bool,
     driver_only: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let version = env!("PLAYWRIGHT_DRIVER_VERSION");
-    let platform = env!("PLAYWRIGHT_DRIVER_PLATFORM");
-
-    let driver_dir = ensure_driver_in_user_cache(version, platform)?;
+    let driver_dir = user_cache_driver()?;
     eprintln!("Driver ready at: {}", driver_dir.display());
 
     if driver_only {
@@ -98,6 +307,126 @@ async fn run_install(
     Ok(())
 }
 
+/// Run the driver's own CLI (`node package/cli.js <args>`) from the user
+/// cache with the terminal attached, and exit with its status.
+async fn run_driver(args: Vec<OsString>) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let driver_dir = user_cache_driver()?;
+    let node = if env!("PLAYWRIGHT_DRIVER_PLATFORM").starts_with("win32") {
+        driver_dir.join("node.exe")
+    } else {
+        driver_dir.join("node")
+    };
+    let status = tokio::process::Command::new(&node)
+        .arg(driver_dir.join("package").join("cli.js"))
+        .args(args)
+        .status()
+        .await
+        .map_err(|e| format!("could not run {}: {e}", node.display()))?;
+    Ok(status
+        .code()
+        .and_then(|code| u8::try_from(code).ok())
+        .map_or(ExitCode::FAILURE, ExitCode::from))
+}
+
+/// Record a session in a headed browser with the driver's recorder and
+/// turn it into a `#[playwright_rs::test]` with `codegen::RustGenerator`.
+async fn run_codegen(
+    url: Option<String>,
+    browser: String,
+    output: Option<PathBuf>,
+    test_name: String,
+    test_id_attribute: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use playwright_rs::codegen::RustGenerator;
+    use playwright_rs::{LaunchOptions, Playwright, RecorderOptions};
+    use std::sync::{Arc, Mutex};
+
+    if !["chromium", "firefox", "webkit"].contains(&browser.as_str()) {
+        return Err(
+            format!("unknown browser `{browser}`; expected chromium, firefox or webkit").into(),
+        );
+    }
+    let playwright = Playwright::launch().await?;
+    let browser_type = match browser.as_str() {
+        "firefox" => playwright.firefox(),
+        "webkit" => playwright.webkit(),
+        _ => playwright.chromium(),
+    };
+    let browser_handle = browser_type
+        .launch_with_options(LaunchOptions::new().headless(false))
+        .await?;
+    let context = browser_handle.new_context().await?;
+
+    let mut generator = RustGenerator::new().test_name(test_name);
+    if browser != "chromium" {
+        generator = generator.browser(browser);
+    }
+    let generator = Arc::new(Mutex::new(generator));
+    let sink = generator.clone();
+    let file = output.clone();
+    context
+        .on_recorder_event(move |event| {
+            let source = {
+                let mut generator = sink.lock().unwrap();
+                generator.record(event);
+                generator.source()
+            };
+            let file = file.clone();
+            async move {
+                if let Some(file) = file
+                    && let Err(e) = std::fs::write(&file, source)
+                {
+                    eprintln!("playwright-rs: could not write {}: {e}", file.display());
+                }
+                Ok(())
+            }
+        })
+        .await?;
+
+    let mut options = RecorderOptions::default();
+    if let Some(attribute) = test_id_attribute {
+        options = options.test_id_attribute_name(attribute);
+    }
+    context.enable_recorder(Some(options)).await?;
+
+    let page = context.new_page().await?;
+    let closed = Arc::new(tokio::sync::Notify::new());
+    let on_closed = closed.clone();
+    page.on_close(move || {
+        on_closed.notify_one();
+        async { Ok(()) }
+    })
+    .await?;
+    if let Some(url) = url {
+        page.goto(&url, None).await?;
+    }
+
+    eprintln!("Recording. Close the browser window or press Ctrl+C to finish.");
+    tokio::select! {
+        _ = closed.notified() => {}
+        _ = tokio::signal::ctrl_c() => {}
+    }
+
+    let source = generator.lock().unwrap().source();
+    match &output {
+        Some(file) => {
+            std::fs::write(file, &source)?;
+            eprintln!("Wrote {}", file.display());
+        }
+        None => print!("{source}"),
+    }
+    let _ = browser_handle.close().await;
+    Ok(())
+}
+
+/// [`ensure_driver_in_user_cache`] for the driver this binary was built against.
+fn user_cache_driver() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    ensure_driver_in_user_cache(
+        env!("PLAYWRIGHT_DRIVER_VERSION"),
+        env!("PLAYWRIGHT_DRIVER_PLATFORM"),
+    )
+}
+
 /// Ensure the Playwright driver exists at
 /// `<cache>/playwright-rust/<version>/playwright-<version>-<platform>/`.
 /// Assembles it from npm + nodejs.org if absent. Returns the driver dir.
@@ -0,0 +1,555 @@
+//! Rust code generation for recorded sessions — the language generator
+//! behind `playwright-rs codegen`.
+//!
+//! The driver's recorder knows how to write JavaScript, Python, Java and
+//! C#, but not Rust. [`RustGenerator`] fills that gap on this side of the
+//! wire: it consumes the [`RecorderEvent`]s that
+//! [`BrowserContext::on_recorder_event`](crate::BrowserContext::on_recorder_event)
+//! delivers and writes a `#[playwright_rs::test]` using this crate's
+//! `get_by_*` locators, actions and [`expect`](crate::expect) assertions.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use playwright_rs::codegen::RustGenerator;
+//! use playwright_rs::{Playwright, RecorderOptions};
+//! use std::sync::{Arc, Mutex};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let playwright = Playwright::launch().await?;
+//! let browser = playwright.chromium().launch().await?;
+//! let context = browser.new_context().await?;
+//!
+//! let generator = Arc::new(Mutex::new(RustGenerator::new()));
+//! let sink = generator.clone();
+//! context
+//!     .on_recorder_event(move |event| {
+//!         sink.lock().unwrap().record(event);
+//!         async { Ok(()) }
+//!     })
+//!     .await?;
+//! context.enable_recorder(Some(RecorderOptions::default())).await?;
+//!
+//! // ... interact with the browser ...
+//!
+//! println!("{}", generator.lock().unwrap().source());
+//! # Ok(())
+//! # }
+//! ```
+
+mod locator;
+
+use crate::protocol::{Action, RecordedAction, RecorderEvent, Signal};
+use locator::string_literal;
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+
+/// Names the generated code imports from `playwright_rs`.
+type Imports = BTreeSet<&'static str>;
+
+/// Turns recorded actions into a Rust test. See the [module docs](self).
+#[derive(Debug, Clone)]
+pub struct RustGenerator {
+    actions: Vec<RecordedAction>,
+    test_name: String,
+    browser: Option<String>,
+}
+
+impl Default for RustGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RustGenerator {
+    /// A generator for a test named `recorded`, on the default browser.
+    pub fn new() -> Self {
+        Self {
+            actions: Vec::new(),
+            test_name: "recorded".into(),
+            browser: None,
+        }
+    }
+
+    /// Name the generated test function.
+    pub fn test_name(mut self, name: impl Into<String>) -> Self {
+        self.test_name = name.into();
+        self
+    }
+
+    /// Run the generated test on `browser` (`"firefox"`, `"webkit"`)
+    /// instead of Chromium.
+    pub fn browser(mut self, browser: impl Into<String>) -> Self {
+        self.browser = Some(browser.into());
+        self
+    }
+
+    /// Apply one recorder event: a new action is appended, an updated or
+    /// signalled one replaces the last.
+    pub fn record(&mut self, event: RecorderEvent) {
+        match event {
+            RecorderEvent::ActionAdded(action) => self.actions.push(action),
+            RecorderEvent::ActionUpdated(action) | RecorderEvent::SignalAdded(action) => {
+                match self.actions.last_mut() {
+                    Some(last) => *last = action,
+                    None => self.actions.push(action),
+                }
+            }
+        }
+    }
+
+    /// The actions recorded so far.
+    pub fn actions(&self) -> &[RecordedAction] {
+        &self.actions
+    }
+
+    /// The whole test file for the actions recorded so far.
+    pub fn source(&self) -> String {
+        let mut imports = Imports::from(["Page"]);
+        let mut body = String::new();
+        let mut pages = BTreeSet::from(["page".to_string()]);
+        let mut dialogs_handled = BTreeSet::new();
+        let mut uses_context = false;
+        // The page and URL of a `goto` just written, so the navigation the
+        // recorder reports for it is not written again.
+        let mut last_goto: Option<(&str, &str)> = None;
+
+        for recorded in &self.actions {
+            let alias = recorded.frame.page_alias.as_str();
+            if let Action::Navigate { url, .. } = &recorded.action
+                && last_goto == Some((alias, url.as_str()))
+            {
+                continue;
+            }
+            last_goto = None;
+            if let Action::OpenPage { url, .. } = &recorded.action {
+                // The first page is the test's; popups were declared by the
+                // action that opened them.
+                if pages.insert(alias.to_string()) {
+                    uses_context = true;
+                    let _ = writeln!(body, "    let {alias} = context.new_page().await?;");
+                    if !is_blank(url) {
+                        let _ = writeln!(
+                            body,
+                            "    {alias}.goto({}, None).await?;",
+                            string_literal(url)
+                        );
+                        last_goto = Some((alias, url));
+                    }
+                } else if alias == "page" && !is_blank(url) && self.is_first(recorded) {
+                    let _ = writeln!(body, "    page.goto({}, None).await?;", string_literal(url));
+                    last_goto = Some((alias, url));
+                }
+                continue;
+            }
+
+            let mut after = Vec::new();
+            for signal in recorded.signals() {
+                match signal {
+                    Signal::Popup { popup_alias } => {
+                        let _ = writeln!(
+                            body,
+                            "    let {popup_alias}_popup = {alias}.expect_popup(None).await?;"
+                        );
+                        after.push(format!(
+                            "    let {popup_alias} = {popup_alias}_popup.wait().await?;"
+                        ));
+                        pages.insert(popup_alias.clone());
+                    }
+                    Signal::Download { download_alias } => {
+                        let _ = writeln!(
+                            body,
+                            "    let {download_alias}_waiter = {alias}.expect_download(None).await?;"
+                        );
+                        after.push(format!(
+                            "    let {download_alias} = {download_alias}_waiter.wait().await?;"
+                        ));
+                    }
+                    Signal::Dialog { .. } if dialogs_handled.insert(alias.to_string()) => {
+                        let _ = writeln!(
+                            body,
+                            "    {alias}.on_dialog(|dialog| async move {{ dialog.dismiss().await }}).await?;"
+                        );
+                    }
+                    _ => {}
+                }
+            }
+            let _ = writeln!(body, "    {}", statement(recorded, &mut imports));
+            if let Action::Navigate { url, .. } = &recorded.action {
+                last_goto = Some((alias, url));
+            }
+            for line in after {
+                let _ = writeln!(body, "{line}");
+            }
+        }
+
+        let mut source = String::from("// Recorded with `playwright-rs codegen`.\n\n");
+        if uses_context {
+            imports.insert("BrowserContext");
+        }
+        let imports: Vec<_> = imports.into_iter().collect();
+        let _ = writeln!(source, "use playwright_rs::{{{}}};\n", imports.join(", "));
+        match &self.browser {
+            Some(browser) => {
+                let _ = writeln!(source, "#[playwright_rs::test(browser = {browser:?})]");
+            }
+            None => source.push_str("#[playwright_rs::test]\n"),
+        }
+        let params = if uses_context {
+            "context: BrowserContext, page: Page"
+        } else {
+            "page: Page"
+        };
+        let _ = writeln!(
+            source,
+            "async fn {}({params}) -> playwright_rs::Result<()> {{",
+            self.test_name
+        );
+        source.push_str(&body);
+        source.push_str("    Ok(())\n}\n");
+        source
+    }
+
+    /// Whether `action` is the first recorded action, i.e. the test's own
+    /// page opening rather than a later navigation.
+    fn is_first(&self, action: &RecordedAction) -> bool {
+        self.actions
+            .first()
+            .is_some_and(|first| std::ptr::eq(first, action))
+    }
+}
+
+fn is_blank(url: &str) -> bool {
+    url.is_empty() || url == "about:blank" || url == "chrome://newtab/"
+}
+
+/// The statement for one action, without indentation.
+fn statement(recorded: &RecordedAction, imports: &mut Imports) -> String {
+    let mut root = recorded.frame.page_alias.clone();
+    for frame in &recorded.frame.frame_path {
+        root = format!(
+            "{}.content_frame()",
+            locator::expression(&root, frame, imports)
+        );
+    }
+    let page = &recorded.frame.page_alias;
+    let locate =
+        |selector: &str, imports: &mut Imports| locator::expression(&root, selector, imports);
+
+    match &recorded.action {
+        Action::OpenPage { url, .. } | Action::Navigate { url, .. } => {
+            format!("{page}.goto({}, None).await?;", string_literal(url))
+        }
+        Action::ClosePage { .. } => format!("{page}.close().await?;"),
+        Action::Click {
+            selector,
+            button,
+            modifiers,
+            click_count,
+            ..
+        } => {
+            let target = locate(selector, imports);
+            if button == "left" && *modifiers == 0 {
+                match click_count {
+                    1 => return format!("{target}.click(None).await?;"),
+                    2 => return format!("{target}.dblclick(None).await?;"),
+                    _ => {}
+                }
+            }
+            imports.insert("ClickOptions");
+            let mut options = String::from("ClickOptions::builder()");
+            if button != "left" {
+                imports.insert("MouseButton");
+                let _ = write!(options, ".button(MouseButton::{})", capitalize(button));
+            }
+            if *modifiers != 0 {
+                imports.insert("KeyboardModifier");
+                let names: Vec<_> = modifier_names(*modifiers)
+                    .map(|name| format!("KeyboardModifier::{name}"))
+                    .collect();
+                let _ = write!(options, ".modifiers(vec![{}])", names.join(", "));
+            }
+            if *click_count != 1 {
+                let _ = write!(options, ".click_count({click_count})");
+            }
+            format!("{target}.click({options}.build()).await?;")
+        }
+        Action::Fill { selector, text, .. } => format!(
+            "{}.fill({}, None).await?;",
+            locate(selector, imports),
+            string_literal(text)
+        ),
+        Action::Press {
+            selector,
+            key,
+            modifiers,
+            ..
+        } => {
+            let mut shortcut: Vec<&str> = modifier_names(*modifiers).collect();
+            shortcut.push(key);
+            format!(
+                "{}.press({}, None).await?;",
+                locate(selector, imports),
+                string_literal(&shortcut.join("+"))
+            )
+        }
+        Action::Check { selector, .. } => {
+            format!("{}.check(None).await?;", locate(selector, imports))
+        }
+        Action::Uncheck { selector, .. } => {
+            format!("{}.uncheck(None).await?;", locate(selector, imports))
+        }
+        Action::Select {
+            selector, options, ..
+        } => {
+            let target = locate(selector, imports);
+            match options.as_slice() {
+                [option] => format!(
+                    "{target}.select_option({}, None).await?;",
+                    string_literal(option)
+                ),
+                _ => {
+                    let values: Vec<_> = options.iter().map(|o| string_literal(o)).collect();
+                    format!(
+                        "{target}.select_option_multiple(&[{}], None).await?;",
+                        values.join(", ")
+                    )
+                }
+            }
+        }
+        Action::SetInputFiles {
+            selector, files, ..
+        } => {
+            let target = locate(selector, imports);
+            let paths: Vec<_> = files
+                .iter()
+                .map(|file| format!("&std::path::PathBuf::from({})", string_literal(file)))
+                .collect();
+            match paths.as_slice() {
+                [path] => format!("{target}.set_input_files({path}, None).await?;"),
+                _ => format!(
+                    "{target}.set_input_files_multiple(&[{}], None).await?;",
+                    paths.join(", ")
+                ),
+            }
+        }
+        Action::AssertText {
+            selector,
+            text,
+            substring,
+            ..
+        } => {
+            let assertion = if *substring {
+                "to_contain_text"
+            } else {
+                "to_have_text"
+            };
+            expect(
+                locate(selector, imports),
+                assertion,
+                &string_literal(text),
+                imports,
+            )
+        }
+        Action::AssertValue {
+            selector, value, ..
+        } => expect(
+            locate(selector, imports),
+            "to_have_value",
+            &string_literal(value),
+            imports,
+        ),
+        Action::AssertChecked {
+            selector, checked, ..
+        } => {
+            let assertion = if *checked {
+                "to_be_checked"
+            } else {
+                "to_be_unchecked"
+            };
+            expect(locate(selector, imports), assertion, "", imports)
+        }
+        Action::AssertVisible { selector, .. } => {
+            expect(locate(selector, imports), "to_be_visible", "", imports)
+        }
+        Action::AssertSnapshot {
+            selector,
+            aria_snapshot,
+            ..
+        } => expect(
+            locate(selector, imports),
+            "to_match_aria_snapshot",
+            &string_literal(aria_snapshot),
+            imports,
+        ),
+        Action::Other => "// (an action this version of playwright-rs can't generate)".into(),
+    }
+}
+
+fn expect(target: String, assertion: &str, argument: &str, imports: &mut Imports) -> String {
+    imports.insert("expect");
+    format!("expect({target}).{assertion}({argument}).await?;")
+}
+
+/// The held modifiers, in Playwright's order.
+fn modifier_names(modifiers: u32) -> impl Iterator<Item = &'static str> {
+    [(1, "Alt"), (2, "Control"), (4, "Meta"), (8, "Shift")]
+        .into_iter()
+        .filter(move |(bit, _)| modifiers & bit != 0)
+        .map(|(_, name)| name)
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn added(page: &str, action: serde_json::Value) -> RecorderEvent {
+        RecorderEvent::from_params(&json!({
+            "event": "actionAdded",
+            "data": {
+                "frame": { "pageGuid": "page@1", "pageAlias": page, "framePath": [] },
+                "action": action,
+                "startTime": 0,
+            },
+        }))
+        .expect("a known action")
+    }
+
+    fn updated(event: RecorderEvent) -> RecorderEvent {
+        RecorderEvent::ActionUpdated(event.action().clone())
+    }
+
+    #[test]
+    fn generates_a_test_from_recorded_actions() {
+        let mut generator = RustGenerator::new();
+        generator.record(added(
+            "page",
+            json!({ "name": "openPage", "url": "https://example.com/", "signals": [] }),
+        ));
+        // The recorder reports the page's first load as a navigation too.
+        generator.record(added(
+            "page",
+            json!({ "name": "navigate", "url": "https://example.com/", "signals": [] }),
+        ));
+        generator.record(added(
+            "page",
+            json!({
+                "name": "fill",
+                "selector": "internal:label=\"Email\"i",
+                "text": "a",
+                "signals": [],
+            }),
+        ));
+        // Typing more updates the same fill.
+        generator.record(updated(added(
+            "page",
+            json!({
+                "name": "fill",
+                "selector": "internal:label=\"Email\"i",
+                "text": "alice@example.com",
+                "signals": [],
+            }),
+        )));
+        generator.record(added(
+            "page",
+            json!({
+                "name": "click",
+                "selector": "internal:role=button[name=\"Sign in\"i]",
+                "button": "left",
+                "modifiers": 0,
+                "clickCount": 1,
+                "signals": [{ "name": "navigation", "url": "https://example.com/home" }],
+            }),
+        ));
+        generator.record(added(
+            "page",
+            json!({
+                "name": "assertText",
+                "selector": "internal:role=heading",
+                "text": "Welcome",
+                "substring": true,
+                "signals": [],
+            }),
+        ));
+
+        assert_eq!(
+            generator.source(),
+            r#"// Recorded with `playwright-rs codegen`.
+
+use playwright_rs::{AriaRole, GetByRoleOptions, Page, expect};
+
+#[playwright_rs::test]
+async fn recorded(page: Page) -> playwright_rs::Result<()> {
+    page.goto("https://example.com/", None).await?;
+    page.get_by_label("Email", false).fill("alice@example.com", None).await?;
+    page.get_by_role(AriaRole::Button, Some(GetByRoleOptions::default().name("Sign in"))).click(None).await?;
+    expect(page.get_by_role(AriaRole::Heading, None)).to_contain_text("Welcome").await?;
+    Ok(())
+}
+"#
+        );
+    }
+
+    #[test]
+    fn popups_wait_for_the_page_the_action_opens() {
+        let mut generator = RustGenerator::new()
+            .test_name("opens_docs")
+            .browser("firefox");
+        generator.record(added(
+            "page",
+            json!({ "name": "openPage", "url": "about:blank", "signals": [] }),
+        ));
+        generator.record(added(
+            "page",
+            json!({
+                "name": "click",
+                "selector": "internal:role=link[name=\"Docs\"s]",
+                "button": "left",
+                "modifiers": 2,
+                "clickCount": 1,
+                "signals": [{ "name": "popup", "popupAlias": "page1" }],
+            }),
+        ));
+        generator.record(added(
+            "page1",
+            json!({ "name": "openPage", "url": "https://example.com/docs", "signals": [] }),
+        ));
+        generator.record(added(
+            "page1",
+            json!({ "name": "closePage", "signals": [] }),
+        ));
+
+        let source = generator.source();
+        assert!(source.contains(
+            "#[playwright_rs::test(browser = \"firefox\")]\nasync fn opens_docs(page: Page)"
+        ));
+        assert!(source.contains(
+            "    let page1_popup = page.expect_popup(None).await?;\n    \
+             page.get_by_role(AriaRole::Link, Some(GetByRoleOptions::default().name(\"Docs\").exact(true)))\
+             .click(ClickOptions::builder().modifiers(vec![KeyboardModifier::Control]).build()).await?;\n    \
+             let page1 = page1_popup.wait().await?;\n    \
+             page1.close().await?;\n"
+        ));
+        assert!(!source.contains("goto"));
+        assert!(source.contains(
+            "use playwright_rs::{AriaRole, ClickOptions, GetByRoleOptions, KeyboardModifier, Page};"
+        ));
+    }
+
+    #[test]
+    fn unknown_actions_are_kept_as_comments() {
+        let mut generator = RustGenerator::new();
+        generator.record(added("page", json!({ "name": "hover", "selector": "a" })));
+        assert!(generator.source().contains("    // (an action"));
+    }
+}
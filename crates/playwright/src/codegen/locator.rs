@@ -0,0 +1,324 @@
+//! Recorder selectors → Rust locator expressions.
+//!
+//! The recorder generates selectors in Playwright's internal syntax, the
+//! same one `get_by_*` produces: `internal:role=button[name="Save"i]`,
+//! `internal:testid=[data-testid="x"s]`, `... >> nth=1`. This turns each
+//! `>>` part back into the call that produces it, so the generated code
+//! reads the way a person would write it. A selector with a part that has
+//! no such call stays a plain `.locator("...")`.
+
+use super::Imports;
+
+/// The expression for `selector`, relative to `root` (a page, a frame
+/// locator, or a locator).
+pub(super) fn expression(root: &str, selector: &str, imports: &mut Imports) -> String {
+    let mut used = Imports::default();
+    match chain(root, selector, &mut used) {
+        Some(expr) => {
+            imports.extend(used);
+            expr
+        }
+        None => format!("{root}.locator({})", string_literal(selector)),
+    }
+}
+
+/// `None` if any part has no equivalent call.
+fn chain(root: &str, selector: &str, imports: &mut Imports) -> Option<String> {
+    let mut expr = root.to_string();
+    for (index, part) in split(selector).into_iter().enumerate() {
+        let (engine, body) = match part.split_once('=') {
+            Some((engine, body)) if is_engine(engine) => (engine, body),
+            _ => ("css", part),
+        };
+        let call = match engine {
+            "internal:role" => role(body, imports)?,
+            "internal:testid" => {
+                let (name, value, _) = attribute(body)?;
+                (name == "data-testid")
+                    .then(|| format!("get_by_test_id({})", string_literal(&value)))?
+            }
+            "internal:text" => text_call("get_by_text", body)?,
+            "internal:label" => text_call("get_by_label", body)?,
+            "internal:attr" => {
+                let (name, value, exact) = attribute(body)?;
+                let method = match name.as_str() {
+                    "placeholder" => "get_by_placeholder",
+                    "alt" => "get_by_alt_text",
+                    "title" => "get_by_title",
+                    _ => return None,
+                };
+                format!("{method}({}, {exact})", string_literal(&value))
+            }
+            "internal:control" if body == "enter-frame" && index > 0 => "content_frame()".into(),
+            "internal:has-text" | "internal:has-not-text" if index > 0 => {
+                let (text, exact) = quoted(body)?;
+                if exact {
+                    return None;
+                }
+                imports.insert("FilterOptions");
+                let setter = &engine["internal:".len()..].replace('-', "_");
+                format!(
+                    "filter(FilterOptions::default().{setter}({}))",
+                    string_literal(&text)
+                )
+            }
+            "internal:has" | "internal:has-not" if index > 0 => {
+                let inner: String = serde_json::from_str(body).ok()?;
+                let inner = chain(root_of(root), &inner, imports)?;
+                imports.insert("FilterOptions");
+                let setter = &engine["internal:".len()..].replace('-', "_");
+                format!("filter(FilterOptions::default().{setter}({inner}))")
+            }
+            "nth" if index > 0 => match body.parse::<i32>().ok()? {
+                0 => "first()".into(),
+                -1 => "last()".into(),
+                n => format!("nth({n})"),
+            },
+            "css" | "xpath" | "text" | "id" | "data-testid" => {
+                format!("locator({})", string_literal(part))
+            }
+            _ => return None,
+        };
+        expr = format!("{expr}.{call}");
+    }
+    Some(expr)
+}
+
+/// The page a frame-locator root hangs off (`page.locator(..).content_frame()`
+/// → `page`): nested `has` selectors are relative to the page, not the frame.
+fn root_of(root: &str) -> &str {
+    root.split('.').next().unwrap_or(root)
+}
+
+fn is_engine(engine: &str) -> bool {
+    !engine.is_empty()
+        && engine
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | ':' | '+' | '*'))
+}
+
+/// Split on `>>` outside JSON-quoted strings.
+fn split(selector: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut quoted = false;
+    let mut escaped = false;
+    let bytes = selector.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if escaped {
+            escaped = false;
+        } else if c == b'\\' {
+            escaped = true;
+        } else if c == b'"' {
+            quoted = !quoted;
+        } else if !quoted && c == b'>' && bytes.get(i + 1) == Some(&b'>') {
+            parts.push(selector[start..i].trim());
+            start = i + 2;
+            i += 1;
+        }
+        i += 1;
+    }
+    parts.push(selector[start..].trim());
+    parts
+}
+
+/// `"Save"i` → (`Save`, false); `"Save"s` → (`Save`, true). `None` for a
+/// regex (`/save/i`) or trailing text.
+fn quoted(body: &str) -> Option<(String, bool)> {
+    let (text, rest) = json_string(body)?;
+    match rest {
+        "" | "i" => Some((text, false)),
+        "s" => Some((text, true)),
+        _ => None,
+    }
+}
+
+/// The JSON string at the start of `s` and what follows it.
+fn json_string(s: &str) -> Option<(String, &str)> {
+    let mut stream = serde_json::Deserializer::from_str(s).into_iter::<String>();
+    let text = stream.next()?.ok()?;
+    Some((text, &s[stream.byte_offset()..]))
+}
+
+fn text_call(method: &str, body: &str) -> Option<String> {
+    let (text, exact) = quoted(body)?;
+    Some(format!("{method}({}, {exact})", string_literal(&text)))
+}
+
+/// `[name="value"s]` → (`name`, `value`, exact).
+fn attribute(body: &str) -> Option<(String, String, bool)> {
+    let inner = body.strip_prefix('[')?.strip_suffix(']')?;
+    let (name, value) = inner.split_once('=')?;
+    let (value, exact) = quoted(value)?;
+    Some((name.to_string(), value, exact))
+}
+
+/// `button[name="Save"i][level=2]` → `get_by_role(AriaRole::Button, ...)`.
+fn role(body: &str, imports: &mut Imports) -> Option<String> {
+    let split = body.find('[').unwrap_or(body.len());
+    let variant = variant(&body[..split])?;
+    let mut rest = &body[split..];
+    let mut options = String::new();
+    while let Some(attr) = rest.strip_prefix('[') {
+        let (name, after_name) = attr.split_at(attr.find(['=', ']'])?);
+        if let Some(after) = after_name.strip_prefix(']') {
+            // A bare `[pressed]` means `[pressed=true]`.
+            options.push_str(&role_word(name, "true")?);
+            rest = after;
+        } else if after_name[1..].starts_with('"') {
+            let (text, after) = json_string(&after_name[1..])?;
+            let (exact, after) = match after.strip_prefix('s') {
+                Some(after) => (true, after),
+                None => (false, after.strip_prefix('i').unwrap_or(after)),
+            };
+            options.push_str(&role_text(name, &text)?);
+            if exact {
+                options.push_str(".exact(true)");
+            }
+            rest = after.strip_prefix(']')?;
+        } else {
+            let (word, after) = after_name[1..].split_once(']')?;
+            options.push_str(&role_word(name, word)?);
+            rest = after;
+        }
+    }
+    if !rest.is_empty() {
+        return None;
+    }
+    imports.insert("AriaRole");
+    if options.is_empty() {
+        return Some(format!("get_by_role(AriaRole::{variant}, None)"));
+    }
+    imports.insert("GetByRoleOptions");
+    Some(format!(
+        "get_by_role(AriaRole::{variant}, Some(GetByRoleOptions::default(){options}))"
+    ))
+}
+
+/// The `GetByRoleOptions` setter for a quoted role attribute.
+fn role_text(name: &str, text: &str) -> Option<String> {
+    matches!(name, "name" | "description").then(|| format!(".{name}({})", string_literal(text)))
+}
+
+/// The `GetByRoleOptions` setter for an unquoted role attribute.
+fn role_word(name: &str, word: &str) -> Option<String> {
+    match name {
+        "checked" | "disabled" | "selected" | "expanded" | "pressed" | "include-hidden"
+            if word == "true" || word == "false" =>
+        {
+            Some(format!(".{}({word})", name.replace('-', "_")))
+        }
+        "level" => Some(format!(".level({})", word.parse::<u32>().ok()?)),
+        _ => None,
+    }
+}
+
+/// `button` → `Button`, if it is a role `AriaRole` has.
+fn variant(role: &str) -> Option<String> {
+    crate::protocol::AriaRole::ALL
+        .iter()
+        .find(|known| known.as_str() == role)
+        .map(|known| format!("{known:?}"))
+}
+
+/// A Rust string literal for `s`: raw when it spans lines or has quotes,
+/// escaped otherwise.
+pub(super) fn string_literal(s: &str) -> String {
+    if (s.contains('\n') || s.contains('"')) && !s.contains("\"#") && !s.contains('\r') {
+        format!("r#\"{s}\"#")
+    } else {
+        format!("{s:?}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expr(selector: &str) -> String {
+        expression("page", selector, &mut Imports::default())
+    }
+
+    #[test]
+    fn role_with_name() {
+        assert_eq!(
+            expr(r#"internal:role=button[name="Save"i]"#),
+            r#"page.get_by_role(AriaRole::Button, Some(GetByRoleOptions::default().name("Save")))"#
+        );
+        assert_eq!(
+            expr(r#"internal:role=heading[level=2][name="Intro"s]"#),
+            r#"page.get_by_role(AriaRole::Heading, Some(GetByRoleOptions::default().level(2).name("Intro").exact(true)))"#
+        );
+        assert_eq!(
+            expr("internal:role=checkbox[checked=true]"),
+            "page.get_by_role(AriaRole::Checkbox, Some(GetByRoleOptions::default().checked(true)))"
+        );
+        assert_eq!(
+            expr("internal:role=navigation"),
+            "page.get_by_role(AriaRole::Navigation, None)"
+        );
+    }
+
+    #[test]
+    fn text_label_and_attribute_engines() {
+        assert_eq!(
+            expr(r#"internal:text="Sign in"i"#),
+            r#"page.get_by_text("Sign in", false)"#
+        );
+        assert_eq!(
+            expr(r#"internal:label="Email"s"#),
+            r#"page.get_by_label("Email", true)"#
+        );
+        assert_eq!(
+            expr(r#"internal:attr=[placeholder="you@example.com"i]"#),
+            r#"page.get_by_placeholder("you@example.com", false)"#
+        );
+        assert_eq!(
+            expr(r#"internal:testid=[data-testid="submit"s]"#),
+            r#"page.get_by_test_id("submit")"#
+        );
+    }
+
+    #[test]
+    fn chains_filters_and_nth() {
+        assert_eq!(
+            expr(r#"li >> internal:has-text="milk"i >> nth=1"#),
+            r#"page.locator("li").filter(FilterOptions::default().has_text("milk")).nth(1)"#
+        );
+        assert_eq!(
+            expr(r#"tr >> internal:has="internal:role=checkbox" >> nth=-1"#),
+            "page.locator(\"tr\").filter(FilterOptions::default().has(page.get_by_role(AriaRole::Checkbox, None))).last()"
+        );
+        assert_eq!(
+            expr(r#"iframe[name="pay"] >> internal:control=enter-frame >> internal:label="Card"i"#),
+            r##"page.locator(r#"iframe[name="pay"]"#).content_frame().get_by_label("Card", false)"##
+        );
+    }
+
+    #[test]
+    fn unknown_parts_fall_back_to_the_whole_selector() {
+        assert_eq!(
+            expr(r#"internal:text=/sign\s+in/i"#),
+            r#"page.locator("internal:text=/sign\\s+in/i")"#
+        );
+        assert_eq!(
+            expr("internal:role=buton"),
+            r#"page.locator("internal:role=buton")"#
+        );
+        assert_eq!(expr("nth=0"), r#"page.locator("nth=0")"#);
+    }
+
+    #[test]
+    fn imports_are_collected_only_for_converted_selectors() {
+        let mut imports = Imports::default();
+        expression("page", "internal:role=buton >> nth=0", &mut imports);
+        assert!(imports.is_empty());
+        expression("page", r#"internal:role=link[name="Docs"i]"#, &mut imports);
+        assert_eq!(
+            imports.into_iter().collect::<Vec<_>>(),
+            ["AriaRole", "GetByRoleOptions"]
+        );
+    }
+}
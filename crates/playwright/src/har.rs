@@ -0,0 +1,66 @@
+//! HAR archives on the Rust side: a serde model of HAR 1.2 with a reader
+//! and writer, and [`HarReplayer`], which serves recorded responses without
+//! the driver's HAR backend.
+//!
+//! # Reading and writing
+//!
+//! [`Har::read`] loads a `.har` or a recorded `.zip`, embedding bodies
+//! stored as attachments, and [`Har::write`] saves one. In between the
+//! archive is plain data, so recorded traffic can be checked or edited
+//! in Rust:
+//!
+//! ```no_run
+//! use playwright_rs::har::Har;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut har = Har::read("recordings/checkout.har").await?;
+//!
+//! assert!(har.hosts().iter().all(|h| h.ends_with("shop.example.com")));
+//! assert_eq!(har.call_counts()["POST https://shop.example.com/api/orders"], 1);
+//!
+//! har.log.entries.retain(|e| !e.request.url.contains("/analytics"));
+//! har.write("recordings/checkout.har").await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Replaying
+//!
+//! [`Page::route_from_har`](crate::protocol::Page::route_from_har) replays
+//! inside the driver: it cannot be told how to match a request, and its
+//! choices are not visible. [`HarReplayer`] matches and fulfills here,
+//! through a route, with a pluggable [`EntryMatcher`] and hooks that rewrite
+//! the matched entry before it is served.
+//!
+//! ```no_run
+//! use playwright_rs::har::HarReplayer;
+//! # use playwright_rs::Playwright;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let pw = Playwright::launch().await?;
+//! # let page = pw.chromium().launch().await?.new_page().await?;
+//! HarReplayer::open("fixtures/session.har")
+//!     .await?
+//!     .rewrite(|_request, entry| {
+//!         if let Some(text) = &mut entry.response.content.text {
+//!             *text = text.replace("2024-01-01", "2026-10-18");
+//!         }
+//!     })
+//!     .mount(&page)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zipped archives
+//!
+//! Reading or writing a `.zip` needs the `har-zip` feature; plain `.har`
+//! files, with embedded or attached bodies, need nothing extra.
+
+mod io;
+mod model;
+mod replay;
+
+pub use model::{
+    Content, Cookie, Creator, Entry, Har, Header, Log, Page, PageTimings, PostData, QueryParam,
+    Request, Response, Timings,
+};
+pub use replay::{DefaultMatcher, EntryMatcher, HarNotFound, HarReplayer, ReplayRequest};
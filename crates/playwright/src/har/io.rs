@@ -0,0 +1,245 @@
+//! Reading and writing HAR files.
+//!
+//! Playwright writes a `.har` either with bodies embedded as `text`, or in
+//! `attach` mode with each body in its own file beside the HAR and named by
+//! the entry's `_file`. A recorded `.zip` holds the HAR and those files
+//! together.
+//!
+//! Architecture Reference:
+//! - JavaScript: playwright/packages/playwright-core/src/server/har/harTracer.ts
+//!   (`_file` attachments) and localUtils.ts (`harUnzip`)
+
+use super::model::Har;
+use crate::error::{Error, Result};
+#[cfg(feature = "har-zip")]
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+impl Har {
+    /// Reads the archive at `path`: a `.har` file, whose attached bodies
+    /// are read from its directory, or a `.zip` as Playwright records it.
+    ///
+    /// Attached bodies are embedded as they are read, so the result no
+    /// longer refers to any file. Post data is embedded as text, with any
+    /// invalid UTF-8 replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file or an attachment cannot be read or
+    /// parsed, or if it is a `.zip` and the `har-zip` feature is disabled.
+    pub async fn read(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = tokio::fs::read(path)
+            .await
+            .map_err(|e| Error::InvalidPath(format!("HAR file {}: {}", path.display(), e)))?;
+        let (mut har, attachments) = if is_zip(path) {
+            read_zip(bytes)?
+        } else {
+            let dir = path.parent().unwrap_or(Path::new("")).to_path_buf();
+            (serde_json::from_slice(&bytes)?, Attachments::Dir(dir))
+        };
+
+        for entry in &mut har.log.entries {
+            if let Some(post_data) = &mut entry.request.post_data
+                && let Some(file) = post_data.file.take()
+            {
+                let body = attachments.read(&file).await?;
+                post_data.set_body(body);
+            }
+            if let Some(file) = &entry.response.content.file {
+                let body = attachments.read(file).await?;
+                entry.response.content.set_body(body);
+            }
+        }
+        Ok(har)
+    }
+
+    /// Writes the archive to `path` as pretty-printed JSON, or as a zip
+    /// holding `har.har` if `path` ends in `.zip`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written, or if it is a `.zip`
+    /// and the `har-zip` feature is disabled.
+    pub async fn write(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut json = serde_json::to_vec_pretty(self)?;
+        json.push(b'\n');
+        let bytes = if is_zip(path) {
+            write_zip(&json)?
+        } else {
+            json
+        };
+        tokio::fs::write(path, bytes)
+            .await
+            .map_err(|e| Error::InvalidPath(format!("HAR file {}: {}", path.display(), e)))
+    }
+}
+
+fn is_zip(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("zip"))
+}
+
+/// Where `_file` bodies live.
+enum Attachments {
+    Dir(PathBuf),
+    #[cfg(feature = "har-zip")]
+    Zip(HashMap<String, Vec<u8>>),
+}
+
+impl Attachments {
+    async fn read(&self, name: &str) -> Result<Vec<u8>> {
+        match self {
+            Self::Dir(dir) => {
+                let path = dir.join(name);
+                tokio::fs::read(&path).await.map_err(|e| {
+                    Error::InvalidPath(format!("HAR attachment {}: {}", path.display(), e))
+                })
+            }
+            #[cfg(feature = "har-zip")]
+            Self::Zip(files) => files
+                .get(name)
+                .cloned()
+                .ok_or_else(|| Error::InvalidPath(format!("HAR attachment {name} not in zip"))),
+        }
+    }
+}
+
+#[cfg(feature = "har-zip")]
+fn zip_error(e: zip::result::ZipError) -> Error {
+    Error::InvalidArgument(format!("HAR zip: {}", e))
+}
+
+/// Splits a Playwright HAR zip into the archive (its one `.har` member) and
+/// the attachments stored beside it.
+#[cfg(feature = "har-zip")]
+fn read_zip(bytes: Vec<u8>) -> Result<(Har, Attachments)> {
+    use std::io::Read as _;
+
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes)).map_err(zip_error)?;
+    let mut har = None;
+    let mut files = HashMap::new();
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index).map_err(zip_error)?;
+        if file.is_dir() {
+            continue;
+        }
+        let name = file.name().to_string();
+        let mut contents = Vec::new();
+        file.read_to_end(&mut contents)?;
+        if har.is_none() && name.ends_with(".har") {
+            har = Some(serde_json::from_slice(&contents)?);
+        } else {
+            files.insert(name, contents);
+        }
+    }
+    let har = har.ok_or_else(|| Error::InvalidArgument("HAR zip has no .har file".to_string()))?;
+    Ok((har, Attachments::Zip(files)))
+}
+
+#[cfg(feature = "har-zip")]
+fn write_zip(json: &[u8]) -> Result<Vec<u8>> {
+    use std::io::Write as _;
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    zip.start_file("har.har", zip::write::SimpleFileOptions::default())
+        .map_err(zip_error)?;
+    zip.write_all(json)?;
+    Ok(zip.finish().map_err(zip_error)?.into_inner())
+}
+
+#[cfg(not(feature = "har-zip"))]
+fn read_zip(_bytes: Vec<u8>) -> Result<(Har, Attachments)> {
+    Err(Error::InvalidArgument(
+        "reading a zipped HAR needs the `har-zip` feature".to_string(),
+    ))
+}
+
+#[cfg(not(feature = "har-zip"))]
+fn write_zip(_json: &[u8]) -> Result<Vec<u8>> {
+    Err(Error::InvalidArgument(
+        "writing a zipped HAR needs the `har-zip` feature".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attached_har() -> String {
+        serde_json::json!({ "log": { "version": "1.2", "entries": [{
+            "request": {
+                "method": "POST",
+                "url": "https://example.com/search",
+                "postData": { "mimeType": "application/json", "_file": "query.json" }
+            },
+            "response": {
+                "status": 200,
+                "content": { "size": 7, "mimeType": "application/json", "_file": "result.json" }
+            }
+        }]}})
+        .to_string()
+    }
+
+    fn assert_embedded(har: &Har) {
+        let entry = &har.log.entries[0];
+        let post_data = entry.request.post_data.as_ref().unwrap();
+        assert_eq!(post_data.text.as_deref(), Some(r#"{"q":"rust"}"#));
+        assert!(post_data.file.is_none());
+        assert_eq!(entry.response.content.body().unwrap(), b"[1,2,3]");
+    }
+
+    #[tokio::test]
+    async fn read_embeds_attachments_beside_a_har_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.har");
+        std::fs::write(&path, attached_har()).unwrap();
+        std::fs::write(dir.path().join("query.json"), r#"{"q":"rust"}"#).unwrap();
+        std::fs::write(dir.path().join("result.json"), "[1,2,3]").unwrap();
+
+        let har = Har::read(&path).await.unwrap();
+        assert_embedded(&har);
+
+        let copy = dir.path().join("copy.har");
+        har.write(&copy).await.unwrap();
+        assert_eq!(Har::read(&copy).await.unwrap(), har);
+    }
+
+    #[tokio::test]
+    async fn read_reports_a_missing_attachment() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.har");
+        std::fs::write(&path, attached_har()).unwrap();
+
+        let err = Har::read(&path).await.unwrap_err();
+        assert!(err.to_string().contains("query.json"), "{err}");
+    }
+
+    #[cfg(feature = "har-zip")]
+    #[tokio::test]
+    async fn read_embeds_attachments_in_a_zip() {
+        use std::io::Write as _;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.zip");
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(&path).unwrap());
+        let options = zip::write::SimpleFileOptions::default();
+        for (name, contents) in [
+            ("har.har", attached_har()),
+            ("query.json", r#"{"q":"rust"}"#.to_string()),
+            ("result.json", "[1,2,3]".to_string()),
+        ] {
+            zip.start_file(name, options).unwrap();
+            zip.write_all(contents.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+
+        let har = Har::read(&path).await.unwrap();
+        assert_embedded(&har);
+
+        let copy = dir.path().join("copy.zip");
+        har.write(&copy).await.unwrap();
+        assert_eq!(Har::read(&copy).await.unwrap(), har);
+    }
+}
@@ -0,0 +1,615 @@
+//! Serde types for HAR 1.2 archives.
+//!
+//! The spec's fields are typed; anything else a recorder wrote (`cache`,
+//! Playwright's `_`-prefixed extensions) is kept in each type's `extra` map,
+//! so a HAR survives a read/write round trip unchanged.
+//!
+//! Architecture Reference:
+//! - Spec: <http://www.softwareishard.com/blog/har-12-spec/>
+//! - Playwright's HAR types: playwright/packages/trace/src/har.ts
+
+use crate::error::{Error, Result};
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Default for the spec's size fields: `-1`, "not known".
+fn unknown() -> i64 {
+    -1
+}
+
+/// A HAR archive: the file's top-level object.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Har {
+    /// The archive's only top-level key
+    pub log: Log,
+}
+
+impl Har {
+    /// The hosts the archive's requests went to, such as to assert that no
+    /// third-party domain was contacted.
+    pub fn hosts(&self) -> BTreeSet<String> {
+        self.log
+            .entries
+            .iter()
+            .filter_map(|entry| entry.request.parsed_url()?.host_str().map(str::to_string))
+            .collect()
+    }
+
+    /// How many requests went to each endpoint, keyed by method and URL
+    /// without the query string: `"GET https://example.com/api/users"`.
+    pub fn call_counts(&self) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for entry in &self.log.entries {
+            let url = match entry.request.parsed_url() {
+                Some(mut url) => {
+                    url.set_query(None);
+                    url.set_fragment(None);
+                    url.to_string()
+                }
+                None => entry.request.url.clone(),
+            };
+            *counts
+                .entry(format!("{} {}", entry.request.method, url))
+                .or_insert(0) += 1;
+        }
+        counts
+    }
+}
+
+/// The `log` object of a [`Har`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Log {
+    /// Format version, `"1.2"` for archives Playwright writes
+    #[serde(default)]
+    pub version: String,
+    /// The tool that wrote the archive
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Creator>,
+    /// The browser the traffic came from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub browser: Option<Creator>,
+    /// The pages the entries belong to, referenced by [`Entry::pageref`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pages: Option<Vec<Page>>,
+    /// The recorded requests, in the order they were sent
+    #[serde(default)]
+    pub entries: Vec<Entry>,
+    /// Fields not modelled above
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The `creator` and `browser` objects of a [`Log`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Creator {
+    /// Application name
+    #[serde(default)]
+    pub name: String,
+    /// Application version
+    #[serde(default)]
+    pub version: String,
+    /// Fields not modelled above (`comment`, ...)
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A page load, grouping the entries made for it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Page {
+    /// When the page load started, as an ISO 8601 date
+    #[serde(default)]
+    pub started_date_time: String,
+    /// Identifier entries refer to in [`Entry::pageref`]
+    pub id: String,
+    /// Page title
+    #[serde(default)]
+    pub title: String,
+    /// Page load milestones
+    #[serde(default)]
+    pub page_timings: PageTimings,
+    /// Fields not modelled above
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The `pageTimings` of a [`Page`], in milliseconds since it started.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageTimings {
+    /// When `DOMContentLoaded` fired; `-1` or absent if not known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_content_load: Option<f64>,
+    /// When `load` fired; `-1` or absent if not known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_load: Option<f64>,
+    /// Fields not modelled above
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// One request/response pair.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Entry {
+    /// When the request started, as an ISO 8601 date
+    #[serde(default)]
+    pub started_date_time: String,
+    /// Total time of the request in milliseconds
+    #[serde(default)]
+    pub time: f64,
+    /// The request
+    pub request: Request,
+    /// The response
+    pub response: Response,
+    /// How [`time`](Self::time) was spent
+    #[serde(default)]
+    pub timings: Timings,
+    /// The [`Page::id`] this request was made for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pageref: Option<String>,
+    /// IP address of the server that answered
+    #[serde(
+        default,
+        rename = "serverIPAddress",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub server_ip_address: Option<String>,
+    /// Identifier of the TCP/IP connection used
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection: Option<String>,
+    /// Fields not modelled above (`cache`, `_frameref`, ...)
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The phases of an [`Entry`], in milliseconds. `-1` marks a phase that
+/// does not apply, such as `dns` for a reused connection.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Timings {
+    /// Time queued waiting for a connection
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked: Option<f64>,
+    /// DNS resolution
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dns: Option<f64>,
+    /// Creating the TCP connection, including `ssl`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect: Option<f64>,
+    /// Sending the request
+    #[serde(default)]
+    pub send: f64,
+    /// Waiting for the first byte of the response
+    #[serde(default)]
+    pub wait: f64,
+    /// Reading the response
+    #[serde(default)]
+    pub receive: f64,
+    /// The TLS handshake
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssl: Option<f64>,
+    /// Fields not modelled above
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Timings {
+    /// The sum of the phases that apply. `ssl` is part of `connect`, so it
+    /// is not added again.
+    pub fn total(&self) -> f64 {
+        [self.blocked, self.dns, self.connect]
+            .into_iter()
+            .flatten()
+            .chain([self.send, self.wait, self.receive])
+            .filter(|t| *t > 0.0)
+            .sum()
+    }
+}
+
+/// The request half of an [`Entry`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Request {
+    /// Request method, such as `"GET"`
+    pub method: String,
+    /// Absolute request URL, including the query string
+    pub url: String,
+    /// HTTP version, such as `"HTTP/1.1"`
+    #[serde(default)]
+    pub http_version: String,
+    /// Request headers, in the order they were sent
+    #[serde(default)]
+    pub headers: Vec<Header>,
+    /// Parsed query string parameters
+    #[serde(default)]
+    pub query_string: Vec<QueryParam>,
+    /// Cookies sent with the request
+    #[serde(default)]
+    pub cookies: Vec<Cookie>,
+    /// Request body, if the request had one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_data: Option<PostData>,
+    /// Size of the request line and headers in bytes, `-1` if unknown
+    #[serde(default = "unknown")]
+    pub headers_size: i64,
+    /// Size of the request body in bytes, `-1` if unknown
+    #[serde(default = "unknown")]
+    pub body_size: i64,
+    /// Fields not modelled above
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Default for Request {
+    fn default() -> Self {
+        Self {
+            method: String::new(),
+            url: String::new(),
+            http_version: String::new(),
+            headers: Vec::new(),
+            query_string: Vec::new(),
+            cookies: Vec::new(),
+            post_data: None,
+            headers_size: unknown(),
+            body_size: unknown(),
+            extra: Map::new(),
+        }
+    }
+}
+
+impl Request {
+    fn parsed_url(&self) -> Option<url::Url> {
+        url::Url::parse(&self.url).ok()
+    }
+}
+
+/// The response half of an [`Entry`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+    /// HTTP status code
+    pub status: u16,
+    /// HTTP status text, such as `"OK"`
+    #[serde(default)]
+    pub status_text: String,
+    /// HTTP version, such as `"HTTP/1.1"`
+    #[serde(default)]
+    pub http_version: String,
+    /// Response headers, in the order they were received
+    #[serde(default)]
+    pub headers: Vec<Header>,
+    /// Cookies the response set
+    #[serde(default)]
+    pub cookies: Vec<Cookie>,
+    /// The response body
+    #[serde(default)]
+    pub content: Content,
+    /// Target of a redirect response, `""` otherwise
+    #[serde(default, rename = "redirectURL")]
+    pub redirect_url: String,
+    /// Size of the status line and headers in bytes, `-1` if unknown
+    #[serde(default = "unknown")]
+    pub headers_size: i64,
+    /// Size of the body as transferred in bytes, `-1` if unknown
+    #[serde(default = "unknown")]
+    pub body_size: i64,
+    /// Fields not modelled above
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Default for Response {
+    fn default() -> Self {
+        Self {
+            status: 0,
+            status_text: String::new(),
+            http_version: String::new(),
+            headers: Vec::new(),
+            cookies: Vec::new(),
+            content: Content::default(),
+            redirect_url: String::new(),
+            headers_size: unknown(),
+            body_size: unknown(),
+            extra: Map::new(),
+        }
+    }
+}
+
+/// A header name and value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Header {
+    /// Header name, in the case it was recorded with
+    pub name: String,
+    /// Header value
+    pub value: String,
+}
+
+impl Header {
+    /// A header.
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// A cookie sent with a [`Request`] or set by a [`Response`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cookie {
+    /// Cookie name
+    pub name: String,
+    /// Cookie value
+    pub value: String,
+    /// Path the cookie applies to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Domain the cookie applies to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// Expiry, as an ISO 8601 date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+    /// Whether the cookie is hidden from JavaScript
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_only: Option<bool>,
+    /// Whether the cookie is only sent over HTTPS
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secure: Option<bool>,
+    /// `"Strict"`, `"Lax"` or `"None"`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub same_site: Option<String>,
+    /// Fields not modelled above
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A query string parameter.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueryParam {
+    /// Parameter name
+    pub name: String,
+    /// Parameter value
+    pub value: String,
+}
+
+/// A request body.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostData {
+    /// MIME type of the body
+    #[serde(default)]
+    pub mime_type: String,
+    /// The body, encoded as `encoding` says
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// `"base64"` for a binary `text`; absent for plain text
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+    /// Playwright's `attach` mode: the body's file name next to the HAR (or
+    /// in the zip) instead of `text`
+    #[serde(default, rename = "_file", skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// Fields not modelled above (`params`, ...)
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl PostData {
+    /// The decoded body: `text`, base64-decoded if `encoding` says so.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body is an attachment (`_file`) this archive
+    /// was not read with [`Har::read`], or if the base64 is invalid.
+    pub fn body(&self) -> Result<Vec<u8>> {
+        decode_body(
+            self.file.as_deref(),
+            self.text.as_deref(),
+            self.encoding.as_deref(),
+        )
+    }
+
+    /// Replaces the body, embedding it as text, or as base64 if it is not
+    /// UTF-8.
+    pub fn set_body(&mut self, body: impl Into<Vec<u8>>) {
+        self.file = None;
+        (self.text, self.encoding) = encode_body(body.into());
+    }
+}
+
+/// A response body.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Content {
+    /// Body size in bytes, `-1` if unknown
+    #[serde(default)]
+    pub size: i64,
+    /// MIME type of the body
+    #[serde(default)]
+    pub mime_type: String,
+    /// The body, encoded as `encoding` says
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// `"base64"` for a binary `text`; absent for plain text
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+    /// Playwright's `attach` mode: the body's file name next to the HAR (or
+    /// in the zip) instead of `text`
+    #[serde(default, rename = "_file", skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// Bytes saved by compression, if the body was compressed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compression: Option<i64>,
+    /// Fields not modelled above
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Content {
+    /// The decoded body: `text`, base64-decoded if `encoding` says so.
+    /// Empty if the response had no body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body is an attachment (`_file`) this archive
+    /// was not read with [`Har::read`], or if the base64 is invalid.
+    pub fn body(&self) -> Result<Vec<u8>> {
+        decode_body(
+            self.file.as_deref(),
+            self.text.as_deref(),
+            self.encoding.as_deref(),
+        )
+    }
+
+    /// Replaces the body, embedding it as text, or as base64 if it is not
+    /// UTF-8, and updates `size`.
+    pub fn set_body(&mut self, body: impl Into<Vec<u8>>) {
+        let body = body.into();
+        self.size = body.len() as i64;
+        self.file = None;
+        (self.text, self.encoding) = encode_body(body);
+    }
+}
+
+/// The bytes of a `text` / `encoding` pair; see [`Content::body`].
+fn decode_body(file: Option<&str>, text: Option<&str>, encoding: Option<&str>) -> Result<Vec<u8>> {
+    if let Some(file) = file {
+        return Err(Error::InvalidArgument(format!(
+            "HAR body is attached as {file}; read the archive with Har::read to load it"
+        )));
+    }
+    match (text, encoding) {
+        (Some(text), Some("base64")) => BASE64
+            .decode(text)
+            .map_err(|e| Error::InvalidArgument(format!("HAR body: {}", e))),
+        (Some(text), _) => Ok(text.as_bytes().to_vec()),
+        (None, _) => Ok(Vec::new()),
+    }
+}
+
+/// `text` and `encoding` for a body: UTF-8 as is, anything else as base64.
+fn encode_body(body: Vec<u8>) -> (Option<String>, Option<String>) {
+    match String::from_utf8(body) {
+        Ok(text) => (Some(text), None),
+        Err(e) => (
+            Some(BASE64.encode(e.as_bytes())),
+            Some("base64".to_string()),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unmodelled_fields_survive_a_round_trip() {
+        let json = serde_json::json!({
+            "log": {
+                "version": "1.2",
+                "creator": { "name": "Playwright", "version": "1.62.1" },
+                "entries": [{
+                    "startedDateTime": "2026-01-01T00:00:00.000Z",
+                    "time": 12.5,
+                    "request": {
+                        "method": "GET",
+                        "url": "https://example.com/a?x=1",
+                        "httpVersion": "HTTP/1.1",
+                        "headers": [{ "name": "Accept", "value": "*/*" }],
+                        "queryString": [{ "name": "x", "value": "1" }],
+                        "cookies": [],
+                        "headersSize": -1,
+                        "bodySize": 0
+                    },
+                    "response": {
+                        "status": 200,
+                        "statusText": "OK",
+                        "httpVersion": "HTTP/1.1",
+                        "headers": [],
+                        "content": { "size": 2, "mimeType": "text/plain", "_file": "abc.txt" },
+                        "redirectURL": "",
+                        "cookies": [],
+                        "headersSize": -1,
+                        "bodySize": 2
+                    },
+                    "timings": { "send": 0.5, "wait": 10.0, "receive": 2.0 },
+                    "_frameref": "frame@1"
+                }]
+            }
+        });
+        let har: Har = serde_json::from_value(json.clone()).unwrap();
+        let entry = &har.log.entries[0];
+        assert_eq!(entry.request.query_string[0].value, "1");
+        assert_eq!(entry.response.content.file.as_deref(), Some("abc.txt"));
+        assert_eq!(entry.timings.total(), 12.5);
+        assert!(entry.extra.contains_key("_frameref"));
+        assert_eq!(har.log.creator.as_ref().unwrap().version, "1.62.1");
+        assert_eq!(serde_json::to_value(&har).unwrap(), json);
+    }
+
+    #[test]
+    fn hosts_and_call_counts() {
+        let mut har = Har::default();
+        for (method, url) in [
+            ("GET", "https://app.example.com/"),
+            ("GET", "https://app.example.com/api/users?page=1"),
+            ("GET", "https://app.example.com/api/users?page=2"),
+            ("POST", "https://app.example.com/api/users"),
+            ("GET", "https://cdn.example.net/app.js"),
+        ] {
+            har.log.entries.push(Entry {
+                request: Request {
+                    method: method.to_string(),
+                    url: url.to_string(),
+                    ..Request::default()
+                },
+                ..Entry::default()
+            });
+        }
+        assert_eq!(
+            har.hosts().into_iter().collect::<Vec<_>>(),
+            ["app.example.com", "cdn.example.net"]
+        );
+        let counts = har.call_counts();
+        assert_eq!(counts["GET https://app.example.com/api/users"], 2);
+        assert_eq!(counts["POST https://app.example.com/api/users"], 1);
+    }
+
+    #[test]
+    fn body_round_trips_text_and_binary() {
+        let mut content = Content::default();
+        content.set_body("hello");
+        assert_eq!(content.text.as_deref(), Some("hello"));
+        assert_eq!(content.body().unwrap(), b"hello");
+
+        content.set_body(vec![0xff, 0x00]);
+        assert_eq!(content.encoding.as_deref(), Some("base64"));
+        assert_eq!(content.size, 2);
+        assert_eq!(content.body().unwrap(), [0xff, 0x00]);
+
+        content.file = Some("abc.bin".to_string());
+        assert!(content.body().is_err());
+    }
+
+    #[test]
+    fn post_data_decodes_base64_text() {
+        let post_data: PostData = serde_json::from_value(serde_json::json!({
+            "mimeType": "application/octet-stream",
+            "text": "AP8=",
+            "encoding": "base64"
+        }))
+        .unwrap();
+        assert_eq!(post_data.body().unwrap(), [0x00, 0xff]);
+        assert!(post_data.extra.is_empty());
+
+        let mut post_data = PostData::default();
+        post_data.set_body(r#"{"q":1}"#);
+        assert_eq!(post_data.encoding, None);
+        assert_eq!(post_data.body().unwrap(), br#"{"q":1}"#);
+    }
+}
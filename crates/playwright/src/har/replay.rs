@@ -0,0 +1,551 @@
+//! Replays a HAR through [`Route::fulfill`], matching in Rust instead of
+//! the driver's `LocalUtils.harOpen` / `harLookup`.
+//!
+//! Architecture Reference:
+//! - JavaScript: playwright/packages/playwright-core/src/server/harBackend.ts
+//!   (the lookup [`DefaultMatcher`] mirrors: method, URL and post data must
+//!   match, and the entry sharing the most headers wins)
+
+use super::model::{Entry, Har};
+use crate::error::Result;
+use crate::protocol::route::{FulfillOptions, RouteHandle, fulfill_headers};
+use crate::protocol::url_matcher::UrlMatcher;
+use crate::protocol::{BrowserContext, Page, Route};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+
+/// What a mounted [`HarReplayer`] does with a request in scope that no
+/// entry matches.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HarNotFound {
+    /// Fail the request with a network error, as `route_from_har` does
+    #[default]
+    Abort,
+    /// Pass the request on with [`Route::fallback`]
+    Fallback,
+}
+
+/// The request a [`HarReplayer`] looks up, as seen by an [`EntryMatcher`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ReplayRequest {
+    /// Request method, upper-cased
+    pub method: String,
+    /// Absolute request URL
+    pub url: String,
+    /// Request headers, keyed by lower-cased name
+    pub headers: HashMap<String, String>,
+    /// Request body, if it had one
+    pub post_data: Option<Vec<u8>>,
+}
+
+impl ReplayRequest {
+    /// A request with no headers or body.
+    pub fn new(method: &str, url: impl Into<String>) -> Self {
+        Self {
+            method: method.to_ascii_uppercase(),
+            url: url.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds a header.
+    pub fn header(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.headers.insert(name.to_ascii_lowercase(), value.into());
+        self
+    }
+
+    /// Sets the body.
+    pub fn post_data(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.post_data = Some(body.into());
+        self
+    }
+
+    fn from_route(route: &Route) -> Self {
+        let request = route.request();
+        let headers = request
+            .headers()
+            .into_iter()
+            .map(|(name, value)| (name.to_ascii_lowercase(), value))
+            .collect();
+        Self {
+            method: request.method().to_ascii_uppercase(),
+            url: request.url().to_string(),
+            headers,
+            post_data: request.post_data_buffer(),
+        }
+    }
+}
+
+/// Decides which HAR entry answers a request.
+///
+/// Every entry is scored; the highest score wins, and the earliest entry
+/// wins a tie. Closures of the same shape implement this trait.
+///
+/// # Example
+///
+/// ```
+/// use playwright_rs::har::{DefaultMatcher, Entry, EntryMatcher, ReplayRequest};
+///
+/// // Ignore the API version segment, otherwise match as usual.
+/// let default = DefaultMatcher::new();
+/// let matcher = move |request: &ReplayRequest, entry: &Entry| {
+///     let unversioned = |url: &str| url.replace("/v1/", "/v2/");
+///     let mut request = request.clone();
+///     request.url = unversioned(&request.url);
+///     let mut entry = entry.clone();
+///     entry.request.url = unversioned(&entry.request.url);
+///     default.score(&request, &entry)
+/// };
+/// # let _ = matcher;
+/// ```
+pub trait EntryMatcher: Send + Sync {
+    /// `None` if `entry` cannot answer `request`; otherwise how well it
+    /// does, higher being better.
+    fn score(&self, request: &ReplayRequest, entry: &Entry) -> Option<u32>;
+}
+
+impl<F> EntryMatcher for F
+where
+    F: Fn(&ReplayRequest, &Entry) -> Option<u32> + Send + Sync,
+{
+    fn score(&self, request: &ReplayRequest, entry: &Entry) -> Option<u32> {
+        self(request, entry)
+    }
+}
+
+/// The [`EntryMatcher`] a [`HarReplayer`] uses unless given another.
+///
+/// An entry matches when its method, URL (ignoring the fragment) and post
+/// data equal the request's; its score is the number of its request headers
+/// the request sent with the same value.
+#[derive(Debug, Clone, Default)]
+pub struct DefaultMatcher {
+    ignored_query_params: Vec<String>,
+    ignore_post_data: bool,
+}
+
+impl DefaultMatcher {
+    /// A matcher comparing method, URL and post data.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ignores the query parameter `name` when comparing URLs, for cache
+    /// busters and tokens that differ on every run.
+    pub fn ignore_query_param(mut self, name: impl Into<String>) -> Self {
+        self.ignored_query_params.push(name.into());
+        self
+    }
+
+    /// Stops comparing post data, so any body matches.
+    pub fn ignore_post_data(mut self) -> Self {
+        self.ignore_post_data = true;
+        self
+    }
+
+    fn normalize_url(&self, url: &str) -> String {
+        let Ok(mut parsed) = url::Url::parse(url) else {
+            return url.to_string();
+        };
+        parsed.set_fragment(None);
+        if !self.ignored_query_params.is_empty() && parsed.query().is_some() {
+            let kept: Vec<(String, String)> = parsed
+                .query_pairs()
+                .filter(|(name, _)| !self.ignored_query_params.iter().any(|p| p == name))
+                .map(|(name, value)| (name.into_owned(), value.into_owned()))
+                .collect();
+            if kept.is_empty() {
+                parsed.set_query(None);
+            } else {
+                parsed.query_pairs_mut().clear().extend_pairs(kept);
+            }
+        }
+        parsed.into()
+    }
+}
+
+impl EntryMatcher for DefaultMatcher {
+    fn score(&self, request: &ReplayRequest, entry: &Entry) -> Option<u32> {
+        if !entry.request.method.eq_ignore_ascii_case(&request.method)
+            || self.normalize_url(&entry.request.url) != self.normalize_url(&request.url)
+        {
+            return None;
+        }
+        if !self.ignore_post_data {
+            let recorded = match &entry.request.post_data {
+                Some(post_data) => post_data.body().ok()?,
+                None => Vec::new(),
+            };
+            if recorded != request.post_data.as_deref().unwrap_or_default() {
+                return None;
+            }
+        }
+        let shared = entry
+            .request
+            .headers
+            .iter()
+            .filter(|h| {
+                request
+                    .headers
+                    .get(&h.name.to_ascii_lowercase())
+                    .is_some_and(|v| *v == h.value)
+            })
+            .count();
+        Some(shared as u32)
+    }
+}
+
+type Rewrite = Arc<dyn Fn(&ReplayRequest, &mut Entry) + Send + Sync>;
+
+/// Serves requests from a HAR archive, looked up and fulfilled on this side
+/// of the wire.
+///
+/// [`Page::route_from_har`] hands matching to the driver, which is neither
+/// observable nor adjustable. A `HarReplayer` does the same job with a
+/// pluggable [`EntryMatcher`] and [`rewrite`](Self::rewrite) hooks that edit
+/// the matched entry before it is served, such as to refresh a timestamp or
+/// a token. The response is sent with `content-encoding` and
+/// `content-length` dropped, since the recorded body is already decoded.
+///
+/// # Example
+///
+/// ```no_run
+/// use playwright_rs::har::{DefaultMatcher, HarNotFound, HarReplayer, Header};
+/// # use playwright_rs::Playwright;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// # let pw = Playwright::launch().await?;
+/// # let page = pw.chromium().launch().await?.new_page().await?;
+/// let replay = HarReplayer::open("fixtures/api.har")
+///     .await?
+///     .url("**/api/**")
+///     .not_found(HarNotFound::Fallback)
+///     .matcher(DefaultMatcher::new().ignore_query_param("_"))
+///     .rewrite(|_request, entry| {
+///         entry.response.headers.retain(|h| !h.name.eq_ignore_ascii_case("date"));
+///         entry.response.headers.push(Header::new("x-replayed", "1"));
+///     })
+///     .mount(&page)
+///     .await?;
+///
+/// page.goto("https://shop.example.com", None).await?;
+/// assert!(replay.hits() > 0);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct HarReplayer {
+    entries: Vec<Entry>,
+    scope: UrlMatcher,
+    not_found: HarNotFound,
+    matcher: Arc<dyn EntryMatcher>,
+    rewrites: Vec<Rewrite>,
+}
+
+impl HarReplayer {
+    /// Reads the archive at `path` with [`Har::read`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive cannot be read.
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Har::read(path).await.map(Self::from_har)
+    }
+
+    /// Replays an archive already in memory. Attached (`_file`) bodies must
+    /// have been embedded, as [`Har::read`] does; a request matching an
+    /// entry whose body is still attached gets a `500`.
+    pub fn from_har(har: Har) -> Self {
+        Self {
+            entries: har.log.entries,
+            scope: UrlMatcher::from("**/*"),
+            not_found: HarNotFound::default(),
+            matcher: Arc::new(DefaultMatcher::new()),
+            rewrites: Vec::new(),
+        }
+    }
+
+    /// Only serves requests `scope` matches (default: all).
+    pub fn url(mut self, scope: impl Into<UrlMatcher>) -> Self {
+        self.scope = scope.into();
+        self
+    }
+
+    /// Sets what happens to a request no entry matches (default: abort).
+    pub fn not_found(mut self, not_found: HarNotFound) -> Self {
+        self.not_found = not_found;
+        self
+    }
+
+    /// Replaces the [`DefaultMatcher`].
+    pub fn matcher(mut self, matcher: impl EntryMatcher + 'static) -> Self {
+        self.matcher = Arc::new(matcher);
+        self
+    }
+
+    /// Adds a hook that edits a copy of the matched entry before it is
+    /// served. Hooks run in the order they were added; the archive itself is
+    /// left unchanged.
+    pub fn rewrite<F>(mut self, rewrite: F) -> Self
+    where
+        F: Fn(&ReplayRequest, &mut Entry) + Send + Sync + 'static,
+    {
+        self.rewrites.push(Arc::new(rewrite));
+        self
+    }
+
+    /// The entries being replayed.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// The entry that would answer `request`, after the rewrite hooks ran.
+    pub fn lookup(&self, request: &ReplayRequest) -> Option<Entry> {
+        let mut best: Option<(u32, &Entry)> = None;
+        for entry in &self.entries {
+            if let Some(score) = self.matcher.score(request, entry)
+                && best.is_none_or(|(top, _)| score > top)
+            {
+                best = Some((score, entry));
+            }
+        }
+        let mut entry = best?.1.clone();
+        for rewrite in &self.rewrites {
+            rewrite(request, &mut entry);
+        }
+        Some(entry)
+    }
+
+    /// Registers the replayer on `page`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the route cannot be registered.
+    pub async fn mount(self, page: &Page) -> Result<RouteHandle> {
+        let scope = self.scope.clone();
+        let replayer = Arc::new(self);
+        page.route(scope, move |route| Arc::clone(&replayer).handle(route))
+            .await
+    }
+
+    /// Registers the replayer on `context`, for every page in it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the route cannot be registered.
+    pub async fn mount_on_context(self, context: &BrowserContext) -> Result<RouteHandle> {
+        let scope = self.scope.clone();
+        let replayer = Arc::new(self);
+        context
+            .route(scope, move |route| Arc::clone(&replayer).handle(route))
+            .await
+    }
+
+    async fn handle(self: Arc<Self>, route: Route) -> Result<()> {
+        let request = ReplayRequest::from_route(&route);
+        let Some(entry) = self.lookup(&request) else {
+            return match self.not_found {
+                HarNotFound::Abort => route.abort(None).await,
+                HarNotFound::Fallback => route.fallback(None).await,
+            };
+        };
+        match self.to_fulfill_options(&entry).await {
+            Ok(options) => route.fulfill(Some(options)).await,
+            Err(e) => {
+                // Leaving the request unanswered would hang the page.
+                tracing::warn!("HAR replay failed for {}: {}", request.url, e);
+                let options = FulfillOptions::builder()
+                    .status(500)
+                    .body_string(e.to_string())
+                    .build();
+                route.fulfill(Some(options)).await
+            }
+        }
+    }
+
+    async fn to_fulfill_options(&self, entry: &Entry) -> Result<FulfillOptions> {
+        let response = &entry.response;
+        let content = &response.content;
+        let body = content.body()?;
+
+        let headers = fulfill_headers(
+            response
+                .headers
+                .iter()
+                .map(|h| (h.name.as_str(), h.value.as_str())),
+        );
+
+        let mut builder = FulfillOptions::builder().status(response.status).body(body);
+        if !headers.contains_key("content-type") && !content.mime_type.is_empty() {
+            builder = builder.content_type(content.mime_type.clone());
+        }
+        Ok(builder.headers(headers).build())
+    }
+}
+
+impl fmt::Debug for HarReplayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HarReplayer")
+            .field("entries", &self.entries.len())
+            .field("scope", &self.scope)
+            .field("not_found", &self.not_found)
+            .field("rewrites", &self.rewrites.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::har::{Content, Header, PostData, Request, Response};
+    use base64::Engine as _;
+    use base64::engine::general_purpose::STANDARD as BASE64;
+
+    fn entry(method: &str, url: &str, body: &str) -> Entry {
+        Entry {
+            request: Request {
+                method: method.to_string(),
+                url: url.to_string(),
+                ..Request::default()
+            },
+            response: Response {
+                status: 200,
+                content: Content {
+                    mime_type: "text/plain".to_string(),
+                    text: Some(body.to_string()),
+                    ..Content::default()
+                },
+                ..Response::default()
+            },
+            ..Entry::default()
+        }
+    }
+
+    fn replayer(entries: Vec<Entry>) -> HarReplayer {
+        let mut har = Har::default();
+        har.log.entries = entries;
+        HarReplayer::from_har(har)
+    }
+
+    fn body(entry: &Entry) -> &str {
+        entry.response.content.text.as_deref().unwrap()
+    }
+
+    #[test]
+    fn default_matcher_checks_method_url_and_post_data() {
+        let mut login = entry("POST", "https://example.com/login", "welcome");
+        login.request.post_data = Some(PostData {
+            text: Some(r#"{"user":"ada"}"#.to_string()),
+            ..PostData::default()
+        });
+        let replay = replayer(vec![entry("GET", "https://example.com/a", "a"), login]);
+
+        let get = ReplayRequest::new("get", "https://example.com/a#top");
+        assert_eq!(body(&replay.lookup(&get).unwrap()), "a");
+        assert!(
+            replay
+                .lookup(&ReplayRequest::new("POST", "https://example.com/a"))
+                .is_none()
+        );
+
+        let post = ReplayRequest::new("POST", "https://example.com/login");
+        assert!(replay.lookup(&post.clone().post_data("{}")).is_none());
+        assert_eq!(
+            body(&replay.lookup(&post.post_data(r#"{"user":"ada"}"#)).unwrap()),
+            "welcome"
+        );
+    }
+
+    #[test]
+    fn default_matcher_compares_base64_post_data_as_bytes() {
+        let mut upload = entry("POST", "https://example.com/upload", "stored");
+        let mut post_data = PostData::default();
+        post_data.set_body(vec![0x89, b'P', 0xff, 0x00]);
+        assert_eq!(post_data.encoding.as_deref(), Some("base64"));
+        upload.request.post_data = Some(post_data);
+        let replay = replayer(vec![upload]);
+
+        let post = ReplayRequest::new("POST", "https://example.com/upload");
+        assert_eq!(
+            body(
+                &replay
+                    .lookup(&post.clone().post_data(vec![0x89, b'P', 0xff, 0x00]))
+                    .unwrap()
+            ),
+            "stored"
+        );
+        assert!(
+            replay
+                .lookup(&post.post_data(vec![0x89, b'P', 0xfe, 0x00]))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn most_shared_headers_wins_and_ties_go_to_the_first() {
+        let mut english = entry("GET", "https://example.com/", "hello");
+        english.request.headers = vec![Header::new("Accept-Language", "en")];
+        let mut french = entry("GET", "https://example.com/", "bonjour");
+        french.request.headers = vec![Header::new("Accept-Language", "fr")];
+        let replay = replayer(vec![english, french]);
+
+        let request = ReplayRequest::new("GET", "https://example.com/");
+        assert_eq!(body(&replay.lookup(&request).unwrap()), "hello");
+        let request = request.header("accept-language", "fr");
+        assert_eq!(body(&replay.lookup(&request).unwrap()), "bonjour");
+    }
+
+    #[test]
+    fn ignored_query_params_and_custom_matchers() {
+        let replay = replayer(vec![entry(
+            "GET",
+            "https://example.com/feed?page=2&_=111",
+            "p2",
+        )])
+        .matcher(DefaultMatcher::new().ignore_query_param("_"));
+        let request = ReplayRequest::new("GET", "https://example.com/feed?_=999&page=2");
+        assert!(replay.lookup(&request).is_some());
+        let request = ReplayRequest::new("GET", "https://example.com/feed?page=3&_=111");
+        assert!(replay.lookup(&request).is_none());
+
+        let replay = replay.matcher(|request: &ReplayRequest, _: &Entry| {
+            request.url.contains("/feed").then_some(0)
+        });
+        assert!(replay.lookup(&request).is_some());
+    }
+
+    #[tokio::test]
+    async fn rewrites_edit_a_copy_and_fulfill_drops_encoding_headers() {
+        let mut recorded = entry("GET", "https://example.com/now", "");
+        recorded.response.content.text = Some(BASE64.encode(b"\x00\x01"));
+        recorded.response.content.encoding = Some("base64".to_string());
+        recorded.response.headers = vec![
+            Header::new("Content-Encoding", "gzip"),
+            Header::new("Content-Length", "99"),
+            Header::new("Set-Cookie", "a=1"),
+            Header::new("Set-Cookie", "b=2"),
+        ];
+        let replay = replayer(vec![recorded]).rewrite(|request, entry| {
+            entry
+                .response
+                .headers
+                .push(Header::new("x-for", request.url.clone()));
+        });
+
+        let entry = replay
+            .lookup(&ReplayRequest::new("GET", "https://example.com/now"))
+            .unwrap();
+        assert_eq!(replay.entries()[0].response.headers.len(), 4);
+        let options = replay.to_fulfill_options(&entry).await.unwrap();
+        assert_eq!(options.body.as_deref(), Some(&b"\x00\x01"[..]));
+        assert_eq!(options.content_type.as_deref(), Some("text/plain"));
+        let headers = options.headers.unwrap();
+        assert!(!headers.contains_key("content-encoding"));
+        assert!(!headers.contains_key("content-length"));
+        assert_eq!(headers["set-cookie"], "a=1\nb=2");
+        assert_eq!(headers["x-for"], "https://example.com/now");
+    }
+}
@@ -49,14 +49,18 @@
 //!    as a `[dev-dependencies]`.
 //!
 //! See [`examples/trace_on_failure.rs`](https://github.com/padamson/playwright-rust/blob/main/crates/playwright/examples/trace_on_failure.rs)
-//! for the canonical Rust pattern (Rust has no async `Drop`, so cleanup
-//! is explicit).
+//! for the canonical Rust pattern: Rust has no async `Drop`, so
+//! [`testing::ArtifactPolicy`] owns the context for the length of the test
+//! and keeps the trace only if the test failed.
 //!
 //! ## Companion crates
 //!
 //! - [`playwright-rs-macros`](https://docs.rs/playwright-rs-macros) —
-//!   compile-time-validated [`locator!`] macro. Default-on via the
-//!   `macros` feature; surfaced here as `playwright_rs::locator!`.
+//!   compile-time-validated [`locator!`] macro, the typed [`loc!`]
+//!   locator builder, the `#[playwright_rs::test]` attribute and
+//!   `#[derive(PageObject)]`. Default-on via the `macros` feature;
+//!   surfaced here as `playwright_rs::locator!`, `playwright_rs::loc!`,
+//!   `playwright_rs::test` and `playwright_rs::PageObject`.
 //! - [`playwright-rs-trace`](https://docs.rs/playwright-rs-trace) —
 //!   pure-Rust parser for `.trace.zip` files. Standalone; add to
 //!   `[dev-dependencies]` for post-mortem analysis.
@@ -251,7 +255,9 @@ pub mod server;
 
 pub mod api;
 mod assertions;
+pub mod codegen;
 mod error;
+pub mod har;
 pub mod protocol;
 pub mod testing;
 mod tty_guard;
@@ -356,7 +362,8 @@ pub use protocol::{
 // them to the crate root matches every other type a consumer constructs
 // (the `protocol::` paths still work).
 pub use protocol::{
-    HarContent, HarMode, StartHarOptions, Tracing, TracingStartOptions, TracingStopOptions,
+    HarContent, HarMode, StartHarOptions, Tracing, TracingGroupLocation, TracingStartChunkOptions,
+    TracingStartOptions, TracingStopOptions,
 };
 
 // Re-export EventWaiter for use with expect_page() / expect_close()
@@ -377,6 +384,12 @@ pub use protocol::{WebError, WebErrorLocation};
 // Re-export WebSocketRoute
 pub use protocol::{WebSocketRoute, WebSocketRouteCloseOptions};
 
+// Re-export page object traits
+pub use protocol::{LocatorRoot, PageObject};
+
+// Re-export the recorder options and event (see `codegen`)
+pub use protocol::{RecorderEvent, RecorderOptions};
+
 // Re-export FileChooser
 pub use protocol::FileChooser;
 
@@ -394,7 +407,22 @@ pub use protocol::{Clock, ClockInstallOptions};
 pub use protocol::Video;
 
 // Re-export routing types
-pub use protocol::{FetchOptions, FetchResponse, FulfillOptions, Route, UnrouteBehavior};
+pub use protocol::{
+    FetchOptions, FetchResponse, FulfillOptions, Route, RouteHandle, RouteOptions, UnrouteBehavior,
+    UrlMatcher,
+};
+
+// Re-export network emulation types
+pub use protocol::{NetworkConditions, NetworkPreset};
+
+// Re-export the network traffic recorder
+pub use protocol::{NetworkEntry, NetworkEntryState, NetworkRecorder};
+
+// Re-export request blocking report types
+pub use protocol::{BlockReason, BlockedRequest};
+
+// Re-export the URL type `UrlMatcher::predicate` closures receive
+pub use url::Url;
 
 // Re-export APIRequest public API
 pub use protocol::{APIRequest, APIRequestContext, APIRequestContextOptions, APIResponse};
@@ -412,6 +440,60 @@ pub use server::driver::{install_browsers, install_browsers_with_deps};
 #[cfg(feature = "macros")]
 pub use playwright_rs_macros::locator;
 
+// Re-export `loc!`, which builds a `Locator` from typed steps. Its
+// expansion names `AriaRole`, `GetByRoleOptions` and `FilterOptions`
+// through this crate.
+#[cfg(feature = "macros")]
+pub use playwright_rs_macros::loc;
+
+/// What `loc!` expansions name beyond the public API. Not public API.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod __loc {
+    use crate::protocol::{Frame, Page};
+
+    /// A root a `has(...)` / `has_not(...)` chain may start from. The
+    /// chain is rebuilt from the outer root and then resolved relative to
+    /// each matched element, so it must not carry a selector of its own:
+    /// only a `Page` or `Frame` root leaves it unscoped.
+    ///
+    /// ```no_run
+    /// # async fn f(page: playwright_rs::Page) {
+    /// let row = playwright_rs::loc!(page, "tr" >> has("a"));
+    /// # }
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # async fn f(table: playwright_rs::Locator) {
+    /// let row = playwright_rs::loc!(table, "tr" >> has("a"));
+    /// # }
+    /// ```
+    #[diagnostic::on_unimplemented(
+        message = "`has(...)` and `has_not(...)` in `loc!` need a `Page` or `Frame` root, not `{Self}`",
+        label = "the nested chain would start from this root's selector"
+    )]
+    pub trait FilterRoot {}
+
+    impl FilterRoot for Page {}
+    impl FilterRoot for Frame {}
+    impl<T: FilterRoot + ?Sized> FilterRoot for &T {}
+
+    /// `root`, if nested chains may start from it.
+    pub fn filter_root<R: FilterRoot + ?Sized>(root: &R) -> &R {
+        root
+    }
+}
+
+// Re-export the `#[playwright_rs::test]` attribute. Its expansion calls
+// into `testing::PlaywrightTest`, which is why it lives behind this crate.
+#[cfg(feature = "macros")]
+pub use playwright_rs_macros::test;
+
+// Re-export `#[derive(PageObject)]`. It shares its name with the trait it
+// implements, as serde's derives do.
+#[cfg(feature = "macros")]
+pub use playwright_rs_macros::PageObject;
+
 // The pure driver-acquisition mapping (platform → Node triple, download URLs)
 // that build.rs and the cli binary `include!`. std-only, so compiling it into
 // the lib's test suite costs nothing at runtime and keeps its unit tests in
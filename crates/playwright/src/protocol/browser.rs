@@ -374,6 +374,8 @@ impl Browser {
             options.storage_state_path = None; // Clear path since we've converted to inline
         }
 
+        let blocker = crate::protocol::request_blocking::RequestBlocker::from_options(&options);
+
         // Convert options to JSON
         let options_json = serde_json::to_value(options).map_err(|e| {
             crate::error::Error::ProtocolError(format!(
@@ -397,6 +399,10 @@ impl Browser {
             tracing::warn!("Failed to register BrowserContext with Selectors: {}", e);
         }
 
+        if let Some(blocker) = blocker {
+            context.install_request_blocker(blocker).await?;
+        }
+
         Ok(context)
     }
 
@@ -9,11 +9,18 @@ use crate::error::{Error, Result};
 use crate::protocol::api_request_context::APIRequestContext;
 use crate::protocol::cdp_session::CDPSession;
 use crate::protocol::event_waiter::EventWaiter;
-use crate::protocol::route::UnrouteBehavior;
+use crate::protocol::har_update::HarUpdate;
+use crate::protocol::request_blocking::{BlockedRequest, RequestBlocker};
+use crate::protocol::route::{
+    RouteHandle, RouteHandlerState, RouteOptions, UnrouteBehavior, remove_route_handlers,
+};
 use crate::protocol::tracing::Tracing;
+use crate::protocol::url_matcher::{UrlMatcher, interception_patterns};
 use crate::protocol::{
-    Browser, Download, Frame, Page, ProxySettings, Request, ResponseObject, Route,
+    Browser, Download, Frame, NetworkConditions, Page, ProxySettings, Request, ResponseObject,
+    Route,
 };
+use crate::server::call_site::CallSite;
 use crate::server::channel::Channel;
 use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
 use crate::server::connection::ConnectionExt;
@@ -24,8 +31,8 @@ use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex};
-use tokio::sync::oneshot;
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::sync::{mpsc, oneshot};
 
 /// BrowserContext represents an isolated browser session.
 ///
@@ -138,6 +145,13 @@ type WebErrorHandlerFuture = Pin<Box<dyn Future<Output = Result<()>> + Send>>;
 type WebErrorHandler =
     Arc<dyn Fn(crate::protocol::WebError) -> WebErrorHandlerFuture + Send + Sync>;
 
+/// Type alias for boxed recorder event handler future
+type RecorderHandlerFuture = Pin<Box<dyn Future<Output = Result<()>> + Send>>;
+
+/// Context-level recorder event handler
+type RecorderHandler =
+    Arc<dyn Fn(crate::protocol::RecorderEvent) -> RecorderHandlerFuture + Send + Sync>;
+
 /// Type alias for boxed service worker handler future
 type ServiceWorkerHandlerFuture = Pin<Box<dyn Future<Output = Result<()>> + Send>>;
 
@@ -166,14 +180,49 @@ type WsRouteHandlerFuture = Pin<Box<dyn Future<Output = Result<()>> + Send>>;
 /// Storage for a single route handler
 #[derive(Clone)]
 struct RouteHandlerEntry {
-    pattern: String,
+    pattern: UrlMatcher,
+    state: Arc<RouteHandlerState>,
     handler: Arc<dyn Fn(Route) -> RouteHandlerFuture + Send + Sync>,
 }
 
+/// What [`BrowserContext::set_network_conditions`] has set up: the current
+/// conditions plus, on Chromium, a CDP session per page applying them, or
+/// elsewhere the route adding latency.
+#[derive(Default)]
+struct NetworkEmulation {
+    conditions: Option<NetworkConditions>,
+    sessions: HashMap<String, CDPSession>,
+    page_hook: bool,
+    latency_route: Option<u64>,
+}
+
+impl NetworkEmulation {
+    /// Applies the current conditions (or lifts them) on `page` through
+    /// its CDP session, opening one first if needed.
+    async fn apply_to_page(&mut self, context: &BrowserContext, page: &Page) -> Result<()> {
+        let guid = page.guid().to_string();
+        let session = match self.sessions.get(&guid) {
+            Some(session) => session.clone(),
+            None if self.conditions.is_none() => return Ok(()),
+            None => {
+                let session = context.new_cdp_session(page).await?;
+                session.send("Network.enable", None).await?;
+                self.sessions.insert(guid, session.clone());
+                session
+            }
+        };
+        let params = self.conditions.clone().unwrap_or_default().to_cdp_params();
+        session
+            .send("Network.emulateNetworkConditions", Some(params))
+            .await
+            .map(|_| ())
+    }
+}
+
 /// Storage for a single WebSocket route handler entry
 #[derive(Clone)]
 struct ContextWsRouteHandlerEntry {
-    pattern: String,
+    pattern: UrlMatcher,
     handler: Arc<dyn Fn(crate::protocol::WebSocketRoute) -> WsRouteHandlerFuture + Send + Sync>,
 }
 
@@ -186,6 +235,12 @@ pub struct BrowserContext {
     pages: Arc<Mutex<Vec<Page>>>,
     /// Route handlers for context-level network interception
     route_handlers: Arc<Mutex<Vec<RouteHandlerEntry>>>,
+    /// Conditions set by `set_network_conditions` and what applies them
+    network_emulation: Arc<tokio::sync::Mutex<NetworkEmulation>>,
+    /// HARs `route_from_har` is recording, written on `close`
+    har_updates: Arc<Mutex<Vec<HarUpdate>>>,
+    /// The blocking options the context was created with, if any
+    request_blocker: Arc<OnceLock<RequestBlocker>>,
     /// APIRequestContext GUID from initializer (resolved lazily)
     request_context_guid: Option<String>,
     /// Tracing GUID from initializer (resolved lazily)
@@ -222,6 +277,11 @@ pub struct BrowserContext {
     console_waiters: Arc<Mutex<Vec<oneshot::Sender<crate::protocol::ConsoleMessage>>>>,
     /// Context-level weberror event handlers (fired for uncaught JS exceptions from any page)
     weberror_handlers: Arc<Mutex<Vec<WebErrorHandler>>>,
+    /// Context-level recorder event handlers (see `on_recorder_event`)
+    recorder_handlers: Arc<Mutex<Vec<RecorderHandler>>>,
+    /// Queue feeding recorder events to the handlers in arrival order;
+    /// created with the first handler.
+    recorder_events: Arc<Mutex<Option<mpsc::UnboundedSender<crate::protocol::RecorderEvent>>>>,
     /// Context-level service worker event handlers (fired when a service worker is registered)
     serviceworker_handlers: Arc<Mutex<Vec<ServiceWorkerHandler>>>,
     /// Context-level lifecycle handlers, forwarded from each page's events.
@@ -298,9 +358,9 @@ impl BrowserContext {
             initializer,
         );
 
-        // Store browser reference if parent is a Browser
-        // Returns None only for special contexts (Android, Electron) where parent is not a Browser
-        // For both regular contexts and persistent contexts, parent is a Browser instance
+        // Store browser reference if parent is a Browser. It is None for
+        // special contexts (Android, Electron), and may be for persistent
+        // contexts, which some drivers parent to the BrowserType instead
         let browser = parent.as_any().downcast_ref::<Browser>().cloned();
 
         let context = Self {
@@ -308,6 +368,9 @@ impl BrowserContext {
             browser,
             pages: Arc::new(Mutex::new(Vec::new())),
             route_handlers: Arc::new(Mutex::new(Vec::new())),
+            network_emulation: Arc::default(),
+            har_updates: Arc::default(),
+            request_blocker: Arc::default(),
             request_context_guid,
             tracing_guid,
             debugger_guid,
@@ -330,6 +393,8 @@ impl BrowserContext {
             console_handlers: Arc::new(Mutex::new(Vec::new())),
             console_waiters: Arc::new(Mutex::new(Vec::new())),
             weberror_handlers: Arc::new(Mutex::new(Vec::new())),
+            recorder_handlers: Arc::new(Mutex::new(Vec::new())),
+            recorder_events: Arc::new(Mutex::new(None)),
             serviceworker_handlers: Arc::new(Mutex::new(Vec::new())),
             download_handlers: Arc::new(Mutex::new(Vec::new())),
             frame_attached_handlers: Arc::new(Mutex::new(Vec::new())),
@@ -388,10 +453,13 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-add-init-script>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn add_init_script(&self, script: &str) -> Result<()> {
-        self.channel()
-            .send_no_result("addInitScript", serde_json::json!({ "source": script }))
-            .await
+    #[track_caller]
+    pub fn add_init_script(&self, script: &str) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.channel()
+                .send_no_result("addInitScript", serde_json::json!({ "source": script }))
+                .await
+        })
     }
 
     /// Creates a new page in this browser context.
@@ -407,46 +475,49 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-new-page>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
-    pub async fn new_page(&self) -> Result<Page> {
-        // Response contains the GUID of the created Page
-        #[derive(Deserialize)]
-        struct NewPageResponse {
-            page: GuidRef,
-        }
-
-        #[derive(Deserialize)]
-        struct GuidRef {
-            #[serde(deserialize_with = "crate::server::connection::deserialize_arc_str")]
-            guid: Arc<str>,
-        }
-
-        // Send newPage RPC to server
-        let response: NewPageResponse = self
-            .channel()
-            .send("newPage", serde_json::json!({}))
-            .await?;
-
-        // Retrieve and downcast the Page object from the connection registry
-        let page: Page = self
-            .connection()
-            .get_typed::<Page>(&response.page.guid)
-            .await?;
+    #[track_caller]
+    pub fn new_page(&self) -> impl Future<Output = Result<Page>> {
+        CallSite::caller().run(async move {
+            // Response contains the GUID of the created Page
+            #[derive(Deserialize)]
+            struct NewPageResponse {
+                page: GuidRef,
+            }
 
-        // Note: Don't track the page here - it will be tracked via the "page" event
-        // that Playwright server sends automatically when a page is created.
-        // Tracking it here would create duplicates.
+            #[derive(Deserialize)]
+            struct GuidRef {
+                #[serde(deserialize_with = "crate::server::connection::deserialize_arc_str")]
+                guid: Arc<str>,
+            }
 
-        // Propagate context-level timeout defaults to the new page
-        let ctx_timeout = self.default_timeout_ms();
-        let ctx_nav_timeout = self.default_navigation_timeout_ms();
-        if ctx_timeout.to_bits() != crate::DEFAULT_TIMEOUT_MS.to_bits() {
-            page.set_default_timeout(ctx_timeout).await;
-        }
-        if ctx_nav_timeout.to_bits() != crate::DEFAULT_TIMEOUT_MS.to_bits() {
-            page.set_default_navigation_timeout(ctx_nav_timeout).await;
-        }
+            // Send newPage RPC to server
+            let response: NewPageResponse = self
+                .channel()
+                .send("newPage", serde_json::json!({}))
+                .await?;
+
+            // Retrieve and downcast the Page object from the connection registry
+            let page: Page = self
+                .connection()
+                .get_typed::<Page>(&response.page.guid)
+                .await?;
+
+            // Note: Don't track the page here - it will be tracked via the "page" event
+            // that Playwright server sends automatically when a page is created.
+            // Tracking it here would create duplicates.
+
+            // Propagate context-level timeout defaults to the new page
+            let ctx_timeout = self.default_timeout_ms();
+            let ctx_nav_timeout = self.default_navigation_timeout_ms();
+            if ctx_timeout.to_bits() != crate::DEFAULT_TIMEOUT_MS.to_bits() {
+                page.set_default_timeout(ctx_timeout).await;
+            }
+            if ctx_nav_timeout.to_bits() != crate::DEFAULT_TIMEOUT_MS.to_bits() {
+                page.set_default_navigation_timeout(ctx_nav_timeout).await;
+            }
 
-        Ok(page)
+            Ok(page)
+        })
     }
 
     /// Returns all open pages in the context.
@@ -504,14 +575,17 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-request>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn request(&self) -> Result<APIRequestContext> {
-        let guid = self.request_context_guid.as_ref().ok_or_else(|| {
-            crate::error::Error::ProtocolError(
-                "No APIRequestContext available for this context".to_string(),
-            )
-        })?;
-
-        self.connection().get_typed::<APIRequestContext>(guid).await
+    #[track_caller]
+    pub fn request(&self) -> impl Future<Output = Result<APIRequestContext>> {
+        CallSite::caller().run(async move {
+            let guid = self.request_context_guid.as_ref().ok_or_else(|| {
+                crate::error::Error::ProtocolError(
+                    "No APIRequestContext available for this context".to_string(),
+                )
+            })?;
+
+            self.connection().get_typed::<APIRequestContext>(guid).await
+        })
     }
 
     /// Creates a new Chrome DevTools Protocol session for the given page.
@@ -532,29 +606,32 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-new-cdp-session>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), page_guid = %page.guid()))]
-    pub async fn new_cdp_session(&self, page: &Page) -> Result<CDPSession> {
-        #[derive(serde::Deserialize)]
-        struct NewCDPSessionResponse {
-            session: GuidRef,
-        }
-
-        #[derive(serde::Deserialize)]
-        struct GuidRef {
-            #[serde(deserialize_with = "crate::server::connection::deserialize_arc_str")]
-            guid: Arc<str>,
-        }
+    #[track_caller]
+    pub fn new_cdp_session(&self, page: &Page) -> impl Future<Output = Result<CDPSession>> {
+        CallSite::caller().run(async move {
+            #[derive(serde::Deserialize)]
+            struct NewCDPSessionResponse {
+                session: GuidRef,
+            }
 
-        let response: NewCDPSessionResponse = self
-            .channel()
-            .send(
-                "newCDPSession",
-                serde_json::json!({ "page": { "guid": page.guid() } }),
-            )
-            .await?;
+            #[derive(serde::Deserialize)]
+            struct GuidRef {
+                #[serde(deserialize_with = "crate::server::connection::deserialize_arc_str")]
+                guid: Arc<str>,
+            }
 
-        self.connection()
-            .get_typed::<CDPSession>(&response.session.guid)
-            .await
+            let response: NewCDPSessionResponse = self
+                .channel()
+                .send(
+                    "newCDPSession",
+                    serde_json::json!({ "page": { "guid": page.guid() } }),
+                )
+                .await?;
+
+            self.connection()
+                .get_typed::<CDPSession>(&response.session.guid)
+                .await
+        })
     }
 
     /// Returns the Tracing object for this browser context.
@@ -569,14 +646,17 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-tracing>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn tracing(&self) -> Result<Tracing> {
-        let guid = self.tracing_guid.as_ref().ok_or_else(|| {
-            crate::error::Error::ProtocolError(
-                "No Tracing object available for this context".to_string(),
-            )
-        })?;
-
-        self.connection().get_typed::<Tracing>(guid).await
+    #[track_caller]
+    pub fn tracing(&self) -> impl Future<Output = Result<Tracing>> {
+        CallSite::caller().run(async move {
+            let guid = self.tracing_guid.as_ref().ok_or_else(|| {
+                crate::error::Error::ProtocolError(
+                    "No Tracing object available for this context".to_string(),
+                )
+            })?;
+
+            self.connection().get_typed::<Tracing>(guid).await
+        })
     }
 
     /// Returns the [`Debugger`](crate::protocol::Debugger) for this context.
@@ -588,15 +668,18 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-debugger>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn debugger(&self) -> Result<crate::protocol::Debugger> {
-        let guid = self.debugger_guid.as_ref().ok_or_else(|| {
-            crate::error::Error::ProtocolError(
-                "No Debugger object available for this context".to_string(),
-            )
-        })?;
-        self.connection()
-            .get_typed::<crate::protocol::Debugger>(guid)
-            .await
+    #[track_caller]
+    pub fn debugger(&self) -> impl Future<Output = Result<crate::protocol::Debugger>> {
+        CallSite::caller().run(async move {
+            let guid = self.debugger_guid.as_ref().ok_or_else(|| {
+                crate::error::Error::ProtocolError(
+                    "No Debugger object available for this context".to_string(),
+                )
+            })?;
+            self.connection()
+                .get_typed::<crate::protocol::Debugger>(guid)
+                .await
+        })
     }
 
     /// Returns the Clock object for this browser context.
@@ -633,19 +716,32 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-close>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
-    pub async fn close(&self) -> Result<()> {
-        // Unregister from Selectors coordinator so closed channels are not sent future messages.
-        let selectors = self.connection().selectors();
-        selectors.remove_context(self.channel());
-
-        // Send close RPC to server
-        let result = self
-            .channel()
-            .send_no_result("close", serde_json::json!({}))
-            .await;
-        // Mark as closed regardless of error (best-effort)
-        self.is_closed.store(true, Ordering::Relaxed);
-        result
+    #[track_caller]
+    pub fn close(&self) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            // Unregister from Selectors coordinator so closed channels are not sent future messages.
+            let selectors = self.connection().selectors();
+            selectors.remove_context(self.channel());
+
+            // Write the HARs `route_from_har` recorded while the context is
+            // still open, then close it whatever happened to them.
+            let har_updates = std::mem::take(&mut *self.har_updates.lock().unwrap());
+            let mut har_result = Ok(());
+            for update in har_updates {
+                if let Err(e) = update.finish(self).await {
+                    har_result = har_result.and(Err(e));
+                }
+            }
+
+            // Send close RPC to server
+            let result = self
+                .channel()
+                .send_no_result("close", serde_json::json!({}))
+                .await;
+            // Mark as closed regardless of error (best-effort)
+            self.is_closed.store(true, Ordering::Relaxed);
+            har_result.and(result)
+        })
     }
 
     /// Sets the default timeout for all operations in this browser context.
@@ -659,19 +755,22 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-set-default-timeout>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn set_default_timeout(&self, timeout: f64) {
-        self.default_timeout_ms
-            .store(timeout.to_bits(), std::sync::atomic::Ordering::Relaxed);
-        let pages: Vec<Page> = self.pages.lock().unwrap().clone();
-        for page in pages {
-            page.set_default_timeout(timeout).await;
-        }
-        crate::protocol::page::set_timeout_and_notify(
-            self.channel(),
-            "setDefaultTimeoutNoReply",
-            timeout,
-        )
-        .await;
+    #[track_caller]
+    pub fn set_default_timeout(&self, timeout: f64) -> impl Future<Output = ()> {
+        CallSite::caller().run(async move {
+            self.default_timeout_ms
+                .store(timeout.to_bits(), std::sync::atomic::Ordering::Relaxed);
+            let pages: Vec<Page> = self.pages.lock().unwrap().clone();
+            for page in pages {
+                page.set_default_timeout(timeout).await;
+            }
+            crate::protocol::page::set_timeout_and_notify(
+                self.channel(),
+                "setDefaultTimeoutNoReply",
+                timeout,
+            )
+            .await;
+        })
     }
 
     /// Sets the default timeout for navigation operations in this browser context.
@@ -685,19 +784,22 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-set-default-navigation-timeout>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn set_default_navigation_timeout(&self, timeout: f64) {
-        self.default_navigation_timeout_ms
-            .store(timeout.to_bits(), std::sync::atomic::Ordering::Relaxed);
-        let pages: Vec<Page> = self.pages.lock().unwrap().clone();
-        for page in pages {
-            page.set_default_navigation_timeout(timeout).await;
-        }
-        crate::protocol::page::set_timeout_and_notify(
-            self.channel(),
-            "setDefaultNavigationTimeoutNoReply",
-            timeout,
-        )
-        .await;
+    #[track_caller]
+    pub fn set_default_navigation_timeout(&self, timeout: f64) -> impl Future<Output = ()> {
+        CallSite::caller().run(async move {
+            self.default_navigation_timeout_ms
+                .store(timeout.to_bits(), std::sync::atomic::Ordering::Relaxed);
+            let pages: Vec<Page> = self.pages.lock().unwrap().clone();
+            for page in pages {
+                page.set_default_navigation_timeout(timeout).await;
+            }
+            crate::protocol::page::set_timeout_and_notify(
+                self.channel(),
+                "setDefaultNavigationTimeoutNoReply",
+                timeout,
+            )
+            .await;
+        })
     }
 
     /// Returns the context's current default action timeout in milliseconds.
@@ -720,10 +822,13 @@ impl BrowserContext {
     ///
     /// This pauses the execution of all pages in the context.
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn pause(&self) -> Result<()> {
-        self.channel()
-            .send_no_result("pause", serde_json::Value::Null)
-            .await
+    #[track_caller]
+    pub fn pause(&self) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.channel()
+                .send_no_result("pause", serde_json::Value::Null)
+                .await
+        })
     }
 
     /// Returns storage state for this browser context.
@@ -732,14 +837,17 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-storage-state>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn storage_state(
+    #[track_caller]
+    pub fn storage_state(
         &self,
         options: impl Into<Option<StorageStateOptions>>,
-    ) -> Result<StorageState> {
-        let params = serde_json::to_value(options.into().unwrap_or_default())
-            .map_err(|e| Error::ProtocolError(format!("Failed to serialize options: {e}")))?;
-        let response: StorageState = self.channel().send("storageState", params).await?;
-        Ok(response)
+    ) -> impl Future<Output = Result<StorageState>> {
+        CallSite::caller().run(async move {
+            let params = serde_json::to_value(options.into().unwrap_or_default())
+                .map_err(|e| Error::ProtocolError(format!("Failed to serialize options: {e}")))?;
+            let response: StorageState = self.channel().send("storageState", params).await?;
+            Ok(response)
+        })
     }
 
     /// Replaces this context's storage state in-place via the driver's
@@ -794,23 +902,27 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-set-storage-state>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn set_storage_state(&self, state: StorageState) -> Result<()> {
-        // Delegates to the driver rather than reconstructing the state
-        // client-side. The previous implementation cleared cookies, re-added
-        // them, then opened a throwaway page per origin to replay
-        // localStorage through `evaluate`. That could only ever restore what
-        // it knew how to replay, so WebAuthn passkeys and
-        // IndexedDB were silently dropped, and every origin cost a page
-        // navigation.
-        let storage_state = serde_json::to_value(&state)
-            .map_err(|e| Error::ProtocolError(format!("Failed to serialize storage state: {e}")))?;
-
-        self.channel()
-            .send_no_result(
-                "setStorageState",
-                serde_json::json!({ "storageState": storage_state }),
-            )
-            .await
+    #[track_caller]
+    pub fn set_storage_state(&self, state: StorageState) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            // Delegates to the driver rather than reconstructing the state
+            // client-side. The previous implementation cleared cookies, re-added
+            // them, then opened a throwaway page per origin to replay
+            // localStorage through `evaluate`. That could only ever restore what
+            // it knew how to replay, so WebAuthn passkeys and
+            // IndexedDB were silently dropped, and every origin cost a page
+            // navigation.
+            let storage_state = serde_json::to_value(&state).map_err(|e| {
+                Error::ProtocolError(format!("Failed to serialize storage state: {e}"))
+            })?;
+
+            self.channel()
+                .send_no_result(
+                    "setStorageState",
+                    serde_json::json!({ "storageState": storage_state }),
+                )
+                .await
+        })
     }
 
     /// Returns whether this browser context has been closed.
@@ -834,15 +946,18 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-add-cookies>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), count = cookies.len()))]
-    pub async fn add_cookies(&self, cookies: &[Cookie]) -> Result<()> {
-        self.channel()
-            .send_no_result(
-                "addCookies",
-                serde_json::json!({
-                    "cookies": cookies
-                }),
-            )
-            .await
+    #[track_caller]
+    pub fn add_cookies(&self, cookies: &[Cookie]) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.channel()
+                .send_no_result(
+                    "addCookies",
+                    serde_json::json!({
+                        "cookies": cookies
+                    }),
+                )
+                .await
+        })
     }
 
     /// Returns cookies for this browser context, optionally filtered by URLs.
@@ -861,17 +976,20 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-cookies>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), count = tracing::field::Empty))]
-    pub async fn cookies(&self, urls: Option<&[&str]>) -> Result<Vec<Cookie>> {
-        let url_list: Vec<&str> = urls.unwrap_or(&[]).to_vec();
-        #[derive(serde::Deserialize)]
-        struct CookiesResponse {
-            cookies: Vec<Cookie>,
-        }
-        let response: CookiesResponse = self
-            .channel()
-            .send("cookies", serde_json::json!({ "urls": url_list }))
-            .await?;
-        Ok(response.cookies)
+    #[track_caller]
+    pub fn cookies(&self, urls: Option<&[&str]>) -> impl Future<Output = Result<Vec<Cookie>>> {
+        CallSite::caller().run(async move {
+            let url_list: Vec<&str> = urls.unwrap_or(&[]).to_vec();
+            #[derive(serde::Deserialize)]
+            struct CookiesResponse {
+                cookies: Vec<Cookie>,
+            }
+            let response: CookiesResponse = self
+                .channel()
+                .send("cookies", serde_json::json!({ "urls": url_list }))
+                .await?;
+            Ok(response.cookies)
+        })
     }
 
     /// Clears cookies from this browser context, with optional filters.
@@ -891,16 +1009,19 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-clear-cookies>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn clear_cookies(
+    #[track_caller]
+    pub fn clear_cookies(
         &self,
         options: impl Into<Option<ClearCookiesOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        let params = match options {
-            None => serde_json::json!({}),
-            Some(opts) => serde_json::to_value(opts).unwrap_or(serde_json::json!({})),
-        };
-        self.channel().send_no_result("clearCookies", params).await
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let params = match options {
+                None => serde_json::json!({}),
+                Some(opts) => serde_json::to_value(opts).unwrap_or(serde_json::json!({})),
+            };
+            self.channel().send_no_result("clearCookies", params).await
+        })
     }
 
     /// Sets extra HTTP headers that will be sent with every request from this context.
@@ -920,18 +1041,24 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-set-extra-http-headers>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), count = headers.len()))]
-    pub async fn set_extra_http_headers(&self, headers: HashMap<String, String>) -> Result<()> {
-        // Playwright protocol expects an array of {name, value} objects
-        let headers_array: Vec<serde_json::Value> = headers
-            .into_iter()
-            .map(|(name, value)| serde_json::json!({ "name": name, "value": value }))
-            .collect();
-        self.channel()
-            .send_no_result(
-                "setExtraHTTPHeaders",
-                serde_json::json!({ "headers": headers_array }),
-            )
-            .await
+    #[track_caller]
+    pub fn set_extra_http_headers(
+        &self,
+        headers: HashMap<String, String>,
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            // Playwright protocol expects an array of {name, value} objects
+            let headers_array: Vec<serde_json::Value> = headers
+                .into_iter()
+                .map(|(name, value)| serde_json::json!({ "name": name, "value": value }))
+                .collect();
+            self.channel()
+                .send_no_result(
+                    "setExtraHTTPHeaders",
+                    serde_json::json!({ "headers": headers_array }),
+                )
+                .await
+        })
     }
 
     /// Grants browser permissions to the context.
@@ -956,21 +1083,24 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-grant-permissions>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn grant_permissions(
+    #[track_caller]
+    pub fn grant_permissions(
         &self,
         permissions: &[&str],
         options: impl Into<Option<GrantPermissionsOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        let mut params = serde_json::json!({ "permissions": permissions });
-        if let Some(opts) = options
-            && let Some(origin) = opts.origin
-        {
-            params["origin"] = serde_json::Value::String(origin);
-        }
-        self.channel()
-            .send_no_result("grantPermissions", params)
-            .await
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let mut params = serde_json::json!({ "permissions": permissions });
+            if let Some(opts) = options
+                && let Some(origin) = opts.origin
+            {
+                params["origin"] = serde_json::Value::String(origin);
+            }
+            self.channel()
+                .send_no_result("grantPermissions", params)
+                .await
+        })
     }
 
     /// Clears all permission overrides for this browser context.
@@ -986,10 +1116,13 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-clear-permissions>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn clear_permissions(&self) -> Result<()> {
-        self.channel()
-            .send_no_result("clearPermissions", serde_json::json!({}))
-            .await
+    #[track_caller]
+    pub fn clear_permissions(&self) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.channel()
+                .send_no_result("clearPermissions", serde_json::json!({}))
+                .await
+        })
     }
 
     /// Sets or clears the geolocation for all pages in this context.
@@ -1013,16 +1146,22 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-set-geolocation>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn set_geolocation(&self, geolocation: Option<Geolocation>) -> Result<()> {
-        // Playwright protocol: omit the "geolocation" key entirely to clear;
-        // passing null causes a validation error on the server side.
-        let params = match geolocation {
-            Some(geo) => serde_json::json!({ "geolocation": geo }),
-            None => serde_json::json!({}),
-        };
-        self.channel()
-            .send_no_result("setGeolocation", params)
-            .await
+    #[track_caller]
+    pub fn set_geolocation(
+        &self,
+        geolocation: Option<Geolocation>,
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            // Playwright protocol: omit the "geolocation" key entirely to clear;
+            // passing null causes a validation error on the server side.
+            let params = match geolocation {
+                Some(geo) => serde_json::json!({ "geolocation": geo }),
+                None => serde_json::json!({}),
+            };
+            self.channel()
+                .send_no_result("setGeolocation", params)
+                .await
+        })
     }
 
     /// Toggles the offline mode for this browser context.
@@ -1042,10 +1181,153 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-set-offline>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), offline))]
-    pub async fn set_offline(&self, offline: bool) -> Result<()> {
-        self.channel()
-            .send_no_result("setOffline", serde_json::json!({ "offline": offline }))
-            .await
+    #[track_caller]
+    pub fn set_offline(&self, offline: bool) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.channel()
+                .send_no_result("setOffline", serde_json::json!({ "offline": offline }))
+                .await
+        })
+    }
+
+    /// Emulates a slow network for every page in the context, current and
+    /// future, until [`clear_network_conditions`](Self::clear_network_conditions).
+    ///
+    /// On Chromium, latency and throughput are applied through CDP
+    /// `Network.emulateNetworkConditions`, as DevTools' throttling menu
+    /// does. Firefox and WebKit expose no throttling, so there only
+    /// `latency` is emulated, by a context route that holds each request
+    /// before letting it continue; throughputs are ignored, and requests a
+    /// page-level route handles are not delayed.
+    ///
+    /// # Arguments
+    ///
+    /// * `conditions` - A [`NetworkPreset`](crate::protocol::NetworkPreset) or custom
+    ///   [`NetworkConditions`]
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Context has been closed
+    /// - Communication with browser process fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use playwright_rs::{NetworkPreset, Playwright};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let pw = Playwright::launch().await?;
+    /// # let context = pw.chromium().launch().await?.new_context().await?;
+    /// context.set_network_conditions(NetworkPreset::Slow3G).await?;
+    /// let page = context.new_page().await?;
+    /// page.goto("https://example.com", None).await?;
+    /// context.clear_network_conditions().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
+    #[track_caller]
+    pub fn set_network_conditions(
+        &self,
+        conditions: impl Into<NetworkConditions>,
+    ) -> impl Future<Output = Result<()>> {
+        let conditions = conditions.into();
+        CallSite::caller().run(async move { self.apply_network_conditions(Some(conditions)).await })
+    }
+
+    /// Lifts the conditions set by
+    /// [`set_network_conditions`](Self::set_network_conditions).
+    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
+    #[track_caller]
+    pub fn clear_network_conditions(&self) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move { self.apply_network_conditions(None).await })
+    }
+
+    /// The engine this context runs in, such as `"chromium"`. A context
+    /// from `launch_persistent_context` may have no [`Browser`], so this
+    /// falls back to the `BrowserType` it descends from.
+    fn engine_name(&self) -> Option<String> {
+        if let Some(browser) = &self.browser {
+            return Some(browser.name().to_string());
+        }
+        let mut owner = self.parent();
+        while let Some(current) = owner {
+            if let Some(browser_type) = current
+                .as_any()
+                .downcast_ref::<crate::protocol::BrowserType>()
+            {
+                return Some(browser_type.name().to_string());
+            }
+            if let Some(browser) = current.as_any().downcast_ref::<Browser>() {
+                return Some(browser.name().to_string());
+            }
+            owner = current.parent();
+        }
+        None
+    }
+
+    async fn apply_network_conditions(&self, conditions: Option<NetworkConditions>) -> Result<()> {
+        let mut emulation = self.network_emulation.lock().await;
+        emulation.conditions = conditions;
+
+        if self.engine_name().as_deref() == Some("chromium") {
+            if !emulation.page_hook {
+                emulation.page_hook = true;
+                let network_emulation = Arc::clone(&self.network_emulation);
+                self.on_page(move |page| {
+                    let network_emulation = Arc::clone(&network_emulation);
+                    async move {
+                        let context = page.context()?;
+                        network_emulation
+                            .lock()
+                            .await
+                            .apply_to_page(&context, &page)
+                            .await
+                    }
+                })
+                .await?;
+            }
+            let pages = self.pages();
+            emulation
+                .sessions
+                .retain(|guid, _| pages.iter().any(|p| p.guid() == guid.as_str()));
+            for page in &pages {
+                emulation.apply_to_page(self, page).await?;
+            }
+            return Ok(());
+        }
+
+        let latency = emulation.conditions.as_ref().and_then(|c| c.latency);
+        match (latency, emulation.latency_route) {
+            (Some(latency), None) => {
+                let network_emulation = Arc::clone(&self.network_emulation);
+                let handle = self
+                    .route("**/*", move |route| {
+                        let network_emulation = Arc::clone(&network_emulation);
+                        async move {
+                            let latency = network_emulation
+                                .lock()
+                                .await
+                                .conditions
+                                .as_ref()
+                                .and_then(|c| c.latency);
+                            if let Some(latency) = latency {
+                                tokio::time::sleep(latency).await;
+                            }
+                            route.fallback(None).await
+                        }
+                    })
+                    .await?;
+                tracing::debug!("Emulating {:?} latency with a route", latency);
+                emulation.latency_route = Some(handle.id());
+            }
+            (None, Some(id)) => {
+                emulation.latency_route = None;
+                self.remove_route_handler(id).await?;
+            }
+            _ => {}
+        }
+        Ok(())
     }
 
     /// Registers a route handler for context-level network interception.
@@ -1055,41 +1337,123 @@ impl BrowserContext {
     ///
     /// # Arguments
     ///
-    /// * `pattern` - URL pattern to match (supports glob patterns like "**/*.png")
+    /// * `pattern` - URL to match: a glob like `"**/*.png"`, a `Regex`, or
+    ///   any other [`UrlMatcher`]
     /// * `handler` - Async closure that handles the route
     ///
+    /// Returns a [`RouteHandle`] that counts the requests the handler was
+    /// given and can unregister it alone.
+    ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-route>
-    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = %pattern))]
-    pub async fn route<F, Fut>(&self, pattern: &str, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn route<F, Fut>(
+        &self,
+        pattern: impl Into<UrlMatcher>,
+        handler: F,
+    ) -> impl Future<Output = Result<RouteHandle>>
     where
         F: Fn(Route) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler =
-            Arc::new(move |route: Route| -> RouteHandlerFuture { Box::pin(handler(route)) });
+        self.route_with_options(pattern, handler, None)
+    }
 
-        self.route_handlers.lock().unwrap().push(RouteHandlerEntry {
-            pattern: pattern.to_string(),
-            handler,
-        });
+    /// [`route`](Self::route) with options: `times` limits how many requests
+    /// the handler is given before it unregisters itself.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-route>
+    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = tracing::field::Empty))]
+    #[track_caller]
+    pub fn route_with_options<F, Fut>(
+        &self,
+        pattern: impl Into<UrlMatcher>,
+        handler: F,
+        options: impl Into<Option<RouteOptions>>,
+    ) -> impl Future<Output = Result<RouteHandle>>
+    where
+        F: Fn(Route) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let pattern = pattern.into();
+        tracing::Span::current().record("url", tracing::field::display(&pattern));
+        let state = RouteHandlerState::new(options.into());
+        CallSite::caller().run(async move {
+            let handler =
+                Arc::new(move |route: Route| -> RouteHandlerFuture { Box::pin(handler(route)) });
+
+            self.route_handlers.lock().unwrap().push(RouteHandlerEntry {
+                pattern,
+                state: state.clone(),
+                handler,
+            });
 
-        self.enable_network_interception().await
+            self.enable_network_interception().await?;
+
+            Ok(RouteHandle::for_context(state, self.clone()))
+        })
+    }
+
+    /// Unregisters the handler a [`RouteHandle`] refers to.
+    pub(crate) async fn remove_route_handler(&self, id: u64) -> Result<()> {
+        let removed =
+            remove_route_handlers(&self.route_handlers, |e| &e.state, |e| e.state.id == id);
+        if removed {
+            self.enable_network_interception().await?;
+        }
+        Ok(())
+    }
+
+    /// The requests the context's blocking options stopped, oldest first:
+    /// [`blocked_urls`](BrowserContextOptions::blocked_urls),
+    /// [`allowed_hosts`](BrowserContextOptions::allowed_hosts) and
+    /// [`blocked_resource_types`](BrowserContextOptions::blocked_resource_types).
+    /// Empty if none were set.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use playwright_rs::Playwright;
+    /// # use playwright_rs::protocol::BrowserContextOptions;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let pw = Playwright::launch().await?;
+    /// # let browser = pw.chromium().launch().await?;
+    /// let options = BrowserContextOptions::builder()
+    ///     .allowed_hosts(["localhost", "*.example.com"])
+    ///     .blocked_resource_types(["image", "font", "media"])
+    ///     .build();
+    /// let context = browser.new_context_with_options(options).await?;
+    /// let page = context.new_page().await?;
+    /// page.goto("http://localhost:8080", None).await?;
+    ///
+    /// for blocked in context.blocked_requests() {
+    ///     println!("{} {}: {}", blocked.method, blocked.url, blocked.reason);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn blocked_requests(&self) -> Vec<BlockedRequest> {
+        self.request_blocker
+            .get()
+            .map(RequestBlocker::blocked)
+            .unwrap_or_default()
     }
 
     /// Removes route handler(s) matching the given URL pattern.
     ///
     /// # Arguments
     ///
-    /// * `pattern` - URL pattern to remove handlers for
+    /// * `pattern` - The matcher the handlers were registered with
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-unroute>
-    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = %pattern))]
-    pub async fn unroute(&self, pattern: &str) -> Result<()> {
-        self.route_handlers
-            .lock()
-            .unwrap()
-            .retain(|entry| entry.pattern != pattern);
-        self.enable_network_interception().await
+    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = tracing::field::Empty))]
+    #[track_caller]
+    pub fn unroute(&self, pattern: impl Into<UrlMatcher>) -> impl Future<Output = Result<()>> {
+        let pattern = pattern.into();
+        tracing::Span::current().record("url", tracing::field::display(&pattern));
+        CallSite::caller().run(async move {
+            remove_route_handlers(&self.route_handlers, |e| &e.state, |e| e.pattern == pattern);
+            self.enable_network_interception().await
+        })
     }
 
     /// Removes all registered route handlers.
@@ -1100,9 +1464,15 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-unroute-all>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn unroute_all(&self, _behavior: Option<UnrouteBehavior>) -> Result<()> {
-        self.route_handlers.lock().unwrap().clear();
-        self.enable_network_interception().await
+    #[track_caller]
+    pub fn unroute_all(
+        &self,
+        _behavior: Option<UnrouteBehavior>,
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            remove_route_handlers(&self.route_handlers, |e| &e.state, |_| true);
+            self.enable_network_interception().await
+        })
     }
 
     /// Replays network requests from a HAR file recorded previously.
@@ -1112,6 +1482,12 @@ impl BrowserContext {
     /// requests are either aborted or passed through depending on
     /// `options.not_found` (`"abort"` is the default).
     ///
+    /// With `options.update`, network traffic is recorded into `har_path`
+    /// instead, and the file is written when the context closes. Adding
+    /// `merge` replays what the file already has and records only the
+    /// requests it misses. A `.har` output is deduplicated, redacted as the
+    /// options ask, and sorted so that it diffs cleanly.
+    ///
     /// # Arguments
     ///
     /// * `har_path` - Path to the `.har` file on disk
@@ -1125,135 +1501,186 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-route-from-har>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn route_from_har(
+    #[track_caller]
+    pub fn route_from_har(
         &self,
         har_path: &str,
         options: impl Into<Option<crate::protocol::RouteFromHarOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        let opts = options.unwrap_or_default();
-        let not_found = opts.not_found.unwrap_or_else(|| "abort".to_string());
-        let url_filter = opts.url.clone();
-
-        let abs_path = std::path::Path::new(har_path).canonicalize().map_err(|e| {
-            Error::InvalidPath(format!(
-                "route_from_har: cannot resolve '{}': {}",
-                har_path, e
-            ))
-        })?;
-        let abs_str = abs_path.to_string_lossy().into_owned();
-
-        let connection = self.connection();
-        let local_utils = {
-            let all = connection.all_objects_sync();
-            all.into_iter()
-                .find(|o| o.type_name() == "LocalUtils")
-                .and_then(|o| {
-                    o.as_any()
-                        .downcast_ref::<crate::protocol::LocalUtils>()
-                        .cloned()
-                })
-                .ok_or_else(|| {
-                    Error::ProtocolError(
-                        "route_from_har: LocalUtils not found in connection registry".to_string(),
-                    )
-                })?
-        };
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let opts = options.unwrap_or_default();
+            let mut not_found = opts
+                .not_found
+                .clone()
+                .unwrap_or_else(|| "abort".to_string());
+            let url_filter = opts.url.clone();
+
+            if opts.update == Some(true) {
+                if !self.start_har_update(None, har_path, &opts).await? {
+                    return Ok(());
+                }
+                // Replay what the archive has; misses go to the network and
+                // are recorded.
+                not_found = "fallback".to_string();
+            }
 
-        let har_id = local_utils.har_open(&abs_str).await?;
+            let abs_path = std::path::Path::new(har_path).canonicalize().map_err(|e| {
+                Error::InvalidPath(format!(
+                    "route_from_har: cannot resolve '{}': {}",
+                    har_path, e
+                ))
+            })?;
+            let abs_str = abs_path.to_string_lossy().into_owned();
 
-        let pattern = url_filter.unwrap_or_else(|| "**/*".to_string());
+            let connection = self.connection();
+            let local_utils = {
+                let all = connection.all_objects_sync();
+                all.into_iter()
+                    .find(|o| o.type_name() == "LocalUtils")
+                    .and_then(|o| {
+                        o.as_any()
+                            .downcast_ref::<crate::protocol::LocalUtils>()
+                            .cloned()
+                    })
+                    .ok_or_else(|| {
+                        Error::ProtocolError(
+                            "route_from_har: LocalUtils not found in connection registry"
+                                .to_string(),
+                        )
+                    })?
+            };
 
-        let har_id_clone = har_id.clone();
-        let local_utils_clone = local_utils.clone();
-        let not_found_clone = not_found.clone();
+            let har_id = local_utils.har_open(&abs_str).await?;
 
-        self.route(&pattern, move |route| {
-            let har_id = har_id_clone.clone();
-            let local_utils = local_utils_clone.clone();
-            let not_found = not_found_clone.clone();
-            async move {
-                let request = route.request();
-                let req_url = request.url().to_string();
-                let req_method = request.method().to_string();
+            let pattern = url_filter.unwrap_or_else(|| "**/*".to_string());
 
-                let headers: Vec<serde_json::Value> = request
-                    .headers()
-                    .iter()
-                    .map(|(k, v)| serde_json::json!({"name": k, "value": v}))
-                    .collect();
-
-                let lookup = local_utils
-                    .har_lookup(
-                        &har_id,
-                        &req_url,
-                        &req_method,
-                        headers,
-                        None,
-                        request.is_navigation_request(),
-                    )
-                    .await;
+            let har_id_clone = har_id.clone();
+            let local_utils_clone = local_utils.clone();
+            let not_found_clone = not_found.clone();
 
-                match lookup {
-                    Err(e) => {
-                        tracing::warn!("har_lookup error for {}: {}", req_url, e);
-                        route.continue_(None).await
-                    }
-                    Ok(result) => match result.action.as_str() {
-                        "redirect" => {
-                            let redirect_url = result.redirect_url.unwrap_or_default();
-                            let opts = crate::protocol::ContinueOptions::builder()
-                                .url(redirect_url)
-                                .build();
-                            route.continue_(Some(opts)).await
+            self.route(&pattern, move |route| {
+                let har_id = har_id_clone.clone();
+                let local_utils = local_utils_clone.clone();
+                let not_found = not_found_clone.clone();
+                async move {
+                    let request = route.request();
+                    let req_url = request.url().to_string();
+                    let req_method = request.method().to_string();
+
+                    let headers: Vec<serde_json::Value> = request
+                        .headers()
+                        .iter()
+                        .map(|(k, v)| serde_json::json!({"name": k, "value": v}))
+                        .collect();
+
+                    let lookup = local_utils
+                        .har_lookup(
+                            &har_id,
+                            &req_url,
+                            &req_method,
+                            headers,
+                            None,
+                            request.is_navigation_request(),
+                        )
+                        .await;
+
+                    match lookup {
+                        Err(e) => {
+                            tracing::warn!("har_lookup error for {}: {}", req_url, e);
+                            route.continue_(None).await
                         }
-                        "fulfill" => {
-                            let status = result.status.unwrap_or(200);
-
-                            let body_bytes = result.body.as_deref().map(|b64| {
-                                use base64::Engine;
-                                base64::engine::general_purpose::STANDARD
-                                    .decode(b64)
-                                    .unwrap_or_default()
-                            });
+                        Ok(result) => match result.action.as_str() {
+                            "redirect" => {
+                                let redirect_url = result.redirect_url.unwrap_or_default();
+                                let opts = crate::protocol::ContinueOptions::builder()
+                                    .url(redirect_url)
+                                    .build();
+                                route.continue_(Some(opts)).await
+                            }
+                            "fulfill" => {
+                                let status = result.status.unwrap_or(200);
+
+                                let body_bytes = result.body.as_deref().map(|b64| {
+                                    use base64::Engine;
+                                    base64::engine::general_purpose::STANDARD
+                                        .decode(b64)
+                                        .unwrap_or_default()
+                                });
 
-                            let mut headers_map = std::collections::HashMap::new();
-                            if let Some(raw_headers) = result.headers {
-                                for h in raw_headers {
-                                    if let (Some(name), Some(value)) = (
-                                        h.get("name").and_then(|v| v.as_str()),
-                                        h.get("value").and_then(|v| v.as_str()),
-                                    ) {
-                                        headers_map.insert(name.to_string(), value.to_string());
+                                let mut headers_map = std::collections::HashMap::new();
+                                if let Some(raw_headers) = result.headers {
+                                    for h in raw_headers {
+                                        if let (Some(name), Some(value)) = (
+                                            h.get("name").and_then(|v| v.as_str()),
+                                            h.get("value").and_then(|v| v.as_str()),
+                                        ) {
+                                            headers_map.insert(name.to_string(), value.to_string());
+                                        }
                                     }
                                 }
-                            }
 
-                            let mut builder =
-                                crate::protocol::FulfillOptions::builder().status(status);
+                                let mut builder =
+                                    crate::protocol::FulfillOptions::builder().status(status);
 
-                            if !headers_map.is_empty() {
-                                builder = builder.headers(headers_map);
-                            }
+                                if !headers_map.is_empty() {
+                                    builder = builder.headers(headers_map);
+                                }
 
-                            if let Some(body) = body_bytes {
-                                builder = builder.body(body);
-                            }
+                                if let Some(body) = body_bytes {
+                                    builder = builder.body(body);
+                                }
 
-                            route.fulfill(Some(builder.build())).await
-                        }
-                        _ => {
-                            if not_found == "fallback" {
-                                route.fallback(None).await
-                            } else {
-                                route.abort(None).await
+                                route.fulfill(Some(builder.build())).await
                             }
-                        }
-                    },
+                            _ => {
+                                if not_found == "fallback" {
+                                    route.fallback(None).await
+                                } else {
+                                    route.abort(None).await
+                                }
+                            }
+                        },
+                    }
                 }
-            }
+            })
+            .await?;
+            Ok(())
         })
-        .await
+    }
+
+    /// Starts recording into `har_path` for `route_from_har` with `update`.
+    /// Returns whether the existing file should also be replayed.
+    pub(crate) async fn start_har_update(
+        &self,
+        page: Option<&Page>,
+        har_path: &str,
+        options: &crate::protocol::RouteFromHarOptions,
+    ) -> Result<bool> {
+        let path = std::path::absolute(har_path).map_err(|e| {
+            Error::InvalidPath(format!(
+                "route_from_har: cannot resolve '{}': {}",
+                har_path, e
+            ))
+        })?;
+        let mut params = serde_json::json!({
+            "options": HarUpdate::record_options(&path, options)?,
+        });
+        if let Some(page) = page {
+            params["page"] = serde_json::json!({ "guid": page.guid() });
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct HarStartResult {
+            har_id: String,
+        }
+        let result: HarStartResult = self.channel().send("harStart", params).await?;
+
+        let update = HarUpdate::new(result.har_id, path, options)?;
+        let replay = update.replays_existing();
+        self.har_updates.lock().unwrap().push(update);
+        Ok(replay)
     }
 
     /// Adds a listener for the `page` event.
@@ -1267,14 +1694,18 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-page>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_page<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_page<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Page) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |page: Page| -> PageHandlerFuture { Box::pin(handler(page)) });
-        self.page_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler =
+                Arc::new(move |page: Page| -> PageHandlerFuture { Box::pin(handler(page)) });
+            self.page_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Adds a listener for the `download` event: fired when any page in the
@@ -1283,20 +1714,23 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-download>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_download<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_download<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Download) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |d: Download| -> CtxHandlerFuture { Box::pin(handler(d)) });
-        let was_empty = self.download_handlers.lock().unwrap().is_empty();
-        self.download_handlers.lock().unwrap().push(handler);
-        if was_empty {
-            for page in self.pages() {
-                Self::wire_download(&page, self.download_handlers.clone()).await;
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move |d: Download| -> CtxHandlerFuture { Box::pin(handler(d)) });
+            let was_empty = self.download_handlers.lock().unwrap().is_empty();
+            self.download_handlers.lock().unwrap().push(handler);
+            if was_empty {
+                for page in self.pages() {
+                    Self::wire_download(&page, self.download_handlers.clone()).await;
+                }
             }
-        }
-        Ok(())
+            Ok(())
+        })
     }
 
     /// Adds a listener for the `frameAttached` event: fired when a frame is
@@ -1304,20 +1738,23 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-frame-attached>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_frame_attached<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_frame_attached<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Frame) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |f: Frame| -> CtxHandlerFuture { Box::pin(handler(f)) });
-        let was_empty = self.frame_attached_handlers.lock().unwrap().is_empty();
-        self.frame_attached_handlers.lock().unwrap().push(handler);
-        if was_empty {
-            for page in self.pages() {
-                Self::wire_frame_attached(&page, self.frame_attached_handlers.clone()).await;
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move |f: Frame| -> CtxHandlerFuture { Box::pin(handler(f)) });
+            let was_empty = self.frame_attached_handlers.lock().unwrap().is_empty();
+            self.frame_attached_handlers.lock().unwrap().push(handler);
+            if was_empty {
+                for page in self.pages() {
+                    Self::wire_frame_attached(&page, self.frame_attached_handlers.clone()).await;
+                }
             }
-        }
-        Ok(())
+            Ok(())
+        })
     }
 
     /// Adds a listener for the `frameDetached` event: fired when a frame is
@@ -1325,20 +1762,23 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-frame-detached>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_frame_detached<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_frame_detached<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Frame) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |f: Frame| -> CtxHandlerFuture { Box::pin(handler(f)) });
-        let was_empty = self.frame_detached_handlers.lock().unwrap().is_empty();
-        self.frame_detached_handlers.lock().unwrap().push(handler);
-        if was_empty {
-            for page in self.pages() {
-                Self::wire_frame_detached(&page, self.frame_detached_handlers.clone()).await;
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move |f: Frame| -> CtxHandlerFuture { Box::pin(handler(f)) });
+            let was_empty = self.frame_detached_handlers.lock().unwrap().is_empty();
+            self.frame_detached_handlers.lock().unwrap().push(handler);
+            if was_empty {
+                for page in self.pages() {
+                    Self::wire_frame_detached(&page, self.frame_detached_handlers.clone()).await;
+                }
             }
-        }
-        Ok(())
+            Ok(())
+        })
     }
 
     /// Adds a listener for the `frameNavigated` event: fired when a frame
@@ -1346,20 +1786,23 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-frame-navigated>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_frame_navigated<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_frame_navigated<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Frame) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |f: Frame| -> CtxHandlerFuture { Box::pin(handler(f)) });
-        let was_empty = self.frame_navigated_handlers.lock().unwrap().is_empty();
-        self.frame_navigated_handlers.lock().unwrap().push(handler);
-        if was_empty {
-            for page in self.pages() {
-                Self::wire_frame_navigated(&page, self.frame_navigated_handlers.clone()).await;
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move |f: Frame| -> CtxHandlerFuture { Box::pin(handler(f)) });
+            let was_empty = self.frame_navigated_handlers.lock().unwrap().is_empty();
+            self.frame_navigated_handlers.lock().unwrap().push(handler);
+            if was_empty {
+                for page in self.pages() {
+                    Self::wire_frame_navigated(&page, self.frame_navigated_handlers.clone()).await;
+                }
             }
-        }
-        Ok(())
+            Ok(())
+        })
     }
 
     /// Adds a listener for the `pageLoad` event: fired when any page in the
@@ -1367,20 +1810,23 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-page-load>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_page_load<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_page_load<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Page) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |p: Page| -> CtxHandlerFuture { Box::pin(handler(p)) });
-        let was_empty = self.page_load_handlers.lock().unwrap().is_empty();
-        self.page_load_handlers.lock().unwrap().push(handler);
-        if was_empty {
-            for page in self.pages() {
-                Self::wire_page_load(&page, self.page_load_handlers.clone()).await;
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move |p: Page| -> CtxHandlerFuture { Box::pin(handler(p)) });
+            let was_empty = self.page_load_handlers.lock().unwrap().is_empty();
+            self.page_load_handlers.lock().unwrap().push(handler);
+            if was_empty {
+                for page in self.pages() {
+                    Self::wire_page_load(&page, self.page_load_handlers.clone()).await;
+                }
             }
-        }
-        Ok(())
+            Ok(())
+        })
     }
 
     /// Adds a listener for the `pageClose` event: fired when any page in the
@@ -1388,20 +1834,23 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-page-close>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_page_close<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_page_close<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Page) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |p: Page| -> CtxHandlerFuture { Box::pin(handler(p)) });
-        let was_empty = self.page_close_handlers.lock().unwrap().is_empty();
-        self.page_close_handlers.lock().unwrap().push(handler);
-        if was_empty {
-            for page in self.pages() {
-                Self::wire_page_close(&page, self.page_close_handlers.clone()).await;
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move |p: Page| -> CtxHandlerFuture { Box::pin(handler(p)) });
+            let was_empty = self.page_close_handlers.lock().unwrap().is_empty();
+            self.page_close_handlers.lock().unwrap().push(handler);
+            if was_empty {
+                for page in self.pages() {
+                    Self::wire_page_close(&page, self.page_close_handlers.clone()).await;
+                }
             }
-        }
-        Ok(())
+            Ok(())
+        })
     }
 
     // --- Forwarders: wire a single page's events to the context handler vecs. ---
@@ -1513,14 +1962,17 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-close>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_close<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_close<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn() -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move || -> CloseHandlerFuture { Box::pin(handler()) });
-        self.close_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move || -> CloseHandlerFuture { Box::pin(handler()) });
+            self.close_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Adds a listener for the `request` event.
@@ -1537,20 +1989,23 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-request>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_request<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_request<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Request) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |request: Request| -> RequestHandlerFuture {
-            Box::pin(handler(request))
-        });
-        let needs_subscription = self.request_handlers.lock().unwrap().is_empty();
-        if needs_subscription {
-            _ = self.channel().update_subscription("request", true).await;
-        }
-        self.request_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move |request: Request| -> RequestHandlerFuture {
+                Box::pin(handler(request))
+            });
+            let needs_subscription = self.request_handlers.lock().unwrap().is_empty();
+            if needs_subscription {
+                _ = self.channel().update_subscription("request", true).await;
+            }
+            self.request_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Adds a listener for the `requestFinished` event.
@@ -1566,23 +2021,26 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-request-finished>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_request_finished<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_request_finished<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Request) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |request: Request| -> RequestHandlerFuture {
-            Box::pin(handler(request))
-        });
-        let needs_subscription = self.request_finished_handlers.lock().unwrap().is_empty();
-        if needs_subscription {
-            _ = self
-                .channel()
-                .update_subscription("requestFinished", true)
-                .await;
-        }
-        self.request_finished_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move |request: Request| -> RequestHandlerFuture {
+                Box::pin(handler(request))
+            });
+            let needs_subscription = self.request_finished_handlers.lock().unwrap().is_empty();
+            if needs_subscription {
+                _ = self
+                    .channel()
+                    .update_subscription("requestFinished", true)
+                    .await;
+            }
+            self.request_finished_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Adds a listener for the `requestFailed` event.
@@ -1598,23 +2056,26 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-request-failed>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_request_failed<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_request_failed<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Request) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |request: Request| -> RequestHandlerFuture {
-            Box::pin(handler(request))
-        });
-        let needs_subscription = self.request_failed_handlers.lock().unwrap().is_empty();
-        if needs_subscription {
-            _ = self
-                .channel()
-                .update_subscription("requestFailed", true)
-                .await;
-        }
-        self.request_failed_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move |request: Request| -> RequestHandlerFuture {
+                Box::pin(handler(request))
+            });
+            let needs_subscription = self.request_failed_handlers.lock().unwrap().is_empty();
+            if needs_subscription {
+                _ = self
+                    .channel()
+                    .update_subscription("requestFailed", true)
+                    .await;
+            }
+            self.request_failed_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Adds a listener for the `response` event.
@@ -1629,20 +2090,23 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-response>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_response<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_response<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(ResponseObject) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |response: ResponseObject| -> ResponseHandlerFuture {
-            Box::pin(handler(response))
-        });
-        let needs_subscription = self.response_handlers.lock().unwrap().is_empty();
-        if needs_subscription {
-            _ = self.channel().update_subscription("response", true).await;
-        }
-        self.response_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move |response: ResponseObject| -> ResponseHandlerFuture {
+                Box::pin(handler(response))
+            });
+            let needs_subscription = self.response_handlers.lock().unwrap().is_empty();
+            if needs_subscription {
+                _ = self.channel().update_subscription("response", true).await;
+            }
+            self.response_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Adds a listener for the `dialog` event on this browser context.
@@ -1665,18 +2129,21 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-dialog>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_dialog<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_dialog<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(crate::protocol::Dialog) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(
-            move |dialog: crate::protocol::Dialog| -> DialogHandlerFuture {
-                Box::pin(handler(dialog))
-            },
-        );
-        self.dialog_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler = Arc::new(
+                move |dialog: crate::protocol::Dialog| -> DialogHandlerFuture {
+                    Box::pin(handler(dialog))
+                },
+            );
+            self.dialog_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Registers a context-level console event handler.
@@ -1693,24 +2160,27 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-console>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_console<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_console<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(crate::protocol::ConsoleMessage) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(
-            move |msg: crate::protocol::ConsoleMessage| -> ConsoleHandlerFuture {
-                Box::pin(handler(msg))
-            },
-        );
-
-        let needs_subscription = self.console_handlers.lock().unwrap().is_empty();
-        if needs_subscription {
-            _ = self.channel().update_subscription("console", true).await;
-        }
-        self.console_handlers.lock().unwrap().push(handler);
+        CallSite::caller().run(async move {
+            let handler = Arc::new(
+                move |msg: crate::protocol::ConsoleMessage| -> ConsoleHandlerFuture {
+                    Box::pin(handler(msg))
+                },
+            );
+
+            let needs_subscription = self.console_handlers.lock().unwrap().is_empty();
+            if needs_subscription {
+                _ = self.channel().update_subscription("console", true).await;
+            }
+            self.console_handlers.lock().unwrap().push(handler);
 
-        Ok(())
+            Ok(())
+        })
     }
 
     /// Registers a context-level handler for uncaught JavaScript exceptions.
@@ -1731,18 +2201,103 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-web-error>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_weberror<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_weberror<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(crate::protocol::WebError) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(
-            move |web_error: crate::protocol::WebError| -> WebErrorHandlerFuture {
-                Box::pin(handler(web_error))
-            },
-        );
-        self.weberror_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler = Arc::new(
+                move |web_error: crate::protocol::WebError| -> WebErrorHandlerFuture {
+                    Box::pin(handler(web_error))
+                },
+            );
+            self.weberror_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
+    }
+
+    /// Turns on the driver's recorder for this context, as
+    /// `playwright codegen` does, and streams what it records to the
+    /// [`on_recorder_event`](Self::on_recorder_event) handlers.
+    ///
+    /// The recorder runs in its programmatic mode: pages get the in-page
+    /// recording toolbar (record, pick locator, assert text / value /
+    /// visibility), but no Inspector window opens. Register handlers
+    /// first; events recorded before any handler exists are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if communication with the browser process fails.
+    ///
+    /// See: <https://playwright.dev/docs/codegen>
+    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
+    #[track_caller]
+    pub fn enable_recorder(
+        &self,
+        options: Option<crate::protocol::RecorderOptions>,
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let mut params = serde_json::to_value(options.unwrap_or_default())
+                .map_err(|e| Error::ProtocolError(format!("Failed to serialize options: {e}")))?;
+            // `api` routes actions to this client instead of the Inspector;
+            // `language` only picks the driver's own code snippet, which
+            // the events carry and this crate ignores.
+            params["recorderMode"] = "api".into();
+            params["language"] = "javascript".into();
+            self.channel()
+                .send_no_result("enableRecorder", params)
+                .await
+        })
+    }
+
+    /// Registers a handler for actions recorded after
+    /// [`enable_recorder`](Self::enable_recorder).
+    ///
+    /// Handlers run one event at a time, in the order the recorder sent
+    /// them, since an [`ActionUpdated`](crate::protocol::RecorderEvent::ActionUpdated)
+    /// rewrites the action before it.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - Async closure that receives a [`RecorderEvent`](crate::protocol::RecorderEvent).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if communication with the browser process fails.
+    #[track_caller]
+    pub fn on_recorder_event<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
+    where
+        F: Fn(crate::protocol::RecorderEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        CallSite::caller().run(async move {
+            let handler = Arc::new(
+                move |event: crate::protocol::RecorderEvent| -> RecorderHandlerFuture {
+                    Box::pin(handler(event))
+                },
+            );
+            self.recorder_handlers.lock().unwrap().push(handler);
+
+            let mut events = self.recorder_events.lock().unwrap();
+            if events.is_none() {
+                let (tx, mut rx) = mpsc::unbounded_channel::<crate::protocol::RecorderEvent>();
+                let handlers = self.recorder_handlers.clone();
+                tokio::spawn(async move {
+                    while let Some(event) = rx.recv().await {
+                        let handlers = handlers.lock().unwrap().clone();
+                        for handler in handlers {
+                            if let Err(e) = handler(event.clone()).await {
+                                tracing::warn!("Recorder event handler error: {}", e);
+                            }
+                        }
+                    }
+                });
+                *events = Some(tx);
+            }
+            Ok(())
+        })
     }
 
     /// Registers a handler for the `serviceWorker` event.
@@ -1757,18 +2312,21 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-service-worker>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_serviceworker<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_serviceworker<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(crate::protocol::Worker) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(
-            move |worker: crate::protocol::Worker| -> ServiceWorkerHandlerFuture {
-                Box::pin(handler(worker))
-            },
-        );
-        self.serviceworker_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler = Arc::new(
+                move |worker: crate::protocol::Worker| -> ServiceWorkerHandlerFuture {
+                    Box::pin(handler(worker))
+                },
+            );
+            self.serviceworker_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Exposes a Rust function to every page in this browser context as
@@ -1796,12 +2354,17 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-expose-function>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), name = %name))]
-    pub async fn expose_function<F, Fut>(&self, name: &str, callback: F) -> Result<()>
+    #[track_caller]
+    pub fn expose_function<F, Fut>(
+        &self,
+        name: &str,
+        callback: F,
+    ) -> impl Future<Output = Result<()>>
     where
         F: Fn(Vec<serde_json::Value>) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = serde_json::Value> + Send + 'static,
     {
-        self.expose_binding_internal(name, callback).await
+        CallSite::caller().run(async move { self.expose_binding_internal(name, callback).await })
     }
 
     /// Exposes a Rust function to every page in this browser context as
@@ -1825,12 +2388,17 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-expose-binding>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), name = %name))]
-    pub async fn expose_binding<F, Fut>(&self, name: &str, callback: F) -> Result<()>
+    #[track_caller]
+    pub fn expose_binding<F, Fut>(
+        &self,
+        name: &str,
+        callback: F,
+    ) -> impl Future<Output = Result<()>>
     where
         F: Fn(Vec<serde_json::Value>) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = serde_json::Value> + Send + 'static,
     {
-        self.expose_binding_internal(name, callback).await
+        CallSite::caller().run(async move { self.expose_binding_internal(name, callback).await })
     }
 
     /// Internal implementation shared by expose_function and expose_binding.
@@ -1895,10 +2463,16 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-wait-for-event>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn expect_page(&self, timeout: Option<f64>) -> Result<EventWaiter<Page>> {
-        let (tx, rx) = oneshot::channel();
-        self.page_waiters.lock().unwrap().push(tx);
-        Ok(EventWaiter::new(rx, timeout.or(Some(30_000.0))))
+    #[track_caller]
+    pub fn expect_page(
+        &self,
+        timeout: Option<f64>,
+    ) -> impl Future<Output = Result<EventWaiter<Page>>> {
+        CallSite::caller().run(async move {
+            let (tx, rx) = oneshot::channel();
+            self.page_waiters.lock().unwrap().push(tx);
+            Ok(EventWaiter::new(rx, timeout.or(Some(30_000.0))))
+        })
     }
 
     /// Waits for this browser context to be closed.
@@ -1933,28 +2507,37 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-wait-for-event>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn expect_close(&self, timeout: Option<f64>) -> Result<EventWaiter<()>> {
-        let (tx, rx) = oneshot::channel();
-        self.close_waiters.lock().unwrap().push(tx);
-        Ok(EventWaiter::new(rx, timeout.or(Some(30_000.0))))
+    #[track_caller]
+    pub fn expect_close(
+        &self,
+        timeout: Option<f64>,
+    ) -> impl Future<Output = Result<EventWaiter<()>>> {
+        CallSite::caller().run(async move {
+            let (tx, rx) = oneshot::channel();
+            self.close_waiters.lock().unwrap().push(tx);
+            Ok(EventWaiter::new(rx, timeout.or(Some(30_000.0))))
+        })
     }
 
     /// Waits for a console message from any page in this context.
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-event-console>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn expect_console_message(
+    #[track_caller]
+    pub fn expect_console_message(
         &self,
         timeout: Option<f64>,
-    ) -> Result<EventWaiter<crate::protocol::ConsoleMessage>> {
-        let needs_subscription = self.console_handlers.lock().unwrap().is_empty()
-            && self.console_waiters.lock().unwrap().is_empty();
-        if needs_subscription {
-            _ = self.channel().update_subscription("console", true).await;
-        }
-        let (tx, rx) = oneshot::channel();
-        self.console_waiters.lock().unwrap().push(tx);
-        Ok(EventWaiter::new(rx, timeout.or(Some(30_000.0))))
+    ) -> impl Future<Output = Result<EventWaiter<crate::protocol::ConsoleMessage>>> {
+        CallSite::caller().run(async move {
+            let needs_subscription = self.console_handlers.lock().unwrap().is_empty()
+                && self.console_waiters.lock().unwrap().is_empty();
+            if needs_subscription {
+                _ = self.channel().update_subscription("console", true).await;
+            }
+            let (tx, rx) = oneshot::channel();
+            self.console_waiters.lock().unwrap().push(tx);
+            Ok(EventWaiter::new(rx, timeout.or(Some(30_000.0))))
+        })
     }
 
     /// Waits for the given event to fire and returns a typed `EventValue`.
@@ -1981,145 +2564,149 @@ impl BrowserContext {
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-wait-for-event>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn expect_event(
+    #[track_caller]
+    pub fn expect_event(
         &self,
         event: &str,
         timeout: Option<f64>,
-    ) -> crate::error::Result<EventWaiter<crate::protocol::EventValue>> {
-        use crate::protocol::EventValue;
-        use tokio::sync::oneshot;
-
-        let timeout_ms = timeout.or(Some(30_000.0));
-
-        match event {
-            "page" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<Page>();
-                self.page_waiters.lock().unwrap().push(inner_tx);
+    ) -> impl Future<Output = crate::error::Result<EventWaiter<crate::protocol::EventValue>>> {
+        CallSite::caller().run(async move {
+            use crate::protocol::EventValue;
+            use tokio::sync::oneshot;
 
-                tokio::spawn(async move {
-                    if let Ok(v) = inner_rx.await {
-                        let _ = tx.send(EventValue::Page(v));
-                    }
-                });
+            let timeout_ms = timeout.or(Some(30_000.0));
 
-                Ok(EventWaiter::new(rx, timeout_ms))
-            }
+            match event {
+                "page" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<Page>();
+                    self.page_waiters.lock().unwrap().push(inner_tx);
 
-            "close" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<()>();
-                self.close_waiters.lock().unwrap().push(inner_tx);
+                    tokio::spawn(async move {
+                        if let Ok(v) = inner_rx.await {
+                            let _ = tx.send(EventValue::Page(v));
+                        }
+                    });
 
-                tokio::spawn(async move {
-                    if inner_rx.await.is_ok() {
-                        let _ = tx.send(EventValue::Close);
-                    }
-                });
+                    Ok(EventWaiter::new(rx, timeout_ms))
+                }
 
-                Ok(EventWaiter::new(rx, timeout_ms))
-            }
+                "close" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<()>();
+                    self.close_waiters.lock().unwrap().push(inner_tx);
 
-            "console" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::ConsoleMessage>();
+                    tokio::spawn(async move {
+                        if inner_rx.await.is_ok() {
+                            let _ = tx.send(EventValue::Close);
+                        }
+                    });
 
-                let needs_subscription = self.console_handlers.lock().unwrap().is_empty()
-                    && self.console_waiters.lock().unwrap().is_empty();
-                if needs_subscription {
-                    _ = self.channel().update_subscription("console", true).await;
+                    Ok(EventWaiter::new(rx, timeout_ms))
                 }
-                self.console_waiters.lock().unwrap().push(inner_tx);
 
-                tokio::spawn(async move {
-                    if let Ok(v) = inner_rx.await {
-                        let _ = tx.send(EventValue::ConsoleMessage(v));
-                    }
-                });
+                "console" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) =
+                        oneshot::channel::<crate::protocol::ConsoleMessage>();
 
-                Ok(EventWaiter::new(rx, timeout_ms))
-            }
+                    let needs_subscription = self.console_handlers.lock().unwrap().is_empty()
+                        && self.console_waiters.lock().unwrap().is_empty();
+                    if needs_subscription {
+                        _ = self.channel().update_subscription("console", true).await;
+                    }
+                    self.console_waiters.lock().unwrap().push(inner_tx);
 
-            "request" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<Request>();
+                    tokio::spawn(async move {
+                        if let Ok(v) = inner_rx.await {
+                            let _ = tx.send(EventValue::ConsoleMessage(v));
+                        }
+                    });
 
-                let needs_subscription = {
-                    let handlers = self.request_handlers.lock().unwrap();
-                    let waiters = self.request_waiters.lock().unwrap();
-                    handlers.is_empty() && waiters.is_empty()
-                };
-                if needs_subscription {
-                    _ = self.channel().update_subscription("request", true).await;
+                    Ok(EventWaiter::new(rx, timeout_ms))
                 }
-                self.request_waiters.lock().unwrap().push(inner_tx);
 
-                tokio::spawn(async move {
-                    if let Ok(v) = inner_rx.await {
-                        let _ = tx.send(EventValue::Request(v));
-                    }
-                });
+                "request" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<Request>();
 
-                Ok(EventWaiter::new(rx, timeout_ms))
-            }
+                    let needs_subscription = {
+                        let handlers = self.request_handlers.lock().unwrap();
+                        let waiters = self.request_waiters.lock().unwrap();
+                        handlers.is_empty() && waiters.is_empty()
+                    };
+                    if needs_subscription {
+                        _ = self.channel().update_subscription("request", true).await;
+                    }
+                    self.request_waiters.lock().unwrap().push(inner_tx);
 
-            "response" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<ResponseObject>();
+                    tokio::spawn(async move {
+                        if let Ok(v) = inner_rx.await {
+                            let _ = tx.send(EventValue::Request(v));
+                        }
+                    });
 
-                let needs_subscription = {
-                    let handlers = self.response_handlers.lock().unwrap();
-                    let waiters = self.response_waiters.lock().unwrap();
-                    handlers.is_empty() && waiters.is_empty()
-                };
-                if needs_subscription {
-                    _ = self.channel().update_subscription("response", true).await;
+                    Ok(EventWaiter::new(rx, timeout_ms))
                 }
-                self.response_waiters.lock().unwrap().push(inner_tx);
 
-                tokio::spawn(async move {
-                    if let Ok(v) = inner_rx.await {
-                        let _ = tx.send(EventValue::Response(v));
+                "response" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<ResponseObject>();
+
+                    let needs_subscription = {
+                        let handlers = self.response_handlers.lock().unwrap();
+                        let waiters = self.response_waiters.lock().unwrap();
+                        handlers.is_empty() && waiters.is_empty()
+                    };
+                    if needs_subscription {
+                        _ = self.channel().update_subscription("response", true).await;
                     }
-                });
+                    self.response_waiters.lock().unwrap().push(inner_tx);
 
-                Ok(EventWaiter::new(rx, timeout_ms))
-            }
+                    tokio::spawn(async move {
+                        if let Ok(v) = inner_rx.await {
+                            let _ = tx.send(EventValue::Response(v));
+                        }
+                    });
 
-            "weberror" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::WebError>();
-                self.weberror_waiters.lock().unwrap().push(inner_tx);
+                    Ok(EventWaiter::new(rx, timeout_ms))
+                }
 
-                tokio::spawn(async move {
-                    if let Ok(v) = inner_rx.await {
-                        let _ = tx.send(EventValue::WebError(v));
-                    }
-                });
+                "weberror" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::WebError>();
+                    self.weberror_waiters.lock().unwrap().push(inner_tx);
 
-                Ok(EventWaiter::new(rx, timeout_ms))
-            }
+                    tokio::spawn(async move {
+                        if let Ok(v) = inner_rx.await {
+                            let _ = tx.send(EventValue::WebError(v));
+                        }
+                    });
 
-            "serviceworker" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::Worker>();
-                self.serviceworker_waiters.lock().unwrap().push(inner_tx);
+                    Ok(EventWaiter::new(rx, timeout_ms))
+                }
 
-                tokio::spawn(async move {
-                    if let Ok(v) = inner_rx.await {
-                        let _ = tx.send(EventValue::Worker(v));
-                    }
-                });
+                "serviceworker" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::Worker>();
+                    self.serviceworker_waiters.lock().unwrap().push(inner_tx);
 
-                Ok(EventWaiter::new(rx, timeout_ms))
-            }
+                    tokio::spawn(async move {
+                        if let Ok(v) = inner_rx.await {
+                            let _ = tx.send(EventValue::Worker(v));
+                        }
+                    });
 
-            other => Err(crate::error::Error::InvalidArgument(format!(
-                "Unknown event name '{}'. Supported: page, close, console, request, response, \
+                    Ok(EventWaiter::new(rx, timeout_ms))
+                }
+
+                other => Err(crate::error::Error::InvalidArgument(format!(
+                    "Unknown event name '{}'. Supported: page, close, console, request, response, \
                  weberror, serviceworker",
-                other
-            ))),
-        }
+                    other
+                ))),
+            }
+        })
     }
 
     /// Intercepts WebSocket connections matching the given URL pattern for all pages in this context.
@@ -2132,7 +2719,8 @@ impl BrowserContext {
     ///
     /// # Arguments
     ///
-    /// * `url` — URL glob pattern (e.g. `"ws://**"` or `"wss://example.com/ws"`).
+    /// * `url` — URL to match: a glob (e.g. `"ws://**"` or
+    ///   `"wss://example.com/ws"`), a `Regex`, or any other [`UrlMatcher`].
     /// * `handler` — Async closure receiving a `WebSocketRoute`.
     ///
     /// # Errors
@@ -2140,38 +2728,47 @@ impl BrowserContext {
     /// Returns an error if the RPC call to enable interception fails.
     ///
     /// See: <https://playwright.dev/docs/api/class-browsercontext#browser-context-route-web-socket>
-    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = %url))]
-    pub async fn route_web_socket<F, Fut>(&self, url: &str, handler: F) -> Result<()>
+    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = tracing::field::Empty))]
+    #[track_caller]
+    pub fn route_web_socket<F, Fut>(
+        &self,
+        url: impl Into<UrlMatcher>,
+        handler: F,
+    ) -> impl Future<Output = Result<()>>
     where
         F: Fn(crate::protocol::WebSocketRoute) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(
-            move |route: crate::protocol::WebSocketRoute| -> WsRouteHandlerFuture {
-                Box::pin(handler(route))
-            },
-        );
-
-        self.ws_route_handlers
-            .lock()
-            .unwrap()
-            .push(ContextWsRouteHandlerEntry {
-                pattern: url.to_string(),
-                handler,
-            });
+        let url = url.into();
+        tracing::Span::current().record("url", tracing::field::display(&url));
+        CallSite::caller().run(async move {
+            let handler = Arc::new(
+                move |route: crate::protocol::WebSocketRoute| -> WsRouteHandlerFuture {
+                    Box::pin(handler(route))
+                },
+            );
+
+            self.ws_route_handlers
+                .lock()
+                .unwrap()
+                .push(ContextWsRouteHandlerEntry {
+                    pattern: url,
+                    handler,
+                });
 
-        self.enable_ws_interception().await
+            self.enable_ws_interception().await
+        })
     }
 
     /// Updates WebSocket interception patterns for this context.
     async fn enable_ws_interception(&self) -> Result<()> {
-        let patterns: Vec<serde_json::Value> = self
-            .ws_route_handlers
-            .lock()
-            .unwrap()
-            .iter()
-            .map(|entry| serde_json::json!({ "glob": entry.pattern }))
-            .collect();
+        let patterns = interception_patterns(
+            self.ws_route_handlers
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|entry| &entry.pattern),
+        );
 
         self.channel()
             .send_no_result(
@@ -2183,13 +2780,18 @@ impl BrowserContext {
 
     /// Updates network interception patterns for this context
     async fn enable_network_interception(&self) -> Result<()> {
-        let patterns: Vec<serde_json::Value> = self
-            .route_handlers
-            .lock()
-            .unwrap()
-            .iter()
-            .map(|entry| serde_json::json!({ "glob": entry.pattern }))
-            .collect();
+        let patterns = if self.request_blocker.get().is_some() {
+            // The blocking options need to see every request.
+            vec![serde_json::json!({ "glob": "**/*" })]
+        } else {
+            interception_patterns(
+                self.route_handlers
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .map(|entry| &entry.pattern),
+            )
+        };
 
         self.channel()
             .send_no_result(
@@ -2225,13 +2827,44 @@ impl BrowserContext {
             .collect()
     }
 
+    /// Installs the blocking options the context was created with.
+    pub(crate) async fn install_request_blocker(&self, blocker: RequestBlocker) -> Result<()> {
+        let _ = self.request_blocker.set(blocker);
+        self.enable_network_interception().await
+    }
+
+    /// Aborts `route` if the context's blocking options block its request.
+    /// Runs before any page or context route handler.
+    pub(crate) async fn block_route(&self, route: &Route) -> bool {
+        let Some(blocker) = self.request_blocker.get() else {
+            return false;
+        };
+        match blocker.block(route).await {
+            Ok(blocked) => blocked,
+            Err(e) => {
+                tracing::warn!("Failed to abort blocked request: {}", e);
+                true
+            }
+        }
+    }
+
     /// Handles a route event from the protocol
-    async fn on_route_event(route_handlers: Arc<Mutex<Vec<RouteHandlerEntry>>>, route: Route) {
-        let handlers = route_handlers.lock().unwrap().clone();
+    async fn on_route_event(&self, route: Route) {
+        if self.block_route(&route).await {
+            return;
+        }
+        let handlers = self.route_handlers.lock().unwrap().clone();
         let url = route.request().url().to_string();
 
+        let mut matched = false;
         for entry in handlers.iter().rev() {
-            if crate::protocol::route::matches_pattern(&entry.pattern, &url) {
+            if entry.pattern.matches(&url) && entry.state.claim() {
+                matched = true;
+                if entry.state.is_spent()
+                    && let Err(e) = self.remove_route_handler(entry.state.id).await
+                {
+                    tracing::warn!("Failed to unregister spent route handler: {}", e);
+                }
                 let handler = entry.handler.clone();
                 if let Err(e) = handler(route.clone()).await {
                     tracing::warn!("Context route handler error: {}", e);
@@ -2243,6 +2876,48 @@ impl BrowserContext {
                 break;
             }
         }
+        // Reported only because a predicate or URLPattern route widened the
+        // server's filter, a handler's `times` just ran out, or a page passed
+        // it on: let it through.
+        if !matched && let Err(e) = route.fallback(None).await {
+            tracing::warn!("Route fallback error: {}", e);
+        }
+    }
+
+    /// Runs the context's route handlers for a route no page handler
+    /// matched.
+    pub(crate) async fn handle_route(&self, route: Route) {
+        self.on_route_event(route).await
+    }
+
+    /// Runs the context's WebSocket route handlers for `route`, or connects
+    /// it to the server if none matches.
+    pub(crate) async fn handle_web_socket_route(&self, route: crate::protocol::WebSocketRoute) {
+        Self::on_web_socket_route_event(self.ws_route_handlers.clone(), route).await
+    }
+
+    async fn on_web_socket_route_event(
+        ws_route_handlers: Arc<Mutex<Vec<ContextWsRouteHandlerEntry>>>,
+        route: crate::protocol::WebSocketRoute,
+    ) {
+        let url = route.url().to_string();
+        let handlers = ws_route_handlers.lock().unwrap().clone();
+        let Some(entry) = handlers
+            .iter()
+            .rev()
+            .find(|entry| entry.pattern.matches(&url))
+        else {
+            if let Err(e) = route.connect_to_server().await {
+                tracing::warn!("WebSocket connectToServer error: {}", e);
+            }
+            return;
+        };
+        let handler = entry.handler.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handler(route).await {
+                tracing::error!("Error in context webSocketRoute handler: {}", e);
+            }
+        });
     }
 
     fn dispatch_request_event(&self, method: &str, params: Value) {
@@ -2605,6 +3280,19 @@ impl ChannelOwner for BrowserContext {
                     }
                 });
             }
+            "recorderEvent" => {
+                // Event format:
+                //   { "event": "actionAdded" | "actionUpdated" | "signalAdded",
+                //     "data": <ActionInContext>, "page": { "guid": "..." }, "code": "..." }
+                if let Some(events) = self.recorder_events.lock().unwrap().as_ref() {
+                    match crate::protocol::RecorderEvent::from_params(&params) {
+                        Some(event) => {
+                            let _ = events.send(event);
+                        }
+                        None => tracing::debug!("Ignoring unknown recorder event: {}", params),
+                    }
+                }
+            }
             "dialog" => {
                 // Dialog events come to BrowserContext.
                 // Dispatch to context-level handlers first, then forward to the Page.
@@ -2713,7 +3401,7 @@ impl ChannelOwner for BrowserContext {
                 {
                     let connection = self.connection();
                     let route_guid_owned = route_guid.to_string();
-                    let route_handlers = self.route_handlers.clone();
+                    let context = self.clone();
                     let request_context_guid = self.request_context_guid.clone();
 
                     tokio::spawn(async move {
@@ -2734,7 +3422,7 @@ impl ChannelOwner for BrowserContext {
                             route.set_api_request_context(api_ctx);
                         }
 
-                        BrowserContext::on_route_event(route_handlers, route).await;
+                        context.on_route_event(route).await;
                     });
                 }
             }
@@ -2933,23 +3621,7 @@ impl ChannelOwner for BrowserContext {
                             }
                         };
 
-                        let url = route.url().to_string();
-                        let handlers = ws_route_handlers.lock().unwrap().clone();
-                        for entry in handlers.iter().rev() {
-                            if crate::protocol::route::matches_pattern(&entry.pattern, &url) {
-                                let handler = entry.handler.clone();
-                                let route_clone = route.clone();
-                                tokio::spawn(async move {
-                                    if let Err(e) = handler(route_clone).await {
-                                        tracing::error!(
-                                            "Error in context webSocketRoute handler: {}",
-                                            e
-                                        );
-                                    }
-                                });
-                                break;
-                            }
-                        }
+                        BrowserContext::on_web_socket_route_event(ws_route_handlers, route).await;
                     });
                 }
             }
@@ -3543,6 +4215,26 @@ pub struct BrowserContextOptions {
     /// Options for recording video
     #[serde(skip_serializing_if = "Option::is_none")]
     pub record_video: Option<RecordVideo>,
+
+    /// Requests matching any of these are aborted before any route handler
+    /// sees them
+    #[serde(skip)]
+    pub blocked_urls: Option<Vec<UrlMatcher>>,
+
+    /// When set, requests to any other host are aborted. `*.example.com`
+    /// allows the subdomains of `example.com`; ports are not compared.
+    #[serde(skip)]
+    pub allowed_hosts: Option<Vec<String>>,
+
+    /// Requests of these resource types (`"image"`, `"font"`, `"media"`,
+    /// ...) are aborted
+    #[serde(skip)]
+    pub blocked_resource_types: Option<Vec<String>>,
+
+    /// The [`Route::abort`] error code blocked requests fail with
+    /// (default: `"blockedbyclient"`)
+    #[serde(skip)]
+    pub block_error_code: Option<String>,
 }
 
 impl BrowserContextOptions {
@@ -3595,6 +4287,10 @@ pub struct BrowserContextOptionsBuilder {
     service_workers: Option<String>,
     record_har: Option<RecordHar>,
     record_video: Option<RecordVideo>,
+    blocked_urls: Option<Vec<UrlMatcher>>,
+    allowed_hosts: Option<Vec<String>>,
+    blocked_resource_types: Option<Vec<String>>,
+    block_error_code: Option<String>,
 }
 
 impl BrowserContextOptionsBuilder {
@@ -3937,6 +4633,43 @@ impl BrowserContextOptionsBuilder {
         self
     }
 
+    /// Aborts requests matching any of `patterns`: globs, regexes or other
+    /// [`UrlMatcher`]s
+    pub fn blocked_urls<I>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<UrlMatcher>,
+    {
+        self.blocked_urls = Some(patterns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Aborts requests to any host not in `hosts`
+    pub fn allowed_hosts<I>(mut self, hosts: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.allowed_hosts = Some(hosts.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Aborts requests of the given resource types
+    pub fn blocked_resource_types<I>(mut self, resource_types: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.blocked_resource_types = Some(resource_types.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the error code blocked requests fail with
+    pub fn block_error_code(mut self, error_code: impl Into<String>) -> Self {
+        self.block_error_code = Some(error_code.into());
+        self
+    }
+
     /// Builds the BrowserContextOptions
     pub fn build(self) -> BrowserContextOptions {
         BrowserContextOptions {
@@ -3980,6 +4713,10 @@ impl BrowserContextOptionsBuilder {
             service_workers: self.service_workers,
             record_har: self.record_har,
             record_video: self.record_video,
+            blocked_urls: self.blocked_urls,
+            allowed_hosts: self.allowed_hosts,
+            blocked_resource_types: self.blocked_resource_types,
+            block_error_code: self.block_error_code,
         }
     }
 }
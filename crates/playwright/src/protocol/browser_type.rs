@@ -367,6 +367,8 @@ impl BrowserType {
             options.storage_state_path = None; // Clear path since we've converted to inline
         }
 
+        let blocker = crate::protocol::request_blocking::RequestBlocker::from_options(&options);
+
         // Convert options to JSON with userDataDir
         let mut params = serde_json::to_value(&options).map_err(|e| {
             crate::error::Error::ProtocolError(format!(
@@ -415,6 +417,10 @@ impl BrowserType {
             tracing::warn!("Failed to register BrowserContext with Selectors: {}", e);
         }
 
+        if let Some(blocker) = blocker {
+            context.install_request_blocker(blocker).await?;
+        }
+
         Ok(context)
     }
     /// Connects to an existing browser instance.
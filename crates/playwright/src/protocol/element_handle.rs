@@ -5,6 +5,7 @@
 
 use crate::error::Result;
 use crate::protocol::locator::BoundingBox;
+use crate::server::call_site::CallSite;
 use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
 use base64::Engine;
 use serde::Deserialize;
@@ -67,39 +68,43 @@ impl ElementHandle {
     ///
     /// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-screenshot>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid(), bytes_len = tracing::field::Empty))]
-    pub async fn screenshot(
+    #[track_caller]
+    pub fn screenshot(
         &self,
         options: impl Into<Option<crate::protocol::ScreenshotOptions>>,
-    ) -> Result<Vec<u8>> {
-        let options = options.into();
-        let params = if let Some(opts) = options {
-            opts.to_json()
-        } else {
-            // Default to PNG with required timeout
-            serde_json::json!({
-                "type": "png",
-                "timeout": crate::DEFAULT_TIMEOUT_MS
-            })
-        };
-
-        #[derive(Deserialize)]
-        struct ScreenshotResponse {
-            binary: String,
-        }
-
-        let response: ScreenshotResponse = self.base.channel().send("screenshot", params).await?;
-
-        // Decode base64 to bytes
-        let bytes = base64::prelude::BASE64_STANDARD
-            .decode(&response.binary)
-            .map_err(|e| {
-                crate::error::Error::ProtocolError(format!(
-                    "Failed to decode element screenshot: {}",
-                    e
-                ))
-            })?;
-
-        Ok(bytes)
+    ) -> impl Future<Output = Result<Vec<u8>>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let params = if let Some(opts) = options {
+                opts.to_json()
+            } else {
+                // Default to PNG with required timeout
+                serde_json::json!({
+                    "type": "png",
+                    "timeout": crate::DEFAULT_TIMEOUT_MS
+                })
+            };
+
+            #[derive(Deserialize)]
+            struct ScreenshotResponse {
+                binary: String,
+            }
+
+            let response: ScreenshotResponse =
+                self.base.channel().send("screenshot", params).await?;
+
+            // Decode base64 to bytes
+            let bytes = base64::prelude::BASE64_STANDARD
+                .decode(&response.binary)
+                .map_err(|e| {
+                    crate::error::Error::ProtocolError(format!(
+                        "Failed to decode element screenshot: {}",
+                        e
+                    ))
+                })?;
+
+            Ok(bytes)
+        })
     }
 
     /// Returns the bounding box of this element, or None if it is not visible.
@@ -108,24 +113,27 @@ impl ElementHandle {
     ///
     /// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-bounding-box>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn bounding_box(&self) -> Result<Option<BoundingBox>> {
-        #[derive(Deserialize)]
-        struct BoundingBoxResponse {
-            value: Option<BoundingBox>,
-        }
-
-        let response: BoundingBoxResponse = self
-            .base
-            .channel()
-            .send(
-                "boundingBox",
-                serde_json::json!({
-                    "timeout": crate::DEFAULT_TIMEOUT_MS
-                }),
-            )
-            .await?;
+    #[track_caller]
+    pub fn bounding_box(&self) -> impl Future<Output = Result<Option<BoundingBox>>> {
+        CallSite::caller().run(async move {
+            #[derive(Deserialize)]
+            struct BoundingBoxResponse {
+                value: Option<BoundingBox>,
+            }
 
-        Ok(response.value)
+            let response: BoundingBoxResponse = self
+                .base
+                .channel()
+                .send(
+                    "boundingBox",
+                    serde_json::json!({
+                        "timeout": crate::DEFAULT_TIMEOUT_MS
+                    }),
+                )
+                .await?;
+
+            Ok(response.value)
+        })
     }
 
     /// Sets files on this element (which must be an `<input type="file">`).
@@ -178,16 +186,19 @@ impl ElementHandle {
     ///
     /// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-scroll-into-view-if-needed>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn scroll_into_view_if_needed(&self) -> Result<()> {
-        self.base
-            .channel()
-            .send_no_result(
-                "scrollIntoViewIfNeeded",
-                serde_json::json!({
-                    "timeout": crate::DEFAULT_TIMEOUT_MS
-                }),
-            )
-            .await
+    #[track_caller]
+    pub fn scroll_into_view_if_needed(&self) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.base
+                .channel()
+                .send_no_result(
+                    "scrollIntoViewIfNeeded",
+                    serde_json::json!({
+                        "timeout": crate::DEFAULT_TIMEOUT_MS
+                    }),
+                )
+                .await
+        })
     }
 
     /// Returns the `Frame` associated with this `<iframe>` element, or `None` if
@@ -195,34 +206,37 @@ impl ElementHandle {
     ///
     /// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-content-frame>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn content_frame(&self) -> Result<Option<crate::protocol::Frame>> {
-        use crate::server::connection::ConnectionExt;
-
-        #[derive(Deserialize)]
-        struct FrameRef {
-            guid: String,
-        }
-        #[derive(Deserialize)]
-        struct ContentFrameResponse {
-            frame: Option<FrameRef>,
-        }
-
-        let response: ContentFrameResponse = self
-            .base
-            .channel()
-            .send("contentFrame", serde_json::json!({}))
-            .await?;
-
-        match response.frame {
-            None => Ok(None),
-            Some(frame_ref) => {
-                let connection = self.base.connection();
-                let frame = connection
-                    .get_typed::<crate::protocol::Frame>(&frame_ref.guid)
-                    .await?;
-                Ok(Some(frame))
+    #[track_caller]
+    pub fn content_frame(&self) -> impl Future<Output = Result<Option<crate::protocol::Frame>>> {
+        CallSite::caller().run(async move {
+            use crate::server::connection::ConnectionExt;
+
+            #[derive(Deserialize)]
+            struct FrameRef {
+                guid: String,
             }
-        }
+            #[derive(Deserialize)]
+            struct ContentFrameResponse {
+                frame: Option<FrameRef>,
+            }
+
+            let response: ContentFrameResponse = self
+                .base
+                .channel()
+                .send("contentFrame", serde_json::json!({}))
+                .await?;
+
+            match response.frame {
+                None => Ok(None),
+                Some(frame_ref) => {
+                    let connection = self.base.connection();
+                    let frame = connection
+                        .get_typed::<crate::protocol::Frame>(&frame_ref.guid)
+                        .await?;
+                    Ok(Some(frame))
+                }
+            }
+        })
     }
 
     /// Returns the `Frame` that owns this element.
@@ -231,34 +245,37 @@ impl ElementHandle {
     ///
     /// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-owner-frame>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn owner_frame(&self) -> Result<Option<crate::protocol::Frame>> {
-        use crate::server::connection::ConnectionExt;
-
-        #[derive(Deserialize)]
-        struct FrameRef {
-            guid: String,
-        }
-        #[derive(Deserialize)]
-        struct OwnerFrameResponse {
-            frame: Option<FrameRef>,
-        }
-
-        let response: OwnerFrameResponse = self
-            .base
-            .channel()
-            .send("ownerFrame", serde_json::json!({}))
-            .await?;
-
-        match response.frame {
-            None => Ok(None),
-            Some(frame_ref) => {
-                let connection = self.base.connection();
-                let frame = connection
-                    .get_typed::<crate::protocol::Frame>(&frame_ref.guid)
-                    .await?;
-                Ok(Some(frame))
+    #[track_caller]
+    pub fn owner_frame(&self) -> impl Future<Output = Result<Option<crate::protocol::Frame>>> {
+        CallSite::caller().run(async move {
+            use crate::server::connection::ConnectionExt;
+
+            #[derive(Deserialize)]
+            struct FrameRef {
+                guid: String,
+            }
+            #[derive(Deserialize)]
+            struct OwnerFrameResponse {
+                frame: Option<FrameRef>,
+            }
+
+            let response: OwnerFrameResponse = self
+                .base
+                .channel()
+                .send("ownerFrame", serde_json::json!({}))
+                .await?;
+
+            match response.frame {
+                None => Ok(None),
+                Some(frame_ref) => {
+                    let connection = self.base.connection();
+                    let frame = connection
+                        .get_typed::<crate::protocol::Frame>(&frame_ref.guid)
+                        .await?;
+                    Ok(Some(frame))
+                }
             }
-        }
+        })
     }
 
     /// Waits until the element reaches the specified state.
@@ -272,18 +289,25 @@ impl ElementHandle {
     ///
     /// See: <https://playwright.dev/docs/api/class-elementhandle#element-handle-wait-for-element-state>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn wait_for_element_state(&self, state: &str, timeout: Option<f64>) -> Result<()> {
-        let timeout_ms = timeout.unwrap_or(crate::DEFAULT_TIMEOUT_MS);
-        self.base
-            .channel()
-            .send_no_result(
-                "waitForElementState",
-                serde_json::json!({
-                    "state": state,
-                    "timeout": timeout_ms
-                }),
-            )
-            .await
+    #[track_caller]
+    pub fn wait_for_element_state(
+        &self,
+        state: &str,
+        timeout: Option<f64>,
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let timeout_ms = timeout.unwrap_or(crate::DEFAULT_TIMEOUT_MS);
+            self.base
+                .channel()
+                .send_no_result(
+                    "waitForElementState",
+                    serde_json::json!({
+                        "state": state,
+                        "timeout": timeout_ms
+                    }),
+                )
+                .await
+        })
     }
 }
 
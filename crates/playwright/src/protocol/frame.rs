@@ -6,6 +6,7 @@
 use crate::error::{Error, Result};
 use crate::protocol::page::{GotoOptions, Response, WaitUntil};
 use crate::protocol::{parse_result, serialize_argument, serialize_null};
+use crate::server::call_site::CallSite;
 use crate::server::channel::Channel;
 use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
 use crate::server::connection::ConnectionExt;
@@ -236,43 +237,46 @@ impl Frame {
     ///
     /// See: <https://playwright.dev/docs/api/class-frame#frame-evaluate-handle>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn evaluate_handle(
+    #[track_caller]
+    pub fn evaluate_handle(
         &self,
         expression: &str,
-    ) -> Result<Arc<crate::protocol::ElementHandle>> {
-        // No isFunction: the driver auto-detects when the flag is absent, so
-        // `evaluate_handle("() => document.body")` resolves to the body
-        // element rather than to a handle on the un-invoked closure.
-        let params = serde_json::json!({
-            "expression": expression,
-            "arg": {"value": {"v": "undefined"}, "handles": []}
-        });
-
-        // The server returns {"handle": {"guid": "JSHandle@..."}}
-        #[derive(Deserialize)]
-        struct HandleRef {
-            guid: String,
-        }
-        #[derive(Deserialize)]
-        struct EvaluateHandleResponse {
-            handle: HandleRef,
-        }
+    ) -> impl Future<Output = Result<Arc<crate::protocol::ElementHandle>>> {
+        CallSite::caller().run(async move {
+            // No isFunction: the driver auto-detects when the flag is absent, so
+            // `evaluate_handle("() => document.body")` resolves to the body
+            // element rather than to a handle on the un-invoked closure.
+            let params = serde_json::json!({
+                "expression": expression,
+                "arg": {"value": {"v": "undefined"}, "handles": []}
+            });
+
+            // The server returns {"handle": {"guid": "JSHandle@..."}}
+            #[derive(Deserialize)]
+            struct HandleRef {
+                guid: String,
+            }
+            #[derive(Deserialize)]
+            struct EvaluateHandleResponse {
+                handle: HandleRef,
+            }
 
-        let response: EvaluateHandleResponse = self
-            .channel()
-            .send("evaluateExpressionHandle", params)
-            .await?;
+            let response: EvaluateHandleResponse = self
+                .channel()
+                .send("evaluateExpressionHandle", params)
+                .await?;
 
-        let guid = &response.handle.guid;
+            let guid = &response.handle.guid;
 
-        // The handle's __create__ may arrive just after the response.
-        let handle = self
-            .base
-            .connection()
-            .wait_for_typed::<crate::protocol::ElementHandle>(guid)
-            .await?;
+            // The handle's __create__ may arrive just after the response.
+            let handle = self
+                .base
+                .connection()
+                .wait_for_typed::<crate::protocol::ElementHandle>(guid)
+                .await?;
 
-        Ok(Arc::new(handle))
+            Ok(Arc::new(handle))
+        })
     }
 
     /// Evaluates a JavaScript expression and returns a [`JSHandle`](crate::protocol::JSHandle) to the result.
@@ -298,39 +302,42 @@ impl Frame {
     ///
     /// See: <https://playwright.dev/docs/api/class-frame#frame-evaluate-handle>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn evaluate_handle_js(
+    #[track_caller]
+    pub fn evaluate_handle_js(
         &self,
         expression: &str,
-    ) -> Result<std::sync::Arc<crate::protocol::JSHandle>> {
-        // No isFunction: absent, the driver's utility script auto-detects a
-        // function expression and invokes it. Any client-side guess misreads
-        // bare arrows like `x => ...`, which then come back as a handle to
-        // the un-invoked function instead of its result.
-        let params = serde_json::json!({
-            "expression": expression,
-            "arg": {"value": {"v": "undefined"}, "handles": []}
-        });
-
-        // The server returns {"handle": {"guid": "JSHandle@..."}}
-        #[derive(Deserialize)]
-        struct HandleRef {
-            guid: String,
-        }
-        #[derive(Deserialize)]
-        struct EvaluateHandleResponse {
-            handle: HandleRef,
-        }
+    ) -> impl Future<Output = Result<std::sync::Arc<crate::protocol::JSHandle>>> {
+        CallSite::caller().run(async move {
+            // No isFunction: absent, the driver's utility script auto-detects a
+            // function expression and invokes it. Any client-side guess misreads
+            // bare arrows like `x => ...`, which then come back as a handle to
+            // the un-invoked function instead of its result.
+            let params = serde_json::json!({
+                "expression": expression,
+                "arg": {"value": {"v": "undefined"}, "handles": []}
+            });
+
+            // The server returns {"handle": {"guid": "JSHandle@..."}}
+            #[derive(Deserialize)]
+            struct HandleRef {
+                guid: String,
+            }
+            #[derive(Deserialize)]
+            struct EvaluateHandleResponse {
+                handle: HandleRef,
+            }
 
-        let response: EvaluateHandleResponse = self
-            .channel()
-            .send("evaluateExpressionHandle", params)
-            .await?;
+            let response: EvaluateHandleResponse = self
+                .channel()
+                .send("evaluateExpressionHandle", params)
+                .await?;
 
-        let guid = &response.handle.guid;
+            let guid = &response.handle.guid;
 
-        let handle = crate::protocol::JSHandle::wait_for(&self.base.connection(), guid).await?;
+            let handle = crate::protocol::JSHandle::wait_for(&self.base.connection(), guid).await?;
 
-        Ok(std::sync::Arc::new(handle))
+            Ok(std::sync::Arc::new(handle))
+        })
     }
 
     /// Shared engine for the `wait_for_function` family.
@@ -406,18 +413,21 @@ impl Frame {
     /// "Timeout ...ms exceeded" message.
     ///
     /// See: <https://playwright.dev/docs/api/class-frame#frame-wait-for-function>
-    pub async fn wait_for_function(
+    #[track_caller]
+    pub fn wait_for_function(
         &self,
         expression: &str,
         options: impl Into<Option<crate::protocol::WaitForFunctionOptions>>,
-    ) -> Result<std::sync::Arc<crate::protocol::JSHandle>> {
-        self.wait_for_function_internal(expression, None, options)
-            .await?
-            .ok_or_else(|| {
-                crate::error::Error::ProtocolError(
-                    "waitForFunction returned no handle for a selector-less wait".to_string(),
-                )
-            })
+    ) -> impl Future<Output = Result<std::sync::Arc<crate::protocol::JSHandle>>> {
+        CallSite::caller().run(async move {
+            self.wait_for_function_internal(expression, None, options)
+                .await?
+                .ok_or_else(|| {
+                    crate::error::Error::ProtocolError(
+                        "waitForFunction returned no handle for a selector-less wait".to_string(),
+                    )
+                })
+        })
     }
 
     /// Evaluates `expression` with a registered binding bound as its
@@ -561,158 +571,171 @@ impl Frame {
     ///
     /// See: <https://playwright.dev/docs/api/class-frame#frame-goto>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid(), url = %url, status = tracing::field::Empty))]
-    pub async fn goto(
+    #[track_caller]
+    pub fn goto(
         &self,
         url: &str,
         options: impl Into<Option<GotoOptions>>,
-    ) -> Result<Option<Response>> {
-        let options = options.into();
-        // Build params manually using json! macro
-        let mut params = serde_json::json!({
-            "url": url,
-        });
-
-        // Add optional parameters
-        if let Some(opts) = options {
-            if let Some(timeout) = opts.timeout {
-                params["timeout"] = serde_json::json!(timeout.as_millis() as u64);
+    ) -> impl Future<Output = Result<Option<Response>>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            // Build params manually using json! macro
+            let mut params = serde_json::json!({
+                "url": url,
+            });
+
+            // Add optional parameters
+            if let Some(opts) = options {
+                if let Some(timeout) = opts.timeout {
+                    params["timeout"] = serde_json::json!(timeout.as_millis() as u64);
+                } else {
+                    // Default timeout required in Playwright 1.56.1+
+                    params["timeout"] = serde_json::json!(crate::DEFAULT_TIMEOUT_MS);
+                }
+                if let Some(wait_until) = opts.wait_until {
+                    params["waitUntil"] = serde_json::json!(wait_until.as_str());
+                }
             } else {
-                // Default timeout required in Playwright 1.56.1+
+                // No options provided, set default timeout (required in Playwright 1.56.1+)
                 params["timeout"] = serde_json::json!(crate::DEFAULT_TIMEOUT_MS);
             }
-            if let Some(wait_until) = opts.wait_until {
-                params["waitUntil"] = serde_json::json!(wait_until.as_str());
+
+            // Send goto RPC to Frame
+            // The server returns { "response": { "guid": "..." } } or null
+            #[derive(Deserialize)]
+            struct GotoResponse {
+                response: Option<ResponseReference>,
             }
-        } else {
-            // No options provided, set default timeout (required in Playwright 1.56.1+)
-            params["timeout"] = serde_json::json!(crate::DEFAULT_TIMEOUT_MS);
-        }
 
-        // Send goto RPC to Frame
-        // The server returns { "response": { "guid": "..." } } or null
-        #[derive(Deserialize)]
-        struct GotoResponse {
-            response: Option<ResponseReference>,
-        }
+            #[derive(Deserialize)]
+            struct ResponseReference {
+                #[serde(deserialize_with = "crate::server::connection::deserialize_arc_str")]
+                guid: Arc<str>,
+            }
 
-        #[derive(Deserialize)]
-        struct ResponseReference {
-            #[serde(deserialize_with = "crate::server::connection::deserialize_arc_str")]
-            guid: Arc<str>,
-        }
+            let goto_result: GotoResponse = self.channel().send("goto", params).await?;
 
-        let goto_result: GotoResponse = self.channel().send("goto", params).await?;
+            // If navigation returned a response, get the Response object from the connection
+            if let Some(response_ref) = goto_result.response {
+                // The Response's __create__ may arrive just after the response.
+                let response_arc = self
+                    .connection()
+                    .wait_for_object(&response_ref.guid)
+                    .await?;
 
-        // If navigation returned a response, get the Response object from the connection
-        if let Some(response_ref) = goto_result.response {
-            // The Response's __create__ may arrive just after the response.
-            let response_arc = self
-                .connection()
-                .wait_for_object(&response_ref.guid)
-                .await?;
+                // Extract Response data from the initializer, and store the Arc for RPC calls
+                // (body(), rawHeaders(), headerValue()) that need to contact the server.
+                let initializer = response_arc.initializer();
 
-            // Extract Response data from the initializer, and store the Arc for RPC calls
-            // (body(), rawHeaders(), headerValue()) that need to contact the server.
-            let initializer = response_arc.initializer();
+                // Extract response data from initializer
+                let status = initializer["status"].as_u64().ok_or_else(|| {
+                    crate::error::Error::ProtocolError("Response missing status".to_string())
+                })? as u16;
 
-            // Extract response data from initializer
-            let status = initializer["status"].as_u64().ok_or_else(|| {
-                crate::error::Error::ProtocolError("Response missing status".to_string())
-            })? as u16;
-
-            // Convert headers from array format to HashMap
-            let headers = initializer["headers"]
-                .as_array()
-                .ok_or_else(|| {
-                    crate::error::Error::ProtocolError("Response missing headers".to_string())
-                })?
-                .iter()
-                .filter_map(|h| {
-                    let name = h["name"].as_str()?;
-                    let value = h["value"].as_str()?;
-                    Some((name.to_string(), value.to_string()))
-                })
-                .collect();
-
-            tracing::Span::current().record("status", status);
-            Ok(Some(Response::new(
-                initializer["url"]
-                    .as_str()
+                // Convert headers from array format to HashMap
+                let headers = initializer["headers"]
+                    .as_array()
                     .ok_or_else(|| {
-                        crate::error::Error::ProtocolError("Response missing url".to_string())
+                        crate::error::Error::ProtocolError("Response missing headers".to_string())
                     })?
-                    .to_string(),
-                status,
-                initializer["statusText"].as_str().unwrap_or("").to_string(),
-                headers,
-                Some(response_arc),
-            )))
-        } else {
-            // Navigation returned null (e.g., data URLs, about:blank)
-            // This is a valid result, not an error
-            Ok(None)
-        }
+                    .iter()
+                    .filter_map(|h| {
+                        let name = h["name"].as_str()?;
+                        let value = h["value"].as_str()?;
+                        Some((name.to_string(), value.to_string()))
+                    })
+                    .collect();
+
+                tracing::Span::current().record("status", status);
+                Ok(Some(Response::new(
+                    initializer["url"]
+                        .as_str()
+                        .ok_or_else(|| {
+                            crate::error::Error::ProtocolError("Response missing url".to_string())
+                        })?
+                        .to_string(),
+                    status,
+                    initializer["statusText"].as_str().unwrap_or("").to_string(),
+                    headers,
+                    Some(response_arc),
+                )))
+            } else {
+                // Navigation returned null (e.g., data URLs, about:blank)
+                // This is a valid result, not an error
+                Ok(None)
+            }
+        })
     }
 
     /// Returns the frame's title.
     ///
     /// See: <https://playwright.dev/docs/api/class-frame#frame-title>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn title(&self) -> Result<String> {
-        #[derive(Deserialize)]
-        struct TitleResponse {
-            value: String,
-        }
+    #[track_caller]
+    pub fn title(&self) -> impl Future<Output = Result<String>> {
+        CallSite::caller().run(async move {
+            #[derive(Deserialize)]
+            struct TitleResponse {
+                value: String,
+            }
 
-        let response: TitleResponse = self.channel().send("title", serde_json::json!({})).await?;
-        Ok(response.value)
+            let response: TitleResponse =
+                self.channel().send("title", serde_json::json!({})).await?;
+            Ok(response.value)
+        })
     }
 
     /// Returns the full HTML content of the frame, including the DOCTYPE.
     ///
     /// See: <https://playwright.dev/docs/api/class-frame#frame-content>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn content(&self) -> Result<String> {
-        #[derive(Deserialize)]
-        struct ContentResponse {
-            value: String,
-        }
+    #[track_caller]
+    pub fn content(&self) -> impl Future<Output = Result<String>> {
+        CallSite::caller().run(async move {
+            #[derive(Deserialize)]
+            struct ContentResponse {
+                value: String,
+            }
 
-        let response: ContentResponse = self
-            .channel()
-            .send("content", serde_json::json!({}))
-            .await?;
-        Ok(response.value)
+            let response: ContentResponse = self
+                .channel()
+                .send("content", serde_json::json!({}))
+                .await?;
+            Ok(response.value)
+        })
     }
 
     /// Sets the content of the frame.
     ///
     /// See: <https://playwright.dev/docs/api/class-frame#frame-set-content>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn set_content(
+    #[track_caller]
+    pub fn set_content(
         &self,
         html: &str,
         options: impl Into<Option<GotoOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        let mut params = serde_json::json!({
-            "html": html,
-        });
-
-        if let Some(opts) = options {
-            if let Some(timeout) = opts.timeout {
-                params["timeout"] = serde_json::json!(timeout.as_millis() as u64);
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let mut params = serde_json::json!({
+                "html": html,
+            });
+
+            if let Some(opts) = options {
+                if let Some(timeout) = opts.timeout {
+                    params["timeout"] = serde_json::json!(timeout.as_millis() as u64);
+                } else {
+                    params["timeout"] = serde_json::json!(crate::DEFAULT_TIMEOUT_MS);
+                }
+                if let Some(wait_until) = opts.wait_until {
+                    params["waitUntil"] = serde_json::json!(wait_until.as_str());
+                }
             } else {
                 params["timeout"] = serde_json::json!(crate::DEFAULT_TIMEOUT_MS);
             }
-            if let Some(wait_until) = opts.wait_until {
-                params["waitUntil"] = serde_json::json!(wait_until.as_str());
-            }
-        } else {
-            params["timeout"] = serde_json::json!(crate::DEFAULT_TIMEOUT_MS);
-        }
 
-        self.channel().send_no_result("setContent", params).await
+            self.channel().send_no_result("setContent", params).await
+        })
     }
 
     /// Waits for the required load state to be reached.
@@ -723,219 +746,224 @@ impl Frame {
     ///
     /// See: <https://playwright.dev/docs/api/class-frame#frame-wait-for-load-state>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn wait_for_load_state(&self, state: Option<WaitUntil>) -> Result<()> {
-        let target_state = state.unwrap_or(WaitUntil::Load);
-
-        let js_check = match target_state {
-            // "load" means the full page has loaded (readyState === "complete")
-            WaitUntil::Load => "document.readyState === 'complete'",
-            // "domcontentloaded" means DOM is ready (readyState !== "loading")
-            WaitUntil::DomContentLoaded => "document.readyState !== 'loading'",
-            // "networkidle" has no direct readyState equivalent; we approximate
-            // by checking "complete" (same as Load)
-            WaitUntil::NetworkIdle => "document.readyState === 'complete'",
-            // "commit" means any response has been received (readyState !== "loading" at minimum)
-            WaitUntil::Commit => "document.readyState !== 'loading'",
-        };
-
-        let timeout_ms = crate::DEFAULT_TIMEOUT_MS as u64;
-        let poll_interval = std::time::Duration::from_millis(50);
-        let start = std::time::Instant::now();
+    #[track_caller]
+    pub fn wait_for_load_state(
+        &self,
+        state: Option<WaitUntil>,
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let target_state = state.unwrap_or(WaitUntil::Load);
+
+            let js_check = match target_state {
+                // "load" means the full page has loaded (readyState === "complete")
+                WaitUntil::Load => "document.readyState === 'complete'",
+                // "domcontentloaded" means DOM is ready (readyState !== "loading")
+                WaitUntil::DomContentLoaded => "document.readyState !== 'loading'",
+                // "networkidle" has no direct readyState equivalent; we approximate
+                // by checking "complete" (same as Load)
+                WaitUntil::NetworkIdle => "document.readyState === 'complete'",
+                // "commit" means any response has been received (readyState !== "loading" at minimum)
+                WaitUntil::Commit => "document.readyState !== 'loading'",
+            };
 
-        loop {
-            #[derive(Deserialize)]
-            struct EvalResponse {
-                value: serde_json::Value,
-            }
+            let timeout_ms = crate::DEFAULT_TIMEOUT_MS as u64;
+            let poll_interval = std::time::Duration::from_millis(50);
+            let start = std::time::Instant::now();
 
-            let result: EvalResponse = self
-                .channel()
-                .send(
-                    "evaluateExpression",
-                    serde_json::json!({
-                        "expression": js_check,
-                        "isFunction": false,
-                        "arg": crate::protocol::serialize_null(),
-                    }),
-                )
-                .await?;
+            loop {
+                #[derive(Deserialize)]
+                struct EvalResponse {
+                    value: serde_json::Value,
+                }
 
-            // Playwright protocol returns booleans as {"b": true/false}
-            let is_ready = result
-                .value
-                .as_object()
-                .and_then(|m| m.get("b"))
-                .and_then(|v| v.as_bool())
-                .unwrap_or(false);
+                let result: EvalResponse = self
+                    .channel()
+                    .send(
+                        "evaluateExpression",
+                        serde_json::json!({
+                            "expression": js_check,
+                            "isFunction": false,
+                            "arg": crate::protocol::serialize_null(),
+                        }),
+                    )
+                    .await?;
+
+                // Playwright protocol returns booleans as {"b": true/false}
+                let is_ready = result
+                    .value
+                    .as_object()
+                    .and_then(|m| m.get("b"))
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+
+                if is_ready {
+                    return Ok(());
+                }
 
-            if is_ready {
-                return Ok(());
-            }
+                if start.elapsed().as_millis() as u64 >= timeout_ms {
+                    return Err(crate::error::Error::Timeout(format!(
+                        "wait_for_load_state({}) timed out after {}ms",
+                        target_state.as_str(),
+                        timeout_ms
+                    )));
+                }
 
-            if start.elapsed().as_millis() as u64 >= timeout_ms {
-                return Err(crate::error::Error::Timeout(format!(
-                    "wait_for_load_state({}) timed out after {}ms",
-                    target_state.as_str(),
-                    timeout_ms
-                )));
+                tokio::time::sleep(poll_interval).await;
             }
-
-            tokio::time::sleep(poll_interval).await;
-        }
+        })
     }
 
-    /// Waits for the frame to navigate to a URL matching the given string or glob pattern.
+    /// Waits for the frame to navigate to a URL matching `url`: a glob, an
+    /// exact URL, a `Regex`, or any other [`UrlMatcher`](crate::protocol::UrlMatcher).
     ///
     /// Playwright's protocol doesn't expose `waitForURL` as a server-side command —
     /// it's implemented client-side. We implement it by polling `window.location.href`.
     ///
     /// See: <https://playwright.dev/docs/api/class-frame#frame-wait-for-url>
-    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = %url))]
-    pub async fn wait_for_url(
+    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = tracing::field::Empty))]
+    #[track_caller]
+    pub fn wait_for_url(
         &self,
-        url: &str,
+        url: impl Into<crate::protocol::UrlMatcher>,
         options: impl Into<Option<GotoOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        let timeout_ms = options
-            .as_ref()
-            .and_then(|o| o.timeout)
-            .map(|d| d.as_millis() as u64)
-            .unwrap_or(crate::DEFAULT_TIMEOUT_MS as u64);
-
-        // Playwright supports string (exact), glob, and regex patterns; we
-        // support the first two. Compile the glob once rather than on every
-        // poll: a 30s wait polls ~600 times.
-        let matcher = if url.contains('*') {
-            Some(crate::protocol::glob::GlobMatcher::new(url))
-        } else {
-            None
-        };
-
-        let poll_interval = std::time::Duration::from_millis(50);
-        let start = std::time::Instant::now();
-
-        loop {
-            let current_url = self.url();
-
-            let matches = match &matcher {
-                // A malformed glob matches nothing, as it does in the driver.
-                Some(matcher) => matcher.as_ref().is_some_and(|m| m.matches(&current_url)),
-                None => current_url == url,
-            };
+    ) -> impl Future<Output = Result<()>> {
+        // Converting compiles a glob once rather than on every poll: a 30s
+        // wait polls ~600 times.
+        let url = url.into();
+        tracing::Span::current().record("url", tracing::field::display(&url));
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let timeout_ms = options
+                .as_ref()
+                .and_then(|o| o.timeout)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(crate::DEFAULT_TIMEOUT_MS as u64);
+
+            let poll_interval = std::time::Duration::from_millis(50);
+            let start = std::time::Instant::now();
+
+            loop {
+                let current_url = self.url();
+
+                if url.matches(&current_url) {
+                    // URL matches — optionally wait for load state
+                    if let Some(ref opts) = options
+                        && let Some(wait_until) = opts.wait_until
+                    {
+                        self.wait_for_load_state(Some(wait_until)).await?;
+                    }
+                    return Ok(());
+                }
 
-            if matches {
-                // URL matches — optionally wait for load state
-                if let Some(ref opts) = options
-                    && let Some(wait_until) = opts.wait_until
-                {
-                    self.wait_for_load_state(Some(wait_until)).await?;
+                if start.elapsed().as_millis() as u64 >= timeout_ms {
+                    return Err(crate::error::Error::Timeout(format!(
+                        "wait_for_url({}) timed out after {}ms, current URL: {}",
+                        url, timeout_ms, current_url
+                    )));
                 }
-                return Ok(());
-            }
 
-            if start.elapsed().as_millis() as u64 >= timeout_ms {
-                return Err(crate::error::Error::Timeout(format!(
-                    "wait_for_url({}) timed out after {}ms, current URL: {}",
-                    url, timeout_ms, current_url
-                )));
+                tokio::time::sleep(poll_interval).await;
             }
-
-            tokio::time::sleep(poll_interval).await;
-        }
+        })
     }
 
     /// Returns the first element matching the selector, or None if not found.
     ///
     /// See: <https://playwright.dev/docs/api/class-frame#frame-query-selector>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn query_selector(
+    #[track_caller]
+    pub fn query_selector(
         &self,
         selector: &str,
-    ) -> Result<Option<Arc<crate::protocol::ElementHandle>>> {
-        let response: serde_json::Value = self
-            .channel()
-            .send(
-                "querySelector",
-                serde_json::json!({
-                    "selector": selector
-                }),
-            )
-            .await?;
+    ) -> impl Future<Output = Result<Option<Arc<crate::protocol::ElementHandle>>>> {
+        CallSite::caller().run(async move {
+            let response: serde_json::Value = self
+                .channel()
+                .send(
+                    "querySelector",
+                    serde_json::json!({
+                        "selector": selector
+                    }),
+                )
+                .await?;
 
-        // Check if response is empty (no element found)
-        if response.as_object().map(|o| o.is_empty()).unwrap_or(true) {
-            return Ok(None);
-        }
+            // Check if response is empty (no element found)
+            if response.as_object().map(|o| o.is_empty()).unwrap_or(true) {
+                return Ok(None);
+            }
 
-        // Try different possible field names
-        let element_value = if let Some(elem) = response.get("element") {
-            elem
-        } else if let Some(elem) = response.get("handle") {
-            elem
-        } else {
-            // Maybe the response IS the guid object itself
-            &response
-        };
+            // Try different possible field names
+            let element_value = if let Some(elem) = response.get("element") {
+                elem
+            } else if let Some(elem) = response.get("handle") {
+                elem
+            } else {
+                // Maybe the response IS the guid object itself
+                &response
+            };
 
-        if element_value.is_null() {
-            return Ok(None);
-        }
+            if element_value.is_null() {
+                return Ok(None);
+            }
 
-        // Element response contains { guid: "elementHandle@123" }
-        let guid = element_value["guid"].as_str().ok_or_else(|| {
-            crate::error::Error::ProtocolError("Element GUID missing".to_string())
-        })?;
+            // Element response contains { guid: "elementHandle@123" }
+            let guid = element_value["guid"].as_str().ok_or_else(|| {
+                crate::error::Error::ProtocolError("Element GUID missing".to_string())
+            })?;
 
-        // Look up the ElementHandle object in the connection's object registry and downcast
-        let connection = self.base.connection();
-        let handle: crate::protocol::ElementHandle = connection
-            .get_typed::<crate::protocol::ElementHandle>(guid)
-            .await?;
+            // Look up the ElementHandle object in the connection's object registry and downcast
+            let connection = self.base.connection();
+            let handle: crate::protocol::ElementHandle = connection
+                .get_typed::<crate::protocol::ElementHandle>(guid)
+                .await?;
 
-        Ok(Some(Arc::new(handle)))
+            Ok(Some(Arc::new(handle)))
+        })
     }
 
     /// Returns all elements matching the selector.
     ///
     /// See: <https://playwright.dev/docs/api/class-frame#frame-query-selector-all>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn query_selector_all(
+    #[track_caller]
+    pub fn query_selector_all(
         &self,
         selector: &str,
-    ) -> Result<Vec<Arc<crate::protocol::ElementHandle>>> {
-        #[derive(Deserialize)]
-        struct QueryAllResponse {
-            elements: Vec<serde_json::Value>,
-        }
+    ) -> impl Future<Output = Result<Vec<Arc<crate::protocol::ElementHandle>>>> {
+        CallSite::caller().run(async move {
+            #[derive(Deserialize)]
+            struct QueryAllResponse {
+                elements: Vec<serde_json::Value>,
+            }
 
-        let response: QueryAllResponse = self
-            .channel()
-            .send(
-                "querySelectorAll",
-                serde_json::json!({
-                    "selector": selector
-                }),
-            )
-            .await?;
+            let response: QueryAllResponse = self
+                .channel()
+                .send(
+                    "querySelectorAll",
+                    serde_json::json!({
+                        "selector": selector
+                    }),
+                )
+                .await?;
 
-        // Convert GUID responses to ElementHandle objects
-        let connection = self.base.connection();
-        let mut handles = Vec::new();
+            // Convert GUID responses to ElementHandle objects
+            let connection = self.base.connection();
+            let mut handles = Vec::new();
 
-        for element_value in response.elements {
-            let guid = element_value["guid"].as_str().ok_or_else(|| {
-                crate::error::Error::ProtocolError("Element GUID missing".to_string())
-            })?;
+            for element_value in response.elements {
+                let guid = element_value["guid"].as_str().ok_or_else(|| {
+                    crate::error::Error::ProtocolError("Element GUID missing".to_string())
+                })?;
 
-            let handle: crate::protocol::ElementHandle = connection
-                .get_typed::<crate::protocol::ElementHandle>(guid)
-                .await?;
+                let handle: crate::protocol::ElementHandle = connection
+                    .get_typed::<crate::protocol::ElementHandle>(guid)
+                    .await?;
 
-            handles.push(Arc::new(handle));
-        }
+                handles.push(Arc::new(handle));
+            }
 
-        Ok(handles)
+            Ok(handles)
+        })
     }
 
     // Locator delegate methods
@@ -2230,33 +2258,36 @@ impl Frame {
     ///
     /// See: <https://playwright.dev/docs/api/class-frame#frame-evaluate>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
-    pub async fn evaluate<T: serde::Serialize>(
+    #[track_caller]
+    pub fn evaluate<T: serde::Serialize>(
         &self,
         expression: &str,
         arg: Option<&T>,
-    ) -> Result<Value> {
-        // Serialize the argument
-        let serialized_arg = match arg {
-            Some(a) => serialize_argument(a),
-            None => serialize_null(),
-        };
+    ) -> impl Future<Output = Result<Value>> {
+        CallSite::caller().run(async move {
+            // Serialize the argument
+            let serialized_arg = match arg {
+                Some(a) => serialize_argument(a),
+                None => serialize_null(),
+            };
 
-        // Build the parameters
-        let params = serde_json::json!({
-            "expression": expression,
-            "arg": serialized_arg
-        });
+            // Build the parameters
+            let params = serde_json::json!({
+                "expression": expression,
+                "arg": serialized_arg
+            });
 
-        // Send the evaluateExpression command
-        #[derive(Deserialize)]
-        struct EvaluateResult {
-            value: serde_json::Value,
-        }
+            // Send the evaluateExpression command
+            #[derive(Deserialize)]
+            struct EvaluateResult {
+                value: serde_json::Value,
+            }
 
-        let result: EvaluateResult = self.channel().send("evaluateExpression", params).await?;
+            let result: EvaluateResult = self.channel().send("evaluateExpression", params).await?;
 
-        // Deserialize the result using parse_result
-        Ok(parse_result(&result.value))
+            // Deserialize the result using parse_result
+            Ok(parse_result(&result.value))
+        })
     }
 
     /// Adds a `<style>` tag into the page with the desired content.
@@ -2299,49 +2330,52 @@ impl Frame {
     ///
     /// See: <https://playwright.dev/docs/api/class-frame#frame-add-style-tag>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn add_style_tag(
+    #[track_caller]
+    pub fn add_style_tag(
         &self,
         options: crate::protocol::page::AddStyleTagOptions,
-    ) -> Result<Arc<crate::protocol::ElementHandle>> {
-        // Validate that at least one option is provided
-        options.validate()?;
+    ) -> impl Future<Output = Result<Arc<crate::protocol::ElementHandle>>> {
+        CallSite::caller().run(async move {
+            // Validate that at least one option is provided
+            options.validate()?;
 
-        // Build protocol parameters
-        let mut params = serde_json::json!({});
+            // Build protocol parameters
+            let mut params = serde_json::json!({});
 
-        if let Some(content) = &options.content {
-            params["content"] = serde_json::json!(content);
-        }
+            if let Some(content) = &options.content {
+                params["content"] = serde_json::json!(content);
+            }
 
-        if let Some(url) = &options.url {
-            params["url"] = serde_json::json!(url);
-        }
+            if let Some(url) = &options.url {
+                params["url"] = serde_json::json!(url);
+            }
 
-        if let Some(path) = &options.path {
-            // Read file content and send as content
-            let css_content = tokio::fs::read_to_string(path).await.map_err(|e| {
-                Error::InvalidArgument(format!("Failed to read CSS file '{}': {}", path, e))
-            })?;
-            params["content"] = serde_json::json!(css_content);
-        }
+            if let Some(path) = &options.path {
+                // Read file content and send as content
+                let css_content = tokio::fs::read_to_string(path).await.map_err(|e| {
+                    Error::InvalidArgument(format!("Failed to read CSS file '{}': {}", path, e))
+                })?;
+                params["content"] = serde_json::json!(css_content);
+            }
 
-        #[derive(Deserialize)]
-        struct AddStyleTagResponse {
-            element: serde_json::Value,
-        }
+            #[derive(Deserialize)]
+            struct AddStyleTagResponse {
+                element: serde_json::Value,
+            }
 
-        let response: AddStyleTagResponse = self.channel().send("addStyleTag", params).await?;
+            let response: AddStyleTagResponse = self.channel().send("addStyleTag", params).await?;
 
-        let guid = response.element["guid"].as_str().ok_or_else(|| {
-            Error::ProtocolError("Element GUID missing in addStyleTag response".to_string())
-        })?;
+            let guid = response.element["guid"].as_str().ok_or_else(|| {
+                Error::ProtocolError("Element GUID missing in addStyleTag response".to_string())
+            })?;
 
-        let connection = self.base.connection();
-        let handle: crate::protocol::ElementHandle = connection
-            .get_typed::<crate::protocol::ElementHandle>(guid)
-            .await?;
+            let connection = self.base.connection();
+            let handle: crate::protocol::ElementHandle = connection
+                .get_typed::<crate::protocol::ElementHandle>(guid)
+                .await?;
 
-        Ok(Arc::new(handle))
+            Ok(Arc::new(handle))
+        })
     }
 
     /// Dispatches a DOM event on the element matching the selector.
@@ -2470,53 +2504,57 @@ impl Frame {
     ///
     /// See: <https://playwright.dev/docs/api/class-frame#frame-add-script-tag>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn add_script_tag(
+    #[track_caller]
+    pub fn add_script_tag(
         &self,
         options: crate::protocol::page::AddScriptTagOptions,
-    ) -> Result<Arc<crate::protocol::ElementHandle>> {
-        // Validate that at least one option is provided
-        options.validate()?;
+    ) -> impl Future<Output = Result<Arc<crate::protocol::ElementHandle>>> {
+        CallSite::caller().run(async move {
+            // Validate that at least one option is provided
+            options.validate()?;
 
-        // Build protocol parameters
-        let mut params = serde_json::json!({});
+            // Build protocol parameters
+            let mut params = serde_json::json!({});
 
-        if let Some(content) = &options.content {
-            params["content"] = serde_json::json!(content);
-        }
+            if let Some(content) = &options.content {
+                params["content"] = serde_json::json!(content);
+            }
 
-        if let Some(url) = &options.url {
-            params["url"] = serde_json::json!(url);
-        }
+            if let Some(url) = &options.url {
+                params["url"] = serde_json::json!(url);
+            }
 
-        if let Some(path) = &options.path {
-            // Read file content and send as content
-            let js_content = tokio::fs::read_to_string(path).await.map_err(|e| {
-                Error::InvalidArgument(format!("Failed to read JS file '{}': {}", path, e))
-            })?;
-            params["content"] = serde_json::json!(js_content);
-        }
+            if let Some(path) = &options.path {
+                // Read file content and send as content
+                let js_content = tokio::fs::read_to_string(path).await.map_err(|e| {
+                    Error::InvalidArgument(format!("Failed to read JS file '{}': {}", path, e))
+                })?;
+                params["content"] = serde_json::json!(js_content);
+            }
 
-        if let Some(type_) = &options.type_ {
-            params["type"] = serde_json::json!(type_);
-        }
+            if let Some(type_) = &options.type_ {
+                params["type"] = serde_json::json!(type_);
+            }
 
-        #[derive(Deserialize)]
-        struct AddScriptTagResponse {
-            element: serde_json::Value,
-        }
+            #[derive(Deserialize)]
+            struct AddScriptTagResponse {
+                element: serde_json::Value,
+            }
 
-        let response: AddScriptTagResponse = self.channel().send("addScriptTag", params).await?;
+            let response: AddScriptTagResponse =
+                self.channel().send("addScriptTag", params).await?;
 
-        let guid = response.element["guid"].as_str().ok_or_else(|| {
-            Error::ProtocolError("Element GUID missing in addScriptTag response".to_string())
-        })?;
+            let guid = response.element["guid"].as_str().ok_or_else(|| {
+                Error::ProtocolError("Element GUID missing in addScriptTag response".to_string())
+            })?;
 
-        let connection = self.base.connection();
-        let handle: crate::protocol::ElementHandle = connection
-            .get_typed::<crate::protocol::ElementHandle>(guid)
-            .await?;
+            let connection = self.base.connection();
+            let handle: crate::protocol::ElementHandle = connection
+                .get_typed::<crate::protocol::ElementHandle>(guid)
+                .await?;
 
-        Ok(Arc::new(handle))
+            Ok(Arc::new(handle))
+        })
     }
 }
 
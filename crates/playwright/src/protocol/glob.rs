@@ -1,5 +1,6 @@
-// URL glob matching, shared by client-side waits (`Frame::wait_for_url`) and
-// client-side route matching (`Page::route`, `BrowserContext::route`).
+// URL glob matching, behind `UrlMatcher::glob`: client-side waits
+// (`Frame::wait_for_url`) and client-side route matching (`Page::route`,
+// `BrowserContext::route`).
 //
 // This is a port of the driver's `globToRegexPattern`
 // (`packages/isomorphic/urlMatch.ts`), not an independent implementation.
@@ -123,8 +124,9 @@ pub(crate) fn glob_to_regex_pattern(glob: &str) -> Option<String> {
 /// Returns `false` for a malformed pattern, matching the driver's behavior
 /// of treating it as matching nothing rather than panicking.
 ///
-/// Callers that match repeatedly against one pattern should compile it once
-/// with [`GlobMatcher::new`] instead.
+/// Only the tests match one-off; everything else compiles once with
+/// [`GlobMatcher::new`], through [`UrlMatcher`](crate::protocol::UrlMatcher).
+#[cfg(test)]
 pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
     GlobMatcher::new(pattern).is_some_and(|m| m.matches(text))
 }
@@ -133,6 +135,7 @@ pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
 ///
 /// `Frame::wait_for_url` polls every 50ms, so compiling per call meant
 /// hundreds of identical regex compilations for a single wait.
+#[derive(Clone)]
 pub(crate) struct GlobMatcher {
     regex: regex::Regex,
 }
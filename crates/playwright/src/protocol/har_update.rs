@@ -0,0 +1,489 @@
+// `route_from_har` update mode: record into a HAR, written when the context
+// closes.
+//
+// Playwright records through `harStart` and exports the archive with
+// `harExport` on close. For a `.har` path the exported JSON is then rewritten
+// here so it can live in git: entries are merged with the existing file,
+// deduplicated by method, URL and post data, redacted, sorted and
+// pretty-printed. A `.zip` path is saved as the server produced it.
+//
+// Architecture Reference:
+// - JavaScript: playwright/packages/playwright-core/src/client/browserContext.ts
+//   (`_recordIntoHAR`, and the `harExport` loop in `close`)
+
+use crate::error::{Error, Result};
+use crate::protocol::RouteFromHarOptions;
+use crate::protocol::har_options::{HarContent, HarMode, StartHarOptions};
+use crate::server::channel_owner::ChannelOwner;
+use crate::server::connection::ConnectionExt;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// What replaces a redacted header or cookie value.
+pub(crate) const REDACTED: &str = "[REDACTED]";
+
+/// A HAR being recorded by `route_from_har` with `update: true`.
+#[derive(Debug)]
+pub(crate) struct HarUpdate {
+    har_id: String,
+    path: PathBuf,
+    merge: bool,
+    redaction: HarRedaction,
+}
+
+/// Which header and cookie values are rewritten before the HAR is written.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HarRedaction {
+    redact_headers: Vec<String>,
+    strip_headers: Vec<String>,
+    redact_cookies: Vec<String>,
+}
+
+impl HarUpdate {
+    /// The `harStart` options for recording `options` into `path`.
+    pub(crate) fn record_options(path: &Path, options: &RouteFromHarOptions) -> Result<Value> {
+        let content = match options.update_content.as_deref() {
+            None => None,
+            Some("embed") => Some(HarContent::Embed),
+            Some("attach") => Some(HarContent::Attach),
+            Some(other) => {
+                return Err(Error::InvalidArgument(format!(
+                    "route_from_har: unknown update_content {other:?}: expected \"embed\" or \"attach\""
+                )));
+            }
+        };
+        let mode = match options.update_mode.as_deref() {
+            None | Some("minimal") => HarMode::Minimal,
+            Some("full") => HarMode::Full,
+            Some(other) => {
+                return Err(Error::InvalidArgument(format!(
+                    "route_from_har: unknown update_mode {other:?}: expected \"minimal\" or \"full\""
+                )));
+            }
+        };
+        let mut start = StartHarOptions::default().mode(mode);
+        start.content = content;
+        start.url_filter = options.url.clone();
+        Ok(start.to_record_har_json(&path.to_string_lossy()))
+    }
+
+    pub(crate) fn new(
+        har_id: String,
+        path: PathBuf,
+        options: &RouteFromHarOptions,
+    ) -> Result<Self> {
+        let redaction = HarRedaction {
+            redact_headers: options.redact_headers.clone().unwrap_or_default(),
+            strip_headers: options.strip_headers.clone().unwrap_or_default(),
+            redact_cookies: options.redact_cookies.clone().unwrap_or_default(),
+        };
+        let merge = options.merge.unwrap_or(false);
+        let rewrites = merge
+            || !redaction.redact_headers.is_empty()
+            || !redaction.strip_headers.is_empty()
+            || !redaction.redact_cookies.is_empty();
+        if rewrites && is_zip(&path) {
+            return Err(Error::InvalidArgument(format!(
+                "route_from_har: merge and redaction need a .har path, not {}",
+                path.display()
+            )));
+        }
+        Ok(Self {
+            har_id,
+            path,
+            merge,
+            redaction,
+        })
+    }
+
+    /// Whether the recording replays the existing file and only records
+    /// what it misses.
+    pub(crate) fn replays_existing(&self) -> bool {
+        self.merge && self.path.exists()
+    }
+
+    /// Exports the recording from `context` and writes it to the HAR path.
+    pub(crate) async fn finish(self, context: &crate::protocol::BrowserContext) -> Result<()> {
+        let result: Value = context
+            .channel()
+            .send("harExport", serde_json::json!({ "harId": self.har_id }))
+            .await?;
+        let Some(artifact_guid) = result
+            .get("artifact")
+            .and_then(|a| a.get("guid"))
+            .and_then(|g| g.as_str())
+        else {
+            return Ok(());
+        };
+        let artifact = context
+            .connection()
+            .get_typed::<crate::protocol::artifact::Artifact>(artifact_guid)
+            .await?;
+
+        let path_str = self.path.to_string_lossy().into_owned();
+        if is_zip(&self.path) {
+            return artifact.save_as(&path_str).await;
+        }
+
+        // Unzip next to the destination, so attached resources land beside it
+        let tmp_zip = format!("{path_str}.tmp.zip");
+        let tmp_har = format!("{path_str}.tmp");
+        artifact.save_as(&tmp_zip).await?;
+        let unzipped = find_local_utils(context)?
+            .har_unzip(&tmp_zip, &tmp_har, None)
+            .await;
+        let _ = std::fs::remove_file(&tmp_zip);
+        unzipped?;
+        let recorded = read_har(Path::new(&tmp_har));
+        let _ = std::fs::remove_file(&tmp_har);
+
+        let existing = if self.merge && self.path.exists() {
+            Some(read_har(&self.path)?)
+        } else {
+            None
+        };
+        let mut har = merge_hars(existing, recorded?);
+        self.redaction.apply(&mut har);
+
+        let mut json = serde_json::to_string_pretty(&har)?;
+        json.push('\n');
+        std::fs::write(&self.path, json)?;
+        Ok(())
+    }
+}
+
+fn is_zip(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "zip")
+}
+
+fn read_har(path: &Path) -> Result<Value> {
+    let text = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&text)?)
+}
+
+fn find_local_utils(
+    context: &crate::protocol::BrowserContext,
+) -> Result<crate::protocol::LocalUtils> {
+    context
+        .connection()
+        .all_objects_sync()
+        .into_iter()
+        .find(|o| o.type_name() == "LocalUtils")
+        .and_then(|o| {
+            o.as_any()
+                .downcast_ref::<crate::protocol::LocalUtils>()
+                .cloned()
+        })
+        .ok_or_else(|| {
+            Error::ProtocolError(
+                "route_from_har: LocalUtils not found in connection registry".to_string(),
+            )
+        })
+}
+
+/// Identifies a request, for deduplication and ordering: URL, method and
+/// body. A body Playwright attached rather than inlined has an empty
+/// `text` and its content-addressed file name in `_file`, so that name
+/// stands for it.
+fn entry_key(entry: &Value) -> (String, String, String) {
+    let request = &entry["request"];
+    let post_data = &request["postData"];
+    let body = post_data["_file"]
+        .as_str()
+        .or_else(|| post_data["text"].as_str());
+    (
+        request["url"].as_str().unwrap_or("").to_string(),
+        request["method"].as_str().unwrap_or("").to_string(),
+        body.unwrap_or("").to_string(),
+    )
+}
+
+/// Adds the `recorded` entries `existing` lacks to it (or takes `recorded`
+/// alone), keeping the first entry per request, and orders the result so
+/// that re-recording the same traffic produces the same file.
+///
+/// Page groupings are dropped: their ids change on every run.
+pub(crate) fn merge_hars(existing: Option<Value>, recorded: Value) -> Value {
+    let mut recorded = recorded;
+    let recorded_entries = take_entries(&mut recorded);
+    let (mut har, mut entries) = match existing {
+        Some(mut existing) => {
+            let entries = take_entries(&mut existing);
+            (existing, entries)
+        }
+        None => (recorded, Vec::new()),
+    };
+    entries.extend(recorded_entries);
+
+    let mut seen = HashSet::new();
+    entries.retain(|entry| seen.insert(entry_key(entry)));
+    for entry in &mut entries {
+        if let Some(entry) = entry.as_object_mut() {
+            entry.remove("pageref");
+        }
+    }
+    entries.sort_by_cached_key(entry_key);
+
+    if let Some(log) = har["log"].as_object_mut() {
+        log.remove("pages");
+        log.insert("entries".to_string(), Value::Array(entries));
+    }
+    har
+}
+
+fn take_entries(har: &mut Value) -> Vec<Value> {
+    match har["log"]["entries"].take() {
+        Value::Array(entries) => entries,
+        _ => Vec::new(),
+    }
+}
+
+impl HarRedaction {
+    fn apply(&self, har: &mut Value) {
+        let Some(entries) = har["log"]["entries"].as_array_mut() else {
+            return;
+        };
+        for entry in entries {
+            for side in ["request", "response"] {
+                let message = &mut entry[side];
+                if let Some(headers) = message["headers"].as_array_mut() {
+                    self.apply_to_headers(headers);
+                }
+                if let Some(cookies) = message["cookies"].as_array_mut() {
+                    for cookie in cookies {
+                        if cookie["name"]
+                            .as_str()
+                            .is_some_and(|n| self.redacts_cookie(n))
+                        {
+                            cookie["value"] = Value::from(REDACTED);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn apply_to_headers(&self, headers: &mut Vec<Value>) {
+        let named = |names: &[String], header: &Value| {
+            header["name"]
+                .as_str()
+                .is_some_and(|n| names.iter().any(|name| name.eq_ignore_ascii_case(n)))
+        };
+        headers.retain(|header| !named(&self.strip_headers, header));
+        for header in headers {
+            if named(&self.redact_headers, header) {
+                header["value"] = Value::from(REDACTED);
+                continue;
+            }
+            let name = header["name"].as_str().unwrap_or("").to_ascii_lowercase();
+            let Some(value) = header["value"].as_str() else {
+                continue;
+            };
+            let redacted = match name.as_str() {
+                "cookie" => self.redact_cookie_pairs(value, "; "),
+                "set-cookie" => {
+                    // Only the leading pair is the cookie; the rest are attributes
+                    let (pair, attributes) = value.split_once(';').unwrap_or((value, ""));
+                    let pair = self.redact_cookie_pairs(pair, "; ");
+                    match attributes {
+                        "" => pair,
+                        attributes => format!("{pair};{attributes}"),
+                    }
+                }
+                _ => continue,
+            };
+            header["value"] = Value::from(redacted);
+        }
+    }
+
+    fn redact_cookie_pairs(&self, value: &str, separator: &str) -> String {
+        value
+            .split(';')
+            .map(|pair| {
+                let pair = pair.trim();
+                match pair.split_once('=') {
+                    Some((name, _)) if self.redacts_cookie(name) => format!("{name}={REDACTED}"),
+                    _ => pair.to_string(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    fn redacts_cookie(&self, name: &str) -> bool {
+        self.redact_cookies.iter().any(|c| c == "*" || c == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn entry(method: &str, url: &str, body: Option<&str>) -> Value {
+        let mut request = json!({ "method": method, "url": url, "headers": [], "cookies": [] });
+        if let Some(body) = body {
+            request["postData"] = json!({ "mimeType": "text/plain", "text": body });
+        }
+        json!({ "pageref": "page@1", "request": request, "response": { "status": 200 } })
+    }
+
+    fn har(entries: Vec<Value>) -> Value {
+        json!({ "log": { "version": "1.2", "pages": [{ "id": "page@1" }], "entries": entries } })
+    }
+
+    fn urls(har: &Value) -> Vec<(&str, &str)> {
+        har["log"]["entries"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| {
+                (
+                    e["request"]["method"].as_str().unwrap(),
+                    e["request"]["url"].as_str().unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn merge_keeps_existing_entries_and_adds_missing_ones() {
+        let mut existing = har(vec![entry("GET", "https://a.test/b", None)]);
+        existing["log"]["entries"][0]["response"]["status"] = json!(201);
+        let recorded = har(vec![
+            entry("GET", "https://a.test/b", None),
+            entry("GET", "https://a.test/a", None),
+            entry("POST", "https://a.test/a", Some("x")),
+            entry("POST", "https://a.test/a", Some("y")),
+            entry("POST", "https://a.test/a", Some("x")),
+        ]);
+
+        let merged = merge_hars(Some(existing), recorded);
+        let entries = merged["log"]["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 4);
+        let kept = entries
+            .iter()
+            .find(|e| e["request"]["url"] == "https://a.test/b")
+            .unwrap();
+        assert_eq!(kept["response"]["status"], 201, "existing entry wins");
+        assert!(merged["log"].get("pages").is_none());
+        assert!(entries.iter().all(|e| e.get("pageref").is_none()));
+    }
+
+    #[test]
+    fn merge_orders_entries_independently_of_recording_order() {
+        let one = merge_hars(
+            None,
+            har(vec![
+                entry("GET", "https://a.test/2", None),
+                entry("GET", "https://a.test/1", None),
+            ]),
+        );
+        let other = merge_hars(
+            None,
+            har(vec![
+                entry("GET", "https://a.test/1", None),
+                entry("GET", "https://a.test/2", None),
+            ]),
+        );
+        assert_eq!(one, other);
+        assert_eq!(
+            urls(&one),
+            vec![("GET", "https://a.test/1"), ("GET", "https://a.test/2")]
+        );
+    }
+
+    #[test]
+    fn merge_orders_same_request_by_post_text() {
+        let merged = merge_hars(
+            None,
+            har(vec![
+                entry("POST", "https://a.test/a", Some("zebra")),
+                entry("POST", "https://a.test/a", Some("apple")),
+                entry("POST", "https://a.test/a", Some("mango")),
+            ]),
+        );
+        let bodies: Vec<&str> = merged["log"]["entries"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["request"]["postData"]["text"].as_str().unwrap())
+            .collect();
+        assert_eq!(bodies, ["apple", "mango", "zebra"]);
+    }
+
+    #[test]
+    fn merge_tells_attached_bodies_apart_by_file() {
+        let attached = |file: &str| {
+            let mut entry = entry("POST", "https://a.test/upload", Some(""));
+            entry["request"]["postData"]["_file"] = json!(file);
+            entry
+        };
+        let merged = merge_hars(None, har(vec![attached("f2.bin"), attached("e1.bin")]));
+        let files: Vec<&str> = merged["log"]["entries"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["request"]["postData"]["_file"].as_str().unwrap())
+            .collect();
+        assert_eq!(files, ["e1.bin", "f2.bin"]);
+    }
+
+    #[test]
+    fn redaction_rewrites_headers_and_cookies() {
+        let mut har = har(vec![json!({
+            "request": {
+                "headers": [
+                    { "name": "Authorization", "value": "Bearer secret" },
+                    { "name": "Cookie", "value": "session=abc; theme=dark" },
+                    { "name": "X-Request-Id", "value": "42" },
+                ],
+                "cookies": [{ "name": "session", "value": "abc" }],
+            },
+            "response": {
+                "headers": [{ "name": "set-cookie", "value": "session=def; Path=/; HttpOnly" }],
+                "cookies": [],
+            },
+        })]);
+        HarRedaction {
+            redact_headers: vec!["authorization".to_string()],
+            strip_headers: vec!["x-request-id".to_string()],
+            redact_cookies: vec!["session".to_string()],
+        }
+        .apply(&mut har);
+
+        let entry = &har["log"]["entries"][0];
+        assert_eq!(
+            entry["request"]["headers"],
+            json!([
+                { "name": "Authorization", "value": REDACTED },
+                { "name": "Cookie", "value": "session=[REDACTED]; theme=dark" },
+            ])
+        );
+        assert_eq!(entry["request"]["cookies"][0]["value"], REDACTED);
+        assert_eq!(
+            entry["response"]["headers"][0]["value"],
+            "session=[REDACTED]; Path=/; HttpOnly"
+        );
+    }
+
+    #[test]
+    fn record_options_default_to_minimal() {
+        let options = HarUpdate::record_options(
+            Path::new("/tmp/api.har"),
+            &RouteFromHarOptions::default().url("**/api/**"),
+        )
+        .unwrap();
+        assert_eq!(options["mode"], "minimal");
+        assert_eq!(options["content"], "embed");
+        assert_eq!(options["urlGlob"], "**/api/**");
+
+        let err = HarUpdate::record_options(
+            Path::new("/tmp/api.har"),
+            &RouteFromHarOptions::default().update_mode("everything"),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("everything"), "{err}");
+    }
+}
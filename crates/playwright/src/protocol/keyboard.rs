@@ -4,6 +4,7 @@
 
 use crate::error::Result;
 use crate::protocol::page::Page;
+use crate::server::call_site::CallSite;
 
 /// Keyboard provides low-level keyboard control.
 ///
@@ -22,45 +23,54 @@ impl Keyboard {
     /// Dispatches a `keydown` event.
     ///
     /// See: <https://playwright.dev/docs/api/class-keyboard#keyboard-down>
-    pub async fn down(&self, key: &str) -> Result<()> {
-        self.page.keyboard_down(key).await
+    #[track_caller]
+    pub fn down(&self, key: &str) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move { self.page.keyboard_down(key).await })
     }
 
     /// Dispatches a `keyup` event.
     ///
     /// See: <https://playwright.dev/docs/api/class-keyboard#keyboard-up>
-    pub async fn up(&self, key: &str) -> Result<()> {
-        self.page.keyboard_up(key).await
+    #[track_caller]
+    pub fn up(&self, key: &str) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move { self.page.keyboard_up(key).await })
     }
 
     /// Executes a complete key press (down + up sequence).
     ///
     /// See: <https://playwright.dev/docs/api/class-keyboard#keyboard-press>
-    pub async fn press(
+    #[track_caller]
+    pub fn press(
         &self,
         key: &str,
         options: impl Into<Option<crate::protocol::KeyboardOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.page.keyboard_press(key, options).await
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.page.keyboard_press(key, options).await
+        })
     }
 
     /// Sends a `keydown`, `keypress`/`input`, and `keyup` event for each character.
     ///
     /// See: <https://playwright.dev/docs/api/class-keyboard#keyboard-type>
-    pub async fn type_text(
+    #[track_caller]
+    pub fn type_text(
         &self,
         text: &str,
         options: impl Into<Option<crate::protocol::KeyboardOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.page.keyboard_type(text, options).await
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.page.keyboard_type(text, options).await
+        })
     }
 
     /// Dispatches only `input` event, does not emit `keydown`, `keyup` or `keypress` events.
     ///
     /// See: <https://playwright.dev/docs/api/class-keyboard#keyboard-insert-text>
-    pub async fn insert_text(&self, text: &str) -> Result<()> {
-        self.page.keyboard_insert_text(text).await
+    #[track_caller]
+    pub fn insert_text(&self, text: &str) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move { self.page.keyboard_insert_text(text).await })
     }
 }
@@ -100,6 +100,58 @@ impl LocalUtils {
         }
         self.channel().send_no_result("harUnzip", params).await
     }
+
+    /// Opens a stack-collection session for a trace chunk named `trace_name`.
+    /// Call stacks sent with `addStackToTracingNoReply` accumulate in it until
+    /// [`zip_append`](Self::zip_append) writes them out or
+    /// [`trace_discarded`](Self::trace_discarded) drops them.
+    pub async fn tracing_started(
+        &self,
+        traces_dir: Option<&str>,
+        trace_name: &str,
+    ) -> Result<String> {
+        #[derive(Deserialize)]
+        struct TracingStartedResult {
+            #[serde(rename = "stacksId")]
+            stacks_id: String,
+        }
+        let mut params = serde_json::json!({ "traceName": trace_name });
+        if let Some(dir) = traces_dir {
+            params["tracesDir"] = serde_json::json!(dir);
+        }
+        let result: TracingStartedResult = self.channel().send("tracingStarted", params).await?;
+        Ok(result.stacks_id)
+    }
+
+    /// Drops the stack session of a trace chunk that was discarded.
+    pub async fn trace_discarded(&self, stacks_id: &str) -> Result<()> {
+        self.channel()
+            .send_no_result(
+                "traceDiscarded",
+                serde_json::json!({ "stacksId": stacks_id }),
+            )
+            .await
+    }
+
+    /// Appends the call stacks collected under `stacks_id` to the trace zip at
+    /// `zip_file` and, with `include_sources`, the source files they reference.
+    pub async fn zip_append(
+        &self,
+        zip_file: &str,
+        stacks_id: Option<&str>,
+        include_sources: bool,
+    ) -> Result<()> {
+        let mut params = serde_json::json!({
+            "zipFile": zip_file,
+            "entries": [],
+            "mode": "append",
+            "includeSources": include_sources,
+        });
+        if let Some(id) = stacks_id {
+            params["stacksId"] = serde_json::json!(id);
+        }
+        self.channel().send_no_result("zip", params).await
+    }
 }
 
 /// Result from a `harLookup` RPC call.
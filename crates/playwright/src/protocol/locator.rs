@@ -57,6 +57,7 @@
 
 use crate::error::Result;
 use crate::protocol::Frame;
+use crate::server::call_site::CallSite;
 use serde::Deserialize;
 
 /// Trait for action option structs that have an optional timeout field.
@@ -335,6 +336,92 @@ pub enum AriaRole {
 }
 
 impl AriaRole {
+    /// Every role, in declaration order.
+    pub(crate) const ALL: [Self; 82] = [
+        Self::Alert,
+        Self::Alertdialog,
+        Self::Application,
+        Self::Article,
+        Self::Banner,
+        Self::Blockquote,
+        Self::Button,
+        Self::Caption,
+        Self::Cell,
+        Self::Checkbox,
+        Self::Code,
+        Self::Columnheader,
+        Self::Combobox,
+        Self::Complementary,
+        Self::Contentinfo,
+        Self::Definition,
+        Self::Deletion,
+        Self::Dialog,
+        Self::Directory,
+        Self::Document,
+        Self::Emphasis,
+        Self::Feed,
+        Self::Figure,
+        Self::Form,
+        Self::Generic,
+        Self::Grid,
+        Self::Gridcell,
+        Self::Group,
+        Self::Heading,
+        Self::Img,
+        Self::Insertion,
+        Self::Link,
+        Self::List,
+        Self::Listbox,
+        Self::Listitem,
+        Self::Log,
+        Self::Main,
+        Self::Marquee,
+        Self::Math,
+        Self::Meter,
+        Self::Menu,
+        Self::Menubar,
+        Self::Menuitem,
+        Self::Menuitemcheckbox,
+        Self::Menuitemradio,
+        Self::Navigation,
+        Self::None,
+        Self::Note,
+        Self::Option,
+        Self::Paragraph,
+        Self::Presentation,
+        Self::Progressbar,
+        Self::Radio,
+        Self::Radiogroup,
+        Self::Region,
+        Self::Row,
+        Self::Rowgroup,
+        Self::Rowheader,
+        Self::Scrollbar,
+        Self::Search,
+        Self::Searchbox,
+        Self::Separator,
+        Self::Slider,
+        Self::Spinbutton,
+        Self::Status,
+        Self::Strong,
+        Self::Subscript,
+        Self::Superscript,
+        Self::Switch,
+        Self::Tab,
+        Self::Table,
+        Self::Tablist,
+        Self::Tabpanel,
+        Self::Term,
+        Self::Textbox,
+        Self::Time,
+        Self::Timer,
+        Self::Toolbar,
+        Self::Tooltip,
+        Self::Tree,
+        Self::Treegrid,
+        Self::Treeitem,
+    ];
+
     /// Returns the lowercase string representation used in selectors.
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -712,17 +799,20 @@ impl Locator {
     /// "Timeout ...ms exceeded" message, with the selector appended.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-wait-for-function>
-    pub async fn wait_for_function(
+    #[track_caller]
+    pub fn wait_for_function(
         &self,
         expression: &str,
         options: impl Into<Option<crate::protocol::WaitForFunctionOptions>>,
-    ) -> Result<()> {
-        let options = self.with_timeout(options.into());
-        self.frame
-            .wait_for_function_internal(expression, Some(&self.selector), options)
-            .await
-            .map(|_| ())
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = self.with_timeout(options.into());
+            self.frame
+                .wait_for_function_internal(expression, Some(&self.selector), options)
+                .await
+                .map(|_| ())
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Serializes this locator as a screenshot `mask` entry — `{ frame, selector }`
@@ -1031,14 +1121,17 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-count>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector, count = tracing::field::Empty))]
-    pub async fn count(&self) -> Result<usize> {
-        let n = self
-            .frame
-            .locator_count(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))?;
-        tracing::Span::current().record("count", n);
-        Ok(n)
+    #[track_caller]
+    pub fn count(&self) -> impl Future<Output = Result<usize>> {
+        CallSite::caller().run(async move {
+            let n = self
+                .frame
+                .locator_count(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))?;
+            tracing::Span::current().record("count", n);
+            Ok(n)
+        })
     }
 
     /// Returns an array of locators, one for each matching element.
@@ -1048,130 +1141,166 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-all>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn all(&self) -> Result<Vec<Locator>> {
-        let count = self.count().await?;
-        Ok((0..count).map(|i| self.nth(i as i32)).collect())
+    #[track_caller]
+    pub fn all(&self) -> impl Future<Output = Result<Vec<Locator>>> {
+        CallSite::caller().run(async move {
+            let count = self.count().await?;
+            Ok((0..count).map(|i| self.nth(i as i32)).collect())
+        })
     }
 
     /// Returns the text content of the element.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-text-content>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn text_content(&self) -> Result<Option<String>> {
-        self.frame
-            .locator_text_content(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn text_content(&self) -> impl Future<Output = Result<Option<String>>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_text_content(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Returns the inner text of the element (visible text).
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-inner-text>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn inner_text(&self) -> Result<String> {
-        self.frame
-            .locator_inner_text(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn inner_text(&self) -> impl Future<Output = Result<String>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_inner_text(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Returns the inner HTML of the element.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-inner-html>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn inner_html(&self) -> Result<String> {
-        self.frame
-            .locator_inner_html(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn inner_html(&self) -> impl Future<Output = Result<String>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_inner_html(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Returns the value of the specified attribute.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-get-attribute>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector, name = %name))]
-    pub async fn get_attribute(&self, name: &str) -> Result<Option<String>> {
-        self.frame
-            .locator_get_attribute(&self.selector, name)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn get_attribute(&self, name: &str) -> impl Future<Output = Result<Option<String>>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_get_attribute(&self.selector, name)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Returns whether the element is visible.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-is-visible>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn is_visible(&self) -> Result<bool> {
-        self.frame
-            .locator_is_visible(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn is_visible(&self) -> impl Future<Output = Result<bool>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_is_visible(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Returns whether the element is enabled.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-is-enabled>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn is_enabled(&self) -> Result<bool> {
-        self.frame
-            .locator_is_enabled(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn is_enabled(&self) -> impl Future<Output = Result<bool>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_is_enabled(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Returns whether the checkbox or radio button is checked.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-is-checked>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn is_checked(&self) -> Result<bool> {
-        self.frame
-            .locator_is_checked(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn is_checked(&self) -> impl Future<Output = Result<bool>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_is_checked(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Returns whether the element is editable.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-is-editable>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn is_editable(&self) -> Result<bool> {
-        self.frame
-            .locator_is_editable(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn is_editable(&self) -> impl Future<Output = Result<bool>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_is_editable(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Returns whether the element is hidden.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-is-hidden>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn is_hidden(&self) -> Result<bool> {
-        self.frame
-            .locator_is_hidden(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn is_hidden(&self) -> impl Future<Output = Result<bool>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_is_hidden(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Returns whether the element is disabled.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-is-disabled>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn is_disabled(&self) -> Result<bool> {
-        self.frame
-            .locator_is_disabled(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn is_disabled(&self) -> impl Future<Output = Result<bool>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_is_disabled(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Returns whether the element is focused (currently has focus).
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-is-focused>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn is_focused(&self) -> Result<bool> {
-        self.frame
-            .locator_is_focused(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn is_focused(&self) -> impl Future<Output = Result<bool>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_is_focused(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     // Action methods
@@ -1180,15 +1309,18 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-click>
     #[tracing::instrument(level = "info", skip_all, fields(selector = %self.selector))]
-    pub async fn click(
+    #[track_caller]
+    pub fn click(
         &self,
         options: impl Into<Option<crate::protocol::ClickOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.frame
-            .locator_click(&self.selector, Some(self.with_timeout(options)))
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.frame
+                .locator_click(&self.selector, Some(self.with_timeout(options)))
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Ensures an options struct has the page's default timeout when none is explicitly set.
@@ -1218,62 +1350,74 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-dblclick>
     #[tracing::instrument(level = "info", skip_all, fields(selector = %self.selector))]
-    pub async fn dblclick(
+    #[track_caller]
+    pub fn dblclick(
         &self,
         options: impl Into<Option<crate::protocol::ClickOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.frame
-            .locator_dblclick(&self.selector, Some(self.with_timeout(options)))
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.frame
+                .locator_dblclick(&self.selector, Some(self.with_timeout(options)))
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Fills the element with text.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-fill>
     #[tracing::instrument(level = "info", skip_all, fields(selector = %self.selector))]
-    pub async fn fill(
+    #[track_caller]
+    pub fn fill(
         &self,
         text: &str,
         options: impl Into<Option<crate::protocol::FillOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.frame
-            .locator_fill(&self.selector, text, Some(self.with_timeout(options)))
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.frame
+                .locator_fill(&self.selector, text, Some(self.with_timeout(options)))
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Clears the element's value.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-clear>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn clear(
+    #[track_caller]
+    pub fn clear(
         &self,
         options: impl Into<Option<crate::protocol::FillOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.frame
-            .locator_clear(&self.selector, Some(self.with_timeout(options)))
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.frame
+                .locator_clear(&self.selector, Some(self.with_timeout(options)))
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Presses a key on the element.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-press>
     #[tracing::instrument(level = "info", skip_all, fields(selector = %self.selector))]
-    pub async fn press(
+    #[track_caller]
+    pub fn press(
         &self,
         key: &str,
         options: impl Into<Option<crate::protocol::PressOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.frame
-            .locator_press(&self.selector, key, Some(self.with_timeout(options)))
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.frame
+                .locator_press(&self.selector, key, Some(self.with_timeout(options)))
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Sets focus on the element.
@@ -1283,11 +1427,14 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-focus>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn focus(&self) -> Result<()> {
-        self.frame
-            .locator_focus(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn focus(&self) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_focus(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Removes focus from the element.
@@ -1296,11 +1443,14 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-blur>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn blur(&self) -> Result<()> {
-        self.frame
-            .locator_blur(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn blur(&self) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_blur(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Types `text` into the element character by character, as though it was typed
@@ -1317,16 +1467,19 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-press-sequentially>
     #[tracing::instrument(level = "info", skip_all, fields(selector = %self.selector))]
-    pub async fn press_sequentially(
+    #[track_caller]
+    pub fn press_sequentially(
         &self,
         text: &str,
         options: impl Into<Option<crate::protocol::PressSequentiallyOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.frame
-            .locator_press_sequentially(&self.selector, text, options)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.frame
+                .locator_press_sequentially(&self.selector, text, options)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Returns the `innerText` values of all elements matching this locator.
@@ -1336,11 +1489,14 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-all-inner-texts>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn all_inner_texts(&self) -> Result<Vec<String>> {
-        self.frame
-            .locator_all_inner_texts(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn all_inner_texts(&self) -> impl Future<Output = Result<Vec<String>>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_all_inner_texts(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Returns the `textContent` values of all elements matching this locator.
@@ -1350,11 +1506,14 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-all-text-contents>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn all_text_contents(&self) -> Result<Vec<String>> {
-        self.frame
-            .locator_all_text_contents(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn all_text_contents(&self) -> impl Future<Output = Result<Vec<String>>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_all_text_contents(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Ensures the checkbox or radio button is checked.
@@ -1363,15 +1522,18 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-check>
     #[tracing::instrument(level = "info", skip_all, fields(selector = %self.selector))]
-    pub async fn check(
+    #[track_caller]
+    pub fn check(
         &self,
         options: impl Into<Option<crate::protocol::CheckOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.frame
-            .locator_check(&self.selector, Some(self.with_timeout(options)))
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.frame
+                .locator_check(&self.selector, Some(self.with_timeout(options)))
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Ensures the checkbox is unchecked.
@@ -1380,15 +1542,18 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-uncheck>
     #[tracing::instrument(level = "info", skip_all, fields(selector = %self.selector))]
-    pub async fn uncheck(
+    #[track_caller]
+    pub fn uncheck(
         &self,
         options: impl Into<Option<crate::protocol::CheckOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.frame
-            .locator_uncheck(&self.selector, Some(self.with_timeout(options)))
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.frame
+                .locator_uncheck(&self.selector, Some(self.with_timeout(options)))
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Sets the checkbox or radio button to the specified checked state.
@@ -1398,43 +1563,55 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-set-checked>
     #[tracing::instrument(level = "info", skip_all, fields(selector = %self.selector))]
-    pub async fn set_checked(
+    #[track_caller]
+    pub fn set_checked(
         &self,
         checked: bool,
         options: impl Into<Option<crate::protocol::CheckOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        if checked {
-            self.check(options).await
-        } else {
-            self.uncheck(options).await
-        }
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            if checked {
+                self.check(options).await
+            } else {
+                self.uncheck(options).await
+            }
+        })
     }
 
     /// Hovers the mouse over the element.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-hover>
     #[tracing::instrument(level = "info", skip_all, fields(selector = %self.selector))]
-    pub async fn hover(
+    #[track_caller]
+    pub fn hover(
         &self,
         options: impl Into<Option<crate::protocol::HoverOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.frame
-            .locator_hover(&self.selector, Some(self.with_timeout(options)))
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.frame
+                .locator_hover(&self.selector, Some(self.with_timeout(options)))
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Returns the value of the input, textarea, or select element.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-input-value>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn input_value(&self, _options: impl Into<Option<()>>) -> Result<String> {
-        self.frame
-            .locator_input_value(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn input_value(
+        &self,
+        _options: impl Into<Option<()>>,
+    ) -> impl Future<Output = Result<String>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_input_value(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Selects one or more options in a select element.
@@ -1443,20 +1620,23 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-select-option>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn select_option(
+    #[track_caller]
+    pub fn select_option(
         &self,
         value: impl Into<crate::protocol::SelectOption>,
         options: impl Into<Option<crate::protocol::SelectOptions>>,
-    ) -> Result<Vec<String>> {
-        let options = options.into();
-        self.frame
-            .locator_select_option(
-                &self.selector,
-                value.into(),
-                Some(self.with_timeout(options)),
-            )
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<Vec<String>>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.frame
+                .locator_select_option(
+                    &self.selector,
+                    value.into(),
+                    Some(self.with_timeout(options)),
+                )
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Selects multiple options in a select element.
@@ -1465,82 +1645,97 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-select-option>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn select_option_multiple(
+    #[track_caller]
+    pub fn select_option_multiple(
         &self,
         values: &[impl Into<crate::protocol::SelectOption> + Clone],
         options: impl Into<Option<crate::protocol::SelectOptions>>,
-    ) -> Result<Vec<String>> {
-        let options = options.into();
-        let select_options: Vec<crate::protocol::SelectOption> =
-            values.iter().map(|v| v.clone().into()).collect();
-        self.frame
-            .locator_select_option_multiple(
-                &self.selector,
-                select_options,
-                Some(self.with_timeout(options)),
-            )
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<Vec<String>>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let select_options: Vec<crate::protocol::SelectOption> =
+                values.iter().map(|v| v.clone().into()).collect();
+            self.frame
+                .locator_select_option_multiple(
+                    &self.selector,
+                    select_options,
+                    Some(self.with_timeout(options)),
+                )
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Sets the file path(s) to upload to a file input element.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-set-input-files>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn set_input_files(
+    #[track_caller]
+    pub fn set_input_files(
         &self,
         file: &std::path::PathBuf,
         _options: impl Into<Option<()>>,
-    ) -> Result<()> {
-        self.frame
-            .locator_set_input_files(&self.selector, file)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_set_input_files(&self.selector, file)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Sets multiple file paths to upload to a file input element.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-set-input-files>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn set_input_files_multiple(
+    #[track_caller]
+    pub fn set_input_files_multiple(
         &self,
         files: &[&std::path::PathBuf],
         _options: impl Into<Option<()>>,
-    ) -> Result<()> {
-        self.frame
-            .locator_set_input_files_multiple(&self.selector, files)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_set_input_files_multiple(&self.selector, files)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Sets a file to upload using FilePayload (explicit name, mimeType, buffer).
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-set-input-files>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn set_input_files_payload(
+    #[track_caller]
+    pub fn set_input_files_payload(
         &self,
         file: crate::protocol::FilePayload,
         _options: impl Into<Option<()>>,
-    ) -> Result<()> {
-        self.frame
-            .locator_set_input_files_payload(&self.selector, file)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_set_input_files_payload(&self.selector, file)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Sets multiple files to upload using FilePayload.
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-set-input-files>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn set_input_files_payload_multiple(
+    #[track_caller]
+    pub fn set_input_files_payload_multiple(
         &self,
         files: &[crate::protocol::FilePayload],
         _options: impl Into<Option<()>>,
-    ) -> Result<()> {
-        self.frame
-            .locator_set_input_files_payload_multiple(&self.selector, files)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_set_input_files_payload_multiple(&self.selector, files)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Dispatches a DOM event on the element.
@@ -1563,15 +1758,18 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-dispatch-event>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn dispatch_event(
+    #[track_caller]
+    pub fn dispatch_event(
         &self,
         type_: &str,
         event_init: Option<serde_json::Value>,
-    ) -> Result<()> {
-        self.frame
-            .locator_dispatch_event(&self.selector, type_, event_init)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_dispatch_event(&self.selector, type_, event_init)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Returns the bounding box of the element, or `None` if the element is not visible.
@@ -1588,11 +1786,14 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-bounding-box>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn bounding_box(&self) -> Result<Option<BoundingBox>> {
-        self.frame
-            .locator_bounding_box(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn bounding_box(&self) -> impl Future<Output = Result<Option<BoundingBox>>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_bounding_box(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Scrolls the element into view if it is not already visible in the viewport.
@@ -1607,11 +1808,14 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-scroll-into-view-if-needed>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn scroll_into_view_if_needed(&self) -> Result<()> {
-        self.frame
-            .locator_scroll_into_view_if_needed(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn scroll_into_view_if_needed(&self) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_scroll_into_view_if_needed(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Takes a screenshot of the element and returns the image bytes.
@@ -1621,31 +1825,34 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-screenshot>
     #[tracing::instrument(level = "info", skip_all, fields(selector = %self.selector, bytes_len = tracing::field::Empty))]
-    pub async fn screenshot(
+    #[track_caller]
+    pub fn screenshot(
         &self,
         options: impl Into<Option<crate::protocol::ScreenshotOptions>>,
-    ) -> Result<Vec<u8>> {
-        let options = options.into();
-        // Query for the element using strict mode (should return exactly one)
-        let element = self
-            .frame
-            .query_selector(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))?
-            .ok_or_else(|| {
-                crate::error::Error::ElementNotFound(format!(
-                    "Element not found: {}",
-                    self.selector
-                ))
-            })?;
-
-        // Delegate to ElementHandle.screenshot() with default timeout injected
-        let bytes = element
-            .screenshot(Some(self.with_timeout(options)))
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))?;
-        tracing::Span::current().record("bytes_len", bytes.len());
-        Ok(bytes)
+    ) -> impl Future<Output = Result<Vec<u8>>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            // Query for the element using strict mode (should return exactly one)
+            let element = self
+                .frame
+                .query_selector(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))?
+                .ok_or_else(|| {
+                    crate::error::Error::ElementNotFound(format!(
+                        "Element not found: {}",
+                        self.selector
+                    ))
+                })?;
+
+            // Delegate to ElementHandle.screenshot() with default timeout injected
+            let bytes = element
+                .screenshot(Some(self.with_timeout(options)))
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))?;
+            tracing::Span::current().record("bytes_len", bytes.len());
+            Ok(bytes)
+        })
     }
 
     /// Performs a touch-tap on the element.
@@ -1667,12 +1874,18 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-tap>
     #[tracing::instrument(level = "info", skip_all, fields(selector = %self.selector))]
-    pub async fn tap(&self, options: impl Into<Option<crate::protocol::TapOptions>>) -> Result<()> {
-        let options = options.into();
-        self.frame
-            .locator_tap(&self.selector, Some(self.with_timeout(options)))
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn tap(
+        &self,
+        options: impl Into<Option<crate::protocol::TapOptions>>,
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.frame
+                .locator_tap(&self.selector, Some(self.with_timeout(options)))
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Drags this element to the `target` element.
@@ -1725,20 +1938,23 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-drag-to>
     #[tracing::instrument(level = "info", skip_all, fields(selector = %self.selector))]
-    pub async fn drag_to(
+    #[track_caller]
+    pub fn drag_to(
         &self,
         target: &Locator,
         options: impl Into<Option<crate::protocol::DragToOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.frame
-            .locator_drag_to(
-                &self.selector,
-                &target.selector,
-                Some(self.with_timeout(options)),
-            )
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.frame
+                .locator_drag_to(
+                    &self.selector,
+                    &target.selector,
+                    Some(self.with_timeout(options)),
+                )
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Drops files and/or data onto this element (external drag-and-drop).
@@ -1764,11 +1980,14 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-drop>
     #[tracing::instrument(level = "info", skip_all, fields(selector = %self.selector))]
-    pub async fn drop(&self, options: crate::protocol::DropOptions) -> Result<()> {
-        self.frame
-            .locator_drop(&self.selector, self.with_timeout(Some(options)))
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn drop(&self, options: crate::protocol::DropOptions) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.frame
+                .locator_drop(&self.selector, self.with_timeout(Some(options)))
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Waits until the element satisfies the given state condition.
@@ -1789,15 +2008,18 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-wait-for>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn wait_for(
+    #[track_caller]
+    pub fn wait_for(
         &self,
         options: impl Into<Option<crate::protocol::WaitForOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.frame
-            .locator_wait_for(&self.selector, Some(self.with_timeout(options)))
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.frame
+                .locator_wait_for(&self.selector, Some(self.with_timeout(options)))
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Evaluates a JavaScript expression in the scope of the matched element.
@@ -1845,21 +2067,28 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-evaluate>
     #[tracing::instrument(level = "info", skip_all, fields(selector = %self.selector))]
-    pub async fn evaluate<R, T>(&self, expression: &str, arg: Option<T>) -> Result<R>
+    #[track_caller]
+    pub fn evaluate<R, T>(
+        &self,
+        expression: &str,
+        arg: Option<T>,
+    ) -> impl Future<Output = Result<R>>
     where
         R: serde::de::DeserializeOwned,
         T: serde::Serialize,
     {
-        let raw = self
-            .frame
-            .locator_evaluate(&self.selector, expression, arg)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))?;
-        serde_json::from_value(raw).map_err(|e| {
-            crate::error::Error::ProtocolError(format!(
-                "evaluate result deserialization failed: {}",
-                e
-            ))
+        CallSite::caller().run(async move {
+            let raw = self
+                .frame
+                .locator_evaluate(&self.selector, expression, arg)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))?;
+            serde_json::from_value(raw).map_err(|e| {
+                crate::error::Error::ProtocolError(format!(
+                    "evaluate result deserialization failed: {}",
+                    e
+                ))
+            })
         })
     }
 
@@ -1907,21 +2136,28 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-evaluate-all>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn evaluate_all<R, T>(&self, expression: &str, arg: Option<T>) -> Result<R>
+    #[track_caller]
+    pub fn evaluate_all<R, T>(
+        &self,
+        expression: &str,
+        arg: Option<T>,
+    ) -> impl Future<Output = Result<R>>
     where
         R: serde::de::DeserializeOwned,
         T: serde::Serialize,
     {
-        let raw = self
-            .frame
-            .locator_evaluate_all(&self.selector, expression, arg)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))?;
-        serde_json::from_value(raw).map_err(|e| {
-            crate::error::Error::ProtocolError(format!(
-                "evaluate_all result deserialization failed: {}",
-                e
-            ))
+        CallSite::caller().run(async move {
+            let raw = self
+                .frame
+                .locator_evaluate_all(&self.selector, expression, arg)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))?;
+            serde_json::from_value(raw).map_err(|e| {
+                crate::error::Error::ProtocolError(format!(
+                    "evaluate_all result deserialization failed: {}",
+                    e
+                ))
+            })
         })
     }
 
@@ -1939,15 +2175,18 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-aria-snapshot>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector, mode = tracing::field::Empty))]
-    pub async fn aria_snapshot(
+    #[track_caller]
+    pub fn aria_snapshot(
         &self,
         options: impl Into<Option<crate::protocol::AriaSnapshotOptions>>,
-    ) -> Result<String> {
-        let options = options.into();
-        self.frame
-            .locator_aria_snapshot(&self.selector, options.as_ref())
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    ) -> impl Future<Output = Result<String>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.frame
+                .locator_aria_snapshot(&self.selector, options.as_ref())
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Returns a new locator whose selector has been resolved to a
@@ -1968,16 +2207,19 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-normalize>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn normalize(&self) -> Result<Locator> {
-        let resolved = self
-            .frame
-            .frame_resolve_selector(&self.selector)
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))?;
-        Ok(Locator {
-            frame: Arc::clone(&self.frame),
-            selector: resolved,
-            page: self.page.clone(),
+    #[track_caller]
+    pub fn normalize(&self) -> impl Future<Output = Result<Locator>> {
+        CallSite::caller().run(async move {
+            let resolved = self
+                .frame
+                .frame_resolve_selector(&self.selector)
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))?;
+            Ok(Locator {
+                frame: Arc::clone(&self.frame),
+                selector: resolved,
+                page: self.page.clone(),
+            })
         })
     }
 
@@ -2013,13 +2255,19 @@ impl Locator {
     ///
     /// See: <https://playwright.dev/docs/api/class-locator#locator-highlight>
     #[tracing::instrument(level = "debug", skip_all, fields(selector = %self.selector))]
-    pub async fn highlight(&self, options: impl Into<Option<HighlightOptions>>) -> Result<()> {
-        let options = options.into();
-        let style = options.and_then(|o| o.style);
-        self.frame
-            .locator_highlight(&self.selector, style.as_deref())
-            .await
-            .map_err(|e| self.wrap_error_with_selector(e))
+    #[track_caller]
+    pub fn highlight(
+        &self,
+        options: impl Into<Option<HighlightOptions>>,
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let style = options.and_then(|o| o.style);
+            self.frame
+                .locator_highlight(&self.selector, style.as_deref())
+                .await
+                .map_err(|e| self.wrap_error_with_selector(e))
+        })
     }
 
     /// Returns a [`FrameLocator`](crate::protocol::FrameLocator) for the content of an
@@ -2293,4 +2541,102 @@ mod tests {
         assert_eq!(AriaRole::Progressbar.as_str(), "progressbar");
         assert_eq!(AriaRole::Treeitem.as_str(), "treeitem");
     }
+
+    /// `locator!` validates `role=` against its own copy of the role list;
+    /// a role added here and not there would be rejected at compile time.
+    /// The match has no wildcard, so a new variant fails to build until it
+    /// is added here too.
+    #[cfg(feature = "macros")]
+    #[test]
+    fn test_locator_macro_accepts_every_aria_role() {
+        for role in AriaRole::ALL {
+            let selector = match role {
+                AriaRole::Alert => playwright_rs_macros::locator!("role=alert"),
+                AriaRole::Alertdialog => playwright_rs_macros::locator!("role=alertdialog"),
+                AriaRole::Application => playwright_rs_macros::locator!("role=application"),
+                AriaRole::Article => playwright_rs_macros::locator!("role=article"),
+                AriaRole::Banner => playwright_rs_macros::locator!("role=banner"),
+                AriaRole::Blockquote => playwright_rs_macros::locator!("role=blockquote"),
+                AriaRole::Button => playwright_rs_macros::locator!("role=button"),
+                AriaRole::Caption => playwright_rs_macros::locator!("role=caption"),
+                AriaRole::Cell => playwright_rs_macros::locator!("role=cell"),
+                AriaRole::Checkbox => playwright_rs_macros::locator!("role=checkbox"),
+                AriaRole::Code => playwright_rs_macros::locator!("role=code"),
+                AriaRole::Columnheader => playwright_rs_macros::locator!("role=columnheader"),
+                AriaRole::Combobox => playwright_rs_macros::locator!("role=combobox"),
+                AriaRole::Complementary => playwright_rs_macros::locator!("role=complementary"),
+                AriaRole::Contentinfo => playwright_rs_macros::locator!("role=contentinfo"),
+                AriaRole::Definition => playwright_rs_macros::locator!("role=definition"),
+                AriaRole::Deletion => playwright_rs_macros::locator!("role=deletion"),
+                AriaRole::Dialog => playwright_rs_macros::locator!("role=dialog"),
+                AriaRole::Directory => playwright_rs_macros::locator!("role=directory"),
+                AriaRole::Document => playwright_rs_macros::locator!("role=document"),
+                AriaRole::Emphasis => playwright_rs_macros::locator!("role=emphasis"),
+                AriaRole::Feed => playwright_rs_macros::locator!("role=feed"),
+                AriaRole::Figure => playwright_rs_macros::locator!("role=figure"),
+                AriaRole::Form => playwright_rs_macros::locator!("role=form"),
+                AriaRole::Generic => playwright_rs_macros::locator!("role=generic"),
+                AriaRole::Grid => playwright_rs_macros::locator!("role=grid"),
+                AriaRole::Gridcell => playwright_rs_macros::locator!("role=gridcell"),
+                AriaRole::Group => playwright_rs_macros::locator!("role=group"),
+                AriaRole::Heading => playwright_rs_macros::locator!("role=heading"),
+                AriaRole::Img => playwright_rs_macros::locator!("role=img"),
+                AriaRole::Insertion => playwright_rs_macros::locator!("role=insertion"),
+                AriaRole::Link => playwright_rs_macros::locator!("role=link"),
+                AriaRole::List => playwright_rs_macros::locator!("role=list"),
+                AriaRole::Listbox => playwright_rs_macros::locator!("role=listbox"),
+                AriaRole::Listitem => playwright_rs_macros::locator!("role=listitem"),
+                AriaRole::Log => playwright_rs_macros::locator!("role=log"),
+                AriaRole::Main => playwright_rs_macros::locator!("role=main"),
+                AriaRole::Marquee => playwright_rs_macros::locator!("role=marquee"),
+                AriaRole::Math => playwright_rs_macros::locator!("role=math"),
+                AriaRole::Meter => playwright_rs_macros::locator!("role=meter"),
+                AriaRole::Menu => playwright_rs_macros::locator!("role=menu"),
+                AriaRole::Menubar => playwright_rs_macros::locator!("role=menubar"),
+                AriaRole::Menuitem => playwright_rs_macros::locator!("role=menuitem"),
+                AriaRole::Menuitemcheckbox => {
+                    playwright_rs_macros::locator!("role=menuitemcheckbox")
+                }
+                AriaRole::Menuitemradio => playwright_rs_macros::locator!("role=menuitemradio"),
+                AriaRole::Navigation => playwright_rs_macros::locator!("role=navigation"),
+                AriaRole::None => playwright_rs_macros::locator!("role=none"),
+                AriaRole::Note => playwright_rs_macros::locator!("role=note"),
+                AriaRole::Option => playwright_rs_macros::locator!("role=option"),
+                AriaRole::Paragraph => playwright_rs_macros::locator!("role=paragraph"),
+                AriaRole::Presentation => playwright_rs_macros::locator!("role=presentation"),
+                AriaRole::Progressbar => playwright_rs_macros::locator!("role=progressbar"),
+                AriaRole::Radio => playwright_rs_macros::locator!("role=radio"),
+                AriaRole::Radiogroup => playwright_rs_macros::locator!("role=radiogroup"),
+                AriaRole::Region => playwright_rs_macros::locator!("role=region"),
+                AriaRole::Row => playwright_rs_macros::locator!("role=row"),
+                AriaRole::Rowgroup => playwright_rs_macros::locator!("role=rowgroup"),
+                AriaRole::Rowheader => playwright_rs_macros::locator!("role=rowheader"),
+                AriaRole::Scrollbar => playwright_rs_macros::locator!("role=scrollbar"),
+                AriaRole::Search => playwright_rs_macros::locator!("role=search"),
+                AriaRole::Searchbox => playwright_rs_macros::locator!("role=searchbox"),
+                AriaRole::Separator => playwright_rs_macros::locator!("role=separator"),
+                AriaRole::Slider => playwright_rs_macros::locator!("role=slider"),
+                AriaRole::Spinbutton => playwright_rs_macros::locator!("role=spinbutton"),
+                AriaRole::Status => playwright_rs_macros::locator!("role=status"),
+                AriaRole::Strong => playwright_rs_macros::locator!("role=strong"),
+                AriaRole::Subscript => playwright_rs_macros::locator!("role=subscript"),
+                AriaRole::Superscript => playwright_rs_macros::locator!("role=superscript"),
+                AriaRole::Switch => playwright_rs_macros::locator!("role=switch"),
+                AriaRole::Tab => playwright_rs_macros::locator!("role=tab"),
+                AriaRole::Table => playwright_rs_macros::locator!("role=table"),
+                AriaRole::Tablist => playwright_rs_macros::locator!("role=tablist"),
+                AriaRole::Tabpanel => playwright_rs_macros::locator!("role=tabpanel"),
+                AriaRole::Term => playwright_rs_macros::locator!("role=term"),
+                AriaRole::Textbox => playwright_rs_macros::locator!("role=textbox"),
+                AriaRole::Time => playwright_rs_macros::locator!("role=time"),
+                AriaRole::Timer => playwright_rs_macros::locator!("role=timer"),
+                AriaRole::Toolbar => playwright_rs_macros::locator!("role=toolbar"),
+                AriaRole::Tooltip => playwright_rs_macros::locator!("role=tooltip"),
+                AriaRole::Tree => playwright_rs_macros::locator!("role=tree"),
+                AriaRole::Treegrid => playwright_rs_macros::locator!("role=treegrid"),
+                AriaRole::Treeitem => playwright_rs_macros::locator!("role=treeitem"),
+            };
+            assert_eq!(selector, format!("role={}", role.as_str()));
+        }
+    }
 }
@@ -58,16 +58,22 @@ pub mod frame;
 pub mod frame_locator;
 pub mod glob;
 pub mod har_options;
+pub(crate) mod har_update;
 pub mod js_handle;
 pub mod keyboard;
 pub mod local_utils;
 pub mod locator;
 pub(crate) mod mime;
 pub mod mouse;
+pub mod network_conditions;
+pub mod network_recorder;
 pub mod page;
+pub mod page_object;
 pub mod playwright;
 pub mod proxy;
+pub mod recorder;
 pub mod request;
+pub mod request_blocking;
 pub mod resource_timing;
 pub mod response;
 pub mod root;
@@ -79,6 +85,7 @@ pub mod selectors;
 pub mod tap;
 pub mod touchscreen;
 pub mod tracing;
+pub mod url_matcher;
 pub mod video;
 pub mod wait_for;
 pub mod web_error;
@@ -139,21 +146,26 @@ pub use locator::{
     AriaRole, BoundingBox, FilterOptions, GetByRoleOptions, HighlightOptions, Locator,
 };
 pub use mouse::Mouse;
+pub use network_conditions::{NetworkConditions, NetworkPreset};
+pub use network_recorder::{NetworkEntry, NetworkEntryState, NetworkRecorder};
 pub use page::{
     AddLocatorHandlerOptions, AddScriptTagOptions, AddScriptTagOptionsBuilder, AddStyleTagOptions,
     ColorScheme, EmulateMediaOptions, EmulateMediaOptionsBuilder, ForcedColors, GotoOptions, Media,
     Page, PdfMargin, PdfOptions, PdfOptionsBuilder, ReducedMotion, Response, RouteFromHarOptions,
     WaitUntil,
 };
+pub use page_object::{LocatorRoot, PageObject};
 pub use playwright::Playwright;
 pub use proxy::ProxySettings;
+pub use recorder::{Action, RecordedAction, RecordedFrame, RecorderEvent, RecorderOptions, Signal};
 pub use request::Request;
+pub use request_blocking::{BlockReason, BlockedRequest};
 pub use resource_timing::ResourceTiming;
 pub use response::{HeaderEntry, RemoteAddr, RequestSizes, ResponseObject, SecurityDetails};
 pub use root::Root;
 pub use route::{
     ContinueOptions, ContinueOptionsBuilder, FetchOptions, FetchOptionsBuilder, FetchResponse,
-    FulfillOptions, FulfillOptionsBuilder, Route, UnrouteBehavior,
+    FulfillOptions, FulfillOptionsBuilder, Route, RouteHandle, RouteOptions, UnrouteBehavior,
 };
 pub use screencast::{
     ActionCursor, ActionPosition, ChapterOptions, OverlayId, Screencast, ScreencastFrame,
@@ -164,7 +176,11 @@ pub use select_option::SelectOption;
 pub use selectors::Selectors;
 pub use tap::{TapOptions, TapOptionsBuilder};
 pub use touchscreen::Touchscreen;
-pub use tracing::{Tracing, TracingStartOptions, TracingStopOptions};
+pub use tracing::{
+    Tracing, TracingGroupLocation, TracingStartChunkOptions, TracingStartOptions,
+    TracingStopOptions,
+};
+pub use url_matcher::UrlMatcher;
 pub use video::Video;
 pub use wait_for::{WaitForFunctionOptions, WaitForOptions, WaitForOptionsBuilder, WaitForState};
 pub use web_error::{WebError, WebErrorLocation};
@@ -4,6 +4,7 @@
 
 use crate::error::Result;
 use crate::protocol::page::Page;
+use crate::server::call_site::CallSite;
 
 /// Mouse provides low-level mouse control.
 ///
@@ -33,68 +34,84 @@ impl Mouse {
     /// unaffected.
     ///
     /// See: <https://playwright.dev/docs/api/class-mouse#mouse-move>
-    pub async fn move_to(
+    #[track_caller]
+    pub fn move_to(
         &self,
         x: f64,
         y: f64,
         options: impl Into<Option<crate::protocol::MouseOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.page.mouse_move(x, y, options).await
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.page.mouse_move(x, y, options).await
+        })
     }
 
     /// Combines `move()`, `down()`, and `up()` actions.
     ///
     /// See: <https://playwright.dev/docs/api/class-mouse#mouse-click>
-    pub async fn click(
+    #[track_caller]
+    pub fn click(
         &self,
         x: f64,
         y: f64,
         options: impl Into<Option<crate::protocol::MouseOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.page.mouse_click(x, y, options).await
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.page.mouse_click(x, y, options).await
+        })
     }
 
     /// Shortcut performing `move()`, `down()`, `up()`, `down()`, and `up()` sequentially.
     ///
     /// See: <https://playwright.dev/docs/api/class-mouse#mouse-dblclick>
-    pub async fn dblclick(
+    #[track_caller]
+    pub fn dblclick(
         &self,
         x: f64,
         y: f64,
         options: impl Into<Option<crate::protocol::MouseOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.page.mouse_dblclick(x, y, options).await
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.page.mouse_dblclick(x, y, options).await
+        })
     }
 
     /// Dispatches a `mousedown` event.
     ///
     /// See: <https://playwright.dev/docs/api/class-mouse#mouse-down>
-    pub async fn down(
+    #[track_caller]
+    pub fn down(
         &self,
         options: impl Into<Option<crate::protocol::MouseOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.page.mouse_down(options).await
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.page.mouse_down(options).await
+        })
     }
 
     /// Dispatches a `mouseup` event.
     ///
     /// See: <https://playwright.dev/docs/api/class-mouse#mouse-up>
-    pub async fn up(
+    #[track_caller]
+    pub fn up(
         &self,
         options: impl Into<Option<crate::protocol::MouseOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        self.page.mouse_up(options).await
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.page.mouse_up(options).await
+        })
     }
 
     /// Dispatches a `wheel` event for manual page scrolling.
     ///
     /// See: <https://playwright.dev/docs/api/class-mouse#mouse-wheel>
-    pub async fn wheel(&self, delta_x: f64, delta_y: f64) -> Result<()> {
-        self.page.mouse_wheel(delta_x, delta_y).await
+    #[track_caller]
+    pub fn wheel(&self, delta_x: f64, delta_y: f64) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move { self.page.mouse_wheel(delta_x, delta_y).await })
     }
 }
@@ -0,0 +1,120 @@
+// Network throttling and latency emulation
+//
+// Chromium throttles through CDP `Network.emulateNetworkConditions`, the
+// same call DevTools' "Slow 3G" menu makes. Firefox and WebKit have no
+// equivalent Playwright can reach, so only latency is emulated there, by
+// delaying each request in a context route.
+//
+// Architecture Reference:
+// - CDP: https://chromedevtools.github.io/devtools-protocol/tot/Network/#method-emulateNetworkConditions
+// - Presets: chromium/src/third_party/devtools-frontend/src/front_end/core/sdk/NetworkManager.ts
+
+use std::time::Duration;
+
+/// Throughput and latency for
+/// [`BrowserContext::set_network_conditions`](crate::protocol::BrowserContext::set_network_conditions).
+///
+/// Throughputs are in bytes per second; `None` leaves that direction
+/// unthrottled.
+///
+/// # Example
+///
+/// ```
+/// use playwright_rs::{NetworkConditions, NetworkPreset};
+/// use std::time::Duration;
+///
+/// let custom = NetworkConditions::default()
+///     .latency(Duration::from_millis(300))
+///     .download_throughput(250_000);
+/// let slow: NetworkConditions = NetworkPreset::Slow3G.into();
+/// assert_eq!(slow.latency, Some(Duration::from_secs(2)));
+/// # let _ = custom;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct NetworkConditions {
+    /// Minimum time from sending a request to receiving its response
+    pub latency: Option<Duration>,
+    /// Maximum download throughput, in bytes per second (Chromium only)
+    pub download_throughput: Option<u64>,
+    /// Maximum upload throughput, in bytes per second (Chromium only)
+    pub upload_throughput: Option<u64>,
+}
+
+impl NetworkConditions {
+    /// Sets the added latency
+    pub fn latency(mut self, latency: Duration) -> Self {
+        self.latency = Some(latency);
+        self
+    }
+
+    /// Sets the download throughput in bytes per second
+    pub fn download_throughput(mut self, bytes_per_second: u64) -> Self {
+        self.download_throughput = Some(bytes_per_second);
+        self
+    }
+
+    /// Sets the upload throughput in bytes per second
+    pub fn upload_throughput(mut self, bytes_per_second: u64) -> Self {
+        self.upload_throughput = Some(bytes_per_second);
+        self
+    }
+
+    /// The `Network.emulateNetworkConditions` parameters. `-1` disables
+    /// throttling in that direction.
+    pub(crate) fn to_cdp_params(&self) -> serde_json::Value {
+        let throughput = |t: Option<u64>| t.map_or(-1.0, |t| t as f64);
+        serde_json::json!({
+            "offline": false,
+            "latency": self.latency.map_or(0.0, |l| l.as_secs_f64() * 1000.0),
+            "downloadThroughput": throughput(self.download_throughput),
+            "uploadThroughput": throughput(self.upload_throughput),
+        })
+    }
+}
+
+/// The throttling presets of Chrome DevTools' network panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NetworkPreset {
+    /// 2 s latency, 50 kB/s down and up
+    Slow3G,
+    /// 562.5 ms latency, 180 kB/s down, 84.375 kB/s up
+    Fast3G,
+}
+
+impl From<NetworkPreset> for NetworkConditions {
+    fn from(preset: NetworkPreset) -> Self {
+        // DevTools' figures, including its 5x / 3.75x latency and 0.8 / 0.9
+        // throughput adjustments for real-world conditions.
+        match preset {
+            NetworkPreset::Slow3G => NetworkConditions::default()
+                .latency(Duration::from_millis(2000))
+                .download_throughput(50_000)
+                .upload_throughput(50_000),
+            NetworkPreset::Fast3G => NetworkConditions::default()
+                .latency(Duration::from_micros(562_500))
+                .download_throughput(180_000)
+                .upload_throughput(84_375),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cdp_params_disable_unset_throughput() {
+        let params = NetworkConditions::default()
+            .latency(Duration::from_millis(150))
+            .to_cdp_params();
+        assert_eq!(params["latency"], 150.0);
+        assert_eq!(params["downloadThroughput"], -1.0);
+        assert_eq!(params["uploadThroughput"], -1.0);
+
+        let params = NetworkConditions::from(NetworkPreset::Fast3G).to_cdp_params();
+        assert_eq!(params["latency"], 562.5);
+        assert_eq!(params["downloadThroughput"], 180_000.0);
+    }
+}
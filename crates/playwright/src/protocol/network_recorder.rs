@@ -0,0 +1,735 @@
+// Network traffic recorder
+//
+// `on_request`, `on_response`, `on_request_finished` and `on_request_failed`
+// each see one moment of a request's life. `NetworkRecorder` listens to all
+// four on a page or context and keeps one entry per request, with its
+// response, timing, sizes, failure, frame and redirect links.
+//
+// The four events are dispatched concurrently, so an entry is created by
+// whichever of them arrives first and the others fill it in.
+//
+// No other Playwright binding has this. The HAR export follows the shape of
+// Playwright's own recorder.
+//
+// Architecture Reference:
+// - JavaScript: playwright/packages/playwright-core/src/server/har/harTracer.ts
+
+use crate::error::{Error, Result};
+use crate::har::{self, Har};
+use crate::protocol::response::RequestSizes;
+use crate::protocol::url_matcher::UrlMatcher;
+use crate::protocol::{BrowserContext, Page, Request, ResourceTiming, ResponseObject};
+use crate::server::channel_owner::ChannelOwner;
+use crate::server::connection::ConnectionExt;
+use serde::Serialize;
+use serde_json::Map;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::Notify;
+
+/// How long [`NetworkRecorder::wait_for_network_idle`] waits in all.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Where a recorded request has got to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum NetworkEntryState {
+    /// Sent, and not yet finished or failed
+    Pending,
+    /// Its response was fully received
+    Finished,
+    /// It failed; see [`NetworkEntry::failure`]
+    Failed,
+}
+
+/// One request seen by a [`NetworkRecorder`], with what became of it.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct NetworkEntry {
+    /// Position in [`NetworkRecorder::entries`]
+    pub index: usize,
+    /// The full request URL
+    pub url: String,
+    /// The request method
+    pub method: String,
+    /// The request's resource type, such as `"document"` or `"fetch"`
+    pub resource_type: String,
+    /// Whether the request drives a frame's navigation
+    pub is_navigation_request: bool,
+    /// Name of the frame that made the request
+    pub frame_name: Option<String>,
+    /// URL of the frame that made the request, when it was made
+    pub frame_url: Option<String>,
+    /// When the request started, in milliseconds since the Unix epoch
+    pub started_at: f64,
+    /// The request headers
+    pub request_headers: HashMap<String, String>,
+    /// The request body as text, if it had one
+    pub post_data: Option<String>,
+    /// The response status, once a response arrived
+    pub status: Option<u16>,
+    /// The response status text
+    pub status_text: String,
+    /// The response headers
+    pub response_headers: HashMap<String, String>,
+    /// Phase timings, once the request finished
+    pub timing: Option<ResourceTiming>,
+    /// Header and body sizes, once the request finished
+    pub sizes: Option<RequestSizes>,
+    /// Whether the request is still in flight, finished or failed
+    pub state: NetworkEntryState,
+    /// The error text of a failed request
+    pub failure: Option<String>,
+    /// Index of the entry this request was redirected from
+    pub redirected_from: Option<usize>,
+    /// Index of the entry this request was redirected to
+    pub redirected_to: Option<usize>,
+}
+
+impl NetworkEntry {
+    /// Time from the start of the request to the last byte of the
+    /// response, once it finished.
+    pub fn duration(&self) -> Option<Duration> {
+        let end = self.timing.as_ref()?.response_end;
+        (end >= 0.0).then(|| Duration::from_secs_f64(end / 1000.0))
+    }
+
+    fn new(index: usize, request: &Request) -> Self {
+        let frame = request.frame();
+        Self {
+            index,
+            url: request.url().to_string(),
+            method: request.method().to_string(),
+            resource_type: request.resource_type().to_string(),
+            is_navigation_request: request.is_navigation_request(),
+            frame_name: frame.as_ref().map(|f| f.name().to_string()),
+            frame_url: frame.as_ref().map(|f| f.url()),
+            started_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0.0, |d| d.as_secs_f64() * 1000.0),
+            request_headers: request.headers(),
+            post_data: request.post_data(),
+            status: None,
+            status_text: String::new(),
+            response_headers: HashMap::new(),
+            timing: None,
+            sizes: None,
+            state: NetworkEntryState::Pending,
+            failure: None,
+            redirected_from: None,
+            redirected_to: None,
+        }
+    }
+
+    fn to_har_entry(&self, redirect_url: Option<&str>) -> har::Entry {
+        let timings = self.timing.as_ref().map(har_timings).unwrap_or_default();
+        let sizes = self.sizes.as_ref();
+        let mut extra = Map::new();
+        extra.insert("_resourceType".into(), self.resource_type.clone().into());
+        if let Some(failure) = &self.failure {
+            extra.insert("_failureText".into(), failure.clone().into());
+        }
+
+        har::Entry {
+            started_date_time: iso_8601(self.started_at),
+            time: timings.total(),
+            request: har::Request {
+                method: self.method.clone(),
+                url: self.url.clone(),
+                headers: har_headers(&self.request_headers),
+                query_string: url::Url::parse(&self.url)
+                    .map(|url| {
+                        url.query_pairs()
+                            .map(|(name, value)| har::QueryParam {
+                                name: name.into_owned(),
+                                value: value.into_owned(),
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                post_data: self.post_data.as_ref().map(|text| har::PostData {
+                    mime_type: header(&self.request_headers, "content-type").to_string(),
+                    text: Some(text.clone()),
+                    ..Default::default()
+                }),
+                headers_size: sizes.map_or(-1, |s| s.request_headers_size),
+                body_size: sizes.map_or(-1, |s| s.request_body_size),
+                ..Default::default()
+            },
+            response: har::Response {
+                status: self.status.unwrap_or(0),
+                status_text: self.status_text.clone(),
+                headers: har_headers(&self.response_headers),
+                content: har::Content {
+                    size: sizes.map_or(-1, |s| s.response_body_size),
+                    mime_type: header(&self.response_headers, "content-type").to_string(),
+                    ..Default::default()
+                },
+                redirect_url: redirect_url.unwrap_or_default().to_string(),
+                headers_size: sizes.map_or(-1, |s| s.response_headers_size),
+                body_size: sizes.map_or(-1, |s| s.response_body_size),
+                ..Default::default()
+            },
+            timings,
+            extra,
+            ..Default::default()
+        }
+    }
+}
+
+/// Subscribes a [`Recording`] to the four network events of a page or
+/// context; their `on_*` methods have the same shape on both.
+macro_rules! listen {
+    ($target:expr, $recording:expr) => {{
+        let recording = $recording.clone();
+        $target
+            .on_request(move |request| {
+                let recording = recording.clone();
+                async move {
+                    recording.request(&request);
+                    Ok(())
+                }
+            })
+            .await?;
+        let recording = $recording.clone();
+        $target
+            .on_response(move |response| {
+                let recording = recording.clone();
+                async move {
+                    recording.response(response).await;
+                    Ok(())
+                }
+            })
+            .await?;
+        let recording = $recording.clone();
+        $target
+            .on_request_finished(move |request| {
+                let recording = recording.clone();
+                async move {
+                    recording.finished(request).await;
+                    Ok(())
+                }
+            })
+            .await?;
+        let recording = $recording.clone();
+        $target
+            .on_request_failed(move |request| {
+                let recording = recording.clone();
+                async move {
+                    recording.failed(&request);
+                    Ok(())
+                }
+            })
+            .await?;
+    }};
+}
+
+/// Records the network traffic of a page or context as one
+/// [`NetworkEntry`] per request.
+///
+/// Redirects are separate entries linked through
+/// [`redirected_from`](NetworkEntry::redirected_from) and
+/// [`redirected_to`](NetworkEntry::redirected_to). Cloning gives another
+/// handle to the same recording.
+///
+/// # Example
+///
+/// ```no_run
+/// # use playwright_rs::{NetworkRecorder, Playwright};
+/// # use std::time::Duration;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// # let playwright = Playwright::launch().await?;
+/// # let browser = playwright.chromium().launch().await?;
+/// # let page = browser.new_page().await?;
+/// let recorder = NetworkRecorder::start(&page).await?;
+/// page.goto("https://example.com", None).await?;
+/// recorder.wait_for_network_idle(Duration::from_millis(500)).await?;
+///
+/// assert!(recorder.failed().is_empty());
+/// for entry in recorder.entries_matching("**/api/**") {
+///     println!("{} {} {:?}", entry.method, entry.url, entry.duration());
+/// }
+/// recorder.to_har().write("traffic.har").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct NetworkRecorder {
+    inner: Arc<Recording>,
+}
+
+impl NetworkRecorder {
+    /// Starts recording the requests `page` makes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if subscribing to the page's network events fails.
+    pub async fn start(page: &Page) -> Result<Self> {
+        let recorder = Self::new();
+        listen!(page, recorder.inner);
+        Ok(recorder)
+    }
+
+    /// Starts recording the requests of every page in `context`, including
+    /// pages opened later.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if subscribing to the context's network events fails.
+    pub async fn start_on_context(context: &BrowserContext) -> Result<Self> {
+        let recorder = Self::new();
+        listen!(context, recorder.inner);
+        Ok(recorder)
+    }
+
+    fn new() -> Self {
+        Self {
+            inner: Arc::new(Recording {
+                active: AtomicBool::new(true),
+                state: Mutex::new(State {
+                    entries: Vec::new(),
+                    by_guid: HashMap::new(),
+                    last_activity: Instant::now(),
+                }),
+                activity: Notify::new(),
+            }),
+        }
+    }
+
+    /// Stops recording. Requests already recorded keep being completed, but
+    /// no new ones are added.
+    pub fn stop(&self) {
+        self.inner.active.store(false, Ordering::SeqCst);
+    }
+
+    /// Every recorded request, in the order they were seen.
+    pub fn entries(&self) -> Vec<NetworkEntry> {
+        self.inner.state.lock().unwrap().entries.clone()
+    }
+
+    /// The recorded requests whose URL matches `url`.
+    pub fn entries_matching(&self, url: impl Into<UrlMatcher>) -> Vec<NetworkEntry> {
+        let url = url.into();
+        self.filter(|entry| url.matches(&entry.url))
+    }
+
+    /// The recorded requests of `resource_type`, such as `"fetch"` or
+    /// `"image"`.
+    pub fn entries_of_type(&self, resource_type: &str) -> Vec<NetworkEntry> {
+        self.filter(|entry| entry.resource_type.eq_ignore_ascii_case(resource_type))
+    }
+
+    /// The recorded requests that failed.
+    pub fn failed(&self) -> Vec<NetworkEntry> {
+        self.filter(|entry| entry.state == NetworkEntryState::Failed)
+    }
+
+    /// The redirect chain `entry` is part of, from the first request to the
+    /// last.
+    pub fn redirect_chain(&self, entry: &NetworkEntry) -> Vec<NetworkEntry> {
+        let state = self.inner.state.lock().unwrap();
+        redirect_chain(&state.entries, entry.index)
+    }
+
+    fn filter(&self, keep: impl Fn(&NetworkEntry) -> bool) -> Vec<NetworkEntry> {
+        let state = self.inner.state.lock().unwrap();
+        state.entries.iter().filter(|e| keep(e)).cloned().collect()
+    }
+
+    /// Waits until no recorded request has been in flight for
+    /// `quiet_period`.
+    ///
+    /// A request that never completes, such as a long poll, keeps the
+    /// network busy; [`stop`](Self::stop) does not change that.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Timeout`] if the network is not idle within 30
+    /// seconds.
+    pub async fn wait_for_network_idle(&self, quiet_period: Duration) -> Result<()> {
+        let idle = async {
+            loop {
+                let activity = self.inner.activity.notified();
+                tokio::pin!(activity);
+                activity.as_mut().enable();
+                let quiet = self.inner.state.lock().unwrap().quiet_for();
+                match quiet {
+                    Some(quiet) if quiet >= quiet_period => return,
+                    Some(quiet) => {
+                        tokio::select! {
+                            _ = activity => {}
+                            _ = tokio::time::sleep(quiet_period - quiet) => {}
+                        }
+                    }
+                    None => activity.await,
+                }
+            }
+        };
+        tokio::time::timeout(IDLE_TIMEOUT, idle).await.map_err(|_| {
+            let pending = self.filter(|e| e.state == NetworkEntryState::Pending);
+            let urls: Vec<&str> = pending.iter().map(|e| e.url.as_str()).collect();
+            Error::Timeout(format!(
+                "network not idle for {}ms within {}ms; {} request(s) pending: {}",
+                quiet_period.as_millis(),
+                IDLE_TIMEOUT.as_millis(),
+                urls.len(),
+                urls.join(", ")
+            ))
+        })
+    }
+
+    /// The recording as a HAR archive. Bodies are not recorded, so entries
+    /// carry sizes but no content.
+    pub fn to_har(&self) -> Har {
+        let state = self.inner.state.lock().unwrap();
+        to_har(&state.entries)
+    }
+
+    /// The recording as a pretty-printed JSON array of entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entries cannot be serialized.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&self.entries())?)
+    }
+}
+
+/// The state shared by a recorder's handles and its event handlers.
+#[derive(Debug)]
+struct Recording {
+    active: AtomicBool,
+    state: Mutex<State>,
+    /// Woken on every event, for `wait_for_network_idle`
+    activity: Notify,
+}
+
+#[derive(Debug)]
+struct State {
+    entries: Vec<NetworkEntry>,
+    /// Entry index by request GUID
+    by_guid: HashMap<String, usize>,
+    last_activity: Instant,
+}
+
+impl State {
+    /// The index of `request`'s entry, adding it (and the requests it was
+    /// redirected from) if they are not recorded yet.
+    fn index_of(&mut self, request: &Request) -> usize {
+        if let Some(&index) = self.by_guid.get(request.guid()) {
+            return index;
+        }
+        let from = request
+            .redirected_from()
+            .map(|previous| self.index_of(&previous));
+        let index = self.entries.len();
+        let mut entry = NetworkEntry::new(index, request);
+        entry.redirected_from = from;
+        self.entries.push(entry);
+        self.by_guid.insert(request.guid().to_string(), index);
+        if let Some(from) = from {
+            self.entries[from].redirected_to = Some(index);
+        }
+        index
+    }
+
+    /// How long no request has been in flight, or `None` if one is.
+    fn quiet_for(&self) -> Option<Duration> {
+        let busy = self
+            .entries
+            .iter()
+            .any(|e| e.state == NetworkEntryState::Pending);
+        (!busy).then(|| self.last_activity.elapsed())
+    }
+}
+
+impl Recording {
+    /// Runs `update` on `request`'s entry. Requests not yet recorded are
+    /// only added while the recorder is active.
+    fn update(&self, request: &Request, update: impl FnOnce(&mut NetworkEntry)) {
+        let mut state = self.state.lock().unwrap();
+        if !state.by_guid.contains_key(request.guid()) && !self.active.load(Ordering::SeqCst) {
+            return;
+        }
+        let index = state.index_of(request);
+        update(&mut state.entries[index]);
+        state.last_activity = Instant::now();
+        drop(state);
+        self.activity.notify_waiters();
+    }
+
+    fn request(&self, request: &Request) {
+        self.update(request, |_| {});
+    }
+
+    async fn response(&self, response: ResponseObject) {
+        let Some(guid) = response
+            .initializer()
+            .get("request")
+            .and_then(|v| v.get("guid"))
+            .and_then(|v| v.as_str())
+        else {
+            return;
+        };
+        let Ok(request) = response.connection().get_typed::<Request>(guid).await else {
+            return;
+        };
+        let headers = response
+            .initializer()
+            .get("headers")
+            .and_then(|v| v.as_array())
+            .map(|headers| {
+                headers
+                    .iter()
+                    .filter_map(|h| {
+                        Some((
+                            h.get("name")?.as_str()?.to_string(),
+                            h.get("value")?.as_str()?.to_string(),
+                        ))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.update(&request, |entry| {
+            entry.status = Some(response.status());
+            entry.status_text = response.status_text().to_string();
+            entry.response_headers = headers;
+        });
+    }
+
+    async fn finished(&self, request: Request) {
+        let timing = request.timing().await.ok();
+        let sizes = request.sizes().await.ok();
+        let response = request.existing_response();
+        self.update(&request, |entry| {
+            if let Some(start) = timing.as_ref().map(|t| t.start_time)
+                && start > 0.0
+            {
+                entry.started_at = start;
+            }
+            if entry.status.is_none()
+                && let Some(response) = response
+            {
+                entry.status = Some(response.status());
+                entry.status_text = response.status_text().to_string();
+                entry.response_headers = response.headers().clone();
+            }
+            entry.timing = timing;
+            entry.sizes = sizes;
+            entry.state = NetworkEntryState::Finished;
+        });
+    }
+
+    fn failed(&self, request: &Request) {
+        let failure = request.failure();
+        self.update(request, |entry| {
+            entry.failure = failure;
+            entry.state = NetworkEntryState::Failed;
+        });
+    }
+}
+
+fn redirect_chain(entries: &[NetworkEntry], index: usize) -> Vec<NetworkEntry> {
+    let mut first = index;
+    while let Some(previous) = entries[first].redirected_from {
+        first = previous;
+    }
+    let mut chain = vec![entries[first].clone()];
+    while let Some(next) = chain.last().and_then(|e| e.redirected_to) {
+        chain.push(entries[next].clone());
+    }
+    chain
+}
+
+fn to_har(entries: &[NetworkEntry]) -> Har {
+    Har {
+        log: har::Log {
+            version: "1.2".to_string(),
+            creator: Some(har::Creator {
+                name: "playwright-rs".to_string(),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                ..Default::default()
+            }),
+            entries: entries
+                .iter()
+                .map(|entry| {
+                    let redirect_url = entry.redirected_to.map(|i| entries[i].url.as_str());
+                    entry.to_har_entry(redirect_url)
+                })
+                .collect(),
+            ..Default::default()
+        },
+    }
+}
+
+/// HAR phases from a resource timing, whose fields are offsets from its
+/// start and `-1` for a phase that was not reached.
+fn har_timings(timing: &ResourceTiming) -> har::Timings {
+    let phase = |start: f64, end: f64| (start >= 0.0 && end >= start).then_some(end - start);
+    har::Timings {
+        dns: Some(phase(timing.domain_lookup_start, timing.domain_lookup_end).unwrap_or(-1.0)),
+        connect: Some(phase(timing.connect_start, timing.connect_end).unwrap_or(-1.0)),
+        ssl: Some(phase(timing.secure_connection_start, timing.connect_end).unwrap_or(-1.0)),
+        wait: phase(timing.request_start, timing.response_start).unwrap_or(0.0),
+        receive: phase(timing.response_start, timing.response_end).unwrap_or(0.0),
+        ..Default::default()
+    }
+}
+
+/// Headers sorted by name, so an exported HAR is stable.
+fn har_headers(headers: &HashMap<String, String>) -> Vec<har::Header> {
+    let mut headers: Vec<_> = headers
+        .iter()
+        .map(|(name, value)| har::Header::new(name.clone(), value.clone()))
+        .collect();
+    headers.sort_by(|a, b| a.name.cmp(&b.name));
+    headers
+}
+
+fn header<'a>(headers: &'a HashMap<String, String>, name: &str) -> &'a str {
+    headers
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map_or("", |(_, v)| v.as_str())
+}
+
+/// Milliseconds since the Unix epoch as an ISO 8601 UTC date, such as
+/// `2023-11-14T22:13:20.123Z`.
+fn iso_8601(ms_since_epoch: f64) -> String {
+    let ms = ms_since_epoch.max(0.0) as i64;
+    let (secs, millis) = (ms / 1000, ms % 1000);
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+    // Howard Hinnant's days-to-civil algorithm.
+    let z = days + 719_468;
+    let (era, doe) = (z / 146_097, z % 146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{millis:03}Z",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(index: usize, url: &str) -> NetworkEntry {
+        NetworkEntry {
+            index,
+            url: url.to_string(),
+            method: "GET".to_string(),
+            resource_type: "document".to_string(),
+            is_navigation_request: true,
+            frame_name: Some(String::new()),
+            frame_url: None,
+            started_at: 1_700_000_000_123.0,
+            request_headers: HashMap::new(),
+            post_data: None,
+            status: None,
+            status_text: String::new(),
+            response_headers: HashMap::new(),
+            timing: None,
+            sizes: None,
+            state: NetworkEntryState::Pending,
+            failure: None,
+            redirected_from: None,
+            redirected_to: None,
+        }
+    }
+
+    #[test]
+    fn iso_8601_formats_utc_milliseconds() {
+        assert_eq!(iso_8601(0.0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(iso_8601(951_782_400_000.0), "2000-02-29T00:00:00.000Z");
+        assert_eq!(iso_8601(1_700_000_000_123.0), "2023-11-14T22:13:20.123Z");
+    }
+
+    #[test]
+    fn redirect_chain_runs_first_to_last() {
+        let mut entries = vec![
+            entry(0, "http://app.test/old"),
+            entry(1, "http://app.test/api"),
+            entry(2, "http://app.test/new"),
+        ];
+        entries[0].redirected_to = Some(2);
+        entries[2].redirected_from = Some(0);
+
+        let urls = |chain: Vec<NetworkEntry>| -> Vec<String> {
+            chain.into_iter().map(|e| e.url).collect()
+        };
+        assert_eq!(
+            urls(redirect_chain(&entries, 2)),
+            ["http://app.test/old", "http://app.test/new"]
+        );
+        assert_eq!(urls(redirect_chain(&entries, 1)), ["http://app.test/api"]);
+    }
+
+    #[test]
+    fn quiet_only_once_nothing_is_pending() {
+        let mut state = State {
+            entries: vec![entry(0, "http://app.test/")],
+            by_guid: HashMap::new(),
+            last_activity: Instant::now(),
+        };
+        assert_eq!(state.quiet_for(), None);
+        state.entries[0].state = NetworkEntryState::Failed;
+        assert!(state.quiet_for().is_some());
+    }
+
+    #[test]
+    fn to_har_maps_responses_timings_and_failures() {
+        let mut redirect = entry(0, "http://app.test/old?a=1&b=x%20y");
+        redirect.status = Some(302);
+        redirect.redirected_to = Some(1);
+        redirect.state = NetworkEntryState::Finished;
+        redirect.timing = Some(ResourceTiming {
+            start_time: 1_700_000_000_000.0,
+            domain_lookup_start: -1.0,
+            domain_lookup_end: -1.0,
+            connect_start: 1.0,
+            secure_connection_start: -1.0,
+            connect_end: 3.0,
+            request_start: 3.0,
+            response_start: 10.0,
+            response_end: 12.5,
+        });
+        redirect.sizes = Some(RequestSizes {
+            request_body_size: 0,
+            request_headers_size: 120,
+            response_body_size: 0,
+            response_headers_size: 80,
+        });
+        let mut failed = entry(1, "http://app.test/new");
+        failed.redirected_from = Some(0);
+        failed.state = NetworkEntryState::Failed;
+        failed.failure = Some("net::ERR_FAILED".to_string());
+
+        let har = to_har(&[redirect, failed]);
+        let [first, second] = &har.log.entries[..] else {
+            panic!("expected two entries");
+        };
+        assert_eq!(first.started_date_time, "2023-11-14T22:13:20.123Z");
+        assert_eq!(first.response.status, 302);
+        assert_eq!(first.response.redirect_url, "http://app.test/new");
+        assert_eq!(first.request.query_string[1].value, "x y");
+        assert_eq!(first.request.headers_size, 120);
+        assert_eq!(first.timings.dns, Some(-1.0));
+        assert_eq!(first.timings.connect, Some(2.0));
+        assert_eq!(first.timings.wait, 7.0);
+        assert_eq!(first.time, 11.5);
+        assert_eq!(second.response.status, 0);
+        assert_eq!(second.extra["_failureText"], "net::ERR_FAILED");
+        assert_eq!(second.request.headers_size, -1);
+    }
+}
@@ -5,7 +5,10 @@
 
 use crate::error::{Error, Result};
 use crate::protocol::browser_context::Viewport;
+use crate::protocol::route::{RouteHandle, RouteHandlerState, RouteOptions, remove_route_handlers};
+use crate::protocol::url_matcher::{UrlMatcher, interception_patterns};
 use crate::protocol::{Dialog, Download, Request, ResponseObject, Route, WebSocket, Worker};
+use crate::server::call_site::CallSite;
 use crate::server::channel::Channel;
 use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
 use crate::server::connection::{ConnectionExt, downcast_parent};
@@ -229,9 +232,9 @@ pub struct Page {
     /// One-shot senders waiting for the next "download" event (expect_download)
     download_waiters: Arc<Mutex<Vec<tokio::sync::oneshot::Sender<Download>>>>,
     /// One-shot senders waiting for the next "response" event (expect_response)
-    response_waiters: Arc<Mutex<Vec<tokio::sync::oneshot::Sender<ResponseObject>>>>,
+    response_waiters: Arc<Mutex<Vec<UrlWaiter<ResponseObject>>>>,
     /// One-shot senders waiting for the next "request" event (expect_request)
-    request_waiters: Arc<Mutex<Vec<tokio::sync::oneshot::Sender<Request>>>>,
+    request_waiters: Arc<Mutex<Vec<UrlWaiter<Request>>>>,
     /// One-shot senders waiting for the next "console" event (expect_console_message)
     console_waiters: Arc<Mutex<Vec<tokio::sync::oneshot::Sender<crate::protocol::ConsoleMessage>>>>,
     /// close event handlers (fires when page is closed)
@@ -305,15 +308,34 @@ type WebSocketRouteHandlerFuture = Pin<Box<dyn Future<Output = Result<()>> + Sen
 /// Storage for a single WebSocket route handler entry
 #[derive(Clone)]
 struct WsRouteHandlerEntry {
-    pattern: String,
+    pattern: UrlMatcher,
     handler:
         Arc<dyn Fn(crate::protocol::WebSocketRoute) -> WebSocketRouteHandlerFuture + Send + Sync>,
 }
 
+/// A pending `expect_request` / `expect_response`, and the URL it waits
+/// for if it came from the `_matching` variant.
+struct UrlWaiter<T> {
+    url: Option<UrlMatcher>,
+    tx: tokio::sync::oneshot::Sender<T>,
+}
+
+/// Takes the most recent live waiter `url` resolves.
+fn take_url_waiter<T>(waiters: &Mutex<Vec<UrlWaiter<T>>>, url: &str) -> Option<UrlWaiter<T>> {
+    let mut waiters = waiters.lock().unwrap();
+    // A timed-out waiter must not take the event from a later one.
+    waiters.retain(|waiter| !waiter.tx.is_closed());
+    let index = waiters
+        .iter()
+        .rposition(|waiter| waiter.url.as_ref().is_none_or(|m| m.matches(url)))?;
+    Some(waiters.remove(index))
+}
+
 /// Storage for a single route handler
 #[derive(Clone)]
 struct RouteHandlerEntry {
-    pattern: String,
+    pattern: UrlMatcher,
+    state: Arc<RouteHandlerState>,
     handler: Arc<dyn Fn(Route) -> RouteHandlerFuture + Send + Sync>,
 }
 
@@ -589,8 +611,9 @@ impl Page {
     /// This method also wires up the back-reference from the frame to the page so that
     /// `frame.page()`, `frame.locator()`, and `frame.get_by_*()` work correctly.
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn main_frame(&self) -> Result<crate::protocol::Frame> {
-        Ok(self.main_frame_wired())
+    #[track_caller]
+    pub fn main_frame(&self) -> impl Future<Output = Result<crate::protocol::Frame>> {
+        CallSite::caller().run(async move { Ok(self.main_frame_wired()) })
     }
 
     /// Waits until `expression` returns a truthy value in the main frame,
@@ -609,21 +632,24 @@ impl Page {
     /// the driver's "Timeout ...ms exceeded" message.
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-wait-for-function>
-    pub async fn wait_for_function(
+    #[track_caller]
+    pub fn wait_for_function(
         &self,
         expression: &str,
         options: impl Into<Option<crate::protocol::WaitForFunctionOptions>>,
-    ) -> Result<std::sync::Arc<crate::protocol::JSHandle>> {
-        // Resolve the page's configured default here: the frame only knows
-        // it through a back-reference that a bare Frame may not have.
-        let mut options = options.into().unwrap_or_default();
-        if options.timeout.is_none() {
-            options.timeout = Some(self.default_timeout_ms());
-        }
-        self.main_frame()
-            .await?
-            .wait_for_function(expression, options)
-            .await
+    ) -> impl Future<Output = Result<std::sync::Arc<crate::protocol::JSHandle>>> {
+        CallSite::caller().run(async move {
+            // Resolve the page's configured default here: the frame only knows
+            // it through a back-reference that a bare Frame may not have.
+            let mut options = options.into().unwrap_or_default();
+            if options.timeout.is_none() {
+                options.timeout = Some(self.default_timeout_ms());
+            }
+            self.main_frame()
+                .await?
+                .wait_for_function(expression, options)
+                .await
+        })
     }
 
     /// Clone of the construction-time main frame with the page back-reference
@@ -660,15 +686,18 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-close>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
-    pub async fn close(&self) -> Result<()> {
-        // Send close RPC to server
-        let result = self
-            .channel()
-            .send_no_result("close", serde_json::json!({}))
-            .await;
-        // Mark as closed regardless of error (best-effort)
-        self.is_closed.store(true, Ordering::Relaxed);
-        result
+    #[track_caller]
+    pub fn close(&self) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            // Send close RPC to server
+            let result = self
+                .channel()
+                .send_no_result("close", serde_json::json!({}))
+                .await;
+            // Mark as closed regardless of error (best-effort)
+            self.is_closed.store(true, Ordering::Relaxed);
+            result
+        })
     }
 
     /// Returns whether the page has been closed.
@@ -773,24 +802,27 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-opener>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn opener(&self) -> Result<Option<Page>> {
-        // The opener guid is stored in the page initializer as {"opener": {"guid": "..."}}.
-        // It is set when the page is created as a popup; absent for non-popup pages.
-        let opener_guid = self
-            .base
-            .initializer()
-            .get("opener")
-            .and_then(|v| v.get("guid"))
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
-
-        match opener_guid {
-            None => Ok(None),
-            Some(guid) => {
-                let page = self.connection().get_typed::<Page>(&guid).await?;
-                Ok(Some(page))
+    #[track_caller]
+    pub fn opener(&self) -> impl Future<Output = Result<Option<Page>>> {
+        CallSite::caller().run(async move {
+            // The opener guid is stored in the page initializer as {"opener": {"guid": "..."}}.
+            // It is set when the page is created as a popup; absent for non-popup pages.
+            let opener_guid = self
+                .base
+                .initializer()
+                .get("opener")
+                .and_then(|v| v.get("guid"))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string());
+
+            match opener_guid {
+                None => Ok(None),
+                Some(guid) => {
+                    let page = self.connection().get_typed::<Page>(&guid).await?;
+                    Ok(Some(page))
+                }
             }
-        }
+        })
     }
 
     /// Returns all active web workers belonging to this page.
@@ -818,10 +850,13 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-set-default-timeout>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn set_default_timeout(&self, timeout: f64) {
-        self.default_timeout_ms
-            .store(timeout.to_bits(), Ordering::Relaxed);
-        set_timeout_and_notify(self.channel(), "setDefaultTimeoutNoReply", timeout).await;
+    #[track_caller]
+    pub fn set_default_timeout(&self, timeout: f64) -> impl Future<Output = ()> {
+        CallSite::caller().run(async move {
+            self.default_timeout_ms
+                .store(timeout.to_bits(), Ordering::Relaxed);
+            set_timeout_and_notify(self.channel(), "setDefaultTimeoutNoReply", timeout).await;
+        })
     }
 
     /// Sets the default timeout for navigation operations on this page.
@@ -835,15 +870,18 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-set-default-navigation-timeout>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn set_default_navigation_timeout(&self, timeout: f64) {
-        self.default_navigation_timeout_ms
-            .store(timeout.to_bits(), Ordering::Relaxed);
-        set_timeout_and_notify(
-            self.channel(),
-            "setDefaultNavigationTimeoutNoReply",
-            timeout,
-        )
-        .await;
+    #[track_caller]
+    pub fn set_default_navigation_timeout(&self, timeout: f64) -> impl Future<Output = ()> {
+        CallSite::caller().run(async move {
+            self.default_navigation_timeout_ms
+                .store(timeout.to_bits(), Ordering::Relaxed);
+            set_timeout_and_notify(
+                self.channel(),
+                "setDefaultNavigationTimeoutNoReply",
+                timeout,
+            )
+            .await;
+        })
     }
 
     /// Returns the current default action timeout in milliseconds.
@@ -888,10 +926,13 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-frames>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn frames(&self) -> Result<Vec<crate::protocol::Frame>> {
-        // Start with the main frame
-        let main = self.main_frame().await?;
-        Ok(vec![main])
+    #[track_caller]
+    pub fn frames(&self) -> impl Future<Output = Result<Vec<crate::protocol::Frame>>> {
+        CallSite::caller().run(async move {
+            // Start with the main frame
+            let main = self.main_frame().await?;
+            Ok(vec![main])
+        })
     }
 
     /// Navigates to the specified URL.
@@ -913,36 +954,39 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-goto>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid(), url = %url, status = tracing::field::Empty))]
-    pub async fn goto(
+    #[track_caller]
+    pub fn goto(
         &self,
         url: &str,
         options: impl Into<Option<GotoOptions>>,
-    ) -> Result<Option<Response>> {
-        let options = options.into();
-        // Inject the page-level navigation timeout when no explicit timeout is given
-        let options = self.with_navigation_timeout(options);
-
-        // Delegate to main frame
-        let frame = self.main_frame().await.map_err(|e| match e {
-            Error::TargetClosed { context, .. } => Error::TargetClosed {
-                target_type: "Page".to_string(),
-                context,
-            },
-            other => other,
-        })?;
+    ) -> impl Future<Output = Result<Option<Response>>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            // Inject the page-level navigation timeout when no explicit timeout is given
+            let options = self.with_navigation_timeout(options);
+
+            // Delegate to main frame
+            let frame = self.main_frame().await.map_err(|e| match e {
+                Error::TargetClosed { context, .. } => Error::TargetClosed {
+                    target_type: "Page".to_string(),
+                    context,
+                },
+                other => other,
+            })?;
 
-        let response = frame.goto(url, Some(options)).await.map_err(|e| match e {
-            Error::TargetClosed { context, .. } => Error::TargetClosed {
-                target_type: "Page".to_string(),
-                context,
-            },
-            other => other,
-        })?;
+            let response = frame.goto(url, Some(options)).await.map_err(|e| match e {
+                Error::TargetClosed { context, .. } => Error::TargetClosed {
+                    target_type: "Page".to_string(),
+                    context,
+                },
+                other => other,
+            })?;
 
-        if let Some(ref resp) = response {
-            tracing::Span::current().record("status", resp.status());
-        }
-        Ok(response)
+            if let Some(ref resp) = response {
+                tracing::Span::current().record("status", resp.status());
+            }
+            Ok(response)
+        })
     }
 
     /// Returns the browser context that the page belongs to.
@@ -989,18 +1033,22 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-pause>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn pause(&self) -> Result<()> {
-        self.context()?.pause().await
+    #[track_caller]
+    pub fn pause(&self) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move { self.context()?.pause().await })
     }
 
     /// Returns the page's title.
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-title>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn title(&self) -> Result<String> {
-        // Delegate to main frame
-        let frame = self.main_frame().await?;
-        frame.title().await
+    #[track_caller]
+    pub fn title(&self) -> impl Future<Output = Result<String>> {
+        CallSite::caller().run(async move {
+            // Delegate to main frame
+            let frame = self.main_frame().await?;
+            frame.title().await
+        })
     }
 
     /// Returns the full HTML content of the page, including the DOCTYPE.
@@ -1010,24 +1058,30 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-content>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn content(&self) -> Result<String> {
-        // Delegate to main frame
-        let frame = self.main_frame().await?;
-        frame.content().await
+    #[track_caller]
+    pub fn content(&self) -> impl Future<Output = Result<String>> {
+        CallSite::caller().run(async move {
+            // Delegate to main frame
+            let frame = self.main_frame().await?;
+            frame.content().await
+        })
     }
 
     /// Sets the content of the page.
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-set-content>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn set_content(
+    #[track_caller]
+    pub fn set_content(
         &self,
         html: &str,
         options: impl Into<Option<GotoOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        let frame = self.main_frame().await?;
-        frame.set_content(html, options).await
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let frame = self.main_frame().await?;
+            frame.set_content(html, options).await
+        })
     }
 
     /// Waits for the required load state to be reached.
@@ -1038,23 +1092,35 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-wait-for-load-state>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn wait_for_load_state(&self, state: Option<WaitUntil>) -> Result<()> {
-        let frame = self.main_frame().await?;
-        frame.wait_for_load_state(state).await
+    #[track_caller]
+    pub fn wait_for_load_state(
+        &self,
+        state: Option<WaitUntil>,
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let frame = self.main_frame().await?;
+            frame.wait_for_load_state(state).await
+        })
     }
 
-    /// Waits for the main frame to navigate to a URL matching the given string or glob pattern.
+    /// Waits for the main frame to navigate to a URL matching `url`: a glob,
+    /// an exact URL, a `Regex`, or any other [`UrlMatcher`].
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-wait-for-url>
-    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = %url))]
-    pub async fn wait_for_url(
+    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = tracing::field::Empty))]
+    #[track_caller]
+    pub fn wait_for_url(
         &self,
-        url: &str,
+        url: impl Into<UrlMatcher>,
         options: impl Into<Option<GotoOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        let frame = self.main_frame().await?;
-        frame.wait_for_url(url, options).await
+    ) -> impl Future<Output = Result<()>> {
+        let url = url.into();
+        tracing::Span::current().record("url", tracing::field::display(&url));
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let frame = self.main_frame().await?;
+            frame.wait_for_url(url, options).await
+        })
     }
 
     /// Replace the URL fragment without firing a navigation.
@@ -1063,20 +1129,24 @@ impl Page {
     /// A leading `#` on `hash` is optional — both `"foo"` and `"#foo"`
     /// produce the same result.
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn set_url_fragment(&self, hash: &str) -> Result<()> {
-        let normalized = if hash.starts_with('#') {
-            hash.to_string()
-        } else {
-            format!("#{hash}")
-        };
-        // JSON-encode so quotes / backslashes / control chars in `hash`
-        // don't break the surrounding JS string literal.
-        let json = serde_json::to_string(&normalized).map_err(|e| {
-            crate::error::Error::ProtocolError(format!("serialize url fragment: {e}"))
-        })?;
-        let js =
-            format!("history.replaceState(null, '', location.pathname + location.search + {json})");
-        self.evaluate_expression(&js).await
+    #[track_caller]
+    pub fn set_url_fragment(&self, hash: &str) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let normalized = if hash.starts_with('#') {
+                hash.to_string()
+            } else {
+                format!("#{hash}")
+            };
+            // JSON-encode so quotes / backslashes / control chars in `hash`
+            // don't break the surrounding JS string literal.
+            let json = serde_json::to_string(&normalized).map_err(|e| {
+                crate::error::Error::ProtocolError(format!("serialize url fragment: {e}"))
+            })?;
+            let js = format!(
+                "history.replaceState(null, '', location.pathname + location.search + {json})"
+            );
+            self.evaluate_expression(&js).await
+        })
     }
 
     /// Clear the URL fragment without firing a navigation.
@@ -1085,11 +1155,14 @@ impl Page {
     /// stripping any trailing `#...`. Pairs with
     /// [`set_url_fragment`](Self::set_url_fragment).
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn clear_url_fragment(&self) -> Result<()> {
-        self.evaluate_expression(
-            "history.replaceState(null, '', location.pathname + location.search)",
-        )
-        .await
+    #[track_caller]
+    pub fn clear_url_fragment(&self) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.evaluate_expression(
+                "history.replaceState(null, '', location.pathname + location.search)",
+            )
+            .await
+        })
     }
 
     /// Creates a locator for finding elements on the page.
@@ -1453,15 +1526,18 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-drag-and-drop>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
-    pub async fn drag_and_drop(
+    #[track_caller]
+    pub fn drag_and_drop(
         &self,
         source: &str,
         target: &str,
         options: impl Into<Option<crate::protocol::DragToOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        let frame = self.main_frame().await?;
-        frame.locator_drag_to(source, target, options).await
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let frame = self.main_frame().await?;
+            frame.locator_drag_to(source, target, options).await
+        })
     }
 
     /// Reloads the current page.
@@ -1475,12 +1551,15 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-reload>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
-    pub async fn reload(
+    #[track_caller]
+    pub fn reload(
         &self,
         options: impl Into<Option<GotoOptions>>,
-    ) -> Result<Option<Response>> {
-        let options = options.into();
-        self.navigate_history("reload", options).await
+    ) -> impl Future<Output = Result<Option<Response>>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.navigate_history("reload", options).await
+        })
     }
 
     /// Navigates to the previous page in history.
@@ -1490,12 +1569,15 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-go-back>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
-    pub async fn go_back(
+    #[track_caller]
+    pub fn go_back(
         &self,
         options: impl Into<Option<GotoOptions>>,
-    ) -> Result<Option<Response>> {
-        let options = options.into();
-        self.navigate_history("goBack", options).await
+    ) -> impl Future<Output = Result<Option<Response>>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.navigate_history("goBack", options).await
+        })
     }
 
     /// Navigates to the next page in history.
@@ -1505,12 +1587,15 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-go-forward>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
-    pub async fn go_forward(
+    #[track_caller]
+    pub fn go_forward(
         &self,
         options: impl Into<Option<GotoOptions>>,
-    ) -> Result<Option<Response>> {
-        let options = options.into();
-        self.navigate_history("goForward", options).await
+    ) -> impl Future<Output = Result<Option<Response>>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            self.navigate_history("goForward", options).await
+        })
     }
 
     /// Shared implementation for reload, go_back and go_forward.
@@ -1595,82 +1680,100 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-query-selector>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn query_selector(
+    #[track_caller]
+    pub fn query_selector(
         &self,
         selector: &str,
-    ) -> Result<Option<Arc<crate::protocol::ElementHandle>>> {
-        let frame = self.main_frame().await?;
-        frame.query_selector(selector).await
+    ) -> impl Future<Output = Result<Option<Arc<crate::protocol::ElementHandle>>>> {
+        CallSite::caller().run(async move {
+            let frame = self.main_frame().await?;
+            frame.query_selector(selector).await
+        })
     }
 
     /// Returns all elements matching the selector.
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-query-selector-all>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn query_selector_all(
+    #[track_caller]
+    pub fn query_selector_all(
         &self,
         selector: &str,
-    ) -> Result<Vec<Arc<crate::protocol::ElementHandle>>> {
-        let frame = self.main_frame().await?;
-        frame.query_selector_all(selector).await
+    ) -> impl Future<Output = Result<Vec<Arc<crate::protocol::ElementHandle>>>> {
+        CallSite::caller().run(async move {
+            let frame = self.main_frame().await?;
+            frame.query_selector_all(selector).await
+        })
     }
 
     /// Takes a screenshot of the page and returns the image bytes.
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-screenshot>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid(), bytes_len = tracing::field::Empty))]
-    pub async fn screenshot(
+    #[track_caller]
+    pub fn screenshot(
         &self,
         options: impl Into<Option<crate::protocol::ScreenshotOptions>>,
-    ) -> Result<Vec<u8>> {
-        let options = options.into();
-        let params = if let Some(opts) = options {
-            opts.to_json()
-        } else {
-            // Default to PNG with required timeout
-            serde_json::json!({
-                "type": "png",
-                "timeout": crate::DEFAULT_TIMEOUT_MS
-            })
-        };
+    ) -> impl Future<Output = Result<Vec<u8>>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let params = if let Some(opts) = options {
+                opts.to_json()
+            } else {
+                // Default to PNG with required timeout
+                serde_json::json!({
+                    "type": "png",
+                    "timeout": crate::DEFAULT_TIMEOUT_MS
+                })
+            };
 
-        #[derive(Deserialize)]
-        struct ScreenshotResponse {
-            binary: String,
-        }
+            #[derive(Deserialize)]
+            struct ScreenshotResponse {
+                binary: String,
+            }
 
-        let response: ScreenshotResponse = self.channel().send("screenshot", params).await?;
+            let response: ScreenshotResponse = self.channel().send("screenshot", params).await?;
 
-        // Decode base64 to bytes
-        let bytes = base64::prelude::BASE64_STANDARD
-            .decode(&response.binary)
-            .map_err(|e| {
-                crate::error::Error::ProtocolError(format!("Failed to decode screenshot: {}", e))
-            })?;
+            // Decode base64 to bytes
+            let bytes = base64::prelude::BASE64_STANDARD
+                .decode(&response.binary)
+                .map_err(|e| {
+                    crate::error::Error::ProtocolError(format!(
+                        "Failed to decode screenshot: {}",
+                        e
+                    ))
+                })?;
 
-        tracing::Span::current().record("bytes_len", bytes.len());
-        Ok(bytes)
+            tracing::Span::current().record("bytes_len", bytes.len());
+            Ok(bytes)
+        })
     }
 
     /// Takes a screenshot and saves it to a file, also returning the bytes.
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-screenshot>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
-    pub async fn screenshot_to_file(
+    #[track_caller]
+    pub fn screenshot_to_file(
         &self,
         path: &std::path::Path,
         options: impl Into<Option<crate::protocol::ScreenshotOptions>>,
-    ) -> Result<Vec<u8>> {
-        let options = options.into();
-        // Get the screenshot bytes
-        let bytes = self.screenshot(options).await?;
-
-        // Write to file
-        tokio::fs::write(path, &bytes).await.map_err(|e| {
-            crate::error::Error::ProtocolError(format!("Failed to write screenshot file: {}", e))
-        })?;
+    ) -> impl Future<Output = Result<Vec<u8>>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            // Get the screenshot bytes
+            let bytes = self.screenshot(options).await?;
+
+            // Write to file
+            tokio::fs::write(path, &bytes).await.map_err(|e| {
+                crate::error::Error::ProtocolError(format!(
+                    "Failed to write screenshot file: {}",
+                    e
+                ))
+            })?;
 
-        Ok(bytes)
+            Ok(bytes)
+        })
     }
 
     /// Evaluates JavaScript in the page context (without return value).
@@ -1680,10 +1783,13 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-evaluate>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
-    pub async fn evaluate_expression(&self, expression: &str) -> Result<()> {
-        // Delegate to the main frame
-        let frame = self.main_frame().await?;
-        frame.frame_evaluate_expression(expression).await
+    #[track_caller]
+    pub fn evaluate_expression(&self, expression: &str) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            // Delegate to the main frame
+            let frame = self.main_frame().await?;
+            frame.frame_evaluate_expression(expression).await
+        })
     }
 
     /// Evaluates JavaScript in the page context with optional arguments,
@@ -1725,15 +1831,18 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-evaluate>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
-    pub async fn evaluate<T: serde::Serialize, U: serde::de::DeserializeOwned>(
+    #[track_caller]
+    pub fn evaluate<T: serde::Serialize, U: serde::de::DeserializeOwned>(
         &self,
         expression: &str,
         arg: Option<&T>,
-    ) -> Result<U> {
-        // Delegate to the main frame
-        let frame = self.main_frame().await?;
-        let result = frame.evaluate(expression, arg).await?;
-        serde_json::from_value(result).map_err(Error::from)
+    ) -> impl Future<Output = Result<U>> {
+        CallSite::caller().run(async move {
+            // Delegate to the main frame
+            let frame = self.main_frame().await?;
+            let result = frame.evaluate(expression, arg).await?;
+            serde_json::from_value(result).map_err(Error::from)
+        })
     }
 
     /// Evaluates a JavaScript expression and returns the result coerced to a
@@ -1755,9 +1864,12 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-evaluate>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
-    pub async fn evaluate_value(&self, expression: &str) -> Result<String> {
-        let frame = self.main_frame().await?;
-        frame.frame_evaluate_expression_value(expression).await
+    #[track_caller]
+    pub fn evaluate_value(&self, expression: &str) -> impl Future<Output = Result<String>> {
+        CallSite::caller().run(async move {
+            let frame = self.main_frame().await?;
+            frame.frame_evaluate_expression_value(expression).await
+        })
     }
 
     /// Evaluates `expression` with a Rust closure bound as its argument.
@@ -1807,33 +1919,36 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-evaluate>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
-    pub async fn evaluate_with_callback<U, F, Fut>(
+    #[track_caller]
+    pub fn evaluate_with_callback<U, F, Fut>(
         &self,
         expression: &str,
         callback: F,
-    ) -> Result<U>
+    ) -> impl Future<Output = Result<U>>
     where
         U: serde::de::DeserializeOwned,
         F: Fn(Vec<serde_json::Value>) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = serde_json::Value> + Send + 'static,
     {
-        use std::sync::atomic::{AtomicU64, Ordering};
-        // The `__pw_fn_` prefix matches upstream's kFunctionBindingPrefix
-        // and is load-bearing: the server-to-page serializer only carries a
-        // function value whose binding name starts with it, so a rename off
-        // the prefix would make the argument deserialize as `undefined` in
-        // the page.
-        static CALLBACK_SEQ: AtomicU64 = AtomicU64::new(0);
-        let name = format!(
-            "__pw_fn_rs_{}",
-            CALLBACK_SEQ.fetch_add(1, Ordering::Relaxed)
-        );
+        CallSite::caller().run(async move {
+            use std::sync::atomic::{AtomicU64, Ordering};
+            // The `__pw_fn_` prefix matches upstream's kFunctionBindingPrefix
+            // and is load-bearing: the server-to-page serializer only carries a
+            // function value whose binding name starts with it, so a rename off
+            // the prefix would make the argument deserialize as `undefined` in
+            // the page.
+            static CALLBACK_SEQ: AtomicU64 = AtomicU64::new(0);
+            let name = format!(
+                "__pw_fn_rs_{}",
+                CALLBACK_SEQ.fetch_add(1, Ordering::Relaxed)
+            );
 
-        self.expose_binding_internal(&name, true, callback).await?;
+            self.expose_binding_internal(&name, true, callback).await?;
 
-        let frame = self.main_frame().await?;
-        let result = frame.evaluate_with_fn_arg(expression, &name).await?;
-        serde_json::from_value(result).map_err(Error::from)
+            let frame = self.main_frame().await?;
+            let result = frame.evaluate_with_fn_arg(expression, &name).await?;
+            serde_json::from_value(result).map_err(Error::from)
+        })
     }
 
     /// Registers a route handler for network interception.
@@ -1841,45 +1956,90 @@ impl Page {
     /// When a request matches the specified pattern, the handler will be called
     /// with a Route object that can abort, continue, or fulfill the request.
     ///
+    /// Requests no page handler matches go on to the context's handlers,
+    /// then to the network.
+    ///
     /// # Arguments
     ///
-    /// * `pattern` - URL pattern to match (supports glob patterns like "**/*.png")
+    /// * `pattern` - URL to match: a glob like `"**/*.png"`, a `Regex`, or
+    ///   any other [`UrlMatcher`]
     /// * `handler` - Async closure that handles the route
     ///
+    /// Returns a [`RouteHandle`] that counts the requests the handler was
+    /// given and can unregister it alone.
+    ///
     /// See: <https://playwright.dev/docs/api/class-page#page-route>
-    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = %pattern))]
-    pub async fn route<F, Fut>(&self, pattern: &str, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn route<F, Fut>(
+        &self,
+        pattern: impl Into<UrlMatcher>,
+        handler: F,
+    ) -> impl Future<Output = Result<RouteHandle>>
     where
         F: Fn(Route) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        // 1. Wrap handler in Arc with type erasure
-        let handler =
-            Arc::new(move |route: Route| -> RouteHandlerFuture { Box::pin(handler(route)) });
-
-        // 2. Store in handlers list
-        self.route_handlers.lock().unwrap().push(RouteHandlerEntry {
-            pattern: pattern.to_string(),
-            handler,
-        });
+        self.route_with_options(pattern, handler, None)
+    }
 
-        // 3. Enable network interception via protocol
-        self.enable_network_interception().await?;
+    /// [`route`](Self::route) with options: `times` limits how many requests
+    /// the handler is given before it unregisters itself.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-page#page-route>
+    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = tracing::field::Empty))]
+    #[track_caller]
+    pub fn route_with_options<F, Fut>(
+        &self,
+        pattern: impl Into<UrlMatcher>,
+        handler: F,
+        options: impl Into<Option<RouteOptions>>,
+    ) -> impl Future<Output = Result<RouteHandle>>
+    where
+        F: Fn(Route) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let pattern = pattern.into();
+        tracing::Span::current().record("url", tracing::field::display(&pattern));
+        let state = RouteHandlerState::new(options.into());
+        CallSite::caller().run(async move {
+            // 1. Wrap handler in Arc with type erasure
+            let handler =
+                Arc::new(move |route: Route| -> RouteHandlerFuture { Box::pin(handler(route)) });
+
+            // 2. Store in handlers list
+            self.route_handlers.lock().unwrap().push(RouteHandlerEntry {
+                pattern,
+                state: state.clone(),
+                handler,
+            });
+
+            // 3. Enable network interception via protocol
+            self.enable_network_interception().await?;
+
+            Ok(RouteHandle::for_page(state, self.clone()))
+        })
+    }
 
+    /// Unregisters the handler a [`RouteHandle`] refers to.
+    pub(crate) async fn remove_route_handler(&self, id: u64) -> Result<()> {
+        let removed =
+            remove_route_handlers(&self.route_handlers, |e| &e.state, |e| e.state.id == id);
+        if removed {
+            self.enable_network_interception().await?;
+        }
         Ok(())
     }
 
     /// Updates network interception patterns for this page
     async fn enable_network_interception(&self) -> Result<()> {
         // Collect all patterns from registered handlers
-        // Each pattern must be an object with "glob" field
-        let patterns: Vec<serde_json::Value> = self
-            .route_handlers
-            .lock()
-            .unwrap()
-            .iter()
-            .map(|entry| serde_json::json!({ "glob": entry.pattern }))
-            .collect();
+        let patterns = interception_patterns(
+            self.route_handlers
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|entry| &entry.pattern),
+        );
 
         // Send protocol command to update network interception patterns
         // Follows playwright-python's approach
@@ -1897,16 +2057,18 @@ impl Page {
     ///
     /// # Arguments
     ///
-    /// * `pattern` - URL pattern to remove handlers for
+    /// * `pattern` - The matcher the handlers were registered with
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-unroute>
-    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = %pattern))]
-    pub async fn unroute(&self, pattern: &str) -> Result<()> {
-        self.route_handlers
-            .lock()
-            .unwrap()
-            .retain(|entry| entry.pattern != pattern);
-        self.enable_network_interception().await
+    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = tracing::field::Empty))]
+    #[track_caller]
+    pub fn unroute(&self, pattern: impl Into<UrlMatcher>) -> impl Future<Output = Result<()>> {
+        let pattern = pattern.into();
+        tracing::Span::current().record("url", tracing::field::display(&pattern));
+        CallSite::caller().run(async move {
+            remove_route_handlers(&self.route_handlers, |e| &e.state, |e| e.pattern == pattern);
+            self.enable_network_interception().await
+        })
     }
 
     /// Removes all registered route handlers.
@@ -1917,12 +2079,15 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-unroute-all>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn unroute_all(
+    #[track_caller]
+    pub fn unroute_all(
         &self,
         _behavior: Option<crate::protocol::route::UnrouteBehavior>,
-    ) -> Result<()> {
-        self.route_handlers.lock().unwrap().clear();
-        self.enable_network_interception().await
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            remove_route_handlers(&self.route_handlers, |e| &e.state, |_| true);
+            self.enable_network_interception().await
+        })
     }
 
     /// Replays network requests from a HAR file recorded previously.
@@ -1932,6 +2097,10 @@ impl Page {
     /// requests are either aborted or passed through depending on
     /// `options.not_found` (`"abort"` is the default).
     ///
+    /// With `options.update`, this page's traffic is recorded into
+    /// `har_path` instead, written when its context closes; see
+    /// [`BrowserContext::route_from_har`](crate::protocol::BrowserContext::route_from_har).
+    ///
     /// # Arguments
     ///
     /// * `har_path` - Path to the `.har` file on disk
@@ -1945,146 +2114,167 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-route-from-har>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn route_from_har(
+    #[track_caller]
+    pub fn route_from_har(
         &self,
         har_path: &str,
         options: impl Into<Option<RouteFromHarOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        let opts = options.unwrap_or_default();
-        let not_found = opts.not_found.unwrap_or_else(|| "abort".to_string());
-        let url_filter = opts.url.clone();
-
-        // Resolve to an absolute path so the Playwright server can open it
-        // regardless of its working directory.
-        let abs_path = std::path::Path::new(har_path).canonicalize().map_err(|e| {
-            Error::InvalidPath(format!(
-                "route_from_har: cannot resolve '{}': {}",
-                har_path, e
-            ))
-        })?;
-        let abs_str = abs_path.to_string_lossy().into_owned();
-
-        // Locate LocalUtils in the connection object registry by type name.
-        // The Playwright server registers it with a guid like "localUtils@1"
-        // so we scan all objects for the one with type_name "LocalUtils".
-        let connection = self.connection();
-        let local_utils = {
-            let all = connection.all_objects_sync();
-            all.into_iter()
-                .find(|o| o.type_name() == "LocalUtils")
-                .and_then(|o| {
-                    o.as_any()
-                        .downcast_ref::<crate::protocol::LocalUtils>()
-                        .cloned()
-                })
-                .ok_or_else(|| {
-                    Error::ProtocolError(
-                        "route_from_har: LocalUtils not found in connection registry".to_string(),
-                    )
-                })?
-        };
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let opts = options.unwrap_or_default();
+            let mut not_found = opts
+                .not_found
+                .clone()
+                .unwrap_or_else(|| "abort".to_string());
+            let url_filter = opts.url.clone();
+
+            if opts.update == Some(true) {
+                let context = self.context()?;
+                if !context
+                    .start_har_update(Some(self), har_path, &opts)
+                    .await?
+                {
+                    return Ok(());
+                }
+                // Replay what the archive has; misses go to the network and
+                // are recorded.
+                not_found = "fallback".to_string();
+            }
 
-        // Open the HAR archive on the server side.
-        let har_id = local_utils.har_open(&abs_str).await?;
-
-        // Determine the URL pattern to intercept.
-        let pattern = url_filter.clone().unwrap_or_else(|| "**/*".to_string());
-
-        // Register a route handler that performs HAR lookup for each request.
-        let har_id_clone = har_id.clone();
-        let local_utils_clone = local_utils.clone();
-        let not_found_clone = not_found.clone();
-
-        self.route(&pattern, move |route| {
-            let har_id = har_id_clone.clone();
-            let local_utils = local_utils_clone.clone();
-            let not_found = not_found_clone.clone();
-            async move {
-                let request = route.request();
-                let req_url = request.url().to_string();
-                let req_method = request.method().to_string();
-
-                // Build headers array as [{name, value}]
-                let headers: Vec<serde_json::Value> = request
-                    .headers()
-                    .iter()
-                    .map(|(k, v)| serde_json::json!({"name": k, "value": v}))
-                    .collect();
-
-                let lookup = local_utils
-                    .har_lookup(
-                        &har_id,
-                        &req_url,
-                        &req_method,
-                        headers,
-                        None,
-                        request.is_navigation_request(),
-                    )
-                    .await;
+            // Resolve to an absolute path so the Playwright server can open it
+            // regardless of its working directory.
+            let abs_path = std::path::Path::new(har_path).canonicalize().map_err(|e| {
+                Error::InvalidPath(format!(
+                    "route_from_har: cannot resolve '{}': {}",
+                    har_path, e
+                ))
+            })?;
+            let abs_str = abs_path.to_string_lossy().into_owned();
+
+            // Locate LocalUtils in the connection object registry by type name.
+            // The Playwright server registers it with a guid like "localUtils@1"
+            // so we scan all objects for the one with type_name "LocalUtils".
+            let connection = self.connection();
+            let local_utils = {
+                let all = connection.all_objects_sync();
+                all.into_iter()
+                    .find(|o| o.type_name() == "LocalUtils")
+                    .and_then(|o| {
+                        o.as_any()
+                            .downcast_ref::<crate::protocol::LocalUtils>()
+                            .cloned()
+                    })
+                    .ok_or_else(|| {
+                        Error::ProtocolError(
+                            "route_from_har: LocalUtils not found in connection registry"
+                                .to_string(),
+                        )
+                    })?
+            };
+
+            // Open the HAR archive on the server side.
+            let har_id = local_utils.har_open(&abs_str).await?;
+
+            // Determine the URL pattern to intercept.
+            let pattern = url_filter.clone().unwrap_or_else(|| "**/*".to_string());
+
+            // Register a route handler that performs HAR lookup for each request.
+            let har_id_clone = har_id.clone();
+            let local_utils_clone = local_utils.clone();
+            let not_found_clone = not_found.clone();
+
+            self.route(&pattern, move |route| {
+                let har_id = har_id_clone.clone();
+                let local_utils = local_utils_clone.clone();
+                let not_found = not_found_clone.clone();
+                async move {
+                    let request = route.request();
+                    let req_url = request.url().to_string();
+                    let req_method = request.method().to_string();
+
+                    // Build headers array as [{name, value}]
+                    let headers: Vec<serde_json::Value> = request
+                        .headers()
+                        .iter()
+                        .map(|(k, v)| serde_json::json!({"name": k, "value": v}))
+                        .collect();
+
+                    let lookup = local_utils
+                        .har_lookup(
+                            &har_id,
+                            &req_url,
+                            &req_method,
+                            headers,
+                            None,
+                            request.is_navigation_request(),
+                        )
+                        .await;
 
-                match lookup {
-                    Err(e) => {
-                        tracing::warn!("har_lookup error for {}: {}", req_url, e);
-                        route.continue_(None).await
-                    }
-                    Ok(result) => match result.action.as_str() {
-                        "redirect" => {
-                            let redirect_url = result.redirect_url.unwrap_or_default();
-                            let opts = crate::protocol::ContinueOptions::builder()
-                                .url(redirect_url)
-                                .build();
-                            route.continue_(Some(opts)).await
+                    match lookup {
+                        Err(e) => {
+                            tracing::warn!("har_lookup error for {}: {}", req_url, e);
+                            route.continue_(None).await
                         }
-                        "fulfill" => {
-                            let status = result.status.unwrap_or(200);
-
-                            // Decode base64 body if present
-                            let body_bytes = result.body.as_deref().map(|b64| {
-                                base64::engine::general_purpose::STANDARD
-                                    .decode(b64)
-                                    .unwrap_or_default()
-                            });
-
-                            // Build headers map
-                            let mut headers_map = std::collections::HashMap::new();
-                            if let Some(raw_headers) = result.headers {
-                                for h in raw_headers {
-                                    if let (Some(name), Some(value)) = (
-                                        h.get("name").and_then(|v| v.as_str()),
-                                        h.get("value").and_then(|v| v.as_str()),
-                                    ) {
-                                        headers_map.insert(name.to_string(), value.to_string());
+                        Ok(result) => match result.action.as_str() {
+                            "redirect" => {
+                                let redirect_url = result.redirect_url.unwrap_or_default();
+                                let opts = crate::protocol::ContinueOptions::builder()
+                                    .url(redirect_url)
+                                    .build();
+                                route.continue_(Some(opts)).await
+                            }
+                            "fulfill" => {
+                                let status = result.status.unwrap_or(200);
+
+                                // Decode base64 body if present
+                                let body_bytes = result.body.as_deref().map(|b64| {
+                                    base64::engine::general_purpose::STANDARD
+                                        .decode(b64)
+                                        .unwrap_or_default()
+                                });
+
+                                // Build headers map
+                                let mut headers_map = std::collections::HashMap::new();
+                                if let Some(raw_headers) = result.headers {
+                                    for h in raw_headers {
+                                        if let (Some(name), Some(value)) = (
+                                            h.get("name").and_then(|v| v.as_str()),
+                                            h.get("value").and_then(|v| v.as_str()),
+                                        ) {
+                                            headers_map.insert(name.to_string(), value.to_string());
+                                        }
                                     }
                                 }
-                            }
 
-                            let mut builder =
-                                crate::protocol::FulfillOptions::builder().status(status);
+                                let mut builder =
+                                    crate::protocol::FulfillOptions::builder().status(status);
 
-                            if !headers_map.is_empty() {
-                                builder = builder.headers(headers_map);
-                            }
+                                if !headers_map.is_empty() {
+                                    builder = builder.headers(headers_map);
+                                }
 
-                            if let Some(body) = body_bytes {
-                                builder = builder.body(body);
-                            }
+                                if let Some(body) = body_bytes {
+                                    builder = builder.body(body);
+                                }
 
-                            route.fulfill(Some(builder.build())).await
-                        }
-                        _ => {
-                            // "fallback" or "error" or unknown
-                            if not_found == "fallback" {
-                                route.fallback(None).await
-                            } else {
-                                route.abort(None).await
+                                route.fulfill(Some(builder.build())).await
                             }
-                        }
-                    },
+                            _ => {
+                                // "fallback" or "error" or unknown
+                                if not_found == "fallback" {
+                                    route.fallback(None).await
+                                } else {
+                                    route.abort(None).await
+                                }
+                            }
+                        },
+                    }
                 }
-            }
+            })
+            .await?;
+            Ok(())
         })
-        .await
     }
 
     /// Intercepts WebSocket connections matching the given URL pattern.
@@ -2097,7 +2287,8 @@ impl Page {
     ///
     /// # Arguments
     ///
-    /// * `url` — URL glob pattern (e.g. `"ws://**"` or `"wss://example.com/ws"`).
+    /// * `url` — URL to match: a glob (e.g. `"ws://**"` or
+    ///   `"wss://example.com/ws"`), a `Regex`, or any other [`UrlMatcher`].
     /// * `handler` — Async closure receiving a `WebSocketRoute`.
     ///
     /// # Errors
@@ -2105,38 +2296,47 @@ impl Page {
     /// Returns an error if the RPC call to enable interception fails.
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-route-web-socket>
-    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = %url))]
-    pub async fn route_web_socket<F, Fut>(&self, url: &str, handler: F) -> Result<()>
+    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = tracing::field::Empty))]
+    #[track_caller]
+    pub fn route_web_socket<F, Fut>(
+        &self,
+        url: impl Into<UrlMatcher>,
+        handler: F,
+    ) -> impl Future<Output = Result<()>>
     where
         F: Fn(crate::protocol::WebSocketRoute) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(
-            move |route: crate::protocol::WebSocketRoute| -> WebSocketRouteHandlerFuture {
-                Box::pin(handler(route))
-            },
-        );
+        let url = url.into();
+        tracing::Span::current().record("url", tracing::field::display(&url));
+        CallSite::caller().run(async move {
+            let handler = Arc::new(
+                move |route: crate::protocol::WebSocketRoute| -> WebSocketRouteHandlerFuture {
+                    Box::pin(handler(route))
+                },
+            );
 
-        self.ws_route_handlers
-            .lock()
-            .unwrap()
-            .push(WsRouteHandlerEntry {
-                pattern: url.to_string(),
-                handler,
-            });
+            self.ws_route_handlers
+                .lock()
+                .unwrap()
+                .push(WsRouteHandlerEntry {
+                    pattern: url,
+                    handler,
+                });
 
-        self.enable_ws_interception().await
+            self.enable_ws_interception().await
+        })
     }
 
     /// Updates WebSocket interception patterns for this page.
     async fn enable_ws_interception(&self) -> Result<()> {
-        let patterns: Vec<serde_json::Value> = self
-            .ws_route_handlers
-            .lock()
-            .unwrap()
-            .iter()
-            .map(|entry| serde_json::json!({ "glob": entry.pattern }))
-            .collect();
+        let patterns = interception_patterns(
+            self.ws_route_handlers
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|entry| &entry.pattern),
+        );
 
         self.channel()
             .send_no_result(
@@ -2153,12 +2353,24 @@ impl Page {
     /// `fallback()` instead of `continue_()`, `abort()`, or `fulfill()`, the
     /// next matching handler in the chain is tried.
     async fn on_route_event(&self, route: Route) {
+        if let Ok(context) = self.context()
+            && context.block_route(&route).await
+        {
+            return;
+        }
         let handlers = self.route_handlers.lock().unwrap().clone();
         let url = route.request().url().to_string();
 
         // Find matching handler (last registered wins, with fallback chaining)
+        let mut matched = false;
         for entry in handlers.iter().rev() {
-            if crate::protocol::route::matches_pattern(&entry.pattern, &url) {
+            if entry.pattern.matches(&url) && entry.state.claim() {
+                matched = true;
+                if entry.state.is_spent()
+                    && let Err(e) = self.remove_route_handler(entry.state.id).await
+                {
+                    tracing::warn!("Failed to unregister spent route handler: {}", e);
+                }
                 let handler = entry.handler.clone();
                 if let Err(e) = handler(route.clone()).await {
                     tracing::warn!("Route handler error: {}", e);
@@ -2171,6 +2383,19 @@ impl Page {
                 break;
             }
         }
+        // Nothing here wanted it: the server also reports requests for a
+        // handler whose `times` just ran out, or every request when a
+        // predicate or URLPattern is registered. Hand it to the context.
+        if !matched {
+            match self.context() {
+                Ok(context) => context.handle_route(route).await,
+                Err(_) => {
+                    if let Err(e) = route.fallback(None).await {
+                        tracing::warn!("Route fallback error: {}", e);
+                    }
+                }
+            }
+        }
     }
 
     /// Registers a download event handler.
@@ -2185,20 +2410,23 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-event-download>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_download<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_download<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Download) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        // Wrap handler with type erasure
-        let handler = Arc::new(move |download: Download| -> DownloadHandlerFuture {
-            Box::pin(handler(download))
-        });
+        CallSite::caller().run(async move {
+            // Wrap handler with type erasure
+            let handler = Arc::new(move |download: Download| -> DownloadHandlerFuture {
+                Box::pin(handler(download))
+            });
 
-        // Store handler
-        self.download_handlers.lock().unwrap().push(handler);
+            // Store handler
+            self.download_handlers.lock().unwrap().push(handler);
 
-        Ok(())
+            Ok(())
+        })
     }
 
     /// Registers a dialog event handler.
@@ -2212,21 +2440,25 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-event-dialog>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_dialog<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_dialog<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Dialog) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        // Wrap handler with type erasure
-        let handler =
-            Arc::new(move |dialog: Dialog| -> DialogHandlerFuture { Box::pin(handler(dialog)) });
+        CallSite::caller().run(async move {
+            // Wrap handler with type erasure
+            let handler = Arc::new(move |dialog: Dialog| -> DialogHandlerFuture {
+                Box::pin(handler(dialog))
+            });
 
-        // Store handler
-        self.dialog_handlers.lock().unwrap().push(handler);
+            // Store handler
+            self.dialog_handlers.lock().unwrap().push(handler);
 
-        // Dialog events are auto-emitted (no subscription needed)
+            // Dialog events are auto-emitted (no subscription needed)
 
-        Ok(())
+            Ok(())
+        })
     }
 
     /// Registers a console event handler.
@@ -2243,28 +2475,31 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-event-console>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_console<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_console<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(crate::protocol::ConsoleMessage) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(
-            move |msg: crate::protocol::ConsoleMessage| -> ConsoleHandlerFuture {
-                Box::pin(handler(msg))
-            },
-        );
+        CallSite::caller().run(async move {
+            let handler = Arc::new(
+                move |msg: crate::protocol::ConsoleMessage| -> ConsoleHandlerFuture {
+                    Box::pin(handler(msg))
+                },
+            );
 
-        let needs_subscription = {
-            let handlers = self.console_handlers.lock().unwrap();
-            let waiters = self.console_waiters.lock().unwrap();
-            handlers.is_empty() && waiters.is_empty()
-        };
-        if needs_subscription {
-            _ = self.channel().update_subscription("console", true).await;
-        }
-        self.console_handlers.lock().unwrap().push(handler);
+            let needs_subscription = {
+                let handlers = self.console_handlers.lock().unwrap();
+                let waiters = self.console_waiters.lock().unwrap();
+                handlers.is_empty() && waiters.is_empty()
+            };
+            if needs_subscription {
+                _ = self.channel().update_subscription("console", true).await;
+            }
+            self.console_handlers.lock().unwrap().push(handler);
 
-        Ok(())
+            Ok(())
+        })
     }
 
     /// Registers a handler for file chooser events.
@@ -2299,31 +2534,34 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-event-file-chooser>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_filechooser<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_filechooser<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(crate::protocol::FileChooser) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(
-            move |chooser: crate::protocol::FileChooser| -> FileChooserHandlerFuture {
-                Box::pin(handler(chooser))
-            },
-        );
+        CallSite::caller().run(async move {
+            let handler = Arc::new(
+                move |chooser: crate::protocol::FileChooser| -> FileChooserHandlerFuture {
+                    Box::pin(handler(chooser))
+                },
+            );
 
-        let needs_subscription = {
-            let handlers = self.filechooser_handlers.lock().unwrap();
-            let waiters = self.filechooser_waiters.lock().unwrap();
-            handlers.is_empty() && waiters.is_empty()
-        };
-        if needs_subscription {
-            _ = self
-                .channel()
-                .update_subscription("fileChooser", true)
-                .await;
-        }
-        self.filechooser_handlers.lock().unwrap().push(handler);
+            let needs_subscription = {
+                let handlers = self.filechooser_handlers.lock().unwrap();
+                let waiters = self.filechooser_waiters.lock().unwrap();
+                handlers.is_empty() && waiters.is_empty()
+            };
+            if needs_subscription {
+                _ = self
+                    .channel()
+                    .update_subscription("fileChooser", true)
+                    .await;
+            }
+            self.filechooser_handlers.lock().unwrap().push(handler);
 
-        Ok(())
+            Ok(())
+        })
     }
 
     /// Creates a one-shot waiter that resolves when the next file chooser opens.
@@ -2360,29 +2598,33 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-wait-for-event>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn expect_file_chooser(
+    #[track_caller]
+    pub fn expect_file_chooser(
         &self,
         timeout: Option<f64>,
-    ) -> Result<crate::protocol::EventWaiter<crate::protocol::FileChooser>> {
-        let (tx, rx) = tokio::sync::oneshot::channel();
-
-        let needs_subscription = {
-            let handlers = self.filechooser_handlers.lock().unwrap();
-            let waiters = self.filechooser_waiters.lock().unwrap();
-            handlers.is_empty() && waiters.is_empty()
-        };
-        if needs_subscription {
-            _ = self
-                .channel()
-                .update_subscription("fileChooser", true)
-                .await;
-        }
-        self.filechooser_waiters.lock().unwrap().push(tx);
+    ) -> impl Future<Output = Result<crate::protocol::EventWaiter<crate::protocol::FileChooser>>>
+    {
+        CallSite::caller().run(async move {
+            let (tx, rx) = tokio::sync::oneshot::channel();
+
+            let needs_subscription = {
+                let handlers = self.filechooser_handlers.lock().unwrap();
+                let waiters = self.filechooser_waiters.lock().unwrap();
+                handlers.is_empty() && waiters.is_empty()
+            };
+            if needs_subscription {
+                _ = self
+                    .channel()
+                    .update_subscription("fileChooser", true)
+                    .await;
+            }
+            self.filechooser_waiters.lock().unwrap().push(tx);
 
-        Ok(crate::protocol::EventWaiter::new(
-            rx,
-            timeout.or(Some(30_000.0)),
-        ))
+            Ok(crate::protocol::EventWaiter::new(
+                rx,
+                timeout.or(Some(30_000.0)),
+            ))
+        })
     }
 
     /// Creates a one-shot waiter that resolves when the next popup window opens.
@@ -2401,16 +2643,19 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-wait-for-event>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn expect_popup(
+    #[track_caller]
+    pub fn expect_popup(
         &self,
         timeout: Option<f64>,
-    ) -> Result<crate::protocol::EventWaiter<Page>> {
-        let (tx, rx) = tokio::sync::oneshot::channel();
-        self.popup_waiters.lock().unwrap().push(tx);
-        Ok(crate::protocol::EventWaiter::new(
-            rx,
-            timeout.or(Some(30_000.0)),
-        ))
+    ) -> impl Future<Output = Result<crate::protocol::EventWaiter<Page>>> {
+        CallSite::caller().run(async move {
+            let (tx, rx) = tokio::sync::oneshot::channel();
+            self.popup_waiters.lock().unwrap().push(tx);
+            Ok(crate::protocol::EventWaiter::new(
+                rx,
+                timeout.or(Some(30_000.0)),
+            ))
+        })
     }
 
     /// Creates a one-shot waiter that resolves when the next download starts.
@@ -2429,16 +2674,19 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-wait-for-event>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn expect_download(
+    #[track_caller]
+    pub fn expect_download(
         &self,
         timeout: Option<f64>,
-    ) -> Result<crate::protocol::EventWaiter<Download>> {
-        let (tx, rx) = tokio::sync::oneshot::channel();
-        self.download_waiters.lock().unwrap().push(tx);
-        Ok(crate::protocol::EventWaiter::new(
-            rx,
-            timeout.or(Some(30_000.0)),
-        ))
+    ) -> impl Future<Output = Result<crate::protocol::EventWaiter<Download>>> {
+        CallSite::caller().run(async move {
+            let (tx, rx) = tokio::sync::oneshot::channel();
+            self.download_waiters.lock().unwrap().push(tx);
+            Ok(crate::protocol::EventWaiter::new(
+                rx,
+                timeout.or(Some(30_000.0)),
+            ))
+        })
     }
 
     /// Creates a one-shot waiter that resolves when the next network response is received.
@@ -2457,8 +2705,34 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-wait-for-event>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn expect_response(
+    #[track_caller]
+    pub fn expect_response(
+        &self,
+        timeout: Option<f64>,
+    ) -> impl Future<Output = Result<crate::protocol::EventWaiter<ResponseObject>>> {
+        CallSite::caller().run(self.add_response_waiter(None, timeout))
+    }
+
+    /// Like [`expect_response`](Self::expect_response), but resolves with
+    /// the next response whose URL matches `url`: a glob, a `Regex`, or any
+    /// other [`UrlMatcher`].
+    ///
+    /// See: <https://playwright.dev/docs/api/class-page#page-wait-for-response>
+    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = tracing::field::Empty))]
+    #[track_caller]
+    pub fn expect_response_matching(
         &self,
+        url: impl Into<UrlMatcher>,
+        timeout: Option<f64>,
+    ) -> impl Future<Output = Result<crate::protocol::EventWaiter<ResponseObject>>> {
+        let url = url.into();
+        tracing::Span::current().record("url", tracing::field::display(&url));
+        CallSite::caller().run(self.add_response_waiter(Some(url), timeout))
+    }
+
+    async fn add_response_waiter(
+        &self,
+        url: Option<UrlMatcher>,
         timeout: Option<f64>,
     ) -> Result<crate::protocol::EventWaiter<ResponseObject>> {
         let (tx, rx) = tokio::sync::oneshot::channel();
@@ -2471,7 +2745,10 @@ impl Page {
         if needs_subscription {
             _ = self.channel().update_subscription("response", true).await;
         }
-        self.response_waiters.lock().unwrap().push(tx);
+        self.response_waiters
+            .lock()
+            .unwrap()
+            .push(UrlWaiter { url, tx });
 
         Ok(crate::protocol::EventWaiter::new(
             rx,
@@ -2495,9 +2772,35 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-wait-for-event>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn expect_request(
+    #[track_caller]
+    pub fn expect_request(
         &self,
         timeout: Option<f64>,
+    ) -> impl Future<Output = Result<crate::protocol::EventWaiter<Request>>> {
+        CallSite::caller().run(self.add_request_waiter(None, timeout))
+    }
+
+    /// Like [`expect_request`](Self::expect_request), but resolves with the
+    /// next request whose URL matches `url`: a glob, a `Regex`, or any other
+    /// [`UrlMatcher`].
+    ///
+    /// See: <https://playwright.dev/docs/api/class-page#page-wait-for-request>
+    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), url = tracing::field::Empty))]
+    #[track_caller]
+    pub fn expect_request_matching(
+        &self,
+        url: impl Into<UrlMatcher>,
+        timeout: Option<f64>,
+    ) -> impl Future<Output = Result<crate::protocol::EventWaiter<Request>>> {
+        let url = url.into();
+        tracing::Span::current().record("url", tracing::field::display(&url));
+        CallSite::caller().run(self.add_request_waiter(Some(url), timeout))
+    }
+
+    async fn add_request_waiter(
+        &self,
+        url: Option<UrlMatcher>,
+        timeout: Option<f64>,
     ) -> Result<crate::protocol::EventWaiter<Request>> {
         let (tx, rx) = tokio::sync::oneshot::channel();
 
@@ -2509,7 +2812,10 @@ impl Page {
         if needs_subscription {
             _ = self.channel().update_subscription("request", true).await;
         }
-        self.request_waiters.lock().unwrap().push(tx);
+        self.request_waiters
+            .lock()
+            .unwrap()
+            .push(UrlWaiter { url, tx });
 
         Ok(crate::protocol::EventWaiter::new(
             rx,
@@ -2533,26 +2839,30 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-wait-for-event>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn expect_console_message(
+    #[track_caller]
+    pub fn expect_console_message(
         &self,
         timeout: Option<f64>,
-    ) -> Result<crate::protocol::EventWaiter<crate::protocol::ConsoleMessage>> {
-        let (tx, rx) = tokio::sync::oneshot::channel();
-
-        let needs_subscription = {
-            let handlers = self.console_handlers.lock().unwrap();
-            let waiters = self.console_waiters.lock().unwrap();
-            handlers.is_empty() && waiters.is_empty()
-        };
-        if needs_subscription {
-            _ = self.channel().update_subscription("console", true).await;
-        }
-        self.console_waiters.lock().unwrap().push(tx);
+    ) -> impl Future<Output = Result<crate::protocol::EventWaiter<crate::protocol::ConsoleMessage>>>
+    {
+        CallSite::caller().run(async move {
+            let (tx, rx) = tokio::sync::oneshot::channel();
+
+            let needs_subscription = {
+                let handlers = self.console_handlers.lock().unwrap();
+                let waiters = self.console_waiters.lock().unwrap();
+                handlers.is_empty() && waiters.is_empty()
+            };
+            if needs_subscription {
+                _ = self.channel().update_subscription("console", true).await;
+            }
+            self.console_waiters.lock().unwrap().push(tx);
 
-        Ok(crate::protocol::EventWaiter::new(
-            rx,
-            timeout.or(Some(30_000.0)),
-        ))
+            Ok(crate::protocol::EventWaiter::new(
+                rx,
+                timeout.or(Some(30_000.0)),
+            ))
+        })
     }
 
     /// Waits for the given event to fire and returns a typed `EventValue`.
@@ -2580,395 +2890,418 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-wait-for-event>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn expect_event(
+    #[track_caller]
+    pub fn expect_event(
         &self,
         event: &str,
         timeout: Option<f64>,
-    ) -> Result<crate::protocol::EventWaiter<crate::protocol::EventValue>> {
-        use crate::protocol::EventValue;
-        use tokio::sync::oneshot;
-
-        let timeout_ms = timeout.or(Some(30_000.0));
-
-        match event {
-            "request" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<Request>();
-
-                let needs_subscription = {
-                    let handlers = self.request_handlers.lock().unwrap();
-                    let waiters = self.request_waiters.lock().unwrap();
-                    handlers.is_empty() && waiters.is_empty()
-                };
-                if needs_subscription {
-                    _ = self.channel().update_subscription("request", true).await;
-                }
-                self.request_waiters.lock().unwrap().push(inner_tx);
-
-                tokio::spawn(
-                    async move {
-                        if let Ok(v) = inner_rx.await {
-                            let _ = tx.send(EventValue::Request(v));
-                        }
+    ) -> impl Future<Output = Result<crate::protocol::EventWaiter<crate::protocol::EventValue>>>
+    {
+        CallSite::caller().run(async move {
+            use crate::protocol::EventValue;
+            use tokio::sync::oneshot;
+
+            let timeout_ms = timeout.or(Some(30_000.0));
+
+            match event {
+                "request" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<Request>();
+
+                    let needs_subscription = {
+                        let handlers = self.request_handlers.lock().unwrap();
+                        let waiters = self.request_waiters.lock().unwrap();
+                        handlers.is_empty() && waiters.is_empty()
+                    };
+                    if needs_subscription {
+                        _ = self.channel().update_subscription("request", true).await;
                     }
-                    .in_current_span(),
-                );
+                    self.request_waiters.lock().unwrap().push(UrlWaiter {
+                        url: None,
+                        tx: inner_tx,
+                    });
 
-                Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
-            }
+                    tokio::spawn(
+                        async move {
+                            if let Ok(v) = inner_rx.await {
+                                let _ = tx.send(EventValue::Request(v));
+                            }
+                        }
+                        .in_current_span(),
+                    );
 
-            "response" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<ResponseObject>();
-
-                let needs_subscription = {
-                    let handlers = self.response_handlers.lock().unwrap();
-                    let waiters = self.response_waiters.lock().unwrap();
-                    handlers.is_empty() && waiters.is_empty()
-                };
-                if needs_subscription {
-                    _ = self.channel().update_subscription("response", true).await;
+                    Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
                 }
-                self.response_waiters.lock().unwrap().push(inner_tx);
 
-                tokio::spawn(
-                    async move {
-                        if let Ok(v) = inner_rx.await {
-                            let _ = tx.send(EventValue::Response(v));
-                        }
+                "response" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<ResponseObject>();
+
+                    let needs_subscription = {
+                        let handlers = self.response_handlers.lock().unwrap();
+                        let waiters = self.response_waiters.lock().unwrap();
+                        handlers.is_empty() && waiters.is_empty()
+                    };
+                    if needs_subscription {
+                        _ = self.channel().update_subscription("response", true).await;
                     }
-                    .in_current_span(),
-                );
+                    self.response_waiters.lock().unwrap().push(UrlWaiter {
+                        url: None,
+                        tx: inner_tx,
+                    });
 
-                Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
-            }
+                    tokio::spawn(
+                        async move {
+                            if let Ok(v) = inner_rx.await {
+                                let _ = tx.send(EventValue::Response(v));
+                            }
+                        }
+                        .in_current_span(),
+                    );
 
-            "popup" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<Page>();
-                self.popup_waiters.lock().unwrap().push(inner_tx);
+                    Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
+                }
 
-                tokio::spawn(
-                    async move {
-                        if let Ok(v) = inner_rx.await {
-                            let _ = tx.send(EventValue::Page(v));
+                "popup" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<Page>();
+                    self.popup_waiters.lock().unwrap().push(inner_tx);
+
+                    tokio::spawn(
+                        async move {
+                            if let Ok(v) = inner_rx.await {
+                                let _ = tx.send(EventValue::Page(v));
+                            }
                         }
-                    }
-                    .in_current_span(),
-                );
+                        .in_current_span(),
+                    );
 
-                Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
-            }
+                    Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
+                }
 
-            "download" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::Download>();
-                self.download_waiters.lock().unwrap().push(inner_tx);
+                "download" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::Download>();
+                    self.download_waiters.lock().unwrap().push(inner_tx);
 
-                tokio::spawn(
-                    async move {
-                        if let Ok(v) = inner_rx.await {
-                            let _ = tx.send(EventValue::Download(v));
+                    tokio::spawn(
+                        async move {
+                            if let Ok(v) = inner_rx.await {
+                                let _ = tx.send(EventValue::Download(v));
+                            }
                         }
-                    }
-                    .in_current_span(),
-                );
-
-                Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
-            }
+                        .in_current_span(),
+                    );
 
-            "console" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::ConsoleMessage>();
-
-                let needs_subscription = {
-                    let handlers = self.console_handlers.lock().unwrap();
-                    let waiters = self.console_waiters.lock().unwrap();
-                    handlers.is_empty() && waiters.is_empty()
-                };
-                if needs_subscription {
-                    _ = self.channel().update_subscription("console", true).await;
+                    Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
                 }
-                self.console_waiters.lock().unwrap().push(inner_tx);
 
-                tokio::spawn(
-                    async move {
-                        if let Ok(v) = inner_rx.await {
-                            let _ = tx.send(EventValue::ConsoleMessage(v));
-                        }
+                "console" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) =
+                        oneshot::channel::<crate::protocol::ConsoleMessage>();
+
+                    let needs_subscription = {
+                        let handlers = self.console_handlers.lock().unwrap();
+                        let waiters = self.console_waiters.lock().unwrap();
+                        handlers.is_empty() && waiters.is_empty()
+                    };
+                    if needs_subscription {
+                        _ = self.channel().update_subscription("console", true).await;
                     }
-                    .in_current_span(),
-                );
+                    self.console_waiters.lock().unwrap().push(inner_tx);
 
-                Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
-            }
+                    tokio::spawn(
+                        async move {
+                            if let Ok(v) = inner_rx.await {
+                                let _ = tx.send(EventValue::ConsoleMessage(v));
+                            }
+                        }
+                        .in_current_span(),
+                    );
 
-            "filechooser" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::FileChooser>();
-
-                let needs_subscription = {
-                    let handlers = self.filechooser_handlers.lock().unwrap();
-                    let waiters = self.filechooser_waiters.lock().unwrap();
-                    handlers.is_empty() && waiters.is_empty()
-                };
-                if needs_subscription {
-                    _ = self
-                        .channel()
-                        .update_subscription("fileChooser", true)
-                        .await;
+                    Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
                 }
-                self.filechooser_waiters.lock().unwrap().push(inner_tx);
 
-                tokio::spawn(
-                    async move {
-                        if let Ok(v) = inner_rx.await {
-                            let _ = tx.send(EventValue::FileChooser(v));
-                        }
+                "filechooser" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::FileChooser>();
+
+                    let needs_subscription = {
+                        let handlers = self.filechooser_handlers.lock().unwrap();
+                        let waiters = self.filechooser_waiters.lock().unwrap();
+                        handlers.is_empty() && waiters.is_empty()
+                    };
+                    if needs_subscription {
+                        _ = self
+                            .channel()
+                            .update_subscription("fileChooser", true)
+                            .await;
                     }
-                    .in_current_span(),
-                );
+                    self.filechooser_waiters.lock().unwrap().push(inner_tx);
 
-                Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
-            }
+                    tokio::spawn(
+                        async move {
+                            if let Ok(v) = inner_rx.await {
+                                let _ = tx.send(EventValue::FileChooser(v));
+                            }
+                        }
+                        .in_current_span(),
+                    );
 
-            "close" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<()>();
-                self.close_waiters.lock().unwrap().push(inner_tx);
+                    Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
+                }
 
-                tokio::spawn(
-                    async move {
-                        if inner_rx.await.is_ok() {
-                            let _ = tx.send(EventValue::Close);
+                "close" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<()>();
+                    self.close_waiters.lock().unwrap().push(inner_tx);
+
+                    tokio::spawn(
+                        async move {
+                            if inner_rx.await.is_ok() {
+                                let _ = tx.send(EventValue::Close);
+                            }
                         }
-                    }
-                    .in_current_span(),
-                );
+                        .in_current_span(),
+                    );
 
-                Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
-            }
+                    Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
+                }
 
-            "load" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<()>();
-                self.load_waiters.lock().unwrap().push(inner_tx);
+                "load" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<()>();
+                    self.load_waiters.lock().unwrap().push(inner_tx);
 
-                tokio::spawn(
-                    async move {
-                        if inner_rx.await.is_ok() {
-                            let _ = tx.send(EventValue::Load);
+                    tokio::spawn(
+                        async move {
+                            if inner_rx.await.is_ok() {
+                                let _ = tx.send(EventValue::Load);
+                            }
                         }
-                    }
-                    .in_current_span(),
-                );
+                        .in_current_span(),
+                    );
 
-                Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
-            }
+                    Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
+                }
 
-            "crash" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<()>();
-                self.crash_waiters.lock().unwrap().push(inner_tx);
+                "crash" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<()>();
+                    self.crash_waiters.lock().unwrap().push(inner_tx);
 
-                tokio::spawn(
-                    async move {
-                        if inner_rx.await.is_ok() {
-                            let _ = tx.send(EventValue::Crash);
+                    tokio::spawn(
+                        async move {
+                            if inner_rx.await.is_ok() {
+                                let _ = tx.send(EventValue::Crash);
+                            }
                         }
-                    }
-                    .in_current_span(),
-                );
+                        .in_current_span(),
+                    );
 
-                Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
-            }
+                    Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
+                }
 
-            "pageerror" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<String>();
-                self.pageerror_waiters.lock().unwrap().push(inner_tx);
+                "pageerror" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<String>();
+                    self.pageerror_waiters.lock().unwrap().push(inner_tx);
 
-                tokio::spawn(
-                    async move {
-                        if let Ok(msg) = inner_rx.await {
-                            let _ = tx.send(EventValue::PageError(msg));
+                    tokio::spawn(
+                        async move {
+                            if let Ok(msg) = inner_rx.await {
+                                let _ = tx.send(EventValue::PageError(msg));
+                            }
                         }
-                    }
-                    .in_current_span(),
-                );
+                        .in_current_span(),
+                    );
 
-                Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
-            }
+                    Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
+                }
 
-            "frameattached" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::Frame>();
-                self.frameattached_waiters.lock().unwrap().push(inner_tx);
+                "frameattached" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::Frame>();
+                    self.frameattached_waiters.lock().unwrap().push(inner_tx);
 
-                tokio::spawn(
-                    async move {
-                        if let Ok(v) = inner_rx.await {
-                            let _ = tx.send(EventValue::Frame(v));
+                    tokio::spawn(
+                        async move {
+                            if let Ok(v) = inner_rx.await {
+                                let _ = tx.send(EventValue::Frame(v));
+                            }
                         }
-                    }
-                    .in_current_span(),
-                );
+                        .in_current_span(),
+                    );
 
-                Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
-            }
+                    Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
+                }
 
-            "framedetached" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::Frame>();
-                self.framedetached_waiters.lock().unwrap().push(inner_tx);
+                "framedetached" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::Frame>();
+                    self.framedetached_waiters.lock().unwrap().push(inner_tx);
 
-                tokio::spawn(
-                    async move {
-                        if let Ok(v) = inner_rx.await {
-                            let _ = tx.send(EventValue::Frame(v));
+                    tokio::spawn(
+                        async move {
+                            if let Ok(v) = inner_rx.await {
+                                let _ = tx.send(EventValue::Frame(v));
+                            }
                         }
-                    }
-                    .in_current_span(),
-                );
+                        .in_current_span(),
+                    );
 
-                Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
-            }
+                    Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
+                }
 
-            "framenavigated" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::Frame>();
-                self.framenavigated_waiters.lock().unwrap().push(inner_tx);
+                "framenavigated" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::Frame>();
+                    self.framenavigated_waiters.lock().unwrap().push(inner_tx);
 
-                tokio::spawn(
-                    async move {
-                        if let Ok(v) = inner_rx.await {
-                            let _ = tx.send(EventValue::Frame(v));
+                    tokio::spawn(
+                        async move {
+                            if let Ok(v) = inner_rx.await {
+                                let _ = tx.send(EventValue::Frame(v));
+                            }
                         }
-                    }
-                    .in_current_span(),
-                );
+                        .in_current_span(),
+                    );
 
-                Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
-            }
+                    Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
+                }
 
-            "worker" => {
-                let (tx, rx) = oneshot::channel::<EventValue>();
-                let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::Worker>();
-                self.worker_waiters.lock().unwrap().push(inner_tx);
+                "worker" => {
+                    let (tx, rx) = oneshot::channel::<EventValue>();
+                    let (inner_tx, inner_rx) = oneshot::channel::<crate::protocol::Worker>();
+                    self.worker_waiters.lock().unwrap().push(inner_tx);
 
-                tokio::spawn(
-                    async move {
-                        if let Ok(v) = inner_rx.await {
-                            let _ = tx.send(EventValue::Worker(v));
+                    tokio::spawn(
+                        async move {
+                            if let Ok(v) = inner_rx.await {
+                                let _ = tx.send(EventValue::Worker(v));
+                            }
                         }
-                    }
-                    .in_current_span(),
-                );
+                        .in_current_span(),
+                    );
 
-                Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
-            }
+                    Ok(crate::protocol::EventWaiter::new(rx, timeout_ms))
+                }
 
-            other => Err(Error::InvalidArgument(format!(
-                "Unknown event name '{}'. Supported: request, response, popup, download, \
+                other => Err(Error::InvalidArgument(format!(
+                    "Unknown event name '{}'. Supported: request, response, popup, download, \
                  console, filechooser, close, load, crash, pageerror, \
                  frameattached, framedetached, framenavigated, worker",
-                other
-            ))),
-        }
+                    other
+                ))),
+            }
+        })
     }
 
     /// See: <https://playwright.dev/docs/api/class-page#page-event-request>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_request<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_request<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Request) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |request: Request| -> RequestHandlerFuture {
-            Box::pin(handler(request))
-        });
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move |request: Request| -> RequestHandlerFuture {
+                Box::pin(handler(request))
+            });
 
-        let needs_subscription = {
-            let handlers = self.request_handlers.lock().unwrap();
-            let waiters = self.request_waiters.lock().unwrap();
-            handlers.is_empty() && waiters.is_empty()
-        };
-        if needs_subscription {
-            _ = self.channel().update_subscription("request", true).await;
-        }
-        self.request_handlers.lock().unwrap().push(handler);
+            let needs_subscription = {
+                let handlers = self.request_handlers.lock().unwrap();
+                let waiters = self.request_waiters.lock().unwrap();
+                handlers.is_empty() && waiters.is_empty()
+            };
+            if needs_subscription {
+                _ = self.channel().update_subscription("request", true).await;
+            }
+            self.request_handlers.lock().unwrap().push(handler);
 
-        Ok(())
+            Ok(())
+        })
     }
 
     /// See: <https://playwright.dev/docs/api/class-page#page-event-request-finished>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_request_finished<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_request_finished<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Request) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |request: Request| -> RequestHandlerFuture {
-            Box::pin(handler(request))
-        });
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move |request: Request| -> RequestHandlerFuture {
+                Box::pin(handler(request))
+            });
 
-        let needs_subscription = self.request_finished_handlers.lock().unwrap().is_empty();
-        if needs_subscription {
-            _ = self
-                .channel()
-                .update_subscription("requestFinished", true)
-                .await;
-        }
-        self.request_finished_handlers.lock().unwrap().push(handler);
+            let needs_subscription = self.request_finished_handlers.lock().unwrap().is_empty();
+            if needs_subscription {
+                _ = self
+                    .channel()
+                    .update_subscription("requestFinished", true)
+                    .await;
+            }
+            self.request_finished_handlers.lock().unwrap().push(handler);
 
-        Ok(())
+            Ok(())
+        })
     }
 
     /// See: <https://playwright.dev/docs/api/class-page#page-event-request-failed>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_request_failed<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_request_failed<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Request) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |request: Request| -> RequestHandlerFuture {
-            Box::pin(handler(request))
-        });
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move |request: Request| -> RequestHandlerFuture {
+                Box::pin(handler(request))
+            });
 
-        let needs_subscription = self.request_failed_handlers.lock().unwrap().is_empty();
-        if needs_subscription {
-            _ = self
-                .channel()
-                .update_subscription("requestFailed", true)
-                .await;
-        }
-        self.request_failed_handlers.lock().unwrap().push(handler);
+            let needs_subscription = self.request_failed_handlers.lock().unwrap().is_empty();
+            if needs_subscription {
+                _ = self
+                    .channel()
+                    .update_subscription("requestFailed", true)
+                    .await;
+            }
+            self.request_failed_handlers.lock().unwrap().push(handler);
 
-        Ok(())
+            Ok(())
+        })
     }
 
     /// See: <https://playwright.dev/docs/api/class-page#page-event-response>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_response<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_response<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(ResponseObject) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |response: ResponseObject| -> ResponseHandlerFuture {
-            Box::pin(handler(response))
-        });
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move |response: ResponseObject| -> ResponseHandlerFuture {
+                Box::pin(handler(response))
+            });
 
-        let needs_subscription = {
-            let handlers = self.response_handlers.lock().unwrap();
-            let waiters = self.response_waiters.lock().unwrap();
-            handlers.is_empty() && waiters.is_empty()
-        };
-        if needs_subscription {
-            _ = self.channel().update_subscription("response", true).await;
-        }
-        self.response_handlers.lock().unwrap().push(handler);
+            let needs_subscription = {
+                let handlers = self.response_handlers.lock().unwrap();
+                let waiters = self.response_waiters.lock().unwrap();
+                handlers.is_empty() && waiters.is_empty()
+            };
+            if needs_subscription {
+                _ = self.channel().update_subscription("response", true).await;
+            }
+            self.response_handlers.lock().unwrap().push(handler);
 
-        Ok(())
+            Ok(())
+        })
     }
 
     /// Adds a listener for the `websocket` event.
@@ -2981,15 +3314,18 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-on-websocket>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_websocket<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_websocket<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(WebSocket) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler =
-            Arc::new(move |ws: WebSocket| -> WebSocketHandlerFuture { Box::pin(handler(ws)) });
-        self.websocket_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler =
+                Arc::new(move |ws: WebSocket| -> WebSocketHandlerFuture { Box::pin(handler(ws)) });
+            self.websocket_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Registers a handler for the `worker` event.
@@ -3002,14 +3338,18 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-event-worker>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_worker<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_worker<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Worker) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |w: Worker| -> WorkerHandlerFuture { Box::pin(handler(w)) });
-        self.worker_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler =
+                Arc::new(move |w: Worker| -> WorkerHandlerFuture { Box::pin(handler(w)) });
+            self.worker_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Registers a handler for the `close` event.
@@ -3023,14 +3363,17 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-event-close>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_close<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_close<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn() -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move || -> CloseHandlerFuture { Box::pin(handler()) });
-        self.close_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move || -> CloseHandlerFuture { Box::pin(handler()) });
+            self.close_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Registers a handler for the `load` event.
@@ -3047,15 +3390,18 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-event-load>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_load<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_load<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn() -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move || -> LoadHandlerFuture { Box::pin(handler()) });
-        // "load" events come via Frame's "loadstate" event, no subscription needed.
-        self.load_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move || -> LoadHandlerFuture { Box::pin(handler()) });
+            // "load" events come via Frame's "loadstate" event, no subscription needed.
+            self.load_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Registers a handler for the `crash` event.
@@ -3068,14 +3414,17 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-event-crash>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_crash<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_crash<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn() -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move || -> CrashHandlerFuture { Box::pin(handler()) });
-        self.crash_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler = Arc::new(move || -> CrashHandlerFuture { Box::pin(handler()) });
+            self.crash_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Registers a handler for the `pageError` event.
@@ -3092,16 +3441,19 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-event-page-error>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_pageerror<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_pageerror<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(String) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler =
-            Arc::new(move |msg: String| -> PageErrorHandlerFuture { Box::pin(handler(msg)) });
-        // "pageError" events come via BrowserContext, no subscription needed.
-        self.pageerror_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler =
+                Arc::new(move |msg: String| -> PageErrorHandlerFuture { Box::pin(handler(msg)) });
+            // "pageError" events come via BrowserContext, no subscription needed.
+            self.pageerror_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Registers a handler for the `popup` event.
@@ -3118,15 +3470,19 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-event-popup>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_popup<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_popup<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(Page) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(move |page: Page| -> PopupHandlerFuture { Box::pin(handler(page)) });
-        // "popup" events arrive via BrowserContext's "page" event when a page has an opener.
-        self.popup_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler =
+                Arc::new(move |page: Page| -> PopupHandlerFuture { Box::pin(handler(page)) });
+            // "popup" events arrive via BrowserContext's "page" event when a page has an opener.
+            self.popup_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Registers a handler for the `frameAttached` event.
@@ -3140,18 +3496,21 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-event-frameattached>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_frameattached<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_frameattached<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(crate::protocol::Frame) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(
-            move |frame: crate::protocol::Frame| -> FrameEventHandlerFuture {
-                Box::pin(handler(frame))
-            },
-        );
-        self.frameattached_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler = Arc::new(
+                move |frame: crate::protocol::Frame| -> FrameEventHandlerFuture {
+                    Box::pin(handler(frame))
+                },
+            );
+            self.frameattached_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Registers a handler for the `frameDetached` event.
@@ -3165,18 +3524,21 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-event-framedetached>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_framedetached<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_framedetached<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(crate::protocol::Frame) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(
-            move |frame: crate::protocol::Frame| -> FrameEventHandlerFuture {
-                Box::pin(handler(frame))
-            },
-        );
-        self.framedetached_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler = Arc::new(
+                move |frame: crate::protocol::Frame| -> FrameEventHandlerFuture {
+                    Box::pin(handler(frame))
+                },
+            );
+            self.framedetached_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Registers a handler for the `frameNavigated` event.
@@ -3190,18 +3552,21 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-event-framenavigated>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn on_framenavigated<F, Fut>(&self, handler: F) -> Result<()>
+    #[track_caller]
+    pub fn on_framenavigated<F, Fut>(&self, handler: F) -> impl Future<Output = Result<()>>
     where
         F: Fn(crate::protocol::Frame) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let handler = Arc::new(
-            move |frame: crate::protocol::Frame| -> FrameEventHandlerFuture {
-                Box::pin(handler(frame))
-            },
-        );
-        self.framenavigated_handlers.lock().unwrap().push(handler);
-        Ok(())
+        CallSite::caller().run(async move {
+            let handler = Arc::new(
+                move |frame: crate::protocol::Frame| -> FrameEventHandlerFuture {
+                    Box::pin(handler(frame))
+                },
+            );
+            self.framenavigated_handlers.lock().unwrap().push(handler);
+            Ok(())
+        })
     }
 
     /// Exposes a Rust function to this page as `window[name]` in JavaScript.
@@ -3227,12 +3592,18 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-expose-function>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), name = %name))]
-    pub async fn expose_function<F, Fut>(&self, name: &str, callback: F) -> Result<()>
+    #[track_caller]
+    pub fn expose_function<F, Fut>(
+        &self,
+        name: &str,
+        callback: F,
+    ) -> impl Future<Output = Result<()>>
     where
         F: Fn(Vec<serde_json::Value>) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = serde_json::Value> + Send + 'static,
     {
-        self.expose_binding_internal(name, false, callback).await
+        CallSite::caller()
+            .run(async move { self.expose_binding_internal(name, false, callback).await })
     }
 
     /// Exposes a Rust function to this page as `window[name]` in JavaScript.
@@ -3255,12 +3626,18 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-expose-binding>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid(), name = %name))]
-    pub async fn expose_binding<F, Fut>(&self, name: &str, callback: F) -> Result<()>
+    #[track_caller]
+    pub fn expose_binding<F, Fut>(
+        &self,
+        name: &str,
+        callback: F,
+    ) -> impl Future<Output = Result<()>>
     where
         F: Fn(Vec<serde_json::Value>) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = serde_json::Value> + Send + 'static,
     {
-        self.expose_binding_internal(name, false, callback).await
+        CallSite::caller()
+            .run(async move { self.expose_binding_internal(name, false, callback).await })
     }
 
     /// Internal implementation shared by page-level expose_function and expose_binding.
@@ -3329,9 +3706,9 @@ impl Page {
                 tracing::warn!("Request handler error: {}", e);
             }
         }
-        // Notify the first expect_request() waiter (FIFO order)
-        if let Some(tx) = self.request_waiters.lock().unwrap().pop() {
-            let _ = tx.send(request);
+        // Notify the most recent expect_request() waiter this URL satisfies
+        if let Some(waiter) = take_url_waiter(&self.request_waiters, request.url()) {
+            let _ = waiter.tx.send(request);
         }
     }
 
@@ -3363,9 +3740,9 @@ impl Page {
                 tracing::warn!("Response handler error: {}", e);
             }
         }
-        // Notify the first expect_response() waiter (FIFO order)
-        if let Some(tx) = self.response_waiters.lock().unwrap().pop() {
-            let _ = tx.send(response);
+        // Notify the most recent expect_response() waiter this URL satisfies
+        if let Some(waiter) = take_url_waiter(&self.response_waiters, response.url()) {
+            let _ = waiter.tx.send(response);
         }
     }
 
@@ -3390,57 +3767,64 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-add-locator-handler>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn add_locator_handler<F, Fut>(
+    #[track_caller]
+    pub fn add_locator_handler<F, Fut>(
         &self,
         locator: &crate::protocol::Locator,
         handler: F,
         options: impl Into<Option<AddLocatorHandlerOptions>>,
-    ) -> Result<()>
+    ) -> impl Future<Output = Result<()>>
     where
         F: Fn(crate::protocol::Locator) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<()>> + Send + 'static,
     {
-        let options = options.into();
-        let selector = locator.selector().to_string();
-        let no_wait_after = options
-            .as_ref()
-            .and_then(|o| o.no_wait_after)
-            .unwrap_or(false);
-        let times = options.as_ref().and_then(|o| o.times);
-
-        // Send registerLocatorHandler RPC — returns {"uid": N}
-        let params = serde_json::json!({
-            "selector": selector,
-            "noWaitAfter": no_wait_after,
-        });
-        let result: Value = self
-            .channel()
-            .send("registerLocatorHandler", params)
-            .await?;
-
-        let uid = result
-            .get("uid")
-            .and_then(|v| v.as_u64())
-            .map(|v| v as u32)
-            .ok_or_else(|| {
-                Error::ProtocolError("registerLocatorHandler response missing 'uid'".to_string())
-            })?;
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let selector = locator.selector().to_string();
+            let no_wait_after = options
+                .as_ref()
+                .and_then(|o| o.no_wait_after)
+                .unwrap_or(false);
+            let times = options.as_ref().and_then(|o| o.times);
+
+            // Send registerLocatorHandler RPC — returns {"uid": N}
+            let params = serde_json::json!({
+                "selector": selector,
+                "noWaitAfter": no_wait_after,
+            });
+            let result: Value = self
+                .channel()
+                .send("registerLocatorHandler", params)
+                .await?;
 
-        let handler_fn: LocatorHandlerFn = Arc::new(
-            move |loc: crate::protocol::Locator| -> LocatorHandlerFuture { Box::pin(handler(loc)) },
-        );
+            let uid = result
+                .get("uid")
+                .and_then(|v| v.as_u64())
+                .map(|v| v as u32)
+                .ok_or_else(|| {
+                    Error::ProtocolError(
+                        "registerLocatorHandler response missing 'uid'".to_string(),
+                    )
+                })?;
 
-        self.locator_handlers
-            .lock()
-            .unwrap()
-            .push(LocatorHandlerEntry {
-                uid,
-                selector,
-                handler: handler_fn,
-                times_remaining: times,
-            });
+            let handler_fn: LocatorHandlerFn = Arc::new(
+                move |loc: crate::protocol::Locator| -> LocatorHandlerFuture {
+                    Box::pin(handler(loc))
+                },
+            );
 
-        Ok(())
+            self.locator_handlers
+                .lock()
+                .unwrap()
+                .push(LocatorHandlerEntry {
+                    uid,
+                    selector,
+                    handler: handler_fn,
+                    times_remaining: times,
+                });
+
+            Ok(())
+        })
     }
 
     /// Removes a previously registered locator handler.
@@ -3459,40 +3843,46 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-remove-locator-handler>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn remove_locator_handler(&self, locator: &crate::protocol::Locator) -> Result<()> {
-        let selector = locator.selector();
-
-        // Find the uid for this selector
-        let uid = {
-            let handlers = self.locator_handlers.lock().unwrap();
-            handlers
-                .iter()
-                .find(|e| e.selector == selector)
-                .map(|e| e.uid)
-        };
-
-        let uid = uid.ok_or_else(|| {
-            Error::ProtocolError(format!(
-                "No locator handler registered for selector '{}'",
-                selector
-            ))
-        })?;
+    #[track_caller]
+    pub fn remove_locator_handler(
+        &self,
+        locator: &crate::protocol::Locator,
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let selector = locator.selector();
+
+            // Find the uid for this selector
+            let uid = {
+                let handlers = self.locator_handlers.lock().unwrap();
+                handlers
+                    .iter()
+                    .find(|e| e.selector == selector)
+                    .map(|e| e.uid)
+            };
+
+            let uid = uid.ok_or_else(|| {
+                Error::ProtocolError(format!(
+                    "No locator handler registered for selector '{}'",
+                    selector
+                ))
+            })?;
 
-        // Send unregisterLocatorHandler RPC
-        self.channel()
-            .send_no_result(
-                "unregisterLocatorHandler",
-                serde_json::json!({ "uid": uid }),
-            )
-            .await?;
+            // Send unregisterLocatorHandler RPC
+            self.channel()
+                .send_no_result(
+                    "unregisterLocatorHandler",
+                    serde_json::json!({ "uid": uid }),
+                )
+                .await?;
 
-        // Remove from local registry
-        self.locator_handlers
-            .lock()
-            .unwrap()
-            .retain(|e| e.uid != uid);
+            // Remove from local registry
+            self.locator_handlers
+                .lock()
+                .unwrap()
+                .retain(|e| e.uid != uid);
 
-        Ok(())
+            Ok(())
+        })
     }
 
     /// Triggers dialog event (called by BrowserContext when dialog events arrive)
@@ -3500,8 +3890,11 @@ impl Page {
     /// Dialog events are sent to BrowserContext and forwarded to the associated Page.
     /// This method is public so BrowserContext can forward dialog events.
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn trigger_dialog_event(&self, dialog: Dialog) {
-        self.on_dialog_event(dialog).await;
+    #[track_caller]
+    pub fn trigger_dialog_event(&self, dialog: Dialog) -> impl Future<Output = ()> {
+        CallSite::caller().run(async move {
+            self.on_dialog_event(dialog).await;
+        })
     }
 
     /// Triggers request event (called by BrowserContext when request events arrive)
@@ -3736,12 +4129,15 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-add-style-tag>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn add_style_tag(
+    #[track_caller]
+    pub fn add_style_tag(
         &self,
         options: AddStyleTagOptions,
-    ) -> Result<Arc<crate::protocol::ElementHandle>> {
-        let frame = self.main_frame().await?;
-        frame.add_style_tag(options).await
+    ) -> impl Future<Output = Result<Arc<crate::protocol::ElementHandle>>> {
+        CallSite::caller().run(async move {
+            let frame = self.main_frame().await?;
+            frame.add_style_tag(options).await
+        })
     }
 
     /// Adds a script which would be evaluated in one of the following scenarios:
@@ -3771,10 +4167,13 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-add-init-script>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn add_init_script(&self, script: &str) -> Result<()> {
-        self.channel()
-            .send_no_result("addInitScript", serde_json::json!({ "source": script }))
-            .await
+    #[track_caller]
+    pub fn add_init_script(&self, script: &str) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.channel()
+                .send_no_result("addInitScript", serde_json::json!({ "source": script }))
+                .await
+        })
     }
 
     /// Installs an opt-in fake of the File System Access API
@@ -3796,8 +4195,9 @@ impl Page {
     /// Returns an error if the page is closed or installing the script
     /// fails.
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn fake_file_system(&self) -> Result<crate::testing::FakeFileSystem> {
-        crate::testing::FakeFileSystem::install(self).await
+    #[track_caller]
+    pub fn fake_file_system(&self) -> impl Future<Output = Result<crate::testing::FakeFileSystem>> {
+        CallSite::caller().run(async move { crate::testing::FakeFileSystem::install(self).await })
     }
 
     /// Sets the viewport size for the page.
@@ -3843,17 +4243,23 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-set-viewport-size>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn set_viewport_size(&self, viewport: crate::protocol::Viewport) -> Result<()> {
-        // Store the new viewport locally so viewport_size() can reflect the change
-        if let Ok(mut guard) = self.viewport.write() {
-            *guard = Some(viewport.clone());
-        }
-        self.channel()
-            .send_no_result(
-                "setViewportSize",
-                serde_json::json!({ "viewportSize": viewport }),
-            )
-            .await
+    #[track_caller]
+    pub fn set_viewport_size(
+        &self,
+        viewport: crate::protocol::Viewport,
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            // Store the new viewport locally so viewport_size() can reflect the change
+            if let Ok(mut guard) = self.viewport.write() {
+                *guard = Some(viewport.clone());
+            }
+            self.channel()
+                .send_no_result(
+                    "setViewportSize",
+                    serde_json::json!({ "viewportSize": viewport }),
+                )
+                .await
+        })
     }
 
     /// Brings this page to the front (activates the tab).
@@ -3869,30 +4275,39 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-bring-to-front>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn bring_to_front(&self) -> Result<()> {
-        self.channel()
-            .send_no_result("bringToFront", serde_json::json!({}))
-            .await
+    #[track_caller]
+    pub fn bring_to_front(&self) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.channel()
+                .send_no_result("bringToFront", serde_json::json!({}))
+                .await
+        })
     }
 
     /// Clears all element highlights drawn by [`Locator::highlight`](crate::protocol::Locator::highlight).
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-hide-highlight>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn hide_highlight(&self) -> Result<()> {
-        self.channel()
-            .send_no_result("hideHighlight", serde_json::json!({}))
-            .await
+    #[track_caller]
+    pub fn hide_highlight(&self) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.channel()
+                .send_no_result("hideHighlight", serde_json::json!({}))
+                .await
+        })
     }
 
     /// Forces garbage collection in the browser (Chromium only).
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-request-gc>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn request_gc(&self) -> Result<()> {
-        self.channel()
-            .send_no_result("requestGC", serde_json::json!({}))
-            .await
+    #[track_caller]
+    pub fn request_gc(&self) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.channel()
+                .send_no_result("requestGC", serde_json::json!({}))
+                .await
+        })
     }
 
     /// Enters Playwright Inspector's interactive picker mode and resolves
@@ -3908,16 +4323,19 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-pick-locator>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
-    pub async fn pick_locator(&self) -> Result<crate::protocol::Locator> {
-        #[derive(serde::Deserialize)]
-        struct PickLocatorResponse {
-            selector: String,
-        }
-        let response: PickLocatorResponse = self
-            .channel()
-            .send("pickLocator", serde_json::json!({}))
-            .await?;
-        Ok(self.locator(&response.selector))
+    #[track_caller]
+    pub fn pick_locator(&self) -> impl Future<Output = Result<crate::protocol::Locator>> {
+        CallSite::caller().run(async move {
+            #[derive(serde::Deserialize)]
+            struct PickLocatorResponse {
+                selector: String,
+            }
+            let response: PickLocatorResponse = self
+                .channel()
+                .send("pickLocator", serde_json::json!({}))
+                .await?;
+            Ok(self.locator(&response.selector))
+        })
     }
 
     /// Cancels an in-progress [`Page::pick_locator`] call. Has no effect
@@ -3925,10 +4343,13 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-cancel-pick-locator>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn cancel_pick_locator(&self) -> Result<()> {
-        self.channel()
-            .send_no_result("cancelPickLocator", serde_json::json!({}))
-            .await
+    #[track_caller]
+    pub fn cancel_pick_locator(&self) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.channel()
+                .send_no_result("cancelPickLocator", serde_json::json!({}))
+                .await
+        })
     }
 
     /// Sets extra HTTP headers that will be sent with every request from this page.
@@ -3949,22 +4370,25 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-set-extra-http-headers>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn set_extra_http_headers(
+    #[track_caller]
+    pub fn set_extra_http_headers(
         &self,
         headers: std::collections::HashMap<String, String>,
-    ) -> Result<()> {
-        // Playwright protocol expects an array of {name, value} objects
-        // This RPC is sent on the Page channel (not the Frame channel)
-        let headers_array: Vec<serde_json::Value> = headers
-            .into_iter()
-            .map(|(name, value)| serde_json::json!({ "name": name, "value": value }))
-            .collect();
-        self.channel()
-            .send_no_result(
-                "setExtraHTTPHeaders",
-                serde_json::json!({ "headers": headers_array }),
-            )
-            .await
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            // Playwright protocol expects an array of {name, value} objects
+            // This RPC is sent on the Page channel (not the Frame channel)
+            let headers_array: Vec<serde_json::Value> = headers
+                .into_iter()
+                .map(|(name, value)| serde_json::json!({ "name": name, "value": value }))
+                .collect();
+            self.channel()
+                .send_no_result(
+                    "setExtraHTTPHeaders",
+                    serde_json::json!({ "headers": headers_array }),
+                )
+                .await
+        })
     }
 
     /// Emulates media features for the page.
@@ -4012,46 +4436,53 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-emulate-media>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn emulate_media(
+    #[track_caller]
+    pub fn emulate_media(
         &self,
         options: impl Into<Option<EmulateMediaOptions>>,
-    ) -> Result<()> {
-        let options = options.into();
-        let mut params = serde_json::json!({});
-
-        if let Some(opts) = options {
-            if let Some(media) = opts.media {
-                params["media"] = serde_json::to_value(media).map_err(|e| {
-                    crate::error::Error::ProtocolError(format!("Failed to serialize media: {}", e))
-                })?;
-            }
-            if let Some(color_scheme) = opts.color_scheme {
-                params["colorScheme"] = serde_json::to_value(color_scheme).map_err(|e| {
-                    crate::error::Error::ProtocolError(format!(
-                        "Failed to serialize colorScheme: {}",
-                        e
-                    ))
-                })?;
-            }
-            if let Some(reduced_motion) = opts.reduced_motion {
-                params["reducedMotion"] = serde_json::to_value(reduced_motion).map_err(|e| {
-                    crate::error::Error::ProtocolError(format!(
-                        "Failed to serialize reducedMotion: {}",
-                        e
-                    ))
-                })?;
-            }
-            if let Some(forced_colors) = opts.forced_colors {
-                params["forcedColors"] = serde_json::to_value(forced_colors).map_err(|e| {
-                    crate::error::Error::ProtocolError(format!(
-                        "Failed to serialize forcedColors: {}",
-                        e
-                    ))
-                })?;
+    ) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let mut params = serde_json::json!({});
+
+            if let Some(opts) = options {
+                if let Some(media) = opts.media {
+                    params["media"] = serde_json::to_value(media).map_err(|e| {
+                        crate::error::Error::ProtocolError(format!(
+                            "Failed to serialize media: {}",
+                            e
+                        ))
+                    })?;
+                }
+                if let Some(color_scheme) = opts.color_scheme {
+                    params["colorScheme"] = serde_json::to_value(color_scheme).map_err(|e| {
+                        crate::error::Error::ProtocolError(format!(
+                            "Failed to serialize colorScheme: {}",
+                            e
+                        ))
+                    })?;
+                }
+                if let Some(reduced_motion) = opts.reduced_motion {
+                    params["reducedMotion"] =
+                        serde_json::to_value(reduced_motion).map_err(|e| {
+                            crate::error::Error::ProtocolError(format!(
+                                "Failed to serialize reducedMotion: {}",
+                                e
+                            ))
+                        })?;
+                }
+                if let Some(forced_colors) = opts.forced_colors {
+                    params["forcedColors"] = serde_json::to_value(forced_colors).map_err(|e| {
+                        crate::error::Error::ProtocolError(format!(
+                            "Failed to serialize forcedColors: {}",
+                            e
+                        ))
+                    })?;
+                }
             }
-        }
 
-        self.channel().send_no_result("emulateMedia", params).await
+            self.channel().send_no_result("emulateMedia", params).await
+        })
     }
 
     /// Generates a PDF of the page and returns it as bytes.
@@ -4090,82 +4521,94 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-pdf>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid(), bytes_len = tracing::field::Empty))]
-    pub async fn pdf(&self, options: impl Into<Option<PdfOptions>>) -> Result<Vec<u8>> {
-        let options = options.into();
-        let mut params = serde_json::json!({});
-        let mut save_path: Option<std::path::PathBuf> = None;
-
-        if let Some(opts) = options {
-            // Capture the file path before consuming opts
-            save_path = opts.path;
-
-            if let Some(scale) = opts.scale {
-                params["scale"] = serde_json::json!(scale);
-            }
-            if let Some(v) = opts.display_header_footer {
-                params["displayHeaderFooter"] = serde_json::json!(v);
-            }
-            if let Some(v) = opts.header_template {
-                params["headerTemplate"] = serde_json::json!(v);
-            }
-            if let Some(v) = opts.footer_template {
-                params["footerTemplate"] = serde_json::json!(v);
-            }
-            if let Some(v) = opts.print_background {
-                params["printBackground"] = serde_json::json!(v);
-            }
-            if let Some(v) = opts.landscape {
-                params["landscape"] = serde_json::json!(v);
-            }
-            if let Some(v) = opts.page_ranges {
-                params["pageRanges"] = serde_json::json!(v);
-            }
-            if let Some(v) = opts.format {
-                params["format"] = serde_json::json!(v);
-            }
-            if let Some(v) = opts.width {
-                params["width"] = serde_json::json!(v);
-            }
-            if let Some(v) = opts.height {
-                params["height"] = serde_json::json!(v);
-            }
-            if let Some(v) = opts.prefer_css_page_size {
-                params["preferCSSPageSize"] = serde_json::json!(v);
-            }
-            if let Some(margin) = opts.margin {
-                params["margin"] = serde_json::to_value(margin).map_err(|e| {
-                    crate::error::Error::ProtocolError(format!("Failed to serialize margin: {}", e))
-                })?;
+    #[track_caller]
+    pub fn pdf(
+        &self,
+        options: impl Into<Option<PdfOptions>>,
+    ) -> impl Future<Output = Result<Vec<u8>>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let mut params = serde_json::json!({});
+            let mut save_path: Option<std::path::PathBuf> = None;
+
+            if let Some(opts) = options {
+                // Capture the file path before consuming opts
+                save_path = opts.path;
+
+                if let Some(scale) = opts.scale {
+                    params["scale"] = serde_json::json!(scale);
+                }
+                if let Some(v) = opts.display_header_footer {
+                    params["displayHeaderFooter"] = serde_json::json!(v);
+                }
+                if let Some(v) = opts.header_template {
+                    params["headerTemplate"] = serde_json::json!(v);
+                }
+                if let Some(v) = opts.footer_template {
+                    params["footerTemplate"] = serde_json::json!(v);
+                }
+                if let Some(v) = opts.print_background {
+                    params["printBackground"] = serde_json::json!(v);
+                }
+                if let Some(v) = opts.landscape {
+                    params["landscape"] = serde_json::json!(v);
+                }
+                if let Some(v) = opts.page_ranges {
+                    params["pageRanges"] = serde_json::json!(v);
+                }
+                if let Some(v) = opts.format {
+                    params["format"] = serde_json::json!(v);
+                }
+                if let Some(v) = opts.width {
+                    params["width"] = serde_json::json!(v);
+                }
+                if let Some(v) = opts.height {
+                    params["height"] = serde_json::json!(v);
+                }
+                if let Some(v) = opts.prefer_css_page_size {
+                    params["preferCSSPageSize"] = serde_json::json!(v);
+                }
+                if let Some(margin) = opts.margin {
+                    params["margin"] = serde_json::to_value(margin).map_err(|e| {
+                        crate::error::Error::ProtocolError(format!(
+                            "Failed to serialize margin: {}",
+                            e
+                        ))
+                    })?;
+                }
             }
-        }
 
-        #[derive(Deserialize)]
-        struct PdfResponse {
-            pdf: String,
-        }
+            #[derive(Deserialize)]
+            struct PdfResponse {
+                pdf: String,
+            }
 
-        let response: PdfResponse = self.channel().send("pdf", params).await?;
+            let response: PdfResponse = self.channel().send("pdf", params).await?;
 
-        // Decode base64 to bytes
-        let pdf_bytes = base64::engine::general_purpose::STANDARD
-            .decode(&response.pdf)
-            .map_err(|e| {
-                crate::error::Error::ProtocolError(format!("Failed to decode PDF base64: {}", e))
-            })?;
+            // Decode base64 to bytes
+            let pdf_bytes = base64::engine::general_purpose::STANDARD
+                .decode(&response.pdf)
+                .map_err(|e| {
+                    crate::error::Error::ProtocolError(format!(
+                        "Failed to decode PDF base64: {}",
+                        e
+                    ))
+                })?;
 
-        // If a path was specified, save the PDF to disk as well
-        if let Some(path) = save_path {
-            tokio::fs::write(&path, &pdf_bytes).await.map_err(|e| {
-                crate::error::Error::InvalidArgument(format!(
-                    "Failed to write PDF to '{}': {}",
-                    path.display(),
-                    e
-                ))
-            })?;
-        }
+            // If a path was specified, save the PDF to disk as well
+            if let Some(path) = save_path {
+                tokio::fs::write(&path, &pdf_bytes).await.map_err(|e| {
+                    crate::error::Error::InvalidArgument(format!(
+                        "Failed to write PDF to '{}': {}",
+                        path.display(),
+                        e
+                    ))
+                })?;
+            }
 
-        tracing::Span::current().record("bytes_len", pdf_bytes.len());
-        Ok(pdf_bytes)
+            tracing::Span::current().record("bytes_len", pdf_bytes.len());
+            Ok(pdf_bytes)
+        })
     }
 
     /// Adds a `<script>` tag into the page with the desired URL or content.
@@ -4213,18 +4656,21 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-add-script-tag>
     #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
-    pub async fn add_script_tag(
+    #[track_caller]
+    pub fn add_script_tag(
         &self,
         options: impl Into<Option<AddScriptTagOptions>>,
-    ) -> Result<Arc<crate::protocol::ElementHandle>> {
-        let options = options.into();
-        let opts = options.ok_or_else(|| {
-            Error::InvalidArgument(
-                "At least one of content, url, or path must be specified".to_string(),
-            )
-        })?;
-        let frame = self.main_frame().await?;
-        frame.add_script_tag(opts).await
+    ) -> impl Future<Output = Result<Arc<crate::protocol::ElementHandle>>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let opts = options.ok_or_else(|| {
+                Error::InvalidArgument(
+                    "At least one of content, url, or path must be specified".to_string(),
+                )
+            })?;
+            let frame = self.main_frame().await?;
+            frame.add_script_tag(opts).await
+        })
     }
 
     /// Returns the current viewport size of the page, or `None` if no viewport is set.
@@ -4278,19 +4724,22 @@ impl Page {
     ///
     /// See: <https://playwright.dev/docs/api/class-page#page-aria-snapshot>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
-    pub async fn aria_snapshot(
+    #[track_caller]
+    pub fn aria_snapshot(
         &self,
         options: impl Into<Option<crate::protocol::AriaSnapshotOptions>>,
-    ) -> Result<String> {
-        let options = options.into();
-        let frame = self.main_frame().await?;
-        let timeout = options
-            .as_ref()
-            .and_then(|o| o.timeout)
-            .unwrap_or_else(|| self.default_timeout_ms());
-        frame
-            .aria_snapshot_raw("body", timeout, options.as_ref())
-            .await
+    ) -> impl Future<Output = Result<String>> {
+        CallSite::caller().run(async move {
+            let options = options.into();
+            let frame = self.main_frame().await?;
+            let timeout = options
+                .as_ref()
+                .and_then(|o| o.timeout)
+                .unwrap_or_else(|| self.default_timeout_ms());
+            frame
+                .aria_snapshot_raw("body", timeout, options.as_ref())
+                .await
+        })
     }
 
     /// Returns the `Coverage` object for this page (Chromium only).
@@ -4781,24 +5230,35 @@ impl ChannelOwner for Page {
 
                             let url = route.url().to_string();
                             let handlers = self_clone.ws_route_handlers.lock().unwrap().clone();
-                            for entry in handlers.iter().rev() {
-                                if crate::protocol::route::matches_pattern(&entry.pattern, &url) {
-                                    let handler = entry.handler.clone();
-                                    let route_clone = route.clone();
-                                    tokio::spawn(
-                                        async move {
-                                            if let Err(e) = handler(route_clone).await {
-                                                tracing::error!(
-                                                    "Error in webSocketRoute handler: {}",
-                                                    e
-                                                );
-                                            }
+                            let Some(entry) = handlers
+                                .iter()
+                                .rev()
+                                .find(|entry| entry.pattern.matches(&url))
+                            else {
+                                // Reported only because a predicate or
+                                // URLPattern widened the filter.
+                                match self_clone.context() {
+                                    Ok(context) => context.handle_web_socket_route(route).await,
+                                    Err(_) => {
+                                        if let Err(e) = route.connect_to_server().await {
+                                            tracing::warn!(
+                                                "WebSocket connectToServer error: {}",
+                                                e
+                                            );
                                         }
-                                        .in_current_span(),
-                                    );
-                                    break;
+                                    }
                                 }
-                            }
+                                return;
+                            };
+                            let handler = entry.handler.clone();
+                            tokio::spawn(
+                                async move {
+                                    if let Err(e) = handler(route).await {
+                                        tracing::error!("Error in webSocketRoute handler: {}", e);
+                                    }
+                                }
+                                .in_current_span(),
+                            );
                         }
                         .in_current_span(),
                     );
@@ -5893,20 +6353,24 @@ impl Response {
     ///
     /// See: <https://playwright.dev/docs/api/class-response#response-security-details>
     #[tracing::instrument(level = "debug", skip_all, fields(url = %self.url()))]
-    pub async fn security_details(
+    #[track_caller]
+    pub fn security_details(
         &self,
-    ) -> crate::error::Result<Option<crate::protocol::response::SecurityDetails>> {
-        self.response_object()?.security_details().await
+    ) -> impl Future<Output = crate::error::Result<Option<crate::protocol::response::SecurityDetails>>>
+    {
+        CallSite::caller().run(async move { self.response_object()?.security_details().await })
     }
 
     /// Returns the server's IP address and port, or `None`.
     ///
     /// See: <https://playwright.dev/docs/api/class-response#response-server-addr>
     #[tracing::instrument(level = "debug", skip_all, fields(url = %self.url()))]
-    pub async fn server_addr(
+    #[track_caller]
+    pub fn server_addr(
         &self,
-    ) -> crate::error::Result<Option<crate::protocol::response::RemoteAddr>> {
-        self.response_object()?.server_addr().await
+    ) -> impl Future<Output = crate::error::Result<Option<crate::protocol::response::RemoteAddr>>>
+    {
+        CallSite::caller().run(async move { self.response_object()?.server_addr().await })
     }
 
     /// Waits for this response to finish loading.
@@ -5917,12 +6381,15 @@ impl Response {
     ///
     /// See: <https://playwright.dev/docs/api/class-response#response-finished>
     #[tracing::instrument(level = "debug", skip_all, fields(url = %self.url()))]
-    pub async fn finished(&self) -> crate::error::Result<()> {
-        // The Playwright protocol dispatches `requestFinished` as a separate event
-        // rather than exposing a `finished` RPC method on Response.
-        // For responses from goto/reload, the response is already complete.
-        // TODO: For on_response handlers, implement proper waiting via requestFinished event.
-        Ok(())
+    #[track_caller]
+    pub fn finished(&self) -> impl Future<Output = crate::error::Result<()>> {
+        CallSite::caller().run(async move {
+            // The Playwright protocol dispatches `requestFinished` as a separate event
+            // rather than exposing a `finished` RPC method on Response.
+            // For responses from goto/reload, the response is already complete.
+            // TODO: For on_response handlers, implement proper waiting via requestFinished event.
+            Ok(())
+        })
     }
 
     /// Returns the HTTP version used by this response (e.g. `"HTTP/1.1"` or `"HTTP/2.0"`).
@@ -5937,10 +6404,13 @@ impl Response {
     ///
     /// See: <https://playwright.dev/docs/api/class-response#response-http-version>
     #[tracing::instrument(level = "debug", skip_all, fields(url = %self.url(), version = tracing::field::Empty))]
-    pub async fn http_version(&self) -> crate::error::Result<String> {
-        let v = self.response_object()?.http_version().await?;
-        tracing::Span::current().record("version", &v);
-        Ok(v)
+    #[track_caller]
+    pub fn http_version(&self) -> impl Future<Output = crate::error::Result<String>> {
+        CallSite::caller().run(async move {
+            let v = self.response_object()?.http_version().await?;
+            tracing::Span::current().record("version", &v);
+            Ok(v)
+        })
     }
 
     /// Returns the response body as raw bytes.
@@ -5956,10 +6426,13 @@ impl Response {
     ///
     /// See: <https://playwright.dev/docs/api/class-response#response-body>
     #[tracing::instrument(level = "debug", skip_all, fields(url = %self.url(), bytes_len = tracing::field::Empty))]
-    pub async fn body(&self) -> crate::error::Result<Vec<u8>> {
-        let bytes = self.response_object()?.body().await?;
-        tracing::Span::current().record("bytes_len", bytes.len());
-        Ok(bytes)
+    #[track_caller]
+    pub fn body(&self) -> impl Future<Output = crate::error::Result<Vec<u8>>> {
+        CallSite::caller().run(async move {
+            let bytes = self.response_object()?.body().await?;
+            tracing::Span::current().record("bytes_len", bytes.len());
+            Ok(bytes)
+        })
     }
 
     /// Returns the response body as a UTF-8 string.
@@ -5974,10 +6447,16 @@ impl Response {
     ///
     /// See: <https://playwright.dev/docs/api/class-response#response-text>
     #[tracing::instrument(level = "debug", skip_all, fields(url = %self.url()))]
-    pub async fn text(&self) -> crate::error::Result<String> {
-        let bytes = self.body().await?;
-        String::from_utf8(bytes).map_err(|e| {
-            crate::error::Error::ProtocolError(format!("Response body is not valid UTF-8: {}", e))
+    #[track_caller]
+    pub fn text(&self) -> impl Future<Output = crate::error::Result<String>> {
+        CallSite::caller().run(async move {
+            let bytes = self.body().await?;
+            String::from_utf8(bytes).map_err(|e| {
+                crate::error::Error::ProtocolError(format!(
+                    "Response body is not valid UTF-8: {}",
+                    e
+                ))
+            })
         })
     }
 
@@ -5993,10 +6472,15 @@ impl Response {
     ///
     /// See: <https://playwright.dev/docs/api/class-response#response-json>
     #[tracing::instrument(level = "debug", skip_all, fields(url = %self.url()))]
-    pub async fn json<T: serde::de::DeserializeOwned>(&self) -> crate::error::Result<T> {
-        let text = self.text().await?;
-        serde_json::from_str(&text).map_err(|e| {
-            crate::error::Error::ProtocolError(format!("Failed to parse response JSON: {}", e))
+    #[track_caller]
+    pub fn json<T: serde::de::DeserializeOwned>(
+        &self,
+    ) -> impl Future<Output = crate::error::Result<T>> {
+        CallSite::caller().run(async move {
+            let text = self.text().await?;
+            serde_json::from_str(&text).map_err(|e| {
+                crate::error::Error::ProtocolError(format!("Failed to parse response JSON: {}", e))
+            })
         })
     }
 
@@ -6012,10 +6496,12 @@ impl Response {
     ///
     /// See: <https://playwright.dev/docs/api/class-response#response-headers-array>
     #[tracing::instrument(level = "debug", skip_all, fields(url = %self.url()))]
-    pub async fn headers_array(
+    #[track_caller]
+    pub fn headers_array(
         &self,
-    ) -> crate::error::Result<Vec<crate::protocol::response::HeaderEntry>> {
-        self.response_object()?.raw_headers().await
+    ) -> impl Future<Output = crate::error::Result<Vec<crate::protocol::response::HeaderEntry>>>
+    {
+        CallSite::caller().run(async move { self.response_object()?.raw_headers().await })
     }
 
     /// Returns all response headers merged into a HashMap with lowercase keys.
@@ -6031,21 +6517,25 @@ impl Response {
     ///
     /// See: <https://playwright.dev/docs/api/class-response#response-all-headers>
     #[tracing::instrument(level = "debug", skip_all, fields(url = %self.url()))]
-    pub async fn all_headers(
+    #[track_caller]
+    pub fn all_headers(
         &self,
-    ) -> crate::error::Result<std::collections::HashMap<String, String>> {
-        let entries = self.headers_array().await?;
-        let mut map: std::collections::HashMap<String, String> = std::collections::HashMap::new();
-        for entry in entries {
-            let key = entry.name.to_lowercase();
-            map.entry(key)
-                .and_modify(|v| {
-                    v.push_str(", ");
-                    v.push_str(&entry.value);
-                })
-                .or_insert(entry.value);
-        }
-        Ok(map)
+    ) -> impl Future<Output = crate::error::Result<std::collections::HashMap<String, String>>> {
+        CallSite::caller().run(async move {
+            let entries = self.headers_array().await?;
+            let mut map: std::collections::HashMap<String, String> =
+                std::collections::HashMap::new();
+            for entry in entries {
+                let key = entry.name.to_lowercase();
+                map.entry(key)
+                    .and_modify(|v| {
+                        v.push_str(", ");
+                        v.push_str(&entry.value);
+                    })
+                    .or_insert(entry.value);
+            }
+            Ok(map)
+        })
     }
 
     /// Returns the value for a single response header, or `None` if not present.
@@ -6072,22 +6562,28 @@ impl Response {
     ///
     /// See: <https://playwright.dev/docs/api/class-response#response-header-value>
     #[tracing::instrument(level = "debug", skip_all, fields(url = %self.url(), name = %name))]
-    pub async fn header_value(&self, name: &str) -> crate::error::Result<Option<String>> {
-        let entries = self.headers_array().await?;
-        let name_lower = name.to_lowercase();
-        let mut values: Vec<String> = entries
-            .into_iter()
-            .filter(|h| h.name.to_lowercase() == name_lower)
-            .map(|h| h.value)
-            .collect();
-
-        if values.is_empty() {
-            Ok(None)
-        } else if values.len() == 1 {
-            Ok(Some(values.remove(0)))
-        } else {
-            Ok(Some(values.join(", ")))
-        }
+    #[track_caller]
+    pub fn header_value(
+        &self,
+        name: &str,
+    ) -> impl Future<Output = crate::error::Result<Option<String>>> {
+        CallSite::caller().run(async move {
+            let entries = self.headers_array().await?;
+            let name_lower = name.to_lowercase();
+            let mut values: Vec<String> = entries
+                .into_iter()
+                .filter(|h| h.name.to_lowercase() == name_lower)
+                .map(|h| h.value)
+                .collect();
+
+            if values.is_empty() {
+                Ok(None)
+            } else if values.len() == 1 {
+                Ok(Some(values.remove(0)))
+            } else {
+                Ok(Some(values.join(", ")))
+            }
+        })
     }
 }
 
@@ -6133,6 +6629,24 @@ pub struct RouteFromHarOptions {
     /// - `"minimal"` (default) — omit timing, cookies, and security info.
     /// - `"full"` — record everything.
     pub update_mode: Option<String>,
+
+    /// With `update`, replay the entries the HAR already has and record only
+    /// the requests it misses, merging them into the file.  Defaults to
+    /// `false`, which overwrites the file with what was recorded.
+    pub merge: Option<bool>,
+
+    /// Header names (case-insensitive) whose values are replaced with
+    /// `[REDACTED]` when an updated `.har` is written.
+    pub redact_headers: Option<Vec<String>>,
+
+    /// Header names (case-insensitive) removed when an updated `.har` is
+    /// written, such as `date` or `x-request-id`.
+    pub strip_headers: Option<Vec<String>>,
+
+    /// Cookie names whose values are replaced with `[REDACTED]` when an
+    /// updated `.har` is written, in `cookies` arrays and in `Cookie` /
+    /// `Set-Cookie` headers.  `"*"` redacts every cookie.
+    pub redact_cookies: Option<Vec<String>>,
 }
 
 impl RouteFromHarOptions {
@@ -6151,6 +6665,48 @@ impl RouteFromHarOptions {
         self.update = Some(update);
         self
     }
+    /// How recorded bodies are stored ("embed" or "attach").
+    pub fn update_content(mut self, update_content: impl Into<String>) -> Self {
+        self.update_content = Some(update_content.into());
+        self
+    }
+    /// Recording detail level ("minimal" or "full").
+    pub fn update_mode(mut self, update_mode: impl Into<String>) -> Self {
+        self.update_mode = Some(update_mode.into());
+        self
+    }
+    /// Replay existing entries and record only the missing ones.
+    pub fn merge(mut self, merge: bool) -> Self {
+        self.merge = Some(merge);
+        self
+    }
+    /// Replace these headers' values with `[REDACTED]` on write.
+    pub fn redact_headers<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.redact_headers = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
+    /// Remove these headers on write.
+    pub fn strip_headers<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.strip_headers = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
+    /// Replace these cookies' values with `[REDACTED]` on write.
+    pub fn redact_cookies<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.redact_cookies = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
 }
 
 /// Options for `page.add_locator_handler()`.
@@ -0,0 +1,84 @@
+//! Page objects: structs of locators built from a page, frame or locator.
+//!
+//! [`PageObject`] is what `#[derive(PageObject)]` (from
+//! `playwright-rs-macros`, re-exported under the `macros` feature)
+//! implements, and [`LocatorRoot`] is anything it can be built from. The
+//! same page object can describe a whole page or, built from a parent
+//! `Locator`, one component inside it.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use playwright_rs::{Locator, PageObject};
+//!
+//! #[derive(PageObject)]
+//! struct LoginForm {
+//!     #[test_id("username")]
+//!     username: Locator,
+//!     #[role(button, name = "Sign in")]
+//!     submit: Locator,
+//! }
+//!
+//! #[derive(PageObject)]
+//! struct LoginPage {
+//!     #[locator("form#login")]
+//!     form: LoginForm,
+//!     #[locator("nav a")]
+//!     links: Vec<Locator>,
+//! }
+//!
+//! let login = LoginPage::locate(&page).await?;
+//! login.form.username.fill("alice", None).await?;
+//! login.form.submit.click(None).await?;
+//! ```
+
+use crate::error::Result;
+use crate::protocol::{AriaRole, Frame, FrameLocator, GetByRoleOptions, Locator, Page};
+
+/// Something locators can be created from: a [`Page`], a [`Frame`], a
+/// [`FrameLocator`] or a parent [`Locator`].
+///
+/// The methods mirror the inherent ones of the same name, so generic code
+/// (and `#[derive(PageObject)]`) can scope a page object to any of them.
+pub trait LocatorRoot: Send + Sync {
+    /// See [`Page::locator`].
+    fn locator(&self, selector: &str) -> Locator;
+
+    /// See [`Page::get_by_test_id`].
+    fn get_by_test_id(&self, test_id: &str) -> Locator;
+
+    /// See [`Page::get_by_role`].
+    fn get_by_role(&self, role: AriaRole, options: Option<GetByRoleOptions>) -> Locator;
+}
+
+macro_rules! impl_locator_root {
+    ($($ty:ty),*) => {$(
+        impl LocatorRoot for $ty {
+            fn locator(&self, selector: &str) -> Locator {
+                <$ty>::locator(self, selector)
+            }
+
+            fn get_by_test_id(&self, test_id: &str) -> Locator {
+                <$ty>::get_by_test_id(self, test_id)
+            }
+
+            fn get_by_role(&self, role: AriaRole, options: Option<GetByRoleOptions>) -> Locator {
+                <$ty>::get_by_role(self, role, options)
+            }
+        }
+    )*};
+}
+
+impl_locator_root!(Page, Frame, FrameLocator, Locator);
+
+/// A struct of locators (and nested page objects) scoped to a
+/// [`LocatorRoot`]. Usually derived; see the [module docs](self).
+///
+/// Building one is async because list fields (`Vec<Locator>`,
+/// `Vec<Component>`) are resolved with [`Locator::all`], which counts the
+/// matches once, at construction. Single `Locator` fields stay lazy and
+/// re-query on every action, as locators always do.
+pub trait PageObject: Sized {
+    /// Build the page object with every field scoped to `root`.
+    fn locate<R: LocatorRoot + ?Sized>(root: &R) -> impl Future<Output = Result<Self>> + Send;
+}
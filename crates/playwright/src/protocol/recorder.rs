@@ -0,0 +1,271 @@
+//! Recorder — the action stream behind `playwright codegen`.
+//!
+//! [`BrowserContext::enable_recorder`](crate::protocol::BrowserContext::enable_recorder)
+//! turns on the driver's recorder in its programmatic (`api`) mode: the
+//! in-page toolbar records clicks, typing and assertions as usual, but
+//! instead of opening the Inspector window with generated JavaScript the
+//! driver sends each action to the client as a `recorderEvent`.
+//! [`BrowserContext::on_recorder_event`](crate::protocol::BrowserContext::on_recorder_event)
+//! receives them as [`RecorderEvent`]s, and
+//! [`codegen::RustGenerator`](crate::codegen::RustGenerator) turns them
+//! into Rust.
+//!
+//! The action shapes mirror the driver's `recorderActions` types. Actions
+//! this crate does not know yet deserialize as [`Action::Other`] rather
+//! than failing the event.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Options for [`BrowserContext::enable_recorder`](crate::protocol::BrowserContext::enable_recorder).
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RecorderOptions {
+    /// Start by recording (`"recording"`, the default) or by inspecting
+    /// (`"inspecting"`, picking locators without recording actions).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    /// The attribute `getByTestId` locators are generated for. Defaults to
+    /// `data-testid`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_id_attribute_name: Option<String>,
+}
+
+impl RecorderOptions {
+    /// Start recording (`"recording"`) or inspecting (`"inspecting"`).
+    pub fn mode(mut self, mode: impl Into<String>) -> Self {
+        self.mode = Some(mode.into());
+        self
+    }
+    /// Generate `get_by_test_id` for this attribute instead of `data-testid`.
+    pub fn test_id_attribute_name(mut self, name: impl Into<String>) -> Self {
+        self.test_id_attribute_name = Some(name.into());
+        self
+    }
+}
+
+/// One event from the recorder.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum RecorderEvent {
+    /// A new action was recorded.
+    ActionAdded(RecordedAction),
+    /// The last recorded action changed: typing into the same field
+    /// updates one `fill`, a second click becomes a double click.
+    ActionUpdated(RecordedAction),
+    /// The last recorded action caused a navigation, popup, download or
+    /// dialog. The action carries the signal in its
+    /// [`signals`](RecordedAction::signals).
+    SignalAdded(RecordedAction),
+}
+
+impl RecorderEvent {
+    /// Parse a `recorderEvent` payload. `None` for event kinds this crate
+    /// does not know, or a malformed action.
+    pub(crate) fn from_params(params: &Value) -> Option<Self> {
+        let action: RecordedAction = serde_json::from_value(params.get("data")?.clone()).ok()?;
+        match params.get("event")?.as_str()? {
+            "actionAdded" => Some(Self::ActionAdded(action)),
+            "actionUpdated" => Some(Self::ActionUpdated(action)),
+            "signalAdded" => Some(Self::SignalAdded(action)),
+            _ => None,
+        }
+    }
+
+    /// The action the event carries.
+    pub fn action(&self) -> &RecordedAction {
+        match self {
+            Self::ActionAdded(action) | Self::ActionUpdated(action) | Self::SignalAdded(action) => {
+                action
+            }
+        }
+    }
+}
+
+/// A recorded action and where it happened.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RecordedAction {
+    /// The page and frame the action ran in.
+    pub frame: RecordedFrame,
+    /// What was done.
+    pub action: Action,
+    /// Wall time the action started, in milliseconds since the epoch.
+    #[serde(default)]
+    pub start_time: f64,
+}
+
+impl RecordedAction {
+    /// What the action caused: navigations, popups, downloads, dialogs.
+    pub fn signals(&self) -> &[Signal] {
+        match &self.action {
+            Action::OpenPage { signals, .. }
+            | Action::ClosePage { signals }
+            | Action::Navigate { signals, .. }
+            | Action::Click { signals, .. }
+            | Action::Fill { signals, .. }
+            | Action::Press { signals, .. }
+            | Action::Check { signals, .. }
+            | Action::Uncheck { signals, .. }
+            | Action::Select { signals, .. }
+            | Action::SetInputFiles { signals, .. }
+            | Action::AssertText { signals, .. }
+            | Action::AssertValue { signals, .. }
+            | Action::AssertChecked { signals, .. }
+            | Action::AssertVisible { signals, .. }
+            | Action::AssertSnapshot { signals, .. } => signals,
+            Action::Other => &[],
+        }
+    }
+}
+
+/// The page an action ran in and the iframes between it and the frame.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RecordedFrame {
+    /// The name the recorder gave the page: `page`, then `page1`, ...
+    pub page_alias: String,
+    /// Selectors of the iframes to enter, outermost first. Empty for the
+    /// main frame.
+    #[serde(default)]
+    pub frame_path: Vec<String>,
+}
+
+/// A recorded action. Selectors are Playwright selectors as the recorder
+/// generated them, e.g. `internal:role=button[name="Save"i]`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(
+    tag = "name",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+#[non_exhaustive]
+pub enum Action {
+    /// A page was opened, by the recorder or as a popup.
+    OpenPage {
+        url: String,
+        #[serde(default)]
+        signals: Vec<Signal>,
+    },
+    /// A page was closed.
+    ClosePage {
+        #[serde(default)]
+        signals: Vec<Signal>,
+    },
+    /// The address bar was used to navigate.
+    Navigate {
+        url: String,
+        #[serde(default)]
+        signals: Vec<Signal>,
+    },
+    Click {
+        selector: String,
+        /// `left`, `right` or `middle`.
+        button: String,
+        /// Bit set of held modifiers: Alt 1, Control 2, Meta 4, Shift 8.
+        modifiers: u32,
+        click_count: u32,
+        #[serde(default)]
+        signals: Vec<Signal>,
+    },
+    Fill {
+        selector: String,
+        text: String,
+        #[serde(default)]
+        signals: Vec<Signal>,
+    },
+    Press {
+        selector: String,
+        key: String,
+        /// Bit set of held modifiers, as in [`Action::Click`].
+        modifiers: u32,
+        #[serde(default)]
+        signals: Vec<Signal>,
+    },
+    Check {
+        selector: String,
+        #[serde(default)]
+        signals: Vec<Signal>,
+    },
+    Uncheck {
+        selector: String,
+        #[serde(default)]
+        signals: Vec<Signal>,
+    },
+    Select {
+        selector: String,
+        options: Vec<String>,
+        #[serde(default)]
+        signals: Vec<Signal>,
+    },
+    SetInputFiles {
+        selector: String,
+        files: Vec<String>,
+        #[serde(default)]
+        signals: Vec<Signal>,
+    },
+    AssertText {
+        selector: String,
+        text: String,
+        /// Whether the element only has to contain `text`.
+        substring: bool,
+        #[serde(default)]
+        signals: Vec<Signal>,
+    },
+    AssertValue {
+        selector: String,
+        value: String,
+        #[serde(default)]
+        signals: Vec<Signal>,
+    },
+    AssertChecked {
+        selector: String,
+        checked: bool,
+        #[serde(default)]
+        signals: Vec<Signal>,
+    },
+    AssertVisible {
+        selector: String,
+        #[serde(default)]
+        signals: Vec<Signal>,
+    },
+    AssertSnapshot {
+        selector: String,
+        aria_snapshot: String,
+        #[serde(default)]
+        signals: Vec<Signal>,
+    },
+    /// An action this crate does not know.
+    #[serde(other)]
+    Other,
+}
+
+/// Something an action caused.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(
+    tag = "name",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+#[non_exhaustive]
+pub enum Signal {
+    Navigation {
+        url: String,
+    },
+    /// A popup opened; the recorder calls it `popup_alias` from then on.
+    Popup {
+        popup_alias: String,
+    },
+    Download {
+        download_alias: String,
+    },
+    Dialog {
+        dialog_alias: String,
+    },
+    /// A signal this crate does not know.
+    #[serde(other)]
+    Other,
+}
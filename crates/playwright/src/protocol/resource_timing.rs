@@ -12,7 +12,8 @@
 /// A value of `-1` indicates the timing phase was not reached.
 ///
 /// See: <https://playwright.dev/docs/api/class-request#request-timing>
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct ResourceTiming {
     /// Request start time in milliseconds since epoch.
@@ -47,7 +47,8 @@ pub struct RemoteAddr {
 /// Resource size information for a request/response pair.
 ///
 /// See: <https://playwright.dev/docs/api/class-request#request-sizes>
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct RequestSizes {
     /// Size of the request body in bytes. Set to 0 if there was no body.
@@ -8,11 +8,13 @@
 use crate::error::Result;
 use crate::protocol::Request;
 use crate::protocol::api_request_context::{APIRequestContext, InnerFetchOptions};
+use crate::server::call_site::CallSite;
 use crate::server::channel_owner::{ChannelOwner, ChannelOwnerImpl, ParentOrConnection};
 use crate::server::connection::downcast_parent;
 use serde_json::{Value, json};
 use std::any::Any;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
 /// Route represents a network route handler.
@@ -226,28 +228,8 @@ impl Route {
     ///
     /// * `options` - Response configuration (status, headers, body, etc.)
     ///
-    /// # Known Limitations
-    ///
-    /// **Response body fulfillment is not supported in Playwright 1.49.0 - 1.62.1.**
-    ///
-    /// The route.fulfill() method can successfully send requests for status codes and headers,
-    /// but the response body is not transmitted to the browser JavaScript layer. This applies
-    /// to ALL request types (main document, fetch, XHR, etc.), not just document navigation.
-    ///
-    /// **Investigation Findings:**
-    /// - The protocol message is correctly formatted and accepted by the Playwright server
-    /// - The body bytes are present in the fulfill() call
-    /// - The Playwright server creates a Response object
-    /// - But the body content does not reach the browser's fetch/network API
-    ///
-    /// This appears to be a limitation or bug in the Playwright server implementation.
-    /// Tested with versions 1.49.0, 1.56.1, 1.58.2, 1.59.1, 1.60.0, 1.61.1, and
-    /// 1.62.1 (the currently bundled driver). Re-verified against 1.62.1 by the
-    /// reverse-canary integration tests, which still pass — i.e. the limitation
-    /// is unchanged. Those tests assert the broken behavior, so they fail the
-    /// moment upstream fixes it; no manual re-check is needed.
-    /// Workaround: Mock responses at the HTTP server level rather than using network interception,
-    /// or wait for a newer Playwright version that supports response body fulfillment.
+    /// Text bodies are sent as UTF-8; anything else is base64-encoded.
+    /// A `content-length` header is set from the body.
     ///
     /// See: <https://playwright.dev/docs/api/class-route#route-fulfill>
     pub async fn fulfill(&self, options: impl Into<Option<FulfillOptions>>) -> Result<()> {
@@ -255,10 +237,10 @@ impl Route {
         self.handled.store(true, Ordering::SeqCst);
         let opts = options.unwrap_or_default();
 
-        // Build the response object for the protocol
-        let mut response = json!({
+        // The protocol takes the response fields as the call's own
+        // parameters; the server drops anything it doesn't know.
+        let mut params = json!({
             "status": opts.status.unwrap_or(200),
-            "headers": []
         });
 
         // Set headers - prepare them BEFORE adding body
@@ -281,25 +263,21 @@ impl Route {
             .into_iter()
             .map(|(name, value)| json!({"name": name, "value": value}))
             .collect();
-        response["headers"] = json!(headers_array);
+        params["headers"] = json!(headers_array);
 
-        // Set body LAST, after all other fields
         if let Some(body) = body_bytes {
             // Send as plain string for text (UTF-8), base64 for binary
             if let Ok(body_str) = std::str::from_utf8(body) {
-                response["body"] = json!(body_str);
+                params["body"] = json!(body_str);
+                params["isBase64"] = json!(false);
             } else {
                 use base64::Engine;
                 let encoded = base64::engine::general_purpose::STANDARD.encode(body);
-                response["body"] = json!(encoded);
-                response["isBase64"] = json!(true);
+                params["body"] = json!(encoded);
+                params["isBase64"] = json!(true);
             }
         }
 
-        let params = json!({
-            "response": response
-        });
-
         self.channel()
             .send::<_, serde_json::Value>("fulfill", params)
             .await
@@ -353,24 +331,281 @@ impl Route {
 
         api_ctx.inner_fetch(&url, Some(inner_opts)).await
     }
+
+    /// Fetches the request, lets `modify` edit the response, and fulfills
+    /// the route with the result.
+    ///
+    /// The body `modify` sees is already decoded, so `content-encoding` is
+    /// dropped from the response and `content-length` is set from the
+    /// edited body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the fetch or the fulfill fails, or the error
+    /// `modify` returns. The route is then left unhandled.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use playwright_rs::Playwright;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let playwright = Playwright::launch().await?;
+    /// # let browser = playwright.chromium().launch().await?;
+    /// # let page = browser.new_page().await?;
+    /// page.route("**/api/user", |route| async move {
+    ///     route
+    ///         .fetch_and_modify(|response| {
+    ///             response.merge_json(&serde_json::json!({ "beta": true }))?;
+    ///             response.set_header("cache-control", "no-store");
+    ///             Ok(())
+    ///         })
+    ///         .await
+    /// })
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_and_modify<F>(&self, modify: F) -> Result<()>
+    where
+        F: FnOnce(&mut FetchResponse) -> Result<()>,
+    {
+        let mut response = self.fetch(None).await?;
+        modify(&mut response)?;
+        self.fulfill(FulfillOptions::from(response)).await
+    }
+
+    /// Continues the request with `body` serialized as its JSON post data.
+    ///
+    /// The request keeps its other headers; `content-type` becomes
+    /// `application/json` and `content-length` is set from the new body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `body` cannot be serialized or the continue fails.
+    pub async fn continue_with_json(&self, body: &impl serde::Serialize) -> Result<()> {
+        let body = serde_json::to_vec(body)?;
+        let mut headers = self.request().headers();
+        headers.retain(|name, _| {
+            !name.eq_ignore_ascii_case("content-type")
+                && !name.eq_ignore_ascii_case("content-length")
+        });
+        headers.insert("content-type".to_string(), "application/json".to_string());
+        headers.insert("content-length".to_string(), body.len().to_string());
+        self.continue_(Some(
+            ContinueOptions::builder()
+                .headers(headers)
+                .post_data_bytes(body)
+                .build(),
+        ))
+        .await
+    }
+}
+
+/// Folds response headers into the map `fulfill` takes, dropping the
+/// ones that describe the original encoding of the body.
+///
+/// Repeated headers are folded the way Playwright's header objects fold
+/// them: cookies one per line, the rest comma-separated.
+pub(crate) fn fulfill_headers<'a>(
+    headers: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> std::collections::HashMap<String, String> {
+    let mut folded = std::collections::HashMap::<String, String>::new();
+    for (name, value) in headers {
+        let name = name.to_ascii_lowercase();
+        if matches!(
+            name.as_str(),
+            "content-encoding" | "content-length" | "transfer-encoding"
+        ) {
+            continue;
+        }
+        let separator = if name == "set-cookie" { "\n" } else { ", " };
+        folded
+            .entry(name)
+            .and_modify(|v| {
+                v.push_str(separator);
+                v.push_str(value);
+            })
+            .or_insert_with(|| value.to_string());
+    }
+    folded
 }
 
-/// Checks if a URL matches a Playwright URL glob.
+/// Options for [`Page::route_with_options`](crate::protocol::Page::route_with_options)
+/// and [`BrowserContext::route_with_options`](crate::protocol::BrowserContext::route_with_options).
 ///
-/// Delegates to the shared port of the driver's globber, so the client picks
-/// the same handler the server picked when it decided to report the request.
-/// This previously used the `glob` crate, whose filesystem semantics differ
-/// in ways that matter for URLs: `?` matched any single character, `[…]` was
-/// a character class, and `{a,b}` alternation was unsupported. A pattern the
-/// server matched but the client did not left the route event unanswered and
-/// the request hanging until it timed out.
+/// See: <https://playwright.dev/docs/api/class-page#page-route>
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct RouteOptions {
+    /// Handle this many requests, then unregister. Later requests go on to
+    /// the next matching handler, or the network.
+    pub times: Option<usize>,
+}
+
+impl RouteOptions {
+    /// Handle at most `times` requests.
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = Some(times);
+        self
+    }
+}
+
+/// A registered route handler, returned by `route` and `route_with_options`.
 ///
-/// - `*` matches any characters except `/`
-/// - `**` matches any characters including `/`
-/// - `{a,b}` is alternation; `\` escapes the next character
-/// - everything else, `?` included, is literal
-pub(crate) fn matches_pattern(pattern: &str, url: &str) -> bool {
-    crate::protocol::glob::glob_match(pattern, url)
+/// Counts the requests the handler was given and removes just this
+/// handler, where [`Page::unroute`](crate::protocol::Page::unroute) removes
+/// every handler registered for a URL. Dropping the handle leaves the
+/// handler registered.
+///
+/// ```no_run
+/// # use playwright_rs::{Playwright, RouteOptions};
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// # let playwright = Playwright::launch().await?;
+/// # let browser = playwright.chromium().launch().await?;
+/// # let page = browser.new_page().await?;
+/// let api = page
+///     .route_with_options(
+///         "**/api/items",
+///         |route| async move { route.abort(None).await },
+///         RouteOptions::default().times(2),
+///     )
+///     .await?;
+/// page.goto("https://example.com", None).await?;
+/// println!("{} requests aborted", api.hits());
+/// api.unroute().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct RouteHandle {
+    state: Arc<RouteHandlerState>,
+    owner: RouteOwner,
+}
+
+#[derive(Clone)]
+enum RouteOwner {
+    Page(crate::protocol::Page),
+    Context(crate::protocol::BrowserContext),
+}
+
+impl RouteHandle {
+    pub(crate) fn for_page(state: Arc<RouteHandlerState>, page: crate::protocol::Page) -> Self {
+        Self {
+            state,
+            owner: RouteOwner::Page(page),
+        }
+    }
+
+    pub(crate) fn for_context(
+        state: Arc<RouteHandlerState>,
+        context: crate::protocol::BrowserContext,
+    ) -> Self {
+        Self {
+            state,
+            owner: RouteOwner::Context(context),
+        }
+    }
+
+    /// How many requests the handler has been given, including ones it
+    /// passed on with [`Route::fallback`].
+    pub fn hits(&self) -> usize {
+        self.state.hits.load(Ordering::SeqCst)
+    }
+
+    /// Whether the handler is still registered: not unrouted, and with
+    /// requests left if it was registered with [`RouteOptions::times`].
+    pub fn is_active(&self) -> bool {
+        !self.state.removed.load(Ordering::SeqCst) && !self.state.is_spent()
+    }
+
+    pub(crate) fn id(&self) -> u64 {
+        self.state.id
+    }
+
+    /// Unregisters this handler only. Does nothing if it is already gone.
+    #[track_caller]
+    pub fn unroute(&self) -> impl Future<Output = Result<()>> + '_ {
+        CallSite::caller().run(async move {
+            match &self.owner {
+                RouteOwner::Page(page) => page.remove_route_handler(self.state.id).await,
+                RouteOwner::Context(context) => context.remove_route_handler(self.state.id).await,
+            }
+        })
+    }
+}
+
+impl std::fmt::Debug for RouteHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RouteHandle")
+            .field("hits", &self.hits())
+            .field("times", &self.state.times)
+            .field("active", &self.is_active())
+            .finish()
+    }
+}
+
+/// What a registered handler shares with its [`RouteHandle`].
+#[derive(Debug)]
+pub(crate) struct RouteHandlerState {
+    pub(crate) id: u64,
+    times: Option<usize>,
+    hits: AtomicUsize,
+    removed: AtomicBool,
+}
+
+impl RouteHandlerState {
+    pub(crate) fn new(options: Option<RouteOptions>) -> Arc<Self> {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Arc::new(Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            times: options.and_then(|o| o.times),
+            hits: AtomicUsize::new(0),
+            removed: AtomicBool::new(false),
+        })
+    }
+
+    /// Counts one request against the handler. `false`, without counting,
+    /// once its `times` are used up: two requests racing for the last one
+    /// get one `true` between them.
+    pub(crate) fn claim(&self) -> bool {
+        self.hits
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |hits| {
+                self.times
+                    .is_none_or(|times| hits < times)
+                    .then_some(hits + 1)
+            })
+            .is_ok()
+    }
+
+    /// Whether every one of its `times` has been claimed.
+    pub(crate) fn is_spent(&self) -> bool {
+        self.times
+            .is_some_and(|times| self.hits.load(Ordering::SeqCst) >= times)
+    }
+
+    /// Marks the handler unregistered, for [`RouteHandle::is_active`].
+    pub(crate) fn mark_removed(&self) {
+        self.removed.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Removes the route handlers `remove` picks from a page's or context's
+/// list, marking their handles inactive. Returns whether any were removed.
+pub(crate) fn remove_route_handlers<T>(
+    entries: &Mutex<Vec<T>>,
+    state: impl Fn(&T) -> &RouteHandlerState,
+    remove: impl Fn(&T) -> bool,
+) -> bool {
+    let mut entries = entries.lock().unwrap();
+    let before = entries.len();
+    entries.retain(|entry| {
+        let removed = remove(entry);
+        if removed {
+            state(entry).mark_removed();
+        }
+        !removed
+    });
+    entries.len() != before
 }
 
 /// Behavior when removing route handlers via `unroute_all()`.
@@ -442,6 +677,120 @@ impl FetchResponse {
     pub fn ok(&self) -> bool {
         (200..300).contains(&self.status)
     }
+
+    /// Returns the value of the first header named `name`, ignoring case
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Sets the status code
+    pub fn set_status(&mut self, status: u16) {
+        self.status = status;
+    }
+
+    /// Replaces every header named `name`, ignoring case, with one `name: value`
+    pub fn set_header(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        let name = name.into();
+        self.remove_header(&name);
+        self.headers.push((name, value.into()));
+    }
+
+    /// Removes every header named `name`, ignoring case
+    pub fn remove_header(&mut self, name: &str) {
+        self.headers.retain(|(n, _)| !n.eq_ignore_ascii_case(name));
+    }
+
+    /// Replaces the body
+    pub fn set_body(&mut self, body: impl Into<Vec<u8>>) {
+        self.body = body.into();
+    }
+
+    /// Replaces every occurrence of `from` in the body with `to`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body is not valid UTF-8.
+    pub fn replace_text(&mut self, from: &str, to: &str) -> Result<()> {
+        self.body = self.text()?.replace(from, to).into_bytes();
+        Ok(())
+    }
+
+    /// Replaces the body with `value` as JSON and sets `content-type` to
+    /// `application/json`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` cannot be serialized.
+    pub fn set_json(&mut self, value: &impl serde::Serialize) -> Result<()> {
+        self.body = serde_json::to_vec(value)?;
+        self.set_header("content-type", "application/json");
+        Ok(())
+    }
+
+    /// Parses the body as JSON, lets `patch` edit it, and writes it back
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body is not valid JSON.
+    pub fn patch_json(&mut self, patch: impl FnOnce(&mut Value)) -> Result<()> {
+        let mut value: Value = self.json()?;
+        patch(&mut value);
+        self.body = serde_json::to_vec(&value)?;
+        Ok(())
+    }
+
+    /// Applies `patch` to the JSON body as an
+    /// [RFC 7396](https://www.rfc-editor.org/rfc/rfc7396) merge patch:
+    /// objects are merged key by key, `null` removes a key, and anything
+    /// else replaces what was there
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body is not valid JSON.
+    pub fn merge_json(&mut self, patch: &Value) -> Result<()> {
+        self.patch_json(|value| merge_patch(value, patch))
+    }
+}
+
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(serde_json::Map::new());
+    }
+    if let Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(key);
+            } else {
+                merge_patch(target.entry(key.as_str()).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+/// Fulfills with the response as it stands. `content-encoding`,
+/// `content-length` and `transfer-encoding` are dropped, since the body is
+/// already decoded and `fulfill` sets the length itself.
+impl From<FetchResponse> for FulfillOptions {
+    fn from(response: FetchResponse) -> Self {
+        let headers = fulfill_headers(
+            response
+                .headers
+                .iter()
+                .map(|(n, v)| (n.as_str(), v.as_str())),
+        );
+        FulfillOptions::builder()
+            .status(response.status)
+            .headers(headers)
+            .body(response.body)
+            .build()
+    }
 }
 
 /// Options for continuing a request with modifications.
@@ -783,3 +1132,106 @@ impl std::fmt::Debug for Route {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn claim_stops_once_times_are_used_up() {
+        let state = RouteHandlerState::new(Some(RouteOptions::default().times(2)));
+        assert!(state.claim());
+        assert!(!state.is_spent());
+        assert!(state.claim());
+        assert!(state.is_spent());
+        assert!(!state.claim());
+        assert_eq!(state.hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn claim_without_times_never_runs_out() {
+        let state = RouteHandlerState::new(None);
+        for _ in 0..100 {
+            assert!(state.claim());
+        }
+        assert!(!state.is_spent());
+    }
+
+    fn response(headers: &[(&str, &str)], body: &str) -> FetchResponse {
+        FetchResponse {
+            status: 200,
+            status_text: "OK".to_string(),
+            headers: headers
+                .iter()
+                .map(|(n, v)| (n.to_string(), v.to_string()))
+                .collect(),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn fetch_response_edits_body_and_headers() {
+        let mut resp = response(
+            &[("Content-Type", "text/html"), ("X-Trace", "a")],
+            "<h1>Hello</h1>",
+        );
+        resp.replace_text("Hello", "Bye").unwrap();
+        resp.set_header("x-trace", "b");
+        resp.remove_header("CONTENT-TYPE");
+        resp.set_status(201);
+
+        assert_eq!(resp.text().unwrap(), "<h1>Bye</h1>");
+        assert_eq!(resp.header("X-TRACE"), Some("b"));
+        assert_eq!(resp.headers().len(), 1);
+        assert_eq!(resp.status(), 201);
+
+        resp.set_body(vec![0xff]);
+        assert!(resp.replace_text("a", "b").is_err());
+    }
+
+    #[test]
+    fn merge_json_follows_rfc_7396() {
+        let mut resp = response(
+            &[],
+            r#"{"name":"ada","tags":["a"],"meta":{"beta":false,"plan":"free"}}"#,
+        );
+        resp.merge_json(&json!({"tags": ["b"], "meta": {"beta": true, "plan": null}}))
+            .unwrap();
+        assert_eq!(
+            resp.json::<Value>().unwrap(),
+            json!({"name": "ada", "tags": ["b"], "meta": {"beta": true}})
+        );
+
+        resp.patch_json(|v| v["name"] = json!("grace")).unwrap();
+        assert_eq!(resp.json::<Value>().unwrap()["name"], "grace");
+
+        let mut text = response(&[], "not json");
+        assert!(text.merge_json(&json!({})).is_err());
+    }
+
+    #[test]
+    fn fulfill_options_drop_stale_encoding_headers() {
+        let mut resp = response(
+            &[
+                ("Content-Encoding", "gzip"),
+                ("Content-Length", "20"),
+                ("Set-Cookie", "a=1"),
+                ("set-cookie", "b=2"),
+                ("Vary", "Accept"),
+                ("Vary", "Origin"),
+            ],
+            "{}",
+        );
+        resp.set_json(&json!({"ok": true})).unwrap();
+
+        let options = FulfillOptions::from(resp);
+        let headers = options.headers.unwrap();
+        assert_eq!(options.status, Some(200));
+        assert_eq!(options.body.as_deref(), Some(&br#"{"ok":true}"#[..]));
+        assert!(!headers.contains_key("content-encoding"));
+        assert!(!headers.contains_key("content-length"));
+        assert_eq!(headers["content-type"], "application/json");
+        assert_eq!(headers["set-cookie"], "a=1\nb=2");
+        assert_eq!(headers["vary"], "Accept, Origin");
+    }
+}
@@ -4,6 +4,7 @@
 
 use crate::error::Result;
 use crate::protocol::page::Page;
+use crate::server::call_site::CallSite;
 
 /// Touchscreen provides touch input simulation via a single touch point.
 ///
@@ -37,7 +38,8 @@ impl Touchscreen {
     /// Returns error if the RPC call fails or the page has been closed.
     ///
     /// See: <https://playwright.dev/docs/api/class-touchscreen#touchscreen-tap>
-    pub async fn tap(&self, x: f64, y: f64) -> Result<()> {
-        self.page.touchscreen_tap(x, y).await
+    #[track_caller]
+    pub fn tap(&self, x: f64, y: f64) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move { self.page.touchscreen_tap(x, y).await })
     }
 }
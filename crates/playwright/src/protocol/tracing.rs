@@ -50,6 +50,7 @@
 
 use crate::error::Result;
 use crate::protocol::har_options::StartHarOptions;
+use crate::server::call_site::{self, CallSite, SourceLocation};
 use crate::server::channel::Channel;
 use crate::server::channel_owner::{
     ChannelOwner, ChannelOwnerImpl, DisposeReason, ParentOrConnection,
@@ -58,6 +59,7 @@ use crate::server::connection::ConnectionLike;
 use serde_json::Value;
 use std::any::Any;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Options for starting a trace recording.
 ///
@@ -79,6 +81,15 @@ pub struct TracingStartOptions {
     ///
     /// See: <https://playwright.dev/docs/api/class-tracing#tracing-start-option-live>
     pub live: Option<bool>,
+    /// Whether to include the source files of the calls made while tracing,
+    /// so the trace viewer's Source tab can show the Rust line behind each
+    /// action. Call sites are recorded either way; this adds the files.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-tracing#tracing-start-option-sources>
+    pub sources: Option<bool>,
+    /// Title of the first chunk, shown in the trace viewer. Later chunks
+    /// take theirs from [`TracingStartChunkOptions::title`].
+    pub title: Option<String>,
 }
 
 impl TracingStartOptions {
@@ -102,6 +113,94 @@ impl TracingStartOptions {
         self.live = Some(live);
         self
     }
+    /// Bundle the source files of traced calls into the trace.
+    pub fn sources(mut self, sources: bool) -> Self {
+        self.sources = Some(sources);
+        self
+    }
+    /// Title shown in the trace viewer.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+}
+
+/// Options for starting a new chunk of an already started trace.
+///
+/// See: <https://playwright.dev/docs/api/class-tracing#tracing-start-chunk>
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct TracingStartChunkOptions {
+    /// Name of the chunk's trace file in the traces directory. Defaults to
+    /// the name given to [`Tracing::start`].
+    pub name: Option<String>,
+    /// Title shown in the trace viewer.
+    pub title: Option<String>,
+}
+
+impl TracingStartChunkOptions {
+    /// Trace file name for this chunk.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+    /// Title shown in the trace viewer.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+}
+
+/// Source location shown for an action group in the trace viewer.
+///
+/// See: <https://playwright.dev/docs/api/class-tracing#tracing-group-option-location>
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TracingGroupLocation {
+    /// Source file path.
+    pub file: String,
+    /// 1-based line number.
+    pub line: Option<u32>,
+    /// 1-based column number.
+    pub column: Option<u32>,
+}
+
+impl TracingGroupLocation {
+    /// A location in `file`, without line or column.
+    pub fn new(file: impl Into<String>) -> Self {
+        Self {
+            file: file.into(),
+            line: None,
+            column: None,
+        }
+    }
+    /// Line within the file.
+    pub fn line(mut self, line: u32) -> Self {
+        self.line = Some(line);
+        self
+    }
+    /// Column within the line.
+    pub fn column(mut self, column: u32) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    fn to_json(&self) -> Value {
+        let mut location = serde_json::json!({ "file": self.file });
+        if let Some(line) = self.line {
+            location["line"] = line.into();
+        }
+        if let Some(column) = self.column {
+            location["column"] = column.into();
+        }
+        location
+    }
+}
+
+impl From<SourceLocation> for TracingGroupLocation {
+    fn from(site: SourceLocation) -> Self {
+        Self::new(&*site.file).line(site.line).column(site.column)
+    }
 }
 
 /// Options for stopping a trace recording.
@@ -130,6 +229,13 @@ struct HarRecording {
     resources_dir: Option<String>,
 }
 
+/// Stack-collection session of the running chunk, opened with
+/// `LocalUtils.tracingStarted`.
+struct StackSession {
+    stacks_id: String,
+    include_sources: bool,
+}
+
 /// Tracing — records Playwright traces for debugging and inspection.
 ///
 /// Trace files can be opened in the Playwright Trace Viewer.
@@ -144,6 +250,11 @@ pub struct Tracing {
     /// `Tracing` see one recording. `stop_har` takes no path (matching the
     /// upstream API), so the path and `harId` are stashed here at start.
     har: Arc<parking_lot::Mutex<Option<HarRecording>>>,
+    /// Shared across clones for the same reason: `stop` needs the session
+    /// `start` opened.
+    stacks: Arc<parking_lot::Mutex<Option<StackSession>>>,
+    /// `TracingStartOptions::sources`, applied to every chunk of the trace.
+    sources: Arc<AtomicBool>,
 }
 
 impl Tracing {
@@ -159,13 +270,21 @@ impl Tracing {
         Ok(Self {
             base: ChannelOwnerImpl::new(parent, type_name, guid, initializer),
             har: Arc::new(parking_lot::Mutex::new(None)),
+            stacks: Arc::new(parking_lot::Mutex::new(None)),
+            sources: Arc::new(AtomicBool::new(false)),
         })
     }
 
     /// Start tracing.
     ///
     /// Playwright implements tracing as a two-step process: `tracingStart` to
-    /// configure the trace, then `tracingStartChunk` to begin recording.
+    /// configure the trace, then `tracingStartChunk` to begin recording. Use
+    /// [`stop_chunk`](Self::stop_chunk) and [`start_chunk`](Self::start_chunk)
+    /// to split the recording into several traces.
+    ///
+    /// While recording, the Rust call site of each API call is collected
+    /// alongside, and written into the trace by [`stop`](Self::stop). Set
+    /// [`TracingStartOptions::sources`] to include the source files too.
     ///
     /// # Arguments
     ///
@@ -201,16 +320,64 @@ impl Tracing {
         self.channel()
             .send_no_result("tracingStart", start_params)
             .await?;
+        self.sources
+            .store(opts.sources.unwrap_or(false), Ordering::Relaxed);
 
         // Step 2: tracingStartChunk — begin the chunk/recording
+        self.do_start_chunk(opts.name, opts.title).await
+    }
+
+    /// Start a new chunk of a trace started with [`start`](Self::start).
+    ///
+    /// Each chunk is recorded to its own file by the matching
+    /// [`stop_chunk`](Self::stop_chunk), so a context shared by many tests
+    /// can produce one trace per test without being recreated.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Tracing was not started, or a chunk is already recording
+    /// - Communication with browser process fails
+    ///
+    /// See: <https://playwright.dev/docs/api/class-tracing#tracing-start-chunk>
+    #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
+    pub async fn start_chunk(
+        &self,
+        options: impl Into<Option<TracingStartChunkOptions>>,
+    ) -> Result<()> {
+        let opts = options.into().unwrap_or_default();
+        self.do_start_chunk(opts.name, opts.title).await
+    }
+
+    async fn do_start_chunk(&self, name: Option<String>, title: Option<String>) -> Result<()> {
         let mut chunk_params = serde_json::json!({});
-        if let Some(name) = opts.name {
-            chunk_params["name"] = serde_json::Value::String(name);
+        if let Some(name) = name {
+            chunk_params["name"] = Value::String(name);
+        }
+        if let Some(title) = title {
+            chunk_params["title"] = Value::String(title);
         }
 
-        self.channel()
-            .send_no_result("tracingStartChunk", chunk_params)
-            .await
+        let chunk: Value = self
+            .channel()
+            .send("tracingStartChunk", chunk_params)
+            .await?;
+
+        // Open a stack session so call sites reach the trace
+        let trace_name = chunk
+            .get("traceName")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+        let stacks_id = self
+            .find_local_utils()?
+            .tracing_started(None, trace_name)
+            .await?;
+        *self.stacks.lock() = Some(StackSession {
+            stacks_id,
+            include_sources: self.sources.load(Ordering::Relaxed),
+        });
+        self.connection().set_tracing(true);
+        Ok(())
     }
 
     /// Stop tracing.
@@ -234,22 +401,46 @@ impl Tracing {
     /// See: <https://playwright.dev/docs/api/class-tracing#tracing-stop>
     #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
     pub async fn stop(&self, options: impl Into<Option<TracingStopOptions>>) -> Result<()> {
-        let options = options.into();
-        let path = options.and_then(|o| o.path);
-
-        // Step 1: tracingStopChunk — mode "entries" collects trace data
-        // mode "archive" or "compressedTrace" would export, but "entries" is simpler
-        let mode = if path.is_some() { "archive" } else { "discard" };
-        let stop_chunk_params = serde_json::json!({ "mode": mode });
-
-        let chunk_result: Value = self
-            .channel()
-            .send("tracingStopChunk", stop_chunk_params)
+        // Step 1: tracingStopChunk — finalize (and export) the recording
+        self.do_stop_chunk(options.into().and_then(|o| o.path))
             .await?;
 
         // Step 2: tracingStop — tear down
         self.channel()
             .send_no_result("tracingStop", serde_json::json!({}))
+            .await
+    }
+
+    /// Stop the chunk started by [`start_chunk`](Self::start_chunk) (or by
+    /// [`start`](Self::start)), leaving tracing on for the next one.
+    ///
+    /// If `options.path` is provided, the chunk is exported to that file as
+    /// a `.zip` archive. If no path is provided, the chunk is discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - No chunk is recording
+    /// - Communication with browser process fails
+    ///
+    /// See: <https://playwright.dev/docs/api/class-tracing#tracing-stop-chunk>
+    #[tracing::instrument(level = "info", skip_all, fields(guid = %self.guid()))]
+    pub async fn stop_chunk(&self, options: impl Into<Option<TracingStopOptions>>) -> Result<()> {
+        self.do_stop_chunk(options.into().and_then(|o| o.path))
+            .await
+    }
+
+    async fn do_stop_chunk(&self, path: Option<String>) -> Result<()> {
+        let stacks = self.stacks.lock().take();
+        if stacks.is_some() {
+            self.connection().set_tracing(false);
+        }
+
+        // "archive" has the server zip the chunk up as an artifact
+        let mode = if path.is_some() { "archive" } else { "discard" };
+        let chunk_result: Value = self
+            .channel()
+            .send("tracingStopChunk", serde_json::json!({ "mode": mode }))
             .await?;
 
         // If a path was requested, save the artifact
@@ -259,13 +450,66 @@ impl Tracing {
                 .and_then(|a| a.get("guid"))
                 .and_then(|g| g.as_str())
         {
-            // Resolve the artifact and save it
             self.save_artifact(artifact_guid, &dest_path).await?;
+
+            // Add the collected call stacks (and sources) to the saved zip
+            if let Some(stacks) = &stacks {
+                self.find_local_utils()?
+                    .zip_append(&dest_path, Some(&stacks.stacks_id), stacks.include_sources)
+                    .await?;
+            }
+        } else if let Some(stacks) = &stacks {
+            self.find_local_utils()?
+                .trace_discarded(&stacks.stacks_id)
+                .await?;
         }
 
         Ok(())
     }
 
+    /// Open a group in the trace. Actions until the matching
+    /// [`group_end`](Self::group_end) appear nested under `name` in the
+    /// trace viewer, so logical steps ("log in", "checkout") collapse into
+    /// one entry. Groups nest.
+    ///
+    /// `location` defaults to the line that called `group`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if communication with browser process fails.
+    ///
+    /// See: <https://playwright.dev/docs/api/class-tracing#tracing-group>
+    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
+    #[track_caller]
+    pub fn group(
+        &self,
+        name: impl Into<String>,
+        location: impl Into<Option<TracingGroupLocation>>,
+    ) -> impl Future<Output = Result<()>> {
+        let name = name.into();
+        let location = location.into();
+        CallSite::caller().run(async move {
+            let mut params = serde_json::json!({ "name": name });
+            if let Some(location) = location.or_else(|| call_site::current().map(Into::into)) {
+                params["location"] = location.to_json();
+            }
+            self.channel().send_no_result("tracingGroup", params).await
+        })
+    }
+
+    /// Close the innermost group opened by [`group`](Self::group).
+    ///
+    /// See: <https://playwright.dev/docs/api/class-tracing#tracing-group-end>
+    #[tracing::instrument(level = "debug", skip_all, fields(guid = %self.guid()))]
+    #[track_caller]
+    pub fn group_end(&self) -> impl Future<Output = Result<()>> {
+        CallSite::caller().run(async move {
+            self.channel()
+                .send_no_result("tracingGroupEnd", serde_json::json!({}))
+                .await
+        })
+    }
+
     /// Save a trace artifact to a file path.
     async fn save_artifact(&self, artifact_guid: &str, dest_path: &str) -> Result<()> {
         use crate::protocol::artifact::Artifact;
@@ -373,7 +617,7 @@ impl Tracing {
     }
 
     /// Locate the connection's `LocalUtils` (used to extract a `.har` from the
-    /// exported zip archive).
+    /// exported zip archive, and to collect call stacks for traces).
     fn find_local_utils(&self) -> Result<crate::protocol::LocalUtils> {
         let connection = self.connection();
         connection
@@ -387,7 +631,7 @@ impl Tracing {
             })
             .ok_or_else(|| {
                 crate::error::Error::ProtocolError(
-                    "stop_har: LocalUtils not found in connection registry".to_string(),
+                    "LocalUtils not found in connection registry".to_string(),
                 )
             })
     }
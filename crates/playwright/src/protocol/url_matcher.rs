@@ -0,0 +1,345 @@
+// URL matchers: what `route`, `route_web_socket`, `wait_for_url` and the
+// URL-filtered `expect_request` / `expect_response` waiters match against.
+//
+// Matching happens on both sides of the wire. The server only reports
+// requests that match one of the patterns it was given, and the client then
+// picks the handler. Globs and most regexes can be expressed to the server
+// exactly (`{glob}` and `{regexSource, regexFlags}`); a predicate, a
+// URLPattern or a regex using Rust-only syntax cannot, so any of those
+// widens the server's filter to every URL and leaves the decision to the
+// client. A request that then matches no handler is passed on untouched.
+//
+// See: https://playwright.dev/docs/api/class-page#page-route
+
+use crate::error::{Error, Result};
+use crate::protocol::glob::GlobMatcher;
+use serde_json::{Value, json};
+use std::fmt;
+use std::sync::Arc;
+use url::Url;
+
+/// A URL glob, a regex, a predicate or a URLPattern.
+///
+/// Strings convert to globs (`"**/api/*"`) and `regex::Regex` converts to a
+/// regex, so most calls pass one directly:
+///
+/// ```no_run
+/// # use playwright_rs::{Playwright, UrlMatcher};
+/// # use regex::Regex;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// # let playwright = Playwright::launch().await?;
+/// # let browser = playwright.chromium().launch().await?;
+/// # let page = browser.new_page().await?;
+/// page.route("**/*.png", |route| async move { route.abort(None).await }).await?;
+/// page.route(Regex::new(r"/api/v\d+/")?, |route| async move { route.continue_(None).await })
+///     .await?;
+/// page.route(
+///     UrlMatcher::predicate(|url| url.query().is_some()),
+///     |route| async move { route.continue_(None).await },
+/// )
+/// .await?;
+/// page.wait_for_url(UrlMatcher::url_pattern("https://*.example.com/users/:id")?, None)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// A regex matches anywhere in the URL unless anchored, as in JavaScript.
+/// Two matchers are equal, for [`Page::unroute`](crate::protocol::Page::unroute),
+/// when they are the same glob, regex source or URLPattern, or clones of
+/// the same predicate.
+#[derive(Clone)]
+pub struct UrlMatcher {
+    kind: Kind,
+}
+
+#[derive(Clone)]
+enum Kind {
+    Glob {
+        pattern: String,
+        /// `None` for a malformed glob, which matches nothing.
+        compiled: Option<GlobMatcher>,
+    },
+    Regex(regex::Regex),
+    Predicate(Arc<dyn Fn(&Url) -> bool + Send + Sync>),
+    Pattern {
+        source: String,
+        pattern: Arc<urlpattern::UrlPattern>,
+    },
+}
+
+impl UrlMatcher {
+    /// A Playwright URL glob: `*` within a path segment, `**` across
+    /// segments, `{a,b}` alternation.
+    pub fn glob(pattern: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+        let compiled = GlobMatcher::new(&pattern);
+        Self {
+            kind: Kind::Glob { pattern, compiled },
+        }
+    }
+
+    /// URLs `regex` finds a match in.
+    pub fn regex(regex: regex::Regex) -> Self {
+        Self {
+            kind: Kind::Regex(regex),
+        }
+    }
+
+    /// URLs `predicate` returns `true` for. URLs that do not parse never
+    /// match.
+    pub fn predicate(predicate: impl Fn(&Url) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            kind: Kind::Predicate(Arc::new(predicate)),
+        }
+    }
+
+    /// A [URLPattern](https://developer.mozilla.org/en-US/docs/Web/API/URL_Pattern_API)
+    /// such as `https://*.example.com/users/:id`. The pattern must be
+    /// absolute.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if `pattern` is not a valid
+    /// absolute URLPattern.
+    pub fn url_pattern(pattern: &str) -> Result<Self> {
+        let invalid = |e: urlpattern::Error| {
+            Error::InvalidArgument(format!("invalid URLPattern `{pattern}`: {e}"))
+        };
+        let init =
+            urlpattern::UrlPatternInit::parse_constructor_string::<regex::Regex>(pattern, None)
+                .map_err(invalid)?;
+        let compiled = urlpattern::UrlPattern::parse(init, Default::default()).map_err(invalid)?;
+        Ok(Self {
+            kind: Kind::Pattern {
+                source: pattern.to_string(),
+                pattern: Arc::new(compiled),
+            },
+        })
+    }
+
+    /// Whether `url` matches.
+    pub fn matches(&self, url: &str) -> bool {
+        match &self.kind {
+            // A pattern equal to the URL matches even if it is not a valid
+            // glob, so `wait_for_url` with an exact URL containing `{` works.
+            Kind::Glob { pattern, compiled } => {
+                pattern == url || compiled.as_ref().is_some_and(|glob| glob.matches(url))
+            }
+            Kind::Regex(regex) => regex.is_match(url),
+            Kind::Predicate(predicate) => Url::parse(url).is_ok_and(|url| predicate(&url)),
+            Kind::Pattern { pattern, .. } => Url::parse(url).is_ok_and(|url| {
+                pattern
+                    .test(urlpattern::UrlPatternMatchInput::Url(url))
+                    .unwrap_or(false)
+            }),
+        }
+    }
+
+    /// The pattern for the server's interception filter, or `None` if the
+    /// server cannot evaluate this matcher exactly.
+    pub(crate) fn to_protocol(&self) -> Option<Value> {
+        match &self.kind {
+            Kind::Glob { pattern, .. } => Some(json!({ "glob": pattern })),
+            Kind::Regex(regex) => {
+                let (source, flags) = js_regex(regex.as_str())?;
+                Some(json!({ "regexSource": source, "regexFlags": flags }))
+            }
+            Kind::Predicate(_) | Kind::Pattern { .. } => None,
+        }
+    }
+}
+
+/// The `patterns` for `setNetworkInterceptionPatterns` /
+/// `setWebSocketInterceptionPatterns`: each matcher's own pattern, or
+/// every URL if any matcher can only be evaluated here.
+pub(crate) fn interception_patterns<'a>(
+    matchers: impl IntoIterator<Item = &'a UrlMatcher>,
+) -> Vec<Value> {
+    matchers
+        .into_iter()
+        .map(UrlMatcher::to_protocol)
+        .collect::<Option<Vec<_>>>()
+        .unwrap_or_else(|| vec![json!({ "glob": "**/*" })])
+}
+
+/// A Rust regex as JavaScript `(source, flags)`, if it means the same thing
+/// there.
+///
+/// Only the syntax the two engines share is accepted: a leading `(?ims)`
+/// becomes the flags, and anything else Rust-specific (inline flags
+/// elsewhere, `(?P<name>`, `\A`, `\z`, `\p{..}`, `\x{..}`, class set
+/// operations) returns `None`. URLs reaching the matcher are ASCII, so the
+/// engines' Unicode differences for `\d`, `\w` and `\b` never show.
+fn js_regex(source: &str) -> Option<(String, String)> {
+    let (flags, body) = source
+        .strip_prefix("(?")
+        .and_then(|rest| rest.split_once(')'))
+        .filter(|(flags, _)| {
+            !flags.is_empty() && flags.chars().all(|c| matches!(c, 'i' | 'm' | 's'))
+        })
+        .unwrap_or(("", source));
+    if ["[:", "&&", "--", "~~"].iter().any(|op| body.contains(op)) {
+        return None;
+    }
+    let mut chars = body.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                'd' | 'D' | 's' | 'S' | 'w' | 'W' | 'b' | 'B' | 't' | 'n' | 'r' | 'f' | 'v' => {}
+                'x' => {
+                    for _ in 0..2 {
+                        chars.next_if(char::is_ascii_hexdigit)?;
+                    }
+                }
+                escaped if escaped.is_ascii_alphanumeric() => return None,
+                _ => {}
+            },
+            '(' if chars.next_if_eq(&'?').is_some() => match chars.next()? {
+                ':' => {}
+                '<' if chars.peek().is_some_and(|c| c.is_ascii_alphabetic()) => {}
+                _ => return None,
+            },
+            _ => {}
+        }
+    }
+    Some((body.to_string(), flags.to_string()))
+}
+
+impl From<&str> for UrlMatcher {
+    fn from(glob: &str) -> Self {
+        Self::glob(glob)
+    }
+}
+
+impl From<String> for UrlMatcher {
+    fn from(glob: String) -> Self {
+        Self::glob(glob)
+    }
+}
+
+impl From<&String> for UrlMatcher {
+    fn from(glob: &String) -> Self {
+        Self::glob(glob.as_str())
+    }
+}
+
+impl From<regex::Regex> for UrlMatcher {
+    fn from(regex: regex::Regex) -> Self {
+        Self::regex(regex)
+    }
+}
+
+impl PartialEq for UrlMatcher {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.kind, &other.kind) {
+            (Kind::Glob { pattern: a, .. }, Kind::Glob { pattern: b, .. }) => a == b,
+            (Kind::Regex(a), Kind::Regex(b)) => a.as_str() == b.as_str(),
+            (Kind::Predicate(a), Kind::Predicate(b)) => Arc::ptr_eq(a, b),
+            (Kind::Pattern { source: a, .. }, Kind::Pattern { source: b, .. }) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// The glob or URLPattern as written, a regex as `/source/`, a predicate
+/// as `<predicate>`.
+impl fmt::Display for UrlMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            Kind::Glob { pattern, .. } => f.write_str(pattern),
+            Kind::Regex(regex) => write!(f, "/{}/", regex.as_str()),
+            Kind::Predicate(_) => f.write_str("<predicate>"),
+            Kind::Pattern { source, .. } => f.write_str(source),
+        }
+    }
+}
+
+impl fmt::Debug for UrlMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match &self.kind {
+            Kind::Glob { .. } => "Glob",
+            Kind::Regex(_) => "Regex",
+            Kind::Predicate(_) => "Predicate",
+            Kind::Pattern { .. } => "UrlPattern",
+        };
+        f.debug_tuple(kind).field(&format_args!("{self}")).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strings_are_globs_and_exact_urls_match_themselves() {
+        let matcher = UrlMatcher::from("**/api/*");
+        assert!(matcher.matches("https://example.com/api/users"));
+        assert!(!matcher.matches("https://example.com/api/users/1"));
+        assert_eq!(matcher.to_protocol(), Some(json!({ "glob": "**/api/*" })));
+
+        // Not a valid glob, but still the URL itself.
+        assert!(UrlMatcher::from("https://example.com/?q={").matches("https://example.com/?q={"));
+    }
+
+    #[test]
+    fn regexes_search_and_serialize_as_javascript() {
+        let matcher = UrlMatcher::from(regex::Regex::new(r"(?i)/API/v\d+/").unwrap());
+        assert!(matcher.matches("https://example.com/api/v2/users"));
+        assert!(!matcher.matches("https://example.com/api/users"));
+        assert_eq!(
+            matcher.to_protocol(),
+            Some(json!({ "regexSource": r"/API/v\d+/", "regexFlags": "i" }))
+        );
+    }
+
+    #[test]
+    fn rust_only_regex_syntax_widens_the_server_filter() {
+        for source in [
+            r"\Ahttps://",
+            r"(?P<host>[a-z]+)\.com",
+            r"\p{L}",
+            r"a(?i)b",
+            r"[[:alpha:]]",
+        ] {
+            assert_eq!(js_regex(source), None, "{source}");
+        }
+        assert_eq!(js_regex(r"(?x)a b"), None);
+        assert_eq!(
+            js_regex(r"(?:a|b)\.(?<ext>png)\x2F$"),
+            Some((r"(?:a|b)\.(?<ext>png)\x2F$".to_string(), String::new()))
+        );
+        let patterns = interception_patterns(&[
+            UrlMatcher::from("**/*.png"),
+            UrlMatcher::from(regex::Regex::new(r"\Ahttps://").unwrap()),
+        ]);
+        assert_eq!(patterns, [json!({ "glob": "**/*" })]);
+    }
+
+    #[test]
+    fn predicates_see_parsed_urls_and_compare_by_identity() {
+        let matcher = UrlMatcher::predicate(|url| url.path().ends_with(".json"));
+        assert!(matcher.matches("https://example.com/data.json?v=1"));
+        assert!(!matcher.matches("not a url.json"));
+        assert_eq!(matcher.to_protocol(), None);
+        assert_eq!(matcher, matcher.clone());
+        assert_ne!(
+            matcher,
+            UrlMatcher::predicate(|url| url.path().ends_with(".json"))
+        );
+    }
+
+    #[test]
+    fn url_patterns_match_components() {
+        let matcher = UrlMatcher::url_pattern("https://*.example.com/users/:id").unwrap();
+        assert!(matcher.matches("https://api.example.com/users/42"));
+        assert!(!matcher.matches("https://api.example.com/users/42/posts"));
+        assert!(!matcher.matches("https://example.org/users/42"));
+        assert_eq!(matcher.to_string(), "https://*.example.com/users/:id");
+
+        assert!(matches!(
+            UrlMatcher::url_pattern("/users/:id"),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+}
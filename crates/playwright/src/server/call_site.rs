@@ -0,0 +1,137 @@
+// Copyright 2026 Paul Adamson
+// Licensed under the Apache License, Version 2.0
+//
+// Call site — where in the user's code a public API call was made
+//
+// Architecture Reference:
+// - Python: playwright-python/playwright/_impl/_connection.py (wrap_api_call, get_stack)
+// - JavaScript: playwright/packages/playwright-core/src/client/channelOwner.ts (_wrapApiCall)
+//
+// Other bindings walk the interpreter's stack when a message is sent. Rust
+// has no runtime stack to walk, so public methods capture
+// `Location::caller()` via `#[track_caller]` and scope it over the future
+// they return; `Connection::send_message` reads it back for the metadata
+// envelope. `#[track_caller]` is a no-op on `async fn`, which is why the
+// tracked methods are plain `fn`s returning `impl Future`.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::panic::Location;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+use tracing::Instrument;
+
+tokio::task_local! {
+    static CALL_SITE: &'static Location<'static>;
+}
+
+/// The user call site of a public API method, captured when the method is
+/// called (not when its future is first polled).
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CallSite(&'static Location<'static>);
+
+impl CallSite {
+    /// Captures the caller of the enclosing `#[track_caller]` method.
+    #[track_caller]
+    pub(crate) fn caller() -> Self {
+        Self(Location::caller())
+    }
+
+    /// Runs `fut` with this call site attached to every protocol message it
+    /// sends. A call site already in scope wins, so a public method that is
+    /// implemented on top of another (`Page::click` → `Frame::click`) still
+    /// reports the user's line rather than the library's.
+    ///
+    /// Also carries the current span into `fut`: a `#[tracing::instrument]`
+    /// on the (now synchronous) public method has entered its span by the
+    /// time this runs, and would otherwise only cover building the future.
+    pub(crate) fn run<F: Future>(self, fut: F) -> impl Future<Output = F::Output> {
+        let span = tracing::Span::current();
+        async move {
+            if CALL_SITE.try_with(|_| ()).is_ok() {
+                fut.await
+            } else {
+                CALL_SITE.scope(self.0, fut).await
+            }
+        }
+        .instrument(span)
+    }
+}
+
+/// A resolved call site, ready for the `metadata.location` envelope and the
+/// `addStackToTracingNoReply` stack frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SourceLocation {
+    /// Absolute path when it can be resolved, so the driver can read the
+    /// file for the trace's source tab.
+    pub file: Arc<str>,
+    pub line: u32,
+    pub column: u32,
+}
+
+/// The call site in scope for the current task, if any.
+pub(crate) fn current() -> Option<SourceLocation> {
+    let location = CALL_SITE.try_with(|l| *l).ok()?;
+    Some(SourceLocation {
+        file: absolute(location.file()),
+        line: location.line(),
+        column: location.column(),
+    })
+}
+
+/// `Location::file()` is the path as given to rustc: relative to the
+/// workspace root for workspace members, while tests run with the package
+/// root as working directory. Try the working directory and each of its
+/// ancestors; fall back to the path as recorded.
+fn absolute(file: &'static str) -> Arc<str> {
+    static RESOLVED: LazyLock<parking_lot::Mutex<HashMap<&'static str, Arc<str>>>> =
+        LazyLock::new(Default::default);
+
+    if let Some(hit) = RESOLVED.lock().get(file) {
+        return Arc::clone(hit);
+    }
+    let resolved: Arc<str> = resolve(Path::new(file))
+        .map(|p| p.to_string_lossy().into())
+        .unwrap_or_else(|| file.into());
+    RESOLVED.lock().insert(file, Arc::clone(&resolved));
+    resolved
+}
+
+fn resolve(file: &Path) -> Option<PathBuf> {
+    if file.is_absolute() {
+        return Some(file.to_path_buf());
+    }
+    let cwd = std::env::current_dir().ok()?;
+    cwd.ancestors()
+        .map(|dir| dir.join(file))
+        .find(|candidate| candidate.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn tracked() -> impl Future<Output = Option<SourceLocation>> {
+        CallSite::caller().run(async { current() })
+    }
+
+    #[tokio::test]
+    async fn captures_the_outermost_caller() {
+        assert_eq!(current(), None, "nothing in scope outside a call");
+
+        let line = line!() + 1;
+        let site = tracked().await.expect("call site in scope");
+        assert_eq!(site.line, line);
+        assert!(site.file.ends_with("call_site.rs"), "{}", site.file);
+        assert!(
+            Path::new(&*site.file).is_absolute(),
+            "resolved against the working directory: {}",
+            site.file
+        );
+
+        let outer = line!() + 1;
+        let nested = CallSite::caller().run(tracked()).await.expect("in scope");
+        assert_eq!(nested.line, outer, "outer call site wins");
+    }
+}
@@ -6,12 +6,13 @@ use parking_lot::Mutex as ParkingLotMutex;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
 use tokio::sync::Mutex as TokioMutex;
 use tokio::sync::{mpsc, oneshot};
 
 use crate::protocol::selectors::Selectors;
+use crate::server::call_site::{self, SourceLocation};
 use crate::server::channel_owner::ChannelOwner;
 use crate::server::error_parsing::parse_protocol_error;
 use tracing::Instrument;
@@ -82,6 +83,13 @@ pub trait ConnectionLike: Send + Sync {
     /// Synchronous so it can run from `Drop`. Returns `false` if the writer
     /// was already closed, or if a send is in flight and holds the lock.
     fn close_writer(&self) -> bool;
+
+    /// Count a `Tracing` recording starting (`true`) or stopping (`false`).
+    ///
+    /// While any recording is active, each call's Rust call site is also
+    /// sent to `LocalUtils` (`addStackToTracingNoReply`) so it lands in the
+    /// trace's stacks file and, with `sources`, its source in the zip.
+    fn set_tracing(&self, active: bool);
 }
 
 /// Extension trait for typed object retrieval from a connection.
@@ -196,6 +204,16 @@ pub struct Location {
     pub column: Option<i32>,
 }
 
+impl From<&SourceLocation> for Location {
+    fn from(site: &SourceLocation) -> Self {
+        Self {
+            file: site.file.to_string(),
+            line: Some(site.line as i32),
+            column: Some(site.column as i32),
+        }
+    }
+}
+
 impl Metadata {
     pub fn now() -> Self {
         Self {
@@ -342,6 +360,12 @@ pub struct Connection {
     message_rx: Arc<TokioMutex<Option<mpsc::Receiver<Value>>>>,
     transport_receiver: Arc<TokioMutex<Option<Box<dyn TransportReceiver>>>>,
     objects: Arc<ParkingLotMutex<ObjectRegistry>>,
+    /// Active `Tracing` recordings; see [`ConnectionLike::set_tracing`].
+    tracing_count: AtomicUsize,
+    /// Guid of the connection's `LocalUtils`, where call stacks go while
+    /// tracing. Looked up when the first recording starts, since it never
+    /// changes afterwards.
+    local_utils_guid: OnceLock<Arc<str>>,
     /// Signalled whenever an object is registered; lets `wait_for_object`
     /// block on creation instead of polling.
     object_created: tokio::sync::Notify,
@@ -376,6 +400,8 @@ impl Connection {
             message_rx: Arc::new(TokioMutex::new(Some(message_rx))),
             transport_receiver: Arc::new(TokioMutex::new(Some(Box::new(receiver)))),
             objects: Arc::new(ParkingLotMutex::new(HashMap::new())),
+            tracing_count: AtomicUsize::new(0),
+            local_utils_guid: OnceLock::new(),
             object_created: tokio::sync::Notify::new(),
             selectors: Arc::new(Selectors::new()),
             #[cfg(debug_assertions)]
@@ -403,7 +429,7 @@ impl Connection {
                  launched it. The protocol channels are bound to the launching runtime; using \
                  a Browser from another runtime (e.g. sharing across `#[tokio::test]` boundaries \
                  via OnceCell) silently deadlocks. Launch a fresh Playwright + Browser per \
-                 runtime instead."
+                 runtime instead, or share one through `testing::SharedBrowser`."
             );
         }
     }
@@ -427,8 +453,12 @@ impl Connection {
         let (tx, rx) = oneshot::channel();
         self.callbacks.lock().insert(id, tx);
 
+        let call_site = call_site::current();
         let mut metadata = Metadata::now();
         metadata.timeout = take_timeout(&mut params);
+        metadata.location = call_site.as_ref().map(Location::from);
+
+        let stack_request = call_site.and_then(|site| self.stack_request(id, &guid, site));
 
         let request = Request {
             id,
@@ -451,6 +481,13 @@ impl Connection {
                     return Err(e);
                 }
             }
+            if let Some(stack_request) = stack_request {
+                // Best effort, like the other bindings: a lost stack only
+                // leaves the trace's source tab without this call.
+                if let Err(e) = writer.send(stack_request).await {
+                    tracing::debug!("Failed to send call stack for tracing: {:?}", e);
+                }
+            }
         }
 
         tracing::trace!("Waiting for response to ID {}", id);
@@ -459,6 +496,43 @@ impl Connection {
             .and_then(|result| result)
     }
 
+    /// `LocalUtils.addStackToTracingNoReply` for call `id`, when a recording
+    /// is active. Its response is never awaited: the callback's receiver is
+    /// dropped straight away and the dispatcher discards the reply.
+    fn stack_request(&self, id: u32, target: &str, site: SourceLocation) -> Option<Value> {
+        if self.tracing_count.load(Ordering::SeqCst) == 0 {
+            return None;
+        }
+        let local_utils = Arc::clone(self.local_utils_guid.get()?);
+        if *local_utils == *target {
+            return None;
+        }
+
+        let stack_id = self.last_id.fetch_add(1, Ordering::SeqCst);
+        let (tx, _rx) = oneshot::channel();
+        self.callbacks.lock().insert(stack_id, tx);
+        let request = Request {
+            id: stack_id,
+            guid: local_utils,
+            method: "addStackToTracingNoReply".to_string(),
+            params: serde_json::json!({
+                "callData": {
+                    "id": id,
+                    "stack": [{
+                        "file": &*site.file,
+                        "line": site.line,
+                        "column": site.column,
+                    }],
+                },
+            }),
+            metadata: Metadata {
+                internal: Some(true),
+                ..Metadata::now()
+            },
+        };
+        serde_json::to_value(&request).ok()
+    }
+
     pub async fn initialize_playwright(self: &Arc<Self>) -> Result<Arc<dyn ChannelOwner>> {
         use crate::protocol::Root;
         use std::time::Duration;
@@ -801,6 +875,27 @@ impl ConnectionLike for Connection {
     async fn wait_for_object(&self, guid: &str) -> Result<Arc<dyn ChannelOwner>> {
         Connection::wait_for_object(self, guid).await
     }
+
+    fn set_tracing(&self, active: bool) {
+        if active {
+            if self.local_utils_guid.get().is_none()
+                && let Some(guid) = self
+                    .objects
+                    .lock()
+                    .values()
+                    .find(|o| o.type_name() == "LocalUtils")
+                    .map(|o| Arc::<str>::from(o.guid()))
+            {
+                let _ = self.local_utils_guid.set(guid);
+            }
+            self.tracing_count.fetch_add(1, Ordering::SeqCst);
+        } else {
+            // Saturate: a `stop` without a `start` must not wrap to usize::MAX.
+            let _ = self
+                .tracing_count
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1));
+        }
+    }
 }
 
 #[cfg(test)]
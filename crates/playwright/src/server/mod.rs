@@ -7,6 +7,7 @@
 //! The types and APIs in this module are considered internal implementation details
 //! and may change without notice. User code should not depend on these types directly.
 
+pub(crate) mod call_site;
 #[doc(hidden)]
 pub mod channel;
 #[doc(hidden)]
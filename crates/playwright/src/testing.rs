@@ -1,4 +1,8 @@
-//! Opt-in test fakes for browser APIs Playwright cannot drive natively.
+//! Test-harness helpers: opt-in fakes for browser APIs Playwright cannot
+//! drive natively, and [`ArtifactPolicy`] for keeping a failed test's trace,
+//! video and HAR.
+//!
+//! # Fakes
 //!
 //! The File System Access API (`window.showSaveFilePicker` /
 //! `showOpenFilePicker`) opens native OS dialogs with no DOM presence, so no
@@ -30,6 +34,41 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # Artifacts
+//!
+//! [`ArtifactPolicy`] is the `trace: 'retain-on-failure'` option of
+//! Playwright Test as a library: it wraps one test's [`BrowserContext`](crate::protocol::BrowserContext),
+//! and keeps or discards the trace, video and HAR depending on whether the
+//! test passed.
+//!
+//! # Shared browsers
+//!
+//! Launching a browser per test is slow, but a `Browser` cannot outlive the
+//! tokio runtime that launched it, and every `#[tokio::test]` has its own.
+//! [`SharedBrowser`] keeps one driver and one browser per kind for the whole
+//! test binary on a background runtime, and runs each test there in a fresh
+//! context: the worker-scoped `browser` fixture of Playwright Test.
+//! `#[playwright_rs::test]` uses the same shared browsers.
+//!
+//! # API mocks
+//!
+//! [`MockApi`] registers a whole fake backend on a page or context with one
+//! route: endpoints by method and `/users/:id` path template, JSON, text or
+//! file responses, sequences and delays. The mounted mock records each call
+//! for assertions and lists the requests it had no endpoint for.
+
+mod artifacts;
+mod fixtures;
+mod harness;
+mod mock_api;
+
+pub use artifacts::{ArtifactMode, ArtifactPolicy, SavedArtifacts, TestRun};
+pub use fixtures::SharedBrowser;
+pub use harness::{PlaywrightTest, TestFixtures, TestOutcome};
+pub use mock_api::{
+    MockApi, MockCall, MockEndpoint, MockResponse, MountedMockApi, UnmatchedRequest,
+};
 
 use crate::error::Result;
 use crate::protocol::Page;
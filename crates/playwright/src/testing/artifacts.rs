@@ -0,0 +1,445 @@
+//! Keep-or-discard handling for a test's trace, video and HAR.
+//!
+//! Architecture Reference:
+//! - JavaScript: playwright/packages/playwright/src/index.ts (the `trace` /
+//!   `video` fixtures and `shouldCaptureTrace`)
+//! - Docs: <https://playwright.dev/docs/test-use-options#recording-options>
+
+use crate::error::{Error, Result};
+use crate::protocol::{BrowserContext, Page, Tracing, TracingStartOptions, TracingStopOptions};
+use futures_util::FutureExt as _;
+use std::fmt;
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+/// When an artifact is recorded, and whether it is kept.
+///
+/// The variants and their string forms (`"retain-on-failure"`, …) are the
+/// ones `playwright.config` uses, so a value can be taken from the
+/// environment with [`str::parse`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ArtifactMode {
+    /// Not recorded.
+    #[default]
+    Off,
+    /// Recorded and kept for every test.
+    On,
+    /// Recorded for every test, kept only when the test fails.
+    RetainOnFailure,
+    /// Recorded only on the first retry, and kept.
+    OnFirstRetry,
+}
+
+impl ArtifactMode {
+    fn records(self, retry: u32) -> bool {
+        match self {
+            Self::Off => false,
+            Self::On | Self::RetainOnFailure => true,
+            Self::OnFirstRetry => retry == 1,
+        }
+    }
+
+    fn keeps(self, failed: bool) -> bool {
+        match self {
+            Self::Off => false,
+            Self::On | Self::OnFirstRetry => true,
+            Self::RetainOnFailure => failed,
+        }
+    }
+}
+
+impl FromStr for ArtifactMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "off" => Ok(Self::Off),
+            "on" => Ok(Self::On),
+            "retain-on-failure" => Ok(Self::RetainOnFailure),
+            "on-first-retry" => Ok(Self::OnFirstRetry),
+            other => Err(Error::InvalidArgument(format!(
+                "unknown artifact mode {other:?}: expected \"off\", \"on\", \
+                 \"retain-on-failure\" or \"on-first-retry\""
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for ArtifactMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Off => "off",
+            Self::On => "on",
+            Self::RetainOnFailure => "retain-on-failure",
+            Self::OnFirstRetry => "on-first-retry",
+        })
+    }
+}
+
+/// Records a test's trace, video and HAR, and keeps or discards each one
+/// depending on the test's outcome.
+///
+/// [`run`](Self::run) owns the context for the length of the test: it
+/// starts recording, runs the test, closes the context and then saves what
+/// the policy keeps under `<output_dir>/<test-name>/`, which is the same
+/// directory for every run of the same test (`-retry<N>` is appended on
+/// retries). A test fails if it returns `Err` or panics; a panic is
+/// caught and returned in [`TestRun::outcome`] with the saved paths, for
+/// the caller to report them before re-raising it.
+///
+/// Videos only exist if the context was created with
+/// [`record_video`](crate::protocol::BrowserContextOptionsBuilder::record_video);
+/// the policy decides which of them to keep.
+///
+/// # Example
+///
+/// ```no_run
+/// use playwright_rs::testing::{ArtifactMode, ArtifactPolicy};
+/// # use playwright_rs::Playwright;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// # let pw = Playwright::launch().await?;
+/// # let browser = pw.chromium().launch().await?;
+/// let policy = ArtifactPolicy::new(ArtifactMode::RetainOnFailure).output_dir("test-results");
+///
+/// let context = browser.new_context().await?;
+/// let run = policy
+///     .run("checkout applies discount", context, |context| async move {
+///         let page = context.new_page().await?;
+///         page.goto("https://example.com", None).await?;
+///         Ok::<_, playwright_rs::Error>(())
+///     })
+///     .await?;
+///
+/// if let Some(trace) = &run.artifacts.trace {
+///     eprintln!("trace: {}", trace.display());
+/// }
+/// run.into_outcome()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArtifactPolicy {
+    trace: ArtifactMode,
+    video: ArtifactMode,
+    har: ArtifactMode,
+    output_dir: PathBuf,
+    retry: u32,
+    trace_options: TracingStartOptions,
+}
+
+impl ArtifactPolicy {
+    /// A policy applying `mode` to trace, video and HAR, writing under
+    /// `test-results/`.
+    pub fn new(mode: ArtifactMode) -> Self {
+        Self {
+            trace: mode,
+            video: mode,
+            har: mode,
+            output_dir: PathBuf::from("test-results"),
+            retry: 0,
+            trace_options: TracingStartOptions::default()
+                .screenshots(true)
+                .snapshots(true)
+                .sources(true),
+        }
+    }
+
+    /// Mode for the trace.
+    pub fn trace(mut self, mode: ArtifactMode) -> Self {
+        self.trace = mode;
+        self
+    }
+
+    /// Mode for page videos.
+    pub fn video(mut self, mode: ArtifactMode) -> Self {
+        self.video = mode;
+        self
+    }
+
+    /// Mode for the HAR of the context's network traffic.
+    pub fn har(mut self, mode: ArtifactMode) -> Self {
+        self.har = mode;
+        self
+    }
+
+    /// Directory the per-test artifact directories are created in.
+    pub fn output_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.output_dir = dir.into();
+        self
+    }
+
+    /// Which attempt this is: `0` for the first run, `1` for the first
+    /// retry. Drives [`ArtifactMode::OnFirstRetry`] and the directory name.
+    pub fn retry(mut self, retry: u32) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Options for the trace recording. Defaults to screenshots, snapshots
+    /// and sources on; the title is always the test name.
+    pub fn trace_options(mut self, options: TracingStartOptions) -> Self {
+        self.trace_options = options;
+        self
+    }
+
+    /// The directory artifacts of `test_name` are saved in.
+    pub fn test_dir(&self, test_name: &str) -> PathBuf {
+        let mut name = slug(test_name);
+        if self.retry > 0 {
+            name.push_str(&format!("-retry{}", self.retry));
+        }
+        self.output_dir.join(name)
+    }
+
+    /// Run `test` against `context` under this policy, then close the
+    /// context and save or discard each artifact.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if recording could not be started, or if saving or
+    /// discarding an artifact of a test that did not panic failed. The
+    /// test's own result, panic included, is in [`TestRun::outcome`].
+    pub async fn run<F, Fut, T, E>(
+        &self,
+        test_name: &str,
+        context: BrowserContext,
+        test: F,
+    ) -> Result<TestRun<T, E>>
+    where
+        F: FnOnce(BrowserContext) -> Fut,
+        Fut: Future<Output = std::result::Result<T, E>>,
+    {
+        let dir = self.test_dir(test_name);
+        let record_trace = self.trace.records(self.retry);
+        let record_har = self.har.records(self.retry);
+        let record_video = self.video.records(self.retry);
+
+        let tracing = if record_trace || record_har {
+            std::fs::create_dir_all(&dir)?;
+            Some(context.tracing().await?)
+        } else {
+            None
+        };
+        let har_path = dir.join("network.har");
+        if let Some(tracing) = &tracing {
+            if record_trace {
+                let options = self.trace_options.clone().title(test_name);
+                tracing.start(Some(options)).await?;
+            }
+            if record_har {
+                tracing.start_har(path_str(&har_path)?, None).await?;
+            }
+        }
+
+        // Pages closed during the test are gone from `pages()` by the end,
+        // so collect every page as it opens.
+        let pages = Arc::new(Mutex::new(context.pages()));
+        if record_video {
+            let pages = Arc::clone(&pages);
+            context
+                .on_page(move |page| {
+                    pages.lock().unwrap().push(page);
+                    async { Ok(()) }
+                })
+                .await?;
+        }
+
+        let outcome = AssertUnwindSafe(test(context.clone())).catch_unwind().await;
+        let failed = !matches!(outcome, Ok(Ok(_)));
+
+        // Finish every artifact even if one fails, then report the first
+        // error (unless the test panicked: the panic matters more, and is
+        // returned with whatever was saved).
+        let mut artifacts = SavedArtifacts {
+            dir: dir.clone(),
+            ..Default::default()
+        };
+        let mut first_error = None;
+        let mut note = |result: Result<()>| {
+            if let Err(e) = result {
+                first_error.get_or_insert(e);
+            }
+        };
+
+        if let Some(tracing) = &tracing {
+            if record_trace {
+                let keep = self.trace.keeps(failed);
+                let path = dir.join("trace.zip");
+                note(stop_trace(tracing, keep.then_some(&path)).await);
+                if keep {
+                    artifacts.trace = Some(path);
+                }
+            }
+            if record_har {
+                note(tracing.stop_har().await);
+                if self.har.keeps(failed) {
+                    artifacts.har = Some(har_path);
+                } else {
+                    let _ = std::fs::remove_file(&har_path);
+                }
+            }
+        }
+
+        note(context.close().await);
+
+        if record_video {
+            let keep = self.video.keeps(failed);
+            let videos = pages
+                .lock()
+                .unwrap()
+                .iter()
+                .filter_map(Page::video)
+                .collect::<Vec<_>>();
+            for (i, video) in videos.into_iter().enumerate() {
+                if keep {
+                    let path = match i {
+                        0 => dir.join("video.webm"),
+                        n => dir.join(format!("video-{n}.webm")),
+                    };
+                    note(create_dir_and(&dir, video.save_as(&path)).await);
+                    artifacts.videos.push(path);
+                } else {
+                    note(video.delete().await);
+                }
+            }
+        }
+
+        // Leave no empty directory behind for a test that kept nothing
+        let _ = std::fs::remove_dir(&dir);
+
+        match first_error {
+            Some(e) if outcome.is_ok() => Err(e),
+            _ => Ok(TestRun { outcome, artifacts }),
+        }
+    }
+}
+
+impl Default for ArtifactPolicy {
+    /// Everything [`Off`](ArtifactMode::Off).
+    fn default() -> Self {
+        Self::new(ArtifactMode::Off)
+    }
+}
+
+/// The result of [`ArtifactPolicy::run`].
+#[derive(Debug)]
+pub struct TestRun<T, E> {
+    /// What the test returned, as [`catch_unwind`](std::panic::catch_unwind)
+    /// reports it: `Ok` with the test's result, or `Err` with the payload
+    /// of its panic.
+    pub outcome: std::thread::Result<std::result::Result<T, E>>,
+    /// The artifacts the policy kept.
+    pub artifacts: SavedArtifacts,
+}
+
+impl<T, E> TestRun<T, E> {
+    /// What the test returned, re-raising its panic if it panicked.
+    pub fn into_outcome(self) -> std::result::Result<T, E> {
+        self.outcome
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+    }
+}
+
+/// Paths of the artifacts kept for one test run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SavedArtifacts {
+    /// The test's artifact directory. Removed again if nothing was kept.
+    pub dir: PathBuf,
+    /// `trace.zip`, open with `playwright-rs show-trace` or at
+    /// <https://trace.playwright.dev>.
+    pub trace: Option<PathBuf>,
+    /// `network.har`.
+    pub har: Option<PathBuf>,
+    /// `video.webm`, `video-1.webm`, … in page creation order.
+    pub videos: Vec<PathBuf>,
+}
+
+impl SavedArtifacts {
+    /// Whether nothing was kept.
+    pub fn is_empty(&self) -> bool {
+        self.trace.is_none() && self.har.is_none() && self.videos.is_empty()
+    }
+}
+
+async fn stop_trace(tracing: &Tracing, path: Option<&PathBuf>) -> Result<()> {
+    let options = match path {
+        Some(path) => TracingStopOptions::default().path(path_str(path)?),
+        None => TracingStopOptions::default(),
+    };
+    tracing.stop(Some(options)).await
+}
+
+async fn create_dir_and(dir: &Path, save: impl Future<Output = Result<()>>) -> Result<()> {
+    std::fs::create_dir_all(dir)?;
+    save.await
+}
+
+fn path_str(path: &Path) -> Result<&str> {
+    path.to_str()
+        .ok_or_else(|| Error::InvalidArgument(format!("non-UTF-8 path: {}", path.display())))
+}
+
+/// A file-system-safe, stable directory name for a test: lowercase ASCII
+/// alphanumerics with runs of anything else collapsed to `-`.
+fn slug(test_name: &str) -> String {
+    let mut slug = String::with_capacity(test_name.len());
+    for c in test_name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    while slug.ends_with('-') {
+        slug.pop();
+    }
+    if slug.is_empty() {
+        slug.push_str("test");
+    }
+    slug
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modes_parse_as_playwright_config_spells_them() {
+        for mode in [
+            ArtifactMode::Off,
+            ArtifactMode::On,
+            ArtifactMode::RetainOnFailure,
+            ArtifactMode::OnFirstRetry,
+        ] {
+            assert_eq!(mode.to_string().parse::<ArtifactMode>().unwrap(), mode);
+        }
+        assert!("retain_on_failure".parse::<ArtifactMode>().is_err());
+    }
+
+    #[test]
+    fn recording_and_keeping_follow_the_mode() {
+        use ArtifactMode::*;
+        assert!(!Off.records(0) && !Off.keeps(true));
+        assert!(On.records(0) && On.keeps(false));
+        assert!(RetainOnFailure.records(0));
+        assert!(RetainOnFailure.keeps(true) && !RetainOnFailure.keeps(false));
+        assert!(!OnFirstRetry.records(0) && OnFirstRetry.records(1) && !OnFirstRetry.records(2));
+        assert!(OnFirstRetry.keeps(false));
+    }
+
+    #[test]
+    fn test_dirs_are_deterministic() {
+        let policy = ArtifactPolicy::default().output_dir("out");
+        assert_eq!(
+            policy.test_dir("login::Shows an error (bad password)"),
+            Path::new("out/login-shows-an-error-bad-password")
+        );
+        assert_eq!(
+            policy.clone().retry(2).test_dir("checkout"),
+            Path::new("out/checkout-retry2")
+        );
+        assert_eq!(policy.test_dir("→"), Path::new("out/test"));
+    }
+}
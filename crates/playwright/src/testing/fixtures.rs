@@ -0,0 +1,260 @@
+//! Worker-scoped fixtures: one driver and one browser per test binary.
+//!
+//! Architecture Reference:
+//! - JavaScript: playwright/packages/playwright/src/index.ts (`playwright`
+//!   and `browser` are `{ scope: 'worker' }` fixtures; `context` and `page`
+//!   are per test)
+//!
+//! The driver connection belongs to the tokio runtime that launched it,
+//! and `#[tokio::test]` gives every test a runtime of its own, so a
+//! `Browser` cannot simply be stashed in a static (see
+//! `Connection::assert_same_runtime`). Instead the process owns one
+//! background runtime that outlives every test. The driver and the shared
+//! browsers are launched on it, and test code is moved onto it to run:
+//! spawned from an async test, or driven with `block_on` from a plain
+//! `#[test]` thread.
+
+use super::harness::TestFixtures;
+use crate::api::LaunchOptions;
+use crate::error::{Error, Result};
+use crate::protocol::{Browser, BrowserContextOptions, Playwright};
+use futures_util::FutureExt as _;
+use std::collections::HashMap;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, LazyLock};
+use tokio::runtime::Runtime;
+use tokio::sync::OnceCell;
+
+type BrowserKey = (&'static str, Option<bool>);
+
+/// A launch that failed is remembered, so every test sharing it reports
+/// the same error instead of retrying the launch.
+type Shared<T> = Arc<OnceCell<std::result::Result<T, String>>>;
+
+/// The process-wide driver and the browsers launched on it.
+pub(crate) struct Worker {
+    runtime: Runtime,
+    playwright: Shared<Playwright>,
+    browsers: parking_lot::Mutex<HashMap<BrowserKey, Shared<Browser>>>,
+}
+
+pub(crate) fn worker() -> &'static Worker {
+    static WORKER: LazyLock<Worker> = LazyLock::new(|| Worker {
+        runtime: tokio::runtime::Builder::new_multi_thread()
+            .thread_name("playwright-rs-worker")
+            .enable_all()
+            .build()
+            .expect("playwright-rs: failed to build the shared worker runtime"),
+        playwright: Arc::default(),
+        browsers: parking_lot::Mutex::default(),
+    });
+    &WORKER
+}
+
+impl Worker {
+    /// Drive `fut` to completion on the calling thread, inside the
+    /// worker runtime. For threads that are not already in a runtime.
+    pub(crate) fn block_on<F: Future>(&self, fut: F) -> F::Output {
+        self.runtime.block_on(fut)
+    }
+
+    /// Run `fut` on the worker runtime from any runtime, re-raising its
+    /// panic on the caller.
+    async fn spawn<F>(&self, fut: F) -> F::Output
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        match self.runtime.spawn(fut).await {
+            Ok(output) => output,
+            Err(e) => match e.try_into_panic() {
+                Ok(panic) => std::panic::resume_unwind(panic),
+                Err(e) => panic!("playwright-rs: shared worker task cancelled: {e}"),
+            },
+        }
+    }
+
+    /// The shared driver connection. Must be awaited on the worker runtime.
+    pub(crate) async fn playwright(&self) -> Result<Playwright> {
+        let cell = Arc::clone(&self.playwright);
+        cell.get_or_init(|| async { Playwright::launch().await.map_err(|e| e.to_string()) })
+            .await
+            .clone()
+            .map_err(|e| Error::ServerError(format!("shared Playwright failed to launch: {e}")))
+    }
+
+    /// The shared browser for `name` and `headless`, launched on first use.
+    /// Must be awaited on the worker runtime.
+    pub(crate) async fn browser(&self, name: &str, headless: Option<bool>) -> Result<Browser> {
+        let name = match name {
+            "chromium" => "chromium",
+            "firefox" => "firefox",
+            "webkit" => "webkit",
+            other => {
+                return Err(Error::InvalidArgument(format!(
+                    "unknown browser {other:?}: expected \"chromium\", \"firefox\" or \"webkit\""
+                )));
+            }
+        };
+        let playwright = self.playwright().await?;
+        let cell = Arc::clone(self.browsers.lock().entry((name, headless)).or_default());
+        cell.get_or_init(|| async {
+            let browser_type = match name {
+                "firefox" => playwright.firefox(),
+                "webkit" => playwright.webkit(),
+                _ => playwright.chromium(),
+            };
+            let mut options = LaunchOptions::new();
+            if let Some(headless) = headless {
+                options = options.headless(headless);
+            }
+            browser_type
+                .launch_with_options(options)
+                .await
+                .map_err(|e| e.to_string())
+        })
+        .await
+        .clone()
+        .map_err(|e| Error::ServerError(format!("shared {name} failed to launch: {e}")))
+    }
+}
+
+/// A browser shared by every test in the binary that names it, on a
+/// driver launched once for the whole binary. Each test still gets a
+/// fresh `BrowserContext` and `Page`, closed when it ends.
+///
+/// The handle itself is just configuration, so it can be a `static`. Work
+/// is moved onto the driver's runtime to run, which is why the test
+/// closure must be `Send + 'static`; the handles it receives must not be
+/// carried back out of it.
+///
+/// # Example
+///
+/// ```no_run
+/// use playwright_rs::testing::SharedBrowser;
+///
+/// static BROWSER: SharedBrowser = SharedBrowser::chromium();
+///
+/// #[tokio::test]
+/// async fn shows_heading() -> playwright_rs::Result<()> {
+///     BROWSER
+///         .run(|fx| async move {
+///             fx.page.goto("https://example.com", None).await?;
+///             Ok(())
+///         })
+///         .await?
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SharedBrowser {
+    browser: &'static str,
+    headless: Option<bool>,
+}
+
+impl SharedBrowser {
+    /// The shared Chromium.
+    pub const fn chromium() -> Self {
+        Self::named("chromium")
+    }
+
+    /// The shared Firefox.
+    pub const fn firefox() -> Self {
+        Self::named("firefox")
+    }
+
+    /// The shared WebKit.
+    pub const fn webkit() -> Self {
+        Self::named("webkit")
+    }
+
+    const fn named(browser: &'static str) -> Self {
+        Self {
+            browser,
+            headless: None,
+        }
+    }
+
+    /// Share a headed (`false`) or headless browser. Handles that differ
+    /// only here get separate browser processes.
+    pub const fn headless(mut self, headless: bool) -> Self {
+        self.headless = Some(headless);
+        self
+    }
+
+    /// Run `test` with a fresh context and page on the shared browser.
+    /// The context is closed afterwards, even if `test` panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the shared driver or browser failed to launch,
+    /// or the context could not be created. The test's own result is the
+    /// `Ok` value.
+    pub async fn run<F, Fut, R>(self, test: F) -> Result<R>
+    where
+        F: FnOnce(TestFixtures) -> Fut + Send + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+        R: Send + 'static,
+    {
+        self.run_with_options(BrowserContextOptions::default(), test)
+            .await
+    }
+
+    /// [`run`](Self::run) with the context created from `options`.
+    pub async fn run_with_options<F, Fut, R>(
+        self,
+        options: BrowserContextOptions,
+        test: F,
+    ) -> Result<R>
+    where
+        F: FnOnce(TestFixtures) -> Fut + Send + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+        R: Send + 'static,
+    {
+        self.with_browser(move |playwright, browser| async move {
+            let context = browser.new_context_with_options(options).await?;
+            let page = match context.new_page().await {
+                Ok(page) => page,
+                Err(e) => {
+                    let _ = context.close().await;
+                    return Err(e);
+                }
+            };
+            let fixtures = TestFixtures {
+                playwright,
+                browser,
+                context: context.clone(),
+                page,
+            };
+            let outcome = AssertUnwindSafe(test(fixtures)).catch_unwind().await;
+            let _ = context.close().await;
+            match outcome {
+                Ok(output) => Ok(output),
+                Err(panic) => std::panic::resume_unwind(panic),
+            }
+        })
+        .await?
+    }
+
+    /// Run `f` with the shared driver and browser, for work that needs
+    /// more than one context or none at all. Anything `f` opens it must
+    /// also close: the browser outlives the call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the shared driver or browser failed to launch.
+    pub async fn with_browser<F, Fut, R>(self, f: F) -> Result<R>
+    where
+        F: FnOnce(Playwright, Browser) -> Fut + Send + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+        R: Send + 'static,
+    {
+        let worker = worker();
+        worker
+            .spawn(async move {
+                let playwright = worker.playwright().await?;
+                let browser = worker.browser(self.browser, self.headless).await?;
+                Ok(f(playwright, browser).await)
+            })
+            .await
+    }
+}
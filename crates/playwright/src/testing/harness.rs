@@ -0,0 +1,235 @@
+//! The runtime behind `#[playwright_rs::test]`.
+//!
+//! Architecture Reference:
+//! - JavaScript: playwright/packages/playwright/src/index.ts (the
+//!   `playwright` / `browser` / `context` / `page` fixtures)
+//!
+//! Tests run on the shared worker runtime (see `fixtures`), because the
+//! connection to the driver is bound to the runtime that launched it. The
+//! driver and browser are shared across the binary; the context and page
+//! are torn down before the test returns or its panic resumes: there is no
+//! async `Drop` to do it later.
+
+use super::artifacts::{ArtifactMode, ArtifactPolicy};
+use super::fixtures::worker;
+use crate::error::Error;
+use crate::protocol::{Browser, BrowserContext, BrowserContextOptions, Page, Playwright, Viewport};
+use futures_util::FutureExt as _;
+use std::fmt;
+use std::panic::AssertUnwindSafe;
+use std::path::PathBuf;
+
+/// What a test receives: the shared `Playwright` and `Browser`, and a
+/// fresh `BrowserContext` with one `Page` in it.
+///
+/// Handles are cheap clones; take whichever the test needs.
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct TestFixtures {
+    /// The driver connection, shared by every test in the binary.
+    pub playwright: Playwright,
+    /// The browser, shared by every test that asked for the same one.
+    /// Tests must not close it.
+    pub browser: Browser,
+    /// A context created for this test alone, closed when it ends.
+    pub context: BrowserContext,
+    /// A page in [`context`](Self::context).
+    pub page: Page,
+}
+
+/// Configuration and runner for one browser test: the expansion of
+/// `#[playwright_rs::test]`, usable directly from a plain `#[test]`.
+///
+/// # Example
+///
+/// ```no_run
+/// use playwright_rs::testing::{ArtifactMode, PlaywrightTest};
+///
+/// #[test]
+/// fn heading_is_shown() {
+///     PlaywrightTest::new("heading_is_shown")
+///         .browser("firefox")
+///         .trace(ArtifactMode::RetainOnFailure)
+///         .run(|fx| async move {
+///             fx.page.goto("https://example.com", None).await?;
+///             Ok::<_, playwright_rs::Error>(())
+///         });
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PlaywrightTest {
+    name: String,
+    browser: Option<String>,
+    headless: Option<bool>,
+    device: Option<String>,
+    trace: ArtifactMode,
+    output_dir: Option<PathBuf>,
+}
+
+impl PlaywrightTest {
+    /// A test named `name`. The name identifies the test's artifact
+    /// directory, so it should be unique: the macro uses the test's module
+    /// path.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            browser: None,
+            headless: None,
+            device: None,
+            trace: ArtifactMode::Off,
+            output_dir: None,
+        }
+    }
+
+    /// Browser to launch: `"chromium"`, `"firefox"` or `"webkit"`.
+    /// Defaults to the device's browser if a [`device`](Self::device) is
+    /// set, otherwise to Chromium.
+    pub fn browser(mut self, browser: impl Into<String>) -> Self {
+        self.browser = Some(browser.into());
+        self
+    }
+
+    /// Run headless (the default) or headed.
+    pub fn headless(mut self, headless: bool) -> Self {
+        self.headless = Some(headless);
+        self
+    }
+
+    /// Emulate a device from [`Playwright::devices`], e.g. `"iPhone 13"`.
+    pub fn device(mut self, device: impl Into<String>) -> Self {
+        self.device = Some(device.into());
+        self
+    }
+
+    /// When to record a trace and whether to keep it; see
+    /// [`ArtifactPolicy`].
+    pub fn trace(mut self, mode: ArtifactMode) -> Self {
+        self.trace = mode;
+        self
+    }
+
+    /// Where kept traces are written. Defaults to `test-results`.
+    pub fn output_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.output_dir = Some(dir.into());
+        self
+    }
+
+    /// Run `test` in a fresh context on the shared browser, launching the
+    /// driver and browser on first use, and close the context afterwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the test fails (returns `Err` or panics) or if the
+    /// browser cannot be set up, which is how a test harness reports
+    /// either. The test's context is closed first. If a trace was kept,
+    /// its path ends the panic message of a test that returned `Err`; a
+    /// test's own panic is re-raised unchanged, with the path printed to
+    /// stderr just before.
+    #[track_caller]
+    pub fn run<F, Fut, R>(self, test: F)
+    where
+        F: FnOnce(TestFixtures) -> Fut,
+        Fut: Future<Output = R>,
+        R: TestOutcome,
+    {
+        let outcome = worker().block_on(AssertUnwindSafe(self.run_in(test)).catch_unwind());
+
+        match outcome {
+            Err(panic) => std::panic::resume_unwind(panic),
+            Ok(Err(failure)) => panic!("{failure}"),
+            Ok(Ok(())) => {}
+        }
+    }
+
+    async fn run_in<F, Fut, R>(&self, test: F) -> std::result::Result<(), String>
+    where
+        F: FnOnce(TestFixtures) -> Fut,
+        Fut: Future<Output = R>,
+        R: TestOutcome,
+    {
+        let setup = async {
+            let worker = worker();
+            let playwright = worker.playwright().await?;
+            let mut context_options = BrowserContextOptions::default();
+            let mut browser_name = self.browser.clone();
+            if let Some(name) = &self.device {
+                let device = playwright
+                    .devices()
+                    .get(name)
+                    .ok_or_else(|| Error::InvalidArgument(format!("unknown device {name:?}")))?;
+                context_options = BrowserContextOptions::builder()
+                    .user_agent(device.user_agent.clone())
+                    .viewport(Viewport {
+                        width: device.viewport.width,
+                        height: device.viewport.height,
+                    })
+                    .device_scale_factor(device.device_scale_factor)
+                    .is_mobile(device.is_mobile)
+                    .has_touch(device.has_touch)
+                    .build();
+                browser_name.get_or_insert_with(|| device.default_browser_type.clone());
+            }
+
+            let browser = worker
+                .browser(browser_name.as_deref().unwrap_or("chromium"), self.headless)
+                .await?;
+            let context = browser.new_context_with_options(context_options).await?;
+            Ok::<_, Error>((playwright, browser, context))
+        };
+        let (playwright, browser, context) = setup
+            .await
+            .map_err(|e| format!("playwright-rs test: setup failed: {e}"))?;
+
+        let mut policy = ArtifactPolicy::default().trace(self.trace);
+        if let Some(dir) = &self.output_dir {
+            policy = policy.output_dir(dir);
+        }
+        // The policy closes the context, even if the test panics.
+        let run = policy
+            .run(&self.name, context, |context| async move {
+                let page = context.new_page().await.map_err(|e| e.to_string())?;
+                let fixtures = TestFixtures {
+                    playwright,
+                    browser,
+                    context,
+                    page,
+                };
+                test(fixtures).await.into_result()
+            })
+            .await
+            .map_err(|e| format!("artifacts: {e}"))?;
+        let outcome = match run.outcome {
+            Ok(outcome) => outcome,
+            Err(panic) => {
+                // Re-raise the payload as is, so `should_panic(expected)`
+                // still sees the test's own message.
+                if let Some(trace) = &run.artifacts.trace {
+                    eprintln!("trace: {}", trace.display());
+                }
+                std::panic::resume_unwind(panic)
+            }
+        };
+        match &run.artifacts.trace {
+            Some(trace) => outcome.map_err(|e| format!("{e}\ntrace: {}", trace.display())),
+            None => outcome,
+        }
+    }
+}
+
+/// Return types a Playwright test may have: `()` or `Result<(), E>`.
+pub trait TestOutcome {
+    /// `Err` with a printable failure for a failed test.
+    fn into_result(self) -> std::result::Result<(), String>;
+}
+
+impl TestOutcome for () {
+    fn into_result(self) -> std::result::Result<(), String> {
+        Ok(())
+    }
+}
+
+impl<E: fmt::Debug> TestOutcome for std::result::Result<(), E> {
+    fn into_result(self) -> std::result::Result<(), String> {
+        self.map_err(|e| format!("Error: {e:?}"))
+    }
+}
@@ -0,0 +1,679 @@
+//! Declarative API mocks built on [`Page::route`] / [`BrowserContext::route`].
+//!
+//! Architecture Reference:
+//! - Playwright docs: <https://playwright.dev/docs/mock>
+//! - The path templates follow Express's `/users/:id` syntax, as used by
+//!   MSW and most JS mocking libraries.
+
+use crate::error::{Error, Result};
+use crate::protocol::route::{FulfillOptions, RouteHandle};
+use crate::protocol::url_matcher::UrlMatcher;
+use crate::protocol::{BrowserContext, Page, Route};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A set of mocked endpoints, registered on a page or context with one
+/// route.
+///
+/// Requests matching `scope` are checked against the endpoints in the order
+/// they were added; the first whose method, path template and body matchers
+/// all match answers the request. A request in scope that no endpoint
+/// matches is recorded as [unmatched](MountedMockApi::unmatched) and passed
+/// on with [`Route::fallback`], so it reaches other handlers or the network.
+///
+/// # Example
+///
+/// ```no_run
+/// use playwright_rs::testing::{MockApi, MockEndpoint, MockResponse};
+/// # use playwright_rs::Playwright;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// # let pw = Playwright::launch().await?;
+/// # let page = pw.chromium().launch().await?.new_page().await?;
+/// let api = MockApi::new("**/api/**")
+///     .get("/api/users/:id", MockResponse::json(&serde_json::json!({ "name": "Ada" }))?)
+///     .endpoint(
+///         MockEndpoint::post("/api/orders")
+///             .body_contains("\"sku\"")
+///             .respond(MockResponse::empty().status(201))
+///             .then(MockResponse::text("out of stock").status(409)),
+///     )
+///     .mount(&page)
+///     .await?;
+///
+/// page.goto("https://shop.example.com", None).await?;
+///
+/// let lookups = api.calls_to("GET", "/api/users/:id");
+/// assert_eq!(lookups[0].params["id"], "42");
+/// assert!(api.unmatched().is_empty(), "unexpected calls: {:?}", api.unmatched());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockApi {
+    scope: UrlMatcher,
+    endpoints: Vec<MockEndpoint>,
+}
+
+impl MockApi {
+    /// An empty mock for the requests `scope` matches: a glob like
+    /// `"**/api/**"`, a `Regex`, or any other [`UrlMatcher`].
+    pub fn new(scope: impl Into<UrlMatcher>) -> Self {
+        Self {
+            scope: scope.into(),
+            endpoints: Vec::new(),
+        }
+    }
+
+    /// Adds an endpoint.
+    pub fn endpoint(mut self, endpoint: MockEndpoint) -> Self {
+        self.endpoints.push(endpoint);
+        self
+    }
+
+    /// Answers `GET path` with `response`.
+    pub fn get(self, path: &str, response: MockResponse) -> Self {
+        self.endpoint(MockEndpoint::get(path).respond(response))
+    }
+
+    /// Answers `POST path` with `response`.
+    pub fn post(self, path: &str, response: MockResponse) -> Self {
+        self.endpoint(MockEndpoint::post(path).respond(response))
+    }
+
+    /// Answers `PUT path` with `response`.
+    pub fn put(self, path: &str, response: MockResponse) -> Self {
+        self.endpoint(MockEndpoint::put(path).respond(response))
+    }
+
+    /// Answers `PATCH path` with `response`.
+    pub fn patch(self, path: &str, response: MockResponse) -> Self {
+        self.endpoint(MockEndpoint::patch(path).respond(response))
+    }
+
+    /// Answers `DELETE path` with `response`.
+    pub fn delete(self, path: &str, response: MockResponse) -> Self {
+        self.endpoint(MockEndpoint::delete(path).respond(response))
+    }
+
+    /// Registers the mock on `page`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an endpoint has no response, or if the route
+    /// cannot be registered.
+    pub async fn mount(self, page: &Page) -> Result<MountedMockApi> {
+        let (scope, state) = self.into_state()?;
+        let handler_state = Arc::clone(&state);
+        let route = page
+            .route(scope, move |route| {
+                MockState::handle(Arc::clone(&handler_state), route)
+            })
+            .await?;
+        Ok(MountedMockApi { state, route })
+    }
+
+    /// Registers the mock on `context`, for every page in it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an endpoint has no response, or if the route
+    /// cannot be registered.
+    pub async fn mount_on_context(self, context: &BrowserContext) -> Result<MountedMockApi> {
+        let (scope, state) = self.into_state()?;
+        let handler_state = Arc::clone(&state);
+        let route = context
+            .route(scope, move |route| {
+                MockState::handle(Arc::clone(&handler_state), route)
+            })
+            .await?;
+        Ok(MountedMockApi { state, route })
+    }
+
+    fn into_state(self) -> Result<(UrlMatcher, Arc<MockState>)> {
+        if let Some(endpoint) = self.endpoints.iter().find(|e| e.responses.is_empty()) {
+            return Err(Error::InvalidArgument(format!(
+                "mock endpoint {endpoint} has no response"
+            )));
+        }
+        let endpoints = self
+            .endpoints
+            .into_iter()
+            .map(|endpoint| EndpointState {
+                endpoint,
+                served: AtomicUsize::new(0),
+            })
+            .collect();
+        let state = MockState {
+            endpoints,
+            calls: Mutex::new(Vec::new()),
+            unmatched: Mutex::new(Vec::new()),
+        };
+        Ok((self.scope, Arc::new(state)))
+    }
+}
+
+/// One mocked endpoint: a method, a path template, optional body matchers
+/// and the responses it gives.
+///
+/// The template is matched segment by segment against the request's path,
+/// ignoring the query string. `:name` matches any one segment and records it
+/// in [`MockCall::params`]; `*` matches any one segment. A template that
+/// starts with a scheme (`https://api.example.com/users/:id`) is matched
+/// against the origin as well.
+#[derive(Clone)]
+pub struct MockEndpoint {
+    method: Option<String>,
+    path: String,
+    body: Vec<BodyMatcher>,
+    responses: Vec<MockResponse>,
+}
+
+impl MockEndpoint {
+    /// An endpoint for `method` requests to `path`.
+    pub fn new(method: &str, path: &str) -> Self {
+        Self {
+            method: Some(method.to_ascii_uppercase()),
+            path: path.to_string(),
+            body: Vec::new(),
+            responses: Vec::new(),
+        }
+    }
+
+    /// An endpoint for requests to `path` with any method.
+    pub fn any(path: &str) -> Self {
+        Self {
+            method: None,
+            ..Self::new("", path)
+        }
+    }
+
+    /// A `GET` endpoint.
+    pub fn get(path: &str) -> Self {
+        Self::new("GET", path)
+    }
+
+    /// A `POST` endpoint.
+    pub fn post(path: &str) -> Self {
+        Self::new("POST", path)
+    }
+
+    /// A `PUT` endpoint.
+    pub fn put(path: &str) -> Self {
+        Self::new("PUT", path)
+    }
+
+    /// A `PATCH` endpoint.
+    pub fn patch(path: &str) -> Self {
+        Self::new("PATCH", path)
+    }
+
+    /// A `DELETE` endpoint.
+    pub fn delete(path: &str) -> Self {
+        Self::new("DELETE", path)
+    }
+
+    /// Only matches requests whose body parses as JSON equal to `value`.
+    pub fn body_json(mut self, value: serde_json::Value) -> Self {
+        self.body.push(BodyMatcher::Json(value));
+        self
+    }
+
+    /// Only matches requests whose body contains `needle`.
+    pub fn body_contains(mut self, needle: impl Into<String>) -> Self {
+        self.body.push(BodyMatcher::Contains(needle.into()));
+        self
+    }
+
+    /// Only matches requests whose body `predicate` accepts. A request
+    /// without a body is passed as `""`.
+    pub fn body_matches<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.body.push(BodyMatcher::Predicate(Arc::new(predicate)));
+        self
+    }
+
+    /// The endpoint's response. Replaces any responses set before.
+    pub fn respond(mut self, response: MockResponse) -> Self {
+        self.responses = vec![response];
+        self
+    }
+
+    /// A response for the next call after those already set: calls are
+    /// answered in order, and the last response repeats once the sequence
+    /// runs out.
+    pub fn then(mut self, response: MockResponse) -> Self {
+        self.responses.push(response);
+        self
+    }
+
+    fn matches(&self, method: &str, url: &url::Url, body: &str) -> Option<HashMap<String, String>> {
+        if self.method.as_deref().is_some_and(|m| m != method) {
+            return None;
+        }
+        let params = match_template(&self.path, url)?;
+        self.body
+            .iter()
+            .all(|matcher| matcher.matches(body))
+            .then_some(params)
+    }
+}
+
+impl fmt::Display for MockEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method.as_deref().unwrap_or("*"), self.path)
+    }
+}
+
+impl fmt::Debug for MockEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockEndpoint")
+            .field("method", &self.method)
+            .field("path", &self.path)
+            .field("body", &self.body)
+            .field("responses", &self.responses)
+            .finish()
+    }
+}
+
+#[derive(Clone)]
+enum BodyMatcher {
+    Json(serde_json::Value),
+    Contains(String),
+    Predicate(Arc<dyn Fn(&str) -> bool + Send + Sync>),
+}
+
+impl BodyMatcher {
+    fn matches(&self, body: &str) -> bool {
+        match self {
+            Self::Json(expected) => {
+                serde_json::from_str::<serde_json::Value>(body).is_ok_and(|v| &v == expected)
+            }
+            Self::Contains(needle) => body.contains(needle.as_str()),
+            Self::Predicate(predicate) => predicate(body),
+        }
+    }
+}
+
+impl fmt::Debug for BodyMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(value) => f.debug_tuple("Json").field(value).finish(),
+            Self::Contains(needle) => f.debug_tuple("Contains").field(needle).finish(),
+            Self::Predicate(_) => f.write_str("Predicate(..)"),
+        }
+    }
+}
+
+/// A response a [`MockEndpoint`] gives, fulfilled through
+/// [`Route::fulfill`].
+#[derive(Debug, Clone, Default)]
+pub struct MockResponse {
+    status: Option<u16>,
+    headers: HashMap<String, String>,
+    content_type: Option<String>,
+    body: MockBody,
+    delay: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Default)]
+enum MockBody {
+    #[default]
+    Empty,
+    Bytes(Vec<u8>),
+    File(PathBuf),
+}
+
+impl MockResponse {
+    /// A `200` with no body.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// A `200` with `value` serialized as the JSON body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` cannot be serialized.
+    pub fn json(value: &impl serde::Serialize) -> Result<Self> {
+        let options = FulfillOptions::builder().json(value)?.build();
+        Ok(Self {
+            content_type: options.content_type,
+            body: MockBody::Bytes(options.body.unwrap_or_default()),
+            ..Self::default()
+        })
+    }
+
+    /// A `200` with a `text/plain` body.
+    pub fn text(body: impl Into<String>) -> Self {
+        Self {
+            content_type: Some("text/plain".to_string()),
+            body: MockBody::Bytes(body.into().into_bytes()),
+            ..Self::default()
+        }
+    }
+
+    /// A `200` with `body` as is.
+    pub fn bytes(body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            body: MockBody::Bytes(body.into()),
+            ..Self::default()
+        }
+    }
+
+    /// A `200` with the contents of the file at `path`, read each time the
+    /// response is given. The content type is guessed from the extension.
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        Self {
+            body: MockBody::File(path.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the HTTP status code (default: 200).
+    pub fn status(mut self, status: u16) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Adds a response header.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Sets the Content-Type header.
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    /// Waits `delay` before answering, to exercise loading states and
+    /// timeouts.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    async fn to_fulfill_options(&self) -> Result<FulfillOptions> {
+        let mut builder = FulfillOptions::builder();
+        let mut content_type = self.content_type.clone();
+        match &self.body {
+            MockBody::Empty => {}
+            MockBody::Bytes(bytes) => builder = builder.body(bytes.clone()),
+            MockBody::File(path) => {
+                let bytes = tokio::fs::read(path).await.map_err(|e| {
+                    Error::InvalidPath(format!("mock response file {}: {}", path.display(), e))
+                })?;
+                content_type.get_or_insert_with(|| crate::protocol::mime::from_path(path).into());
+                builder = builder.body(bytes);
+            }
+        }
+        if let Some(status) = self.status {
+            builder = builder.status(status);
+        }
+        if !self.headers.is_empty() {
+            builder = builder.headers(self.headers.clone());
+        }
+        if let Some(content_type) = content_type {
+            builder = builder.content_type(content_type);
+        }
+        Ok(builder.build())
+    }
+}
+
+/// A request a mounted [`MockApi`] answered.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct MockCall {
+    /// The endpoint that answered, as `"GET /api/users/:id"`.
+    pub endpoint: String,
+    /// The request method.
+    pub method: String,
+    /// The full request URL.
+    pub url: String,
+    /// The values the template's `:name` segments matched.
+    pub params: HashMap<String, String>,
+    /// The request body, if it had one.
+    pub body: Option<String>,
+}
+
+impl MockCall {
+    /// Parses the request body as JSON. `None` if there was no body.
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> Option<Result<T>> {
+        self.body
+            .as_deref()
+            .map(|body| serde_json::from_str(body).map_err(Error::from))
+    }
+}
+
+/// A request in a mounted [`MockApi`]'s scope that no endpoint matched.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct UnmatchedRequest {
+    /// The request method.
+    pub method: String,
+    /// The full request URL.
+    pub url: String,
+    /// The request body, if it had one.
+    pub body: Option<String>,
+}
+
+/// A [`MockApi`] registered on a page or context, returned by
+/// [`MockApi::mount`]. Records every request it saw.
+#[derive(Debug, Clone)]
+pub struct MountedMockApi {
+    state: Arc<MockState>,
+    route: RouteHandle,
+}
+
+impl MountedMockApi {
+    /// Every call an endpoint answered, in the order they arrived.
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.state.calls.lock().unwrap().clone()
+    }
+
+    /// The calls answered by the endpoint registered for `method` and
+    /// `path`, the template it was added with.
+    pub fn calls_to(&self, method: &str, path: &str) -> Vec<MockCall> {
+        let endpoint = format!("{} {}", method.to_ascii_uppercase(), path);
+        self.state
+            .calls
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|call| call.endpoint == endpoint)
+            .cloned()
+            .collect()
+    }
+
+    /// Requests in scope that no endpoint matched. They were passed on to
+    /// the next handler or the network.
+    pub fn unmatched(&self) -> Vec<UnmatchedRequest> {
+        self.state.unmatched.lock().unwrap().clone()
+    }
+
+    /// The endpoints no request has reached yet, as `"GET /api/users/:id"`.
+    pub fn uncalled(&self) -> Vec<String> {
+        self.state
+            .endpoints
+            .iter()
+            .filter(|e| e.served.load(Ordering::SeqCst) == 0)
+            .map(|e| e.endpoint.to_string())
+            .collect()
+    }
+
+    /// Unregisters the mock. What it recorded stays readable.
+    pub async fn unmount(&self) -> Result<()> {
+        self.route.unroute().await
+    }
+}
+
+#[derive(Debug)]
+struct MockState {
+    endpoints: Vec<EndpointState>,
+    calls: Mutex<Vec<MockCall>>,
+    unmatched: Mutex<Vec<UnmatchedRequest>>,
+}
+
+#[derive(Debug)]
+struct EndpointState {
+    endpoint: MockEndpoint,
+    served: AtomicUsize,
+}
+
+impl MockState {
+    async fn handle(self: Arc<Self>, route: Route) -> Result<()> {
+        let request = route.request();
+        let method = request.method().to_ascii_uppercase();
+        let url = request.url().to_string();
+        let body = request.post_data();
+        let Ok(parsed) = url::Url::parse(&url) else {
+            return route.fallback(None).await;
+        };
+
+        let matched = self.endpoints.iter().find_map(|e| {
+            e.endpoint
+                .matches(&method, &parsed, body.as_deref().unwrap_or(""))
+                .map(|params| (e, params))
+        });
+        let Some((endpoint, params)) = matched else {
+            self.unmatched
+                .lock()
+                .unwrap()
+                .push(UnmatchedRequest { method, url, body });
+            return route.fallback(None).await;
+        };
+
+        let served = endpoint.served.fetch_add(1, Ordering::SeqCst);
+        let responses = &endpoint.endpoint.responses;
+        let response = &responses[served.min(responses.len() - 1)];
+        self.calls.lock().unwrap().push(MockCall {
+            endpoint: endpoint.endpoint.to_string(),
+            method,
+            url,
+            params,
+            body,
+        });
+
+        if let Some(delay) = response.delay {
+            tokio::time::sleep(delay).await;
+        }
+        match response.to_fulfill_options().await {
+            Ok(options) => route.fulfill(Some(options)).await,
+            Err(e) => {
+                // Leaving the request unanswered would hang the page.
+                tracing::warn!("Mock response failed: {}", e);
+                let options = FulfillOptions::builder()
+                    .status(500)
+                    .body_string(e.to_string())
+                    .build();
+                route.fulfill(Some(options)).await
+            }
+        }
+    }
+}
+
+/// Matches a `/users/:id` template against `url`'s path, or against its
+/// origin and path if the template has a scheme. Returns the `:name`
+/// captures.
+fn match_template(template: &str, url: &url::Url) -> Option<HashMap<String, String>> {
+    let target = if template.contains("://") {
+        format!("{}{}", url.origin().ascii_serialization(), url.path())
+    } else {
+        url.path().to_string()
+    };
+
+    let mut template_segments = template.split('/');
+    let mut target_segments = target.split('/');
+    let mut params = HashMap::new();
+    loop {
+        match (template_segments.next(), target_segments.next()) {
+            (None, None) => return Some(params),
+            (Some(expected), Some(actual)) => {
+                if let Some(name) = expected.strip_prefix(':')
+                    && !name.is_empty()
+                    && !actual.is_empty()
+                {
+                    params.insert(name.to_string(), actual.to_string());
+                } else if !(expected == "*" && !actual.is_empty() || expected == actual) {
+                    return None;
+                }
+            }
+            _ => return None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(s: &str) -> url::Url {
+        url::Url::parse(s).unwrap()
+    }
+
+    #[test]
+    fn template_captures_named_segments() {
+        let params = match_template(
+            "/api/users/:id/posts/:post",
+            &url("https://example.com/api/users/42/posts/7?draft=1"),
+        )
+        .unwrap();
+        assert_eq!(params["id"], "42");
+        assert_eq!(params["post"], "7");
+
+        assert!(match_template("/api/users/:id", &url("https://example.com/api/users/")).is_none());
+        assert!(
+            match_template("/api/users/:id", &url("https://example.com/api/users/1/x")).is_none()
+        );
+        assert!(match_template("/api/*/items", &url("https://example.com/api/v2/items")).is_some());
+    }
+
+    #[test]
+    fn template_with_a_scheme_matches_the_origin() {
+        let template = "https://api.example.com/users/:id";
+        assert!(match_template(template, &url("https://api.example.com/users/1")).is_some());
+        assert!(match_template(template, &url("https://other.example.com/users/1")).is_none());
+    }
+
+    #[test]
+    fn endpoint_checks_method_and_body() {
+        let endpoint = MockEndpoint::post("/login").body_json(serde_json::json!({ "user": "ada" }));
+        let login = url("https://example.com/login");
+        assert!(
+            endpoint
+                .matches("POST", &login, r#"{ "user": "ada" }"#)
+                .is_some()
+        );
+        assert!(
+            endpoint
+                .matches("POST", &login, r#"{ "user": "bob" }"#)
+                .is_none()
+        );
+        assert!(
+            endpoint
+                .matches("GET", &login, r#"{ "user": "ada" }"#)
+                .is_none()
+        );
+        assert!(
+            MockEndpoint::any("/login")
+                .matches("PUT", &login, "")
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn mount_rejects_an_endpoint_without_a_response() {
+        let err = MockApi::new("**/*")
+            .endpoint(MockEndpoint::get("/health"))
+            .into_state()
+            .unwrap_err();
+        assert!(err.to_string().contains("GET /health"), "{err}");
+    }
+}
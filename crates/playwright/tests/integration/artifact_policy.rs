@@ -0,0 +1,116 @@
+// Tests for testing::ArtifactPolicy — keep-or-discard of a test's trace,
+// video and HAR by outcome.
+
+use playwright_rs::protocol::{BrowserContextOptions, RecordVideo};
+use playwright_rs::testing::{ArtifactMode, ArtifactPolicy};
+
+fn output_dir(label: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!(
+        "pw-rust-artifacts-{label}-{}",
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    ))
+}
+
+/// A failing test under retain-on-failure keeps every artifact, at the
+/// deterministic paths.
+#[tokio::test]
+async fn test_retain_on_failure_keeps_artifacts_of_failed_test() {
+    crate::common::init_tracing();
+    let (playwright, browser, _context) = crate::common::setup_context().await;
+
+    let out = output_dir("failed");
+    let video_dir = out.join("raw-videos");
+    let context = browser
+        .new_context_with_options(
+            BrowserContextOptions::builder()
+                .record_video(RecordVideo::new(video_dir.to_str().unwrap()))
+                .build(),
+        )
+        .await
+        .expect("Failed to create context");
+
+    let policy = ArtifactPolicy::new(ArtifactMode::RetainOnFailure).output_dir(&out);
+    let run = policy
+        .run("login: bad password", context, |context| async move {
+            let page = context.new_page().await?;
+            page.goto("data:text/html,<h1>Sign in</h1>", None).await?;
+            Err::<(), _>(anyhow::anyhow!("heading mismatch"))
+        })
+        .await
+        .expect("artifacts should be handled");
+
+    assert!(matches!(run.outcome, Ok(Err(_))));
+    let dir = out.join("login-bad-password");
+    assert_eq!(run.artifacts.dir, dir);
+    assert_eq!(run.artifacts.trace, Some(dir.join("trace.zip")));
+    assert_eq!(run.artifacts.har, Some(dir.join("network.har")));
+    assert_eq!(run.artifacts.videos, [dir.join("video.webm")]);
+    assert!(dir.join("trace.zip").is_file());
+    assert!(dir.join("network.har").is_file());
+    assert!(dir.join("video.webm").is_file());
+
+    let _ = std::fs::remove_dir_all(&out);
+    browser.close().await.expect("Failed to close browser");
+    drop(playwright);
+}
+
+/// A panicking test fails too: the panic is returned with the paths of
+/// the artifacts kept for it instead of being re-raised.
+#[tokio::test]
+async fn test_retain_on_failure_returns_artifacts_of_panicking_test() {
+    crate::common::init_tracing();
+    let (playwright, browser, context) = crate::common::setup_context().await;
+
+    let out = output_dir("panicked");
+    let policy = ArtifactPolicy::new(ArtifactMode::RetainOnFailure).output_dir(&out);
+    let run = policy
+        .run("panics", context, |context| async move {
+            let page = context.new_page().await?;
+            page.goto("data:text/html,<h1>Sign in</h1>", None).await?;
+            assert_eq!(1 + 1, 3, "heading mismatch");
+            Ok::<_, playwright_rs::Error>(())
+        })
+        .await
+        .expect("artifacts should be handled");
+
+    let panic = run.outcome.expect_err("test panicked");
+    let message = panic.downcast_ref::<String>().expect("formatted message");
+    assert!(message.contains("heading mismatch"));
+    let dir = out.join("panics");
+    assert_eq!(run.artifacts.trace, Some(dir.join("trace.zip")));
+    assert!(dir.join("trace.zip").is_file());
+
+    let _ = std::fs::remove_dir_all(&out);
+    browser.close().await.expect("Failed to close browser");
+    drop(playwright);
+}
+
+/// A passing test under retain-on-failure keeps nothing and leaves no
+/// directory behind.
+#[tokio::test]
+async fn test_retain_on_failure_discards_artifacts_of_passing_test() {
+    crate::common::init_tracing();
+    let (playwright, browser, context) = crate::common::setup_context().await;
+
+    let out = output_dir("passed");
+    let policy = ArtifactPolicy::new(ArtifactMode::RetainOnFailure).output_dir(&out);
+    let run = policy
+        .run("passes", context, |context| async move {
+            let page = context.new_page().await?;
+            page.goto("data:text/html,<h1>Ok</h1>", None).await?;
+            Ok::<_, playwright_rs::Error>(42)
+        })
+        .await
+        .expect("artifacts should be handled");
+
+    assert!(run.artifacts.is_empty());
+    assert_eq!(run.into_outcome().expect("test passed"), 42);
+    assert!(!out.join("passes").exists());
+
+    let _ = std::fs::remove_dir_all(&out);
+    browser.close().await.expect("Failed to close browser");
+    drop(playwright);
+}
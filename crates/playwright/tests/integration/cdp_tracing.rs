@@ -496,6 +496,89 @@ async fn test_tracing_multiple_start_stop_cycles() {
     drop(playwright);
 }
 
+#[tokio::test]
+async fn test_tracing_chunks_and_groups() {
+    crate::common::init_tracing();
+
+    let (playwright, browser, context) = crate::common::setup_context().await;
+    let tracing = context
+        .tracing()
+        .await
+        .expect("Failed to get tracing object");
+
+    use playwright_rs::protocol::{
+        TracingStartChunkOptions, TracingStartOptions, TracingStopOptions,
+    };
+    tracing
+        .start(Some(
+            TracingStartOptions::default()
+                .snapshots(true)
+                .sources(true)
+                .title("first test"),
+        ))
+        .await
+        .expect("Failed to start tracing");
+
+    let page = context.new_page().await.expect("Failed to create page");
+    let stamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let mut paths = Vec::new();
+    for (i, title) in ["first test", "second test"].into_iter().enumerate() {
+        if i > 0 {
+            tracing
+                .start_chunk(Some(TracingStartChunkOptions::default().title(title)))
+                .await
+                .expect("Failed to start chunk");
+        }
+
+        tracing
+            .group("log in", None)
+            .await
+            .expect("Failed to open group");
+        page.goto(
+            &format!("data:text/html,<html><body>{title}</body></html>"),
+            None,
+        )
+        .await
+        .expect("Failed to navigate");
+        tracing.group_end().await.expect("Failed to close group");
+
+        let path = std::env::temp_dir().join(format!("pw-rust-chunk-{stamp}-{i}.zip"));
+        tracing
+            .stop_chunk(Some(
+                TracingStopOptions::default().path(path.to_str().unwrap()),
+            ))
+            .await
+            .expect("Failed to stop chunk");
+        paths.push(path);
+    }
+    tracing.stop(None).await.expect("Failed to stop tracing");
+
+    // One trace per chunk, each with its call stacks and this file's source
+    for path in &paths {
+        // Entry names are stored uncompressed in the zip's directory
+        let bytes = std::fs::read(path).expect("chunk trace should be written");
+        let has_entry = |name: &[u8]| bytes.windows(name.len()).any(|w| w == name);
+        assert!(
+            has_entry(b"trace.stacks"),
+            "{} lacks stacks",
+            path.display()
+        );
+        assert!(
+            has_entry(b"resources/src@"),
+            "{} lacks sources",
+            path.display()
+        );
+        let _ = std::fs::remove_file(path);
+    }
+
+    context.close().await.expect("Failed to close context");
+    browser.close().await.expect("Failed to close browser");
+    drop(playwright);
+}
+
 #[tokio::test]
 async fn test_tracing_har_records_network() {
     use playwright_rs::protocol::StartHarOptions;
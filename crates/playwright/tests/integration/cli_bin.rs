@@ -49,6 +49,86 @@ fn install_driver_only_second_invocation_is_idempotent() {
     }
 }
 
+#[test]
+fn codegen_rejects_unknown_browser_before_launching() {
+    let bin = env!("CARGO_BIN_EXE_playwright-rs");
+    let output = Command::new(bin)
+        .args(["codegen", "--browser", "opera", "https://example.com"])
+        .output()
+        .expect("spawn playwright-rs");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("unknown browser `opera`"),
+        "expected an unknown-browser error, got: {stderr}",
+    );
+}
+
+#[test]
+fn pdf_rejects_non_chromium_before_fetching_the_driver() {
+    let bin = env!("CARGO_BIN_EXE_playwright-rs");
+    let output = Command::new(bin)
+        .args([
+            "pdf",
+            "--browser",
+            "firefox",
+            "https://example.com",
+            "out.pdf",
+        ])
+        .output()
+        .expect("spawn playwright-rs");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("`pdf` needs Chromium"),
+        "expected a Chromium-only error, got: {stderr}",
+    );
+}
+
+#[test]
+fn install_dry_run_conflicts_with_driver_only() {
+    let bin = env!("CARGO_BIN_EXE_playwright-rs");
+    let output = Command::new(bin)
+        .args(["install", "--dry-run", "--driver-only"])
+        .output()
+        .expect("spawn playwright-rs");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("cannot be used with"),
+        "expected a conflicting-arguments error, got: {stderr}",
+    );
+}
+
+#[test]
+fn help_lists_the_driver_subcommands() {
+    let bin = env!("CARGO_BIN_EXE_playwright-rs");
+    let output = Command::new(bin)
+        .arg("--help")
+        .output()
+        .expect("spawn playwright-rs");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for cmd in [
+        "install",
+        "uninstall",
+        "show-trace",
+        "open",
+        "screenshot",
+        "pdf",
+        "codegen",
+    ] {
+        assert!(
+            stdout.contains(cmd),
+            "`{cmd}` missing from --help:\n{stdout}"
+        );
+    }
+}
+
 fn contains_cli_js(root: &std::path::Path) -> bool {
     let Ok(entries) = std::fs::read_dir(root) else {
         return false;
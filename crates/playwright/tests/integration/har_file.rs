@@ -0,0 +1,55 @@
+use crate::test_server::TestServer;
+use playwright_rs::har::Har;
+use playwright_rs::protocol::StartHarOptions;
+
+#[tokio::test]
+async fn recorded_har_reads_into_the_typed_model() {
+    let server = TestServer::start().await;
+    let (_pw, browser, context) = crate::common::setup_context().await;
+    let dir = tempfile::tempdir().expect("tempdir");
+    let har_path = dir.path().join("session.har");
+    let tracing = context.tracing().await.expect("tracing");
+    tracing
+        .start_har(har_path.to_str().unwrap(), Some(StartHarOptions::default()))
+        .await
+        .expect("start HAR");
+
+    let page = context.new_page().await.expect("new page");
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+    page.evaluate_expression(
+        "Promise.all([fetch('/api/data.json?n=1'), fetch('/api/data.json?n=2')]).then(() => {})",
+    )
+    .await
+    .expect("fetch");
+    tracing.stop_har().await.expect("stop HAR");
+
+    let mut har = Har::read(&har_path).await.expect("read HAR");
+    let host = playwright_rs::Url::parse(&server.url())
+        .unwrap()
+        .host_str()
+        .unwrap()
+        .to_string();
+    assert_eq!(har.hosts().into_iter().collect::<Vec<_>>(), [host]);
+    let api = format!("GET {}/api/data.json", server.url());
+    assert_eq!(har.call_counts()[&api], 2, "{:?}", har.call_counts());
+
+    let entry = har
+        .log
+        .entries
+        .iter()
+        .find(|e| e.request.url.contains("n=1"))
+        .expect("API entry");
+    assert_eq!(entry.response.status, 200);
+    assert!(entry.timings.total() >= 0.0);
+    assert!(!entry.response.content.body().expect("body").is_empty());
+
+    har.log.entries.retain(|e| e.request.url.contains("/api/"));
+    let copy = dir.path().join("api.har");
+    har.write(&copy).await.expect("write HAR");
+    assert_eq!(Har::read(&copy).await.expect("read copy"), har);
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
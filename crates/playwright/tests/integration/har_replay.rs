@@ -0,0 +1,102 @@
+use crate::test_server::TestServer;
+use playwright_rs::har::{Har, HarNotFound, HarReplayer, Header};
+
+fn har(base: &str) -> Har {
+    serde_json::from_value(serde_json::json!({ "log": { "version": "1.2", "entries": [
+        {
+            "request": { "method": "GET", "url": format!("{base}/api/users?token=abc") },
+            "response": {
+                "status": 200,
+                "headers": [{ "name": "Content-Type", "value": "application/json" }],
+                "content": { "mimeType": "application/json", "text": r#"{"name":"Ada","seen":"{{now}}"}"# }
+            }
+        },
+        {
+            "request": {
+                "method": "POST",
+                "url": format!("{base}/api/orders"),
+                "postData": { "mimeType": "application/json", "text": r#"{"sku":1}"# }
+            },
+            "response": {
+                "status": 201,
+                "content": { "mimeType": "text/plain", "text": "Y3JlYXRlZA==", "encoding": "base64" }
+            }
+        }
+    ]}}))
+    .expect("valid HAR")
+}
+
+#[tokio::test]
+async fn har_replayer_serves_rewritten_entries_and_aborts_misses() {
+    let server = TestServer::start().await;
+    let (_pw, browser, page) = crate::common::setup().await;
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+
+    let route = HarReplayer::from_har(har(&server.url()))
+        .url("**/api/**")
+        .rewrite(|_request, entry| {
+            if let Some(text) = &mut entry.response.content.text {
+                *text = text.replace("{{now}}", "today");
+            }
+            entry.response.headers.push(Header::new("x-replayed", "1"));
+        })
+        .mount(&page)
+        .await
+        .expect("mount");
+
+    let user = page
+        .evaluate_value(
+            "fetch('/api/users?token=abc').then(async r => r.headers.get('x-replayed') + ' ' + await r.text())",
+        )
+        .await
+        .expect("fetch user");
+    assert_eq!(user, r#"1 {"name":"Ada","seen":"today"}"#);
+
+    let order = page
+        .evaluate_value(
+            r#"fetch('/api/orders', { method: 'POST', body: '{"sku":1}' }).then(async r => r.status + ' ' + await r.text())"#,
+        )
+        .await
+        .expect("post order");
+    assert_eq!(order, "201 created");
+
+    let miss = page
+        .evaluate_value(
+            "fetch('/api/orders', { method: 'POST', body: '{}' }).then(() => 'ok', () => 'failed')",
+        )
+        .await
+        .expect("post other order");
+    assert_eq!(miss, "failed", "unmatched requests are aborted");
+    assert_eq!(route.hits(), 3);
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn har_replayer_falls_back_to_the_network() {
+    let server = TestServer::start().await;
+    let (_pw, browser, context) = crate::common::setup_context().await;
+
+    HarReplayer::from_har(har(&server.url()))
+        .url("**/api/**")
+        .not_found(HarNotFound::Fallback)
+        .mount_on_context(&context)
+        .await
+        .expect("mount");
+
+    let page = context.new_page().await.expect("new page");
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+    let status = page
+        .evaluate_value("fetch('/api/data.json').then(r => r.status)")
+        .await
+        .expect("fetch");
+    assert_eq!(status, "200", "the test server answered the miss");
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
@@ -0,0 +1,121 @@
+use crate::test_server::TestServer;
+use playwright_rs::protocol::RouteFromHarOptions;
+
+fn entries(har_path: &std::path::Path) -> Vec<serde_json::Value> {
+    let har: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(har_path).expect("read HAR"))
+            .expect("parse HAR");
+    har["log"]["entries"].as_array().expect("entries").clone()
+}
+
+#[tokio::test]
+async fn route_from_har_update_records_redacted_har_on_close() {
+    let server = TestServer::start().await;
+    let (_pw, browser, context) = crate::common::setup_context().await;
+    let dir = tempfile::tempdir().expect("tempdir");
+    let har_path = dir.path().join("api.har");
+
+    let options = RouteFromHarOptions::default()
+        .update(true)
+        .url("**/api/**")
+        .redact_headers(["authorization"]);
+    context
+        .route_from_har(har_path.to_str().unwrap(), options)
+        .await
+        .expect("route_from_har");
+    assert!(!har_path.exists(), "the HAR is written on close");
+
+    let page = context.new_page().await.expect("new page");
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+    page.evaluate_expression(
+        "fetch('/api/data.json', { headers: { Authorization: 'Bearer secret' } }).then(r => r.text())",
+    )
+    .await
+    .expect("fetch");
+
+    context.close().await.expect("close context");
+
+    let entries = entries(&har_path);
+    assert_eq!(
+        entries.len(),
+        1,
+        "only the URL filter's requests: {entries:?}"
+    );
+    let request = &entries[0]["request"];
+    assert!(request["url"].as_str().unwrap().ends_with("/api/data.json"));
+    let authorization = request["headers"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|h| {
+            h["name"]
+                .as_str()
+                .unwrap()
+                .eq_ignore_ascii_case("authorization")
+        })
+        .expect("authorization header");
+    assert_eq!(authorization["value"], "[REDACTED]");
+    assert!(std::fs::read_to_string(&har_path).unwrap().ends_with("}\n"));
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn route_from_har_merge_replays_existing_and_records_missing() {
+    let server = TestServer::start().await;
+    let (_pw, browser, context) = crate::common::setup_context().await;
+    let dir = tempfile::tempdir().expect("tempdir");
+    let har_path = dir.path().join("api.har");
+    let options = || {
+        RouteFromHarOptions::default()
+            .update(true)
+            .merge(true)
+            .url("**/api/**")
+    };
+
+    // First run records /api/data.json
+    context
+        .route_from_har(har_path.to_str().unwrap(), options())
+        .await
+        .expect("route_from_har");
+    let page = context.new_page().await.expect("new page");
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+    page.evaluate_expression("fetch('/api/data.json').then(r => r.text())")
+        .await
+        .expect("fetch");
+    context.close().await.expect("close context");
+    assert_eq!(entries(&har_path).len(), 1);
+
+    // Second run replays it and records only the POST it lacks
+    let context = browser.new_context().await.expect("new context");
+    context
+        .route_from_har(har_path.to_str().unwrap(), options())
+        .await
+        .expect("route_from_har");
+    let page = context.new_page().await.expect("new page");
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+    page.evaluate_expression(
+        "Promise.all([fetch('/api/data.json'), fetch('/api/echo', { method: 'POST', body: 'hi' })])",
+    )
+    .await
+    .expect("fetch");
+    context.close().await.expect("close context");
+
+    let entries = entries(&har_path);
+    let methods: Vec<&str> = entries
+        .iter()
+        .map(|e| e["request"]["method"].as_str().unwrap())
+        .collect();
+    assert_eq!(methods.len(), 2, "data.json is not duplicated: {entries:?}");
+    assert!(methods.contains(&"GET") && methods.contains(&"POST"));
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
@@ -0,0 +1,87 @@
+// Tests for loc! — typed locator chains expanding to get_by_* / filter /
+// nth / and_ / or_ calls.
+
+use playwright_rs::{AriaRole, FilterOptions, GetByRoleOptions, loc};
+
+const DRAFTS_HTML: &str = r#"
+    <table>
+      <tr><td><input type="checkbox" aria-label="Pick"></td><td>report</td><td><button>Save</button></td></tr>
+      <tr><td><input type="checkbox" aria-label="Pick" checked></td><td>draft one</td><td><button>Save</button></td></tr>
+      <tr><td><input type="checkbox" aria-label="Pick"></td><td>draft two</td><td><button>Save</button></td></tr>
+    </table>
+    <label>Email <input placeholder="you@example.com"></label>
+    <a href="/docs" title="Docs">Docs</a>
+"#;
+
+#[tokio::test]
+async fn test_loc_expands_to_the_equivalent_calls() -> playwright_rs::Result<()> {
+    crate::common::init_tracing();
+    let (_pw, browser, page) = crate::common::setup().await;
+
+    let typed = loc!(
+        page,
+        role(button, name = "Save") >> has_text("draft") >> nth(0)
+    );
+    let manual = page
+        .get_by_role(
+            AriaRole::Button,
+            Some(GetByRoleOptions::default().name("Save")),
+        )
+        .filter(FilterOptions::default().has_text("draft"))
+        .nth(0);
+    assert_eq!(typed.selector(), manual.selector());
+
+    let typed = loc!(&page, "tr" >> has(role(checkbox, checked = true)) >> last);
+    let manual = page
+        .locator("tr")
+        .filter(FilterOptions::default().has(page.get_by_role(
+            AriaRole::Checkbox,
+            Some(GetByRoleOptions::default().checked(true)),
+        )))
+        .last();
+    assert_eq!(typed.selector(), manual.selector());
+
+    let typed = loc!(
+        page,
+        text("Docs", exact = true) >> or(title("Docs")) >> first
+    );
+    let manual = page
+        .get_by_text("Docs", true)
+        .or_(&page.get_by_title("Docs", false))
+        .first();
+    assert_eq!(typed.selector(), manual.selector());
+
+    browser.close().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_loc_finds_elements() -> playwright_rs::Result<()> {
+    crate::common::init_tracing();
+    let (_pw, browser, page) = crate::common::setup().await;
+    page.set_content(DRAFTS_HTML, None).await?;
+
+    let rows = loc!(page, "tr" >> has_text("draft"));
+    assert_eq!(rows.count().await?, 2);
+
+    let unchecked = loc!(
+        page,
+        "tr" >> has_text("draft") >> has_not(role(checkbox, checked = true))
+    );
+    assert_eq!(unchecked.count().await?, 1);
+    assert!(
+        unchecked
+            .text_content()
+            .await?
+            .is_some_and(|text| text.contains("draft two"))
+    );
+
+    let save = loc!(rows, role(button, name = "Save") >> last);
+    save.click(None).await?;
+
+    let email = loc!(page, label("Email") >> and(placeholder("you@example.com")));
+    assert_eq!(email.count().await?, 1);
+
+    browser.close().await?;
+    Ok(())
+}
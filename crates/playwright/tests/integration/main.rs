@@ -12,6 +12,7 @@ mod test_server;
 mod actions;
 mod api_request;
 mod aria_snapshot;
+mod artifact_policy;
 mod assertions;
 mod back_references;
 mod browser;
@@ -39,37 +40,51 @@ mod fake_file_system;
 mod file_chooser;
 mod frame_api;
 mod frame_locator;
+mod har_file;
+mod har_replay;
+mod har_update;
 mod initialization;
 mod install_browsers;
 mod js_handle;
 mod keyboard_mouse;
 mod launch_context;
+mod loc_macro;
 mod locator;
+mod mock_api;
 mod navigation;
+mod network_conditions;
+mod network_recorder;
 mod network_route;
 mod page;
 mod page_assertions;
 mod page_event_network;
 mod page_events;
 mod page_new_methods;
+mod page_object;
 mod page_properties;
 mod pause;
 mod playwright_launch;
+mod recorder;
 mod request;
+mod request_blocking;
 mod request_response_complete;
 mod response;
 mod route_advanced;
 mod route_fallback_unroute;
 mod route_fetch;
+mod route_handle;
 mod screencast;
 mod screenshot;
 mod scripts_styles;
 mod select_upload;
 mod selectors;
+mod shared_browser;
 mod stability;
 mod storage_state;
+mod test_attribute;
 mod tracing_emission;
 mod transport;
+mod url_matcher;
 mod wait_for_function;
 mod web_error;
 mod web_storage;
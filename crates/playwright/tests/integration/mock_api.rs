@@ -0,0 +1,111 @@
+use crate::test_server::TestServer;
+use playwright_rs::testing::{MockApi, MockEndpoint, MockResponse};
+use serde_json::json;
+
+#[tokio::test]
+async fn mock_api_answers_endpoints_and_records_calls() {
+    let server = TestServer::start().await;
+    let (_pw, browser, page) = crate::common::setup().await;
+
+    let api = MockApi::new("**/mock/**")
+        .get(
+            "/mock/users/:id",
+            MockResponse::json(&json!({ "name": "Ada" })).unwrap(),
+        )
+        .endpoint(
+            MockEndpoint::post("/mock/orders")
+                .body_json(json!({ "sku": "A1" }))
+                .respond(MockResponse::empty().status(201))
+                .then(MockResponse::text("sold out").status(409)),
+        )
+        .delete("/mock/orders/:id", MockResponse::empty().status(204))
+        .mount(&page)
+        .await
+        .expect("mount");
+
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+
+    let user: serde_json::Value = page
+        .evaluate(
+            "() => fetch('/mock/users/42').then(r => r.json())",
+            None::<&()>,
+        )
+        .await
+        .expect("fetch user");
+    assert_eq!(user, json!({ "name": "Ada" }));
+
+    let statuses: Vec<u16> = page
+        .evaluate(
+            r#"async () => {
+                const order = () => fetch('/mock/orders', {
+                    method: 'POST',
+                    body: JSON.stringify({ sku: 'A1' }),
+                }).then(r => r.status);
+                return [await order(), await order(), await order()];
+            }"#,
+            None::<&()>,
+        )
+        .await
+        .expect("post orders");
+    assert_eq!(statuses, vec![201, 409, 409]);
+
+    let unmatched: u16 = page
+        .evaluate(
+            "() => fetch('/mock/unknown').then(r => r.status)",
+            None::<&()>,
+        )
+        .await
+        .expect("fetch unmatched");
+    assert_eq!(unmatched, 404, "unmatched requests reach the server");
+
+    let lookups = api.calls_to("GET", "/mock/users/:id");
+    assert_eq!(lookups.len(), 1);
+    assert_eq!(lookups[0].params["id"], "42");
+    let orders = api.calls_to("POST", "/mock/orders");
+    assert_eq!(orders.len(), 3);
+    assert_eq!(
+        orders[0].json::<serde_json::Value>().unwrap().unwrap(),
+        json!({ "sku": "A1" })
+    );
+
+    let unmatched = api.unmatched();
+    assert_eq!(unmatched.len(), 1);
+    assert!(unmatched[0].url.ends_with("/mock/unknown"));
+    assert_eq!(api.uncalled(), vec!["DELETE /mock/orders/:id".to_string()]);
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn mock_api_on_context_serves_every_page_until_unmounted() {
+    let server = TestServer::start().await;
+    let (_pw, browser, page) = crate::common::setup().await;
+    let context = page.context().expect("context");
+
+    let api = MockApi::new("**/api/data.json")
+        .get(
+            "/api/data.json",
+            MockResponse::json(&json!({ "mocked": true })).unwrap(),
+        )
+        .mount_on_context(&context)
+        .await
+        .expect("mount");
+
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+    let fetch = "() => fetch('/api/data.json').then(r => r.json())";
+    let body: serde_json::Value = page.evaluate(fetch, None::<&()>).await.expect("fetch");
+    assert_eq!(body, json!({ "mocked": true }));
+
+    api.unmount().await.expect("unmount");
+    let body: serde_json::Value = page.evaluate(fetch, None::<&()>).await.expect("fetch");
+    assert_ne!(body, json!({ "mocked": true }));
+    assert_eq!(api.calls().len(), 1);
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
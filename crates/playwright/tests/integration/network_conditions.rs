@@ -0,0 +1,149 @@
+use crate::test_server::TestServer;
+use playwright_rs::{NetworkConditions, NetworkPreset};
+use std::time::Duration;
+
+const TIMED_FETCH: &str = r#"async () => {
+    const start = performance.now();
+    await fetch('/api/data.json?' + Math.random()).then(r => r.text());
+    return performance.now() - start;
+}"#;
+
+#[tokio::test]
+async fn set_network_conditions_adds_latency_until_cleared() {
+    let server = TestServer::start().await;
+    let (_pw, browser, page) = crate::common::setup().await;
+    let context = page.context().expect("context");
+
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+
+    context
+        .set_network_conditions(NetworkConditions::default().latency(Duration::from_millis(600)))
+        .await
+        .expect("set conditions");
+    let throttled: f64 = page
+        .evaluate(TIMED_FETCH, None::<&()>)
+        .await
+        .expect("fetch");
+    assert!(throttled >= 500.0, "fetch took {throttled} ms");
+
+    context
+        .clear_network_conditions()
+        .await
+        .expect("clear conditions");
+    let unthrottled: f64 = page
+        .evaluate(TIMED_FETCH, None::<&()>)
+        .await
+        .expect("fetch");
+    assert!(unthrottled < 500.0, "fetch took {unthrottled} ms");
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn set_network_conditions_applies_to_pages_opened_later() {
+    let server = TestServer::start().await;
+    let (_pw, browser, page) = crate::common::setup().await;
+    let context = page.context().expect("context");
+
+    context
+        .set_network_conditions(NetworkPreset::Fast3G)
+        .await
+        .expect("set conditions");
+
+    let later = context.new_page().await.expect("new page");
+    later
+        .goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+    let elapsed: f64 = later
+        .evaluate(TIMED_FETCH, None::<&()>)
+        .await
+        .expect("fetch");
+    assert!(elapsed >= 500.0, "fetch took {elapsed} ms");
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn set_network_conditions_adds_latency_with_a_route_outside_chromium() {
+    crate::common::init_tracing();
+    let server = TestServer::start().await;
+    let playwright = playwright_rs::Playwright::launch()
+        .await
+        .expect("launch Playwright");
+    let browser = playwright.firefox().launch().await.expect("launch Firefox");
+    let context = browser.new_context().await.expect("new context");
+    let page = context.new_page().await.expect("new page");
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+
+    context
+        .set_network_conditions(NetworkConditions::default().latency(Duration::from_millis(600)))
+        .await
+        .expect("set conditions");
+    let throttled: f64 = page
+        .evaluate(TIMED_FETCH, None::<&()>)
+        .await
+        .expect("fetch");
+    assert!(throttled >= 500.0, "fetch took {throttled} ms");
+
+    context
+        .clear_network_conditions()
+        .await
+        .expect("clear conditions");
+    let unthrottled: f64 = page
+        .evaluate(TIMED_FETCH, None::<&()>)
+        .await
+        .expect("fetch");
+    assert!(unthrottled < 500.0, "fetch took {unthrottled} ms");
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn set_network_conditions_throttles_chromium_persistent_contexts() {
+    crate::common::init_tracing();
+    let server = TestServer::start().await;
+    let user_data_dir = tempfile::TempDir::new().expect("temp dir");
+    let playwright = playwright_rs::Playwright::launch()
+        .await
+        .expect("launch Playwright");
+    let context = playwright
+        .chromium()
+        .launch_persistent_context(user_data_dir.path().to_str().unwrap())
+        .await
+        .expect("launch persistent context");
+    let page = context.new_page().await.expect("new page");
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+
+    // Throughput is only honoured through CDP; the latency route fallback
+    // would leave this 20 kB echo unthrottled.
+    context
+        .set_network_conditions(NetworkConditions::default().download_throughput(10_000))
+        .await
+        .expect("set conditions");
+    let elapsed: f64 = page
+        .evaluate(
+            r#"async () => {
+                const start = performance.now();
+                await fetch('/api/echo', { method: 'POST', body: 'x'.repeat(20000) })
+                    .then(r => r.text());
+                return performance.now() - start;
+            }"#,
+            None::<&()>,
+        )
+        .await
+        .expect("fetch");
+    assert!(elapsed >= 1000.0, "fetch took {elapsed} ms");
+
+    context.close().await.expect("close");
+    server.shutdown();
+}
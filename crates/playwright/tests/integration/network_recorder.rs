@@ -0,0 +1,98 @@
+use crate::test_server::TestServer;
+use playwright_rs::{NetworkEntryState, NetworkRecorder};
+use std::time::Duration;
+
+#[tokio::test]
+async fn recorder_pairs_redirects_failures_and_exports() {
+    let server = TestServer::start().await;
+    let (_pw, browser, page) = crate::common::setup().await;
+
+    page.route("**/offline", |route| async move {
+        route.abort(Some("internetdisconnected")).await
+    })
+    .await
+    .expect("route");
+    let recorder = NetworkRecorder::start(&page).await.expect("start recorder");
+
+    page.goto(&format!("{}/redirect", server.url()), None)
+        .await
+        .expect("navigate");
+    let fetched = page
+        .evaluate_value(
+            "Promise.all([fetch('/api/data.json').then(r => r.status), \
+             fetch('/offline').then(() => 'ok', () => 'failed')]).then(r => r.join(' '))",
+        )
+        .await
+        .expect("evaluate");
+    assert_eq!(fetched, "200 failed");
+    recorder
+        .wait_for_network_idle(Duration::from_millis(200))
+        .await
+        .expect("network idle");
+
+    let documents = recorder.entries_of_type("document");
+    let last = documents.last().expect("document requests recorded");
+    let chain = recorder.redirect_chain(last);
+    let statuses: Vec<_> = chain.iter().map(|e| e.status).collect();
+    assert_eq!(statuses, [Some(302), Some(200)]);
+    assert!(chain[0].url.ends_with("/redirect"));
+    assert_eq!(chain[1].redirected_from, Some(chain[0].index));
+
+    let api = recorder.entries_matching("**/api/data.json");
+    assert_eq!(api.len(), 1);
+    assert_eq!(api[0].state, NetworkEntryState::Finished);
+    assert!(api[0].timing.is_some(), "finished requests carry timing");
+    assert!(
+        api[0]
+            .sizes
+            .as_ref()
+            .is_some_and(|s| s.response_body_size > 0)
+    );
+    assert_eq!(
+        api[0].frame_url.as_deref(),
+        Some(&*format!("{}/", server.url()))
+    );
+
+    let failed = recorder.failed();
+    assert_eq!(failed.len(), 1);
+    assert!(failed[0].url.ends_with("/offline"));
+    assert!(failed[0].failure.is_some());
+
+    let har = recorder.to_har();
+    assert_eq!(har.log.entries.len(), recorder.entries().len());
+    let json: serde_json::Value =
+        serde_json::from_str(&recorder.to_json().expect("json")).expect("valid JSON");
+    assert_eq!(json.as_array().map(Vec::len), Some(har.log.entries.len()));
+
+    browser.close().await.expect("close browser");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn context_recorder_sees_later_pages_until_stopped() {
+    let server = TestServer::start().await;
+    let (_pw, browser, context) = crate::common::setup_context().await;
+
+    let recorder = NetworkRecorder::start_on_context(&context)
+        .await
+        .expect("start recorder");
+    let page = context.new_page().await.expect("new page");
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+    recorder
+        .wait_for_network_idle(Duration::from_millis(100))
+        .await
+        .expect("network idle");
+    let seen = recorder.entries().len();
+    assert!(seen >= 1);
+
+    recorder.stop();
+    page.goto(&format!("{}/api/data.json", server.url()), None)
+        .await
+        .expect("navigate");
+    assert_eq!(recorder.entries().len(), seen);
+
+    browser.close().await.expect("close browser");
+    server.shutdown();
+}
@@ -0,0 +1,108 @@
+// Tests for #[derive(PageObject)] — structs of locators built from a
+// page, a frame or a parent locator.
+
+use playwright_rs::{Locator, PageObject};
+
+const TODO_HTML: &str = r#"
+    <h1>Todos</h1>
+    <input aria-label="New todo">
+    <ul>
+      <li data-testid="todo"><input type="checkbox"><span class="title">milk</span></li>
+      <li data-testid="todo"><input type="checkbox" checked><span class="title">eggs</span></li>
+    </ul>
+    <footer><button>Clear completed</button><button>Clear all</button></footer>
+"#;
+
+#[derive(PageObject)]
+struct TodoItem {
+    #[role(checkbox)]
+    toggle: Locator,
+    #[locator(".title")]
+    title: Locator,
+}
+
+#[derive(PageObject)]
+struct Footer {
+    #[role(button, name = "Clear completed", exact = true)]
+    clear_completed: Locator,
+    #[locator("button")]
+    buttons: Vec<Locator>,
+}
+
+#[derive(PageObject)]
+struct TodoPage {
+    #[role(heading, level = 1)]
+    heading: Locator,
+    #[role(textbox, name = "New todo")]
+    new_todo: Locator,
+    #[test_id("todo")]
+    items: Vec<TodoItem>,
+    #[locator("footer")]
+    footer: Footer,
+    /// No attribute: scoped to the same root as the page itself.
+    shortcuts: Shortcuts,
+}
+
+#[derive(PageObject)]
+struct Shortcuts {
+    #[locator("ul")]
+    list: Locator,
+}
+
+#[tokio::test]
+async fn test_page_object_from_page() -> playwright_rs::Result<()> {
+    crate::common::init_tracing();
+    let (_pw, browser, page) = crate::common::setup().await;
+    page.set_content(TODO_HTML, None).await?;
+
+    let todos = TodoPage::locate(&page).await?;
+    assert_eq!(
+        todos.heading.text_content().await?.as_deref(),
+        Some("Todos")
+    );
+    todos.new_todo.fill("bread", None).await?;
+    assert_eq!(todos.new_todo.input_value(None).await?, "bread");
+
+    assert_eq!(todos.items.len(), 2);
+    assert_eq!(
+        todos.items[1].title.text_content().await?.as_deref(),
+        Some("eggs")
+    );
+    assert!(!todos.items[0].toggle.is_checked().await?);
+    assert!(todos.items[1].toggle.is_checked().await?);
+
+    assert_eq!(todos.footer.buttons.len(), 2);
+    assert_eq!(
+        todos
+            .footer
+            .clear_completed
+            .text_content()
+            .await?
+            .as_deref(),
+        Some("Clear completed")
+    );
+    assert_eq!(todos.shortcuts.list.count().await?, 1);
+
+    browser.close().await?;
+    Ok(())
+}
+
+/// The same component type works from a parent locator, and fields are
+/// scoped to it.
+#[tokio::test]
+async fn test_page_object_from_locator() -> playwright_rs::Result<()> {
+    crate::common::init_tracing();
+    let (_pw, browser, page) = crate::common::setup().await;
+    page.set_content(TODO_HTML, None).await?;
+
+    let second = page.locator("li").nth(1);
+    let item = TodoItem::locate(&second).await?;
+    assert_eq!(item.title.text_content().await?.as_deref(), Some("eggs"));
+    assert!(item.toggle.is_checked().await?);
+
+    let footer = Footer::locate(&page.main_frame().await?).await?;
+    assert_eq!(footer.buttons.len(), 2);
+
+    browser.close().await?;
+    Ok(())
+}
@@ -0,0 +1,63 @@
+// Tests for BrowserContext::enable_recorder / on_recorder_event — the
+// action stream `playwright-rs codegen` turns into Rust.
+
+use playwright_rs::codegen::RustGenerator;
+use playwright_rs::protocol::Action;
+use playwright_rs::{RecorderEvent, RecorderOptions};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_recorder_reports_opened_pages_and_navigations() -> playwright_rs::Result<()> {
+    crate::common::init_tracing();
+    let (_pw, browser, context) = crate::common::setup_context().await;
+
+    let events: Arc<Mutex<Vec<RecorderEvent>>> = Arc::default();
+    let sink = events.clone();
+    context
+        .on_recorder_event(move |event| {
+            sink.lock().unwrap().push(event);
+            async { Ok(()) }
+        })
+        .await?;
+    context
+        .enable_recorder(Some(RecorderOptions::default().mode("recording")))
+        .await?;
+
+    let page = context.new_page().await?;
+    page.goto("data:text/html,<button>Save</button>", None)
+        .await?;
+
+    let navigated = || {
+        events.lock().unwrap().iter().any(|event| {
+            matches!(&event.action().action, Action::Navigate { url, .. } if url.starts_with("data:"))
+        })
+    };
+    for _ in 0..50 {
+        if navigated() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(
+        navigated(),
+        "no navigate action in {:?}",
+        events.lock().unwrap()
+    );
+
+    let mut generator = RustGenerator::new();
+    for event in events.lock().unwrap().drain(..) {
+        assert_eq!(event.action().frame.page_alias, "page");
+        generator.record(event);
+    }
+    assert!(
+        generator
+            .source()
+            .contains("page.goto(\"data:text/html,<button>Save</button>\", None)"),
+        "{}",
+        generator.source()
+    );
+
+    browser.close().await?;
+    Ok(())
+}
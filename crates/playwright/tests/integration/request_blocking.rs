@@ -0,0 +1,76 @@
+use crate::test_server::TestServer;
+use playwright_rs::BlockReason;
+use playwright_rs::protocol::BrowserContextOptions;
+
+#[tokio::test]
+async fn blocking_options_abort_before_route_handlers() {
+    let server = TestServer::start().await;
+    let (_pw, browser, _page) = crate::common::setup().await;
+    let host = playwright_rs::Url::parse(&server.url())
+        .unwrap()
+        .host_str()
+        .unwrap()
+        .to_string();
+    let options = BrowserContextOptions::builder()
+        .allowed_hosts([host])
+        .blocked_urls(["**/api/data.json"])
+        .blocked_resource_types(["image"])
+        .build();
+    let context = browser
+        .new_context_with_options(options)
+        .await
+        .expect("new context");
+
+    // A catch-all handler must not get to serve blocked requests.
+    context
+        .route("**/*", |route| async move { route.continue_(None).await })
+        .await
+        .expect("route");
+    let page = context.new_page().await.expect("new page");
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+
+    let outcome = |script: &'static str| {
+        let page = page.clone();
+        async move { page.evaluate_value(script).await.expect("evaluate") }
+    };
+    assert_eq!(
+        outcome("fetch('/api/data.json').then(() => 'ok', () => 'failed')").await,
+        "failed"
+    );
+    assert_eq!(
+        outcome("fetch('http://tracker.invalid/collect').then(() => 'ok', () => 'failed')").await,
+        "failed"
+    );
+    assert_eq!(
+        outcome(
+            "new Promise(r => { const i = new Image(); i.onload = () => r('ok'); i.onerror = () => r('failed'); i.src = '/logo.png'; })"
+        )
+        .await,
+        "failed"
+    );
+
+    let blocked = context.blocked_requests();
+    let reasons: Vec<&BlockReason> = blocked.iter().map(|b| &b.reason).collect();
+    assert_eq!(
+        reasons,
+        [
+            &BlockReason::Url("**/api/data.json".to_string()),
+            &BlockReason::Host("tracker.invalid".to_string()),
+            &BlockReason::ResourceType("image".to_string()),
+        ],
+        "{blocked:?}"
+    );
+
+    // unroute_all leaves the blocking options in place.
+    context.unroute_all(None).await.expect("unroute_all");
+    assert_eq!(
+        outcome("fetch('/api/data.json').then(() => 'ok', () => 'failed')").await,
+        "failed"
+    );
+    assert_eq!(context.blocked_requests().len(), 4);
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
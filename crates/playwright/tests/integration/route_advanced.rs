@@ -185,25 +185,17 @@ async fn test_route_continue_no_overrides() {
 // route.fulfill() with main document navigation
 // ============================================================================
 //
-// IMPORTANT: These tests document a KNOWN PLAYWRIGHT SERVER LIMITATION (1.49.0 - 1.62.1):
-// route.fulfill() does not transmit response body content to the browser.
-//
-// These are "reverse canary tests" — they expect the BROKEN behavior. When
-// Playwright fixes this, these tests will FAIL, alerting us to update our code.
-// That failure is the notification, so the version range above just needs
-// extending to whatever driver is bundled while they keep passing.
-
-/// Test: route.fulfill() body content is NOT transmitted (Playwright limitation)
-///
-/// This test documents that Playwright 1.49.0-1.62.1 doesn't transmit fulfilled
-/// response bodies to the browser. When this test fails, it means Playwright has
-/// fixed the issue and we should update our documentation.
+// These once asserted an empty body and a 200 status as a "Playwright
+// limitation". The cause was on our side: fulfill() nested its parameters
+// under a `response` key the server ignores. They now pin the fixed behavior.
+
+/// Test: route.fulfill() serves the body for a main document navigation
 #[tokio::test]
 async fn test_route_fulfill_main_document() {
     let server = TestServer::start().await;
     let (_pw, browser, page) = crate::common::setup().await;
 
-    // Custom HTML that SHOULD be returned but won't be due to Playwright bug
+    // Custom HTML served in place of the real page
     let custom_html = r#"<!DOCTYPE html>
 <html>
 <head><title>Fulfilled Page</title></head>
@@ -221,7 +213,7 @@ async fn test_route_fulfill_main_document() {
         let custom_html = custom_html.to_string();
         async move {
             if is_main_doc {
-                // Attempt to fulfill with custom HTML (body won't be transmitted)
+                // Fulfill with custom HTML
                 let options = FulfillOptions::builder()
                     .status(200)
                     .body_string(custom_html)
@@ -245,42 +237,27 @@ async fn test_route_fulfill_main_document() {
         .expect("Failed to navigate")
         .expect("Expected a response");
 
-    // Status code DOES work correctly
     assert_eq!(response.status(), 200, "Status code is correctly fulfilled");
 
-    // KNOWN ISSUE: Body content is NOT transmitted
-    // We expect empty title due to Playwright server limitation
-    // REVERSE CANARY: When this assertion fails with "Fulfilled Page",
-    // Playwright has fixed the body transmission issue!
     let page_title = page
         .evaluate_value("document.title")
         .await
         .expect("Failed to get title");
 
-    assert_eq!(
-        page_title, "",
-        "REVERSE CANARY: Expected empty (bug), got '{}'. If 'Fulfilled Page', Playwright fixed the issue!",
-        page_title
-    );
+    assert_eq!(page_title, "Fulfilled Page", "Fulfilled body is rendered");
 
-    // Content element won't exist due to empty body
     let content_exists = page
         .evaluate_value("document.getElementById('content') !== null")
         .await
         .expect("Failed to check content");
 
-    assert_eq!(
-        content_exists, "false",
-        "REVERSE CANARY: Content should not exist due to Playwright limitation"
-    );
+    assert_eq!(content_exists, "true", "Fulfilled content is in the DOM");
 
     browser.close().await.expect("Failed to close browser");
     server.shutdown();
 }
 
-/// Test: route.fulfill() status codes work, but body doesn't
-///
-/// Verify that status codes are correctly transmitted even though body isn't.
+/// Test: route.fulfill() serves a non-200 status for a main document
 #[tokio::test]
 async fn test_route_fulfill_main_document_with_status() {
     let server = TestServer::start().await;
@@ -317,32 +294,20 @@ async fn test_route_fulfill_main_document_with_status() {
         .expect("Failed to navigate")
         .expect("Expected a response");
 
-    // KNOWN ISSUE: Even status codes don't work for main document in some cases
-    // We expect 200 instead of 404 due to Playwright limitation with main documents
-    assert_eq!(
-        response.status(),
-        200,
-        "REVERSE CANARY: Should be 404 when Playwright fixes main document fulfillment"
-    );
+    assert_eq!(response.status(), 404, "Fulfilled status is reported");
 
-    // Body content does NOT work - h1 element won't exist
     let has_h1 = page
         .evaluate_value("document.querySelector('h1') !== null")
         .await
         .expect("Failed to check h1");
 
-    assert_eq!(
-        has_h1, "false",
-        "REVERSE CANARY: h1 should not exist due to Playwright limitation"
-    );
+    assert_eq!(has_h1, "true", "Fulfilled body is rendered");
 
     browser.close().await.expect("Failed to close browser");
     server.shutdown();
 }
 
-/// Test: route.fulfill() body limitation in Firefox
-///
-/// Cross-browser test: document that Firefox also has the body transmission issue.
+/// Test: route.fulfill() serves the body in Firefox
 #[tokio::test]
 #[ignore]
 async fn test_route_fulfill_main_document_firefox() {
@@ -395,25 +360,18 @@ async fn test_route_fulfill_main_document_firefox() {
 
     assert_eq!(response.status(), 200, "Status works in Firefox");
 
-    // Body content not transmitted in Firefox either
     let title = page
         .evaluate_value("document.title")
         .await
         .expect("Failed to get title");
 
-    assert_eq!(
-        title, "",
-        "REVERSE CANARY: Firefox also has empty body. Got '{}', expecting 'Firefox Fulfilled' when fixed",
-        title
-    );
+    assert_eq!(title, "Firefox Fulfilled", "Fulfilled body is rendered");
 
     browser.close().await.expect("Failed to close browser");
     server.shutdown();
 }
 
-/// Test: route.fulfill() body limitation in WebKit
-///
-/// Cross-browser test: document that WebKit also has the body transmission issue.
+/// Test: route.fulfill() serves the body in WebKit
 #[tokio::test]
 #[ignore]
 async fn test_route_fulfill_main_document_webkit() {
@@ -466,27 +424,18 @@ async fn test_route_fulfill_main_document_webkit() {
 
     assert_eq!(response.status(), 200, "Status works in WebKit");
 
-    // Body content not transmitted in WebKit either
     let title = page
         .evaluate_value("document.title")
         .await
         .expect("Failed to get title");
 
-    assert_eq!(
-        title, "",
-        "REVERSE CANARY: WebKit also has empty body. Got '{}', expecting 'WebKit Fulfilled' when fixed",
-        title
-    );
+    assert_eq!(title, "WebKit Fulfilled", "Fulfilled body is rendered");
 
     browser.close().await.expect("Failed to close browser");
     server.shutdown();
 }
 
-/// Test: route.fulfill() status and headers work for fetch (partial functionality)
-///
-/// This test verifies that status and headers ARE transmitted correctly for
-/// fetch requests, even though body content is not. This helps document exactly
-/// what works and what doesn't in the current Playwright version.
+/// Test: route.fulfill() serves status and JSON body to fetch requests
 #[tokio::test]
 async fn test_route_fulfill_fetch_still_works() {
     let server = TestServer::start().await;
@@ -525,8 +474,6 @@ async fn test_route_fulfill_fetch_still_works() {
         "Fetch status code is correctly fulfilled"
     );
 
-    // KNOWN ISSUE: Body content is NOT transmitted for fetch either
-    // The response will have status 200 but empty body
     let fetch_body = page
         .evaluate_value(
             r#"
@@ -537,10 +484,12 @@ async fn test_route_fulfill_fetch_still_works() {
         .await
         .expect("Failed to get fetch body");
 
-    // We expect empty body due to Playwright limitation
+    let fetch_body: serde_json::Value =
+        serde_json::from_str(&fetch_body).expect("Fetch body should be JSON");
     assert_eq!(
-        fetch_body, "",
-        "REVERSE CANARY: Fetch body is empty due to Playwright limitation"
+        fetch_body,
+        serde_json::json!({"status": "ok", "mocked": true}),
+        "Fetch body is the fulfilled JSON"
     );
 
     browser.close().await.expect("Failed to close browser");
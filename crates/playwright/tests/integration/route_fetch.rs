@@ -128,3 +128,74 @@ async fn test_route_fetch_with_context_route() {
     browser.close().await.expect("Failed to close browser");
     server.shutdown();
 }
+
+#[tokio::test]
+async fn test_route_fetch_and_modify_json() {
+    let server = TestServer::start().await;
+    let (_pw, browser, page) = crate::common::setup().await;
+
+    page.route("**/api/data.json", |route| async move {
+        route
+            .fetch_and_modify(|response| {
+                response.merge_json(&serde_json::json!({ "message": null, "mocked": true }))?;
+                response.set_header("x-modified", "yes");
+                Ok(())
+            })
+            .await
+    })
+    .await
+    .expect("Failed to set up route");
+
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+
+    let result = page
+        .evaluate_value(
+            "fetch('/api/data.json').then(async r => \
+             r.headers.get('x-modified') + ' ' + await r.text())",
+        )
+        .await
+        .expect("Failed to fetch");
+    assert_eq!(result, r#"yes {"status":"ok","mocked":true}"#);
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn test_route_continue_with_json() {
+    let server = TestServer::start().await;
+    let (_pw, browser, page) = crate::common::setup().await;
+
+    page.route("**/api/echo", |route| async move {
+        let mut body: serde_json::Value = route
+            .request()
+            .post_data_json()
+            .expect("request has a body")?;
+        body["role"] = serde_json::json!("admin");
+        route.continue_with_json(&body).await
+    })
+    .await
+    .expect("Failed to set up route");
+
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("Failed to navigate");
+
+    let echoed = page
+        .evaluate_value(
+            "fetch('/api/echo', { method: 'POST', body: JSON.stringify({ name: 'ada' }) }) \
+             .then(r => r.text())",
+        )
+        .await
+        .expect("Failed to post");
+    let echoed: serde_json::Value = serde_json::from_str(&echoed).expect("echo is JSON");
+    assert_eq!(
+        echoed,
+        serde_json::json!({ "name": "ada", "role": "admin" })
+    );
+
+    browser.close().await.expect("Failed to close browser");
+    server.shutdown();
+}
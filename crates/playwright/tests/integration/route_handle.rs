@@ -0,0 +1,103 @@
+use crate::test_server::TestServer;
+use playwright_rs::RouteOptions;
+
+const FETCH_OUTCOME: &str = "fetch('/api/data.json').then(() => 'loaded', () => 'aborted')";
+
+#[tokio::test]
+async fn route_with_times_stops_after_the_given_requests() {
+    let server = TestServer::start().await;
+    let (_pw, browser, page) = crate::common::setup().await;
+
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+
+    let handle = page
+        .route_with_options(
+            "**/api/data.json",
+            |route| async move { route.abort(None).await },
+            RouteOptions::default().times(1),
+        )
+        .await
+        .expect("route");
+    assert!(handle.is_active());
+
+    let first = page.evaluate_value(FETCH_OUTCOME).await.expect("fetch");
+    assert_eq!(first, "aborted");
+    let second = page.evaluate_value(FETCH_OUTCOME).await.expect("fetch");
+    assert_eq!(second, "loaded");
+
+    assert_eq!(handle.hits(), 1);
+    assert!(!handle.is_active());
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn route_handle_unroute_removes_only_its_handler() {
+    let server = TestServer::start().await;
+    let (_pw, browser, page) = crate::common::setup().await;
+
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+
+    let outer = page
+        .route("**/api/data.json", |route| async move {
+            route.abort(None).await
+        })
+        .await
+        .expect("route outer");
+    let inner = page
+        .route("**/api/data.json", |route| async move {
+            route.fallback(None).await
+        })
+        .await
+        .expect("route inner");
+
+    let outcome = page.evaluate_value(FETCH_OUTCOME).await.expect("fetch");
+    assert_eq!(outcome, "aborted");
+    assert_eq!((inner.hits(), outer.hits()), (1, 1));
+
+    outer.unroute().await.expect("unroute outer");
+    assert!(!outer.is_active());
+    assert!(inner.is_active());
+
+    let outcome = page.evaluate_value(FETCH_OUTCOME).await.expect("fetch");
+    assert_eq!(outcome, "loaded");
+    assert_eq!((inner.hits(), outer.hits()), (2, 1));
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn context_route_with_times_counts_hits() {
+    let server = TestServer::start().await;
+    let (_pw, browser, page) = crate::common::setup().await;
+    let context = page.context().expect("context");
+
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+
+    let handle = context
+        .route_with_options(
+            "**/api/data.json",
+            |route| async move { route.abort(None).await },
+            RouteOptions::default().times(2),
+        )
+        .await
+        .expect("route");
+
+    for expected in ["aborted", "aborted", "loaded"] {
+        let outcome = page.evaluate_value(FETCH_OUTCOME).await.expect("fetch");
+        assert_eq!(outcome, expected);
+    }
+    assert_eq!(handle.hits(), 2);
+    assert!(!handle.is_active());
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
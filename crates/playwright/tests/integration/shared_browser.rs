@@ -0,0 +1,82 @@
+// Tests for testing::SharedBrowser — one browser reused across tests and
+// runtimes, with a fresh context per test.
+//
+// Each #[tokio::test] has its own runtime; a Browser launched on one of
+// them could not be used from another, so these also check that the
+// shared browser really lives on neither.
+
+use playwright_rs::testing::SharedBrowser;
+
+static BROWSER: SharedBrowser = SharedBrowser::chromium();
+
+/// Two calls see the same browser process: a context left open by the
+/// first is visible to the second.
+#[tokio::test]
+async fn test_shared_browser_is_reused_across_calls() -> playwright_rs::Result<()> {
+    crate::common::init_tracing();
+
+    let url = BROWSER
+        .with_browser(|_, browser| async move {
+            let context = browser.new_context().await?;
+            let page = context.new_page().await?;
+            page.goto("about:blank#left-open", None).await?;
+            Ok::<_, playwright_rs::Error>(page.url())
+        })
+        .await??;
+
+    BROWSER
+        .with_browser(move |_, browser| async move {
+            let context = browser
+                .contexts()
+                .into_iter()
+                .find(|c| c.pages().iter().any(|p| p.url() == url))
+                .expect("the context from the first call is on the same browser");
+            context.close().await
+        })
+        .await?
+}
+
+/// Each run gets its own context, closed afterwards, even though the
+/// browser is shared.
+#[tokio::test]
+async fn test_shared_browser_runs_get_fresh_contexts() -> playwright_rs::Result<()> {
+    crate::common::init_tracing();
+
+    BROWSER
+        .run(|fx| async move {
+            fx.page.set_content("<p>first</p>", None).await?;
+            fx.context.add_init_script("window.__first = true").await?;
+            Ok::<_, playwright_rs::Error>(())
+        })
+        .await??;
+
+    BROWSER
+        .run(|fx| async move {
+            assert_eq!(fx.context.pages().len(), 1);
+            fx.page.set_content("<p>second</p>", None).await?;
+            let leaked = fx
+                .page
+                .evaluate::<(), bool>("() => window.__first === true", None)
+                .await?;
+            assert!(!leaked, "init script from the first run leaked");
+            Ok(())
+        })
+        .await?
+}
+
+/// A panic inside the test is re-raised in the calling test.
+#[tokio::test]
+#[should_panic(expected = "shared boom")]
+async fn test_shared_browser_panics_propagate() {
+    crate::common::init_tracing();
+
+    let _ = BROWSER
+        .run(|fx| async move {
+            fx.page
+                .set_content("<p>before the panic</p>", None)
+                .await
+                .expect("set_content");
+            panic!("shared boom");
+        })
+        .await;
+}
@@ -0,0 +1,58 @@
+// Tests for #[playwright_rs::test] — the attribute that hands each test a
+// fresh context and page on the shared browser.
+//
+// The attribute expands to a plain #[test], so these run alongside the
+// #[tokio::test]s in this binary without a runtime of their own.
+
+use playwright_rs::{Browser, BrowserContext, Page};
+
+#[playwright_rs::test]
+async fn test_attribute_without_parameters() {}
+
+#[playwright_rs::test]
+async fn test_attribute_page_belongs_to_context(
+    page: Page,
+    context: BrowserContext,
+) -> playwright_rs::Result<()> {
+    page.set_content("<h1>fixture</h1>", None).await?;
+    assert_eq!(context.pages().len(), 1);
+    assert_eq!(
+        page.locator("h1").text_content().await?.as_deref(),
+        Some("fixture")
+    );
+    Ok(())
+}
+
+#[playwright_rs::test(browser = ["chromium", "firefox", "webkit"])]
+async fn test_attribute_browser_matrix(browser: Browser) {
+    // Each generated test is named after its browser, and the test
+    // harness names the thread after the test.
+    let thread = std::thread::current();
+    let test_name = thread.name().unwrap_or_default();
+    assert!(
+        test_name.ends_with(&format!(
+            "test_attribute_browser_matrix::{}",
+            browser.name()
+        )),
+        "{test_name} ran {}",
+        browser.name()
+    );
+}
+
+#[playwright_rs::test(device = "iPhone 13")]
+async fn test_attribute_device_emulation(page: Page) -> playwright_rs::Result<()> {
+    let width = page
+        .evaluate::<(), u32>("() => window.innerWidth", None)
+        .await?;
+    assert_eq!(width, 390);
+    Ok(())
+}
+
+#[playwright_rs::test]
+#[should_panic(expected = "boom")]
+async fn test_attribute_panics_propagate(page: Page) {
+    page.set_content("<p>before the panic</p>", None)
+        .await
+        .expect("set_content");
+    panic!("boom");
+}
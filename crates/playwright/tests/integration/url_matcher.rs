@@ -0,0 +1,136 @@
+use crate::test_server::TestServer;
+use playwright_rs::{FulfillOptions, UrlMatcher};
+use regex::Regex;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[tokio::test]
+async fn route_with_regex_intercepts_only_matching_urls() {
+    let server = TestServer::start().await;
+    let (_pw, browser, page) = crate::common::setup().await;
+
+    page.route(
+        Regex::new(r"/api/\w+\.json$").unwrap(),
+        |route| async move {
+            route
+                .fulfill(Some(
+                    FulfillOptions::builder()
+                        .body_string(r#"{"mocked":true}"#)
+                        .content_type("application/json")
+                        .build(),
+                ))
+                .await
+        },
+    )
+    .await
+    .expect("route");
+
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+    let body = page
+        .evaluate_value("fetch('/api/data.json').then(r => r.text())")
+        .await
+        .expect("fetch");
+    assert_eq!(body, r#"{"mocked":true}"#);
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn route_with_predicate_lets_unmatched_requests_through() {
+    let server = TestServer::start().await;
+    let (_pw, browser, page) = crate::common::setup().await;
+
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = hits.clone();
+    page.route(
+        UrlMatcher::predicate(|url| url.path() == "/api/data.json"),
+        move |route| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async move { route.abort(None).await }
+        },
+    )
+    .await
+    .expect("route");
+
+    // The predicate widens the server's filter to every URL; the page itself
+    // must still load.
+    let response = page
+        .goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate")
+        .expect("response");
+    assert_eq!(response.status(), 200);
+
+    let outcome = page
+        .evaluate_value("fetch('/api/data.json').then(() => 'loaded', () => 'aborted')")
+        .await
+        .expect("fetch");
+    assert_eq!(outcome, "aborted");
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn unroute_removes_a_regex_route_by_source() {
+    let server = TestServer::start().await;
+    let (_pw, browser, page) = crate::common::setup().await;
+
+    page.route(Regex::new(r"button\.html").unwrap(), |route| async move {
+        route.abort(None).await
+    })
+    .await
+    .expect("route");
+    page.unroute(Regex::new(r"button\.html").unwrap())
+        .await
+        .expect("unroute");
+
+    let response = page
+        .goto(&format!("{}/button.html", server.url()), None)
+        .await
+        .expect("navigate")
+        .expect("response");
+    assert_eq!(response.status(), 200);
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}
+
+#[tokio::test]
+async fn wait_for_url_and_expect_request_accept_matchers() {
+    let server = TestServer::start().await;
+    let (_pw, browser, page) = crate::common::setup().await;
+
+    page.goto(&format!("{}/", server.url()), None)
+        .await
+        .expect("navigate");
+
+    let waiter = page
+        .expect_request_matching("**/api/data.json", Some(5000.0))
+        .await
+        .expect("waiter");
+    page.evaluate_expression("fetch('/favicon.ico').catch(() => {}); fetch('/api/data.json')")
+        .await
+        .expect("fetch");
+    let request = waiter.wait().await.expect("request");
+    assert!(request.url().ends_with("/api/data.json"));
+
+    page.evaluate_expression("setTimeout(() => location.href = '/button.html', 50)")
+        .await
+        .expect("schedule navigation");
+    page.wait_for_url(Regex::new(r"/button\.html$").unwrap(), None)
+        .await
+        .expect("wait_for_url with regex");
+
+    let pattern = UrlMatcher::url_pattern(&format!("{}/:page.html", server.url())).unwrap();
+    page.wait_for_url(pattern, None)
+        .await
+        .expect("wait_for_url with URLPattern");
+
+    browser.close().await.expect("close");
+    server.shutdown();
+}